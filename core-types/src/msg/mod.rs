@@ -1 +1 @@
-pub use ibc_proto::cosmos::bank::v1beta1::MsgSend;
+pub use ibc_proto::cosmos::bank::v1beta1::{Input, MsgMultiSend, MsgSend, Output};
@@ -111,6 +111,54 @@ pub struct CompactBitArray {
     pub elems: Vec<u8>,
 }
 
+impl CompactBitArray {
+    /// Creates a bit array of the given size with all bits unset
+    pub fn new(bits: usize) -> Self {
+        Self {
+            extra_bits_stored: (bits % 8) as u32,
+            elems: vec![0; bits.div_ceil(8)],
+        }
+    }
+
+    /// Returns the number of bits in the bit array
+    pub fn count(&self) -> usize {
+        match self.extra_bits_stored {
+            0 => self.elems.len() * 8,
+            extra => self.elems.len().saturating_sub(1) * 8 + extra as usize,
+        }
+    }
+
+    /// Returns true if the bit at `index` is set. Indexes out of range are never set.
+    pub fn get_index(&self, index: usize) -> bool {
+        match self.elems.get(index >> 3) {
+            Some(elem) if index < self.count() => elem & (1 << (7 - (index % 8))) > 0,
+            _ => false,
+        }
+    }
+
+    /// Sets the bit at `index`, returns false if the index is out of range
+    pub fn set_index(&mut self, index: usize, value: bool) -> bool {
+        if index >= self.count() {
+            return false;
+        }
+
+        match self.elems.get_mut(index >> 3) {
+            Some(elem) if value => *elem |= 1 << (7 - (index % 8)),
+            Some(elem) => *elem &= !(1 << (7 - (index % 8))),
+            None => return false,
+        }
+
+        true
+    }
+
+    /// Returns the number of bits set before `index`
+    pub fn num_true_bits_before(&self, index: usize) -> usize {
+        (0..index.min(self.count()))
+            .filter(|i| self.get_index(*i))
+            .count()
+    }
+}
+
 impl From<RawCompactBitArray> for CompactBitArray {
     fn from(value: RawCompactBitArray) -> Self {
         let RawCompactBitArray {
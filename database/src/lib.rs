@@ -1,7 +1,9 @@
 #![warn(rust_2018_idioms)]
 
+mod batch;
 pub mod error;
 mod memory;
+mod overlay;
 pub mod prefix;
 #[cfg(feature = "rocksdb")]
 pub mod rocks;
@@ -10,7 +12,9 @@ pub mod sled;
 
 use std::fmt::Debug;
 
+pub use batch::*;
 pub use memory::*;
+pub use overlay::*;
 
 /// Default builder which implements(if enable) builds for all db's
 #[derive(Debug, Clone, Default)]
@@ -21,6 +25,11 @@ pub trait Database: Clone + Send + Sync + 'static {
 
     fn put(&self, key: Vec<u8>, value: Vec<u8>);
 
+    fn delete(&self, key: &[u8]);
+
+    /// Applies all operations of the batch atomically
+    fn write(&self, batch: WriteBatch);
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a>;
 
     fn prefix_iterator<'a>(
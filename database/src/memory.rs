@@ -4,7 +4,7 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use crate::Database;
+use crate::{BatchOp, Database, WriteBatch};
 
 #[derive(Debug, Clone)]
 pub struct MemDB {
@@ -37,6 +37,25 @@ impl Database for MemDB {
             .insert(key, value);
     }
 
+    fn delete(&self, key: &[u8]) {
+        self.store.write().expect("poisoned lock").remove(key);
+    }
+
+    fn write(&self, batch: WriteBatch) {
+        let mut store = self.store.write().expect("poisoned lock");
+
+        for op in batch {
+            match op {
+                BatchOp::Put { key, value } => {
+                    store.insert(key, value);
+                }
+                BatchOp::Delete { key } => {
+                    store.remove(&key);
+                }
+            }
+        }
+    }
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         Box::new(
             self.store
@@ -76,7 +95,7 @@ impl Database for MemDB {
 ///
 /// That is the smallest x such that, prefix + y < x for all y. If
 /// no such x exists (i.e. prefix = vec![255; N]; for some N) it returns Bound::Unbounded
-fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
+pub(crate) fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
     loop {
         let last = prefix.last_mut();
 
@@ -114,6 +133,34 @@ mod tests {
         assert!(got_pairs.iter().all(|e| { expected_pairs.contains(e) }));
     }
 
+    #[test]
+    fn delete_works() {
+        let db = MemDB::new();
+        db.put(vec![1], vec![1]);
+        db.put(vec![2], vec![2]);
+        db.delete(&[1]);
+
+        assert!(db.get(&[1]).is_none());
+        assert_eq!(db.get(&[2]), Some(vec![2]));
+    }
+
+    #[test]
+    fn write_works() {
+        let db = MemDB::new();
+        db.put(vec![1], vec![1]);
+
+        let mut batch = WriteBatch::new();
+        batch.put(vec![2], vec![2]);
+        batch.delete(vec![1]);
+        batch.put(vec![3], vec![3]);
+        batch.delete(vec![3]);
+        db.write(batch);
+
+        assert!(db.get(&[1]).is_none());
+        assert_eq!(db.get(&[2]), Some(vec![2]));
+        assert!(db.get(&[3]).is_none());
+    }
+
     #[test]
     fn prefix_iterator_works() {
         let db = MemDB::new();
@@ -0,0 +1,214 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    iter::Peekable,
+    ops::Bound,
+    sync::{Arc, RwLock},
+};
+
+use crate::{BatchOp, Database, WriteBatch};
+
+type Pair = (Box<[u8]>, Box<[u8]>);
+
+/// Database which keeps all writes in memory on top of another database. The underlying
+/// database is only read, so changes can be made to a state which must not be modified.
+#[derive(Debug, Clone)]
+pub struct OverlayDB<DB> {
+    base: DB,
+    /// `None` marks a deleted key
+    changes: Arc<RwLock<BTreeMap<Vec<u8>, Option<Vec<u8>>>>>,
+}
+
+impl<DB> OverlayDB<DB> {
+    pub fn new(base: DB) -> Self {
+        Self {
+            base,
+            changes: Arc::new(RwLock::new(BTreeMap::new())),
+        }
+    }
+}
+
+impl<DB: Database> Database for OverlayDB<DB> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.changes.read().expect("poisoned lock").get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    fn put(&self, key: Vec<u8>, value: Vec<u8>) {
+        self.changes
+            .write()
+            .expect("poisoned lock")
+            .insert(key, Some(value));
+    }
+
+    fn delete(&self, key: &[u8]) {
+        self.changes
+            .write()
+            .expect("poisoned lock")
+            .insert(key.to_vec(), None);
+    }
+
+    fn write(&self, batch: WriteBatch) {
+        let mut changes = self.changes.write().expect("poisoned lock");
+
+        for op in batch {
+            match op {
+                BatchOp::Put { key, value } => {
+                    changes.insert(key, Some(value));
+                }
+                BatchOp::Delete { key } => {
+                    changes.insert(key, None);
+                }
+            }
+        }
+    }
+
+    fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = Pair> + 'a> {
+        let changes = self
+            .changes
+            .read()
+            .expect("poisoned lock")
+            .clone()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        Box::new(Merge {
+            base: self.base.iterator().peekable(),
+            changes: changes.into_iter().peekable(),
+        })
+    }
+
+    fn prefix_iterator<'a>(&'a self, prefix: Vec<u8>) -> Box<dyn Iterator<Item = Pair> + 'a> {
+        let changes = self
+            .changes
+            .read()
+            .expect("poisoned lock")
+            .range((
+                Bound::Included(prefix.clone()),
+                crate::memory::prefix_end_bound(prefix.clone()),
+            ))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<Vec<_>>();
+
+        Box::new(Merge {
+            base: self.base.prefix_iterator(prefix).peekable(),
+            changes: changes.into_iter().peekable(),
+        })
+    }
+}
+
+/// Merges the sorted pairs of the underlying database with the sorted changes on top of it
+struct Merge<B: Iterator<Item = Pair>, C: Iterator<Item = (Vec<u8>, Option<Vec<u8>>)>> {
+    base: Peekable<B>,
+    changes: Peekable<C>,
+}
+
+impl<B, C> Iterator for Merge<B, C>
+where
+    B: Iterator<Item = Pair>,
+    C: Iterator<Item = (Vec<u8>, Option<Vec<u8>>)>,
+{
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let order = match (self.base.peek(), self.changes.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((base_key, _)), Some((change_key, _))) => {
+                    base_key.as_ref().cmp(change_key.as_slice())
+                }
+            };
+
+            match order {
+                Ordering::Less => return self.base.next(),
+                // the change replaces the value of the underlying database
+                Ordering::Equal => {
+                    self.base.next();
+                }
+                Ordering::Greater => (),
+            }
+
+            match self.changes.next() {
+                Some((key, Some(value))) => {
+                    return Some((key.into_boxed_slice(), value.into_boxed_slice()))
+                }
+                // deleted key
+                _ => continue,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemDB;
+
+    fn overlay() -> OverlayDB<MemDB> {
+        let base = MemDB::new();
+        base.put(vec![1, 1], vec![1]);
+        base.put(vec![2, 1], vec![2]);
+        base.put(vec![2, 2], vec![3]);
+        base.put(vec![3, 1], vec![4]);
+
+        OverlayDB::new(base)
+    }
+
+    fn pairs(pairs: &[(&[u8], &[u8])]) -> Vec<Pair> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_vec().into(), value.to_vec().into()))
+            .collect()
+    }
+
+    #[test]
+    fn writes_are_not_applied_to_base() {
+        let db = overlay();
+        db.put(vec![1, 1], vec![10]);
+        db.delete(&[2, 1]);
+
+        assert_eq!(db.get(&[1, 1]), Some(vec![10]));
+        assert!(db.get(&[2, 1]).is_none());
+        assert_eq!(db.base.get(&[1, 1]), Some(vec![1]));
+        assert_eq!(db.base.get(&[2, 1]), Some(vec![2]));
+    }
+
+    #[test]
+    fn iterator_merges_changes() {
+        let db = overlay();
+        let mut batch = WriteBatch::new();
+        batch.put(vec![0], vec![0]);
+        batch.put(vec![2, 2], vec![30]);
+        batch.delete(vec![3, 1]);
+        batch.put(vec![4], vec![5]);
+        db.write(batch);
+
+        assert_eq!(
+            db.iterator().collect::<Vec<_>>(),
+            pairs(&[
+                (&[0], &[0]),
+                (&[1, 1], &[1]),
+                (&[2, 1], &[2]),
+                (&[2, 2], &[30]),
+                (&[4], &[5]),
+            ])
+        );
+    }
+
+    #[test]
+    fn prefix_iterator_merges_changes() {
+        let db = overlay();
+        db.delete(&[2, 1]);
+        db.put(vec![2, 3], vec![6]);
+        db.put(vec![3, 2], vec![7]);
+
+        assert_eq!(
+            db.prefix_iterator(vec![2]).collect::<Vec<_>>(),
+            pairs(&[(&[2, 2], &[3]), (&[2, 3], &[6])])
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use crate::Database;
+use crate::{Database, WriteBatch};
 
 #[derive(Debug, Clone)]
 pub struct PrefixDB<T> {
@@ -24,6 +24,15 @@ impl<T: Database> Database for PrefixDB<T> {
         self.db.put(key, value)
     }
 
+    fn delete(&self, key: &[u8]) {
+        let key = [&self.prefix, key].concat();
+        self.db.delete(&key)
+    }
+
+    fn write(&self, batch: WriteBatch) {
+        self.db.write(batch.prefixed(&self.prefix))
+    }
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         let prefix_length = self.prefix.len();
         Box::new(
@@ -77,6 +86,33 @@ mod tests {
         assert_eq!(prefix_db.get(&[2]), Some(vec![1, 2, 3]));
     }
 
+    #[test]
+    fn delete_works() {
+        let db = Arc::new(MemDB::new());
+        db.put(vec![2, 1], vec![2]);
+        db.put(vec![1], vec![1]);
+        let prefix_db = PrefixDB::new(Arc::clone(&db), vec![2]);
+        prefix_db.delete(&[1]);
+
+        assert!(prefix_db.get(&[1]).is_none());
+        assert_eq!(db.get(&[1]), Some(vec![1]));
+    }
+
+    #[test]
+    fn write_works() {
+        let db = Arc::new(MemDB::new());
+        db.put(vec![2, 1], vec![1]);
+        let prefix_db = PrefixDB::new(Arc::clone(&db), vec![2]);
+
+        let mut batch = WriteBatch::new();
+        batch.put(vec![2], vec![2]);
+        batch.delete(vec![1]);
+        prefix_db.write(batch);
+
+        assert!(db.get(&[2, 1]).is_none());
+        assert_eq!(db.get(&[2, 2]), Some(vec![2]));
+    }
+
     #[test]
     fn iterator_works() {
         let db = MemDB::new();
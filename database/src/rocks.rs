@@ -1,4 +1,4 @@
-use crate::{error::DatabaseError, DBBuilder, Database, DatabaseBuilder};
+use crate::{error::DatabaseError, BatchOp, DBBuilder, Database, DatabaseBuilder, WriteBatch};
 use std::{path::Path, sync::Arc};
 
 use rocksdb::{DBWithThreadMode, SingleThreaded};
@@ -42,6 +42,26 @@ impl Database for RocksDB {
             .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
     }
 
+    fn delete(&self, key: &[u8]) {
+        self.db
+            .delete(key)
+            .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
+
+    fn write(&self, batch: WriteBatch) {
+        let mut rocks_batch = rocksdb::WriteBatch::default();
+        for op in batch {
+            match op {
+                BatchOp::Put { key, value } => rocks_batch.put(key, value),
+                BatchOp::Delete { key } => rocks_batch.delete(key),
+            }
+        }
+
+        self.db
+            .write(rocks_batch)
+            .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         Box::new(
             self.db
@@ -1,6 +1,6 @@
 use extensions::corruption::UnwrapCorrupt;
 
-use crate::{error::DatabaseError, DBBuilder, Database, DatabaseBuilder};
+use crate::{error::DatabaseError, BatchOp, DBBuilder, Database, DatabaseBuilder, WriteBatch};
 
 impl DatabaseBuilder<SledDb> for DBBuilder {
     type Err = DatabaseError;
@@ -31,6 +31,22 @@ impl Database for SledDb {
         let _ = self.0.insert(key, value).unwrap_or_corrupt();
     }
 
+    fn delete(&self, key: &[u8]) {
+        let _ = self.0.remove(key).unwrap_or_corrupt();
+    }
+
+    fn write(&self, batch: WriteBatch) {
+        let mut sled_batch = ::sled::Batch::default();
+        for op in batch {
+            match op {
+                BatchOp::Put { key, value } => sled_batch.insert(key, value),
+                BatchOp::Delete { key } => sled_batch.remove(key),
+            }
+        }
+
+        self.0.apply_batch(sled_batch).unwrap_or_corrupt();
+    }
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         Box::new(
             self.0
@@ -1,11 +1,21 @@
+use std::collections::HashMap;
+
 use crate::{
     config::AppConfig,
     genesis::GenesisState,
+    keepers::{
+        GaiaAuthKeeper, GaiaAuthzKeeper, GaiaBankKeeper, GaiaFeeGrantKeeper,
+        GaiaHooklessStakingKeeper, GaiaSlashingKeeper, GaiaStakingHooks, GaiaStakingKeeper,
+    },
     message::Message,
     modules::GaiaModules,
+    params::GaiaProposalHandler,
     store_keys::{GaiaParamsStoreKey, GaiaStoreKey},
+    upgrades::{self, GaiaUpgradeHandler},
     GaiaNodeQueryRequest, GaiaNodeQueryResponse,
 };
+use evidence::RawEquivocation;
+use gears::baseapp::mode::RunTxMode;
 use gears::store::database::Database;
 use gears::tendermint::types::request::query::RequestQuery;
 use gears::types::tx::raw::TxWithRaw;
@@ -37,12 +47,58 @@ impl ModuleInfo for StakingModuleInfo {
     const NAME: &'static str = "staking";
 }
 
+#[derive(Debug, Clone)]
+struct DistributionModuleInfo;
+
+impl ModuleInfo for DistributionModuleInfo {
+    const NAME: &'static str = "distribution";
+}
+
+#[derive(Debug, Clone)]
+struct GovModuleInfo;
+
+impl ModuleInfo for GovModuleInfo {
+    const NAME: &'static str = "gov";
+}
+
+#[derive(Debug, Clone)]
+struct EvidenceModuleInfo;
+
+impl ModuleInfo for EvidenceModuleInfo {
+    const NAME: &'static str = "evidence";
+}
+
+#[derive(Debug, Clone)]
+struct VestingModuleInfo;
+
+impl ModuleInfo for VestingModuleInfo {
+    const NAME: &'static str = "vesting";
+}
+
+#[derive(Debug, Clone)]
+struct FeeGrantModuleInfo;
+
+impl ModuleInfo for FeeGrantModuleInfo {
+    const NAME: &'static str = "feegrant";
+}
+
+#[derive(Debug, Clone)]
+struct AuthzModuleInfo;
+
+impl ModuleInfo for AuthzModuleInfo {
+    const NAME: &'static str = "authz";
+}
+
+/// Slashing keeper of the application. Slashes go through the staking keeper with hooks, so the
+/// distribution module records the slash events.
+type GaiaAppSlashingKeeper = GaiaSlashingKeeper<GaiaStakingKeeper<GaiaStakingHooks>>;
+
 #[derive(Debug, Clone)]
 pub struct GaiaABCIHandler {
     bank_abci_handler: bank::BankABCIHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+        GaiaAuthKeeper,
         GaiaModules,
         BankModuleInfo,
     >,
@@ -50,30 +106,59 @@ pub struct GaiaABCIHandler {
     staking_abci_handler: staking::StakingABCIHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        staking::MockHookKeeper<
-            GaiaStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
+        GaiaAuthKeeper,
+        GaiaBankKeeper,
+        GaiaStakingHooks,
         GaiaModules,
         StakingModuleInfo,
     >,
+    distribution_abci_handler: distribution::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaAuthKeeper,
+        GaiaBankKeeper,
+        GaiaStakingKeeper<GaiaStakingHooks>,
+        GaiaModules,
+    >,
+    slashing_abci_handler: slashing::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaStakingKeeper<GaiaStakingHooks>,
+        GaiaModules,
+    >,
+    gov_abci_handler: gov::abci_handler::GovAbciHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaModules,
+        GaiaBankKeeper,
+        GaiaStakingKeeper<GaiaStakingHooks>,
+        GaiaProposalHandler,
+        GovModuleInfo,
+    >,
+    evidence_abci_handler: evidence::ABCIHandler<
+        GaiaStoreKey,
+        GaiaStakingKeeper<GaiaStakingHooks>,
+        GaiaAppSlashingKeeper,
+        RawEquivocation,
+        GaiaModules,
+    >,
+    mint_abci_handler: mint::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaBankKeeper,
+        GaiaStakingKeeper<GaiaStakingHooks>,
+        GaiaModules,
+    >,
+    upgrade_abci_handler: upgrade::ABCIHandler<GaiaStoreKey, GaiaUpgradeHandler>,
+    vesting_abci_handler:
+        vesting::ABCIHandler<GaiaStoreKey, GaiaAuthKeeper, GaiaBankKeeper, GaiaModules>,
+    feegrant_abci_handler: feegrant::ABCIHandler<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>,
+    authz_abci_handler: authz::ABCIHandler<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>,
     ibc_abci_handler: ibc_rs::ABCIHandler<GaiaStoreKey, GaiaParamsStoreKey, IbcModuleInfo>,
     ante_handler: BaseAnteHandler<
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+        GaiaBankKeeper,
+        GaiaAuthKeeper,
+        GaiaFeeGrantKeeper,
         GaiaStoreKey,
         DefaultSignGasConsumer,
         GaiaModules,
@@ -81,18 +166,10 @@ pub struct GaiaABCIHandler {
     genutil_handler: GenutilAbciHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        staking::MockHookKeeper<
-            GaiaStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
+        GaiaAuthKeeper,
+        GaiaBankKeeper,
+        GaiaStakingHooks,
+        GaiaFeeGrantKeeper,
         GaiaModules,
         DefaultSignGasConsumer,
     >,
@@ -106,10 +183,48 @@ impl GaiaABCIHandler {
             GaiaModules::FeeCollector,
         );
 
+        // As in the cosmos SDK, the distribution module account is allowed to receive funds
         let bank_keeper = bank::Keeper::new(
             GaiaStoreKey::Bank,
             GaiaParamsStoreKey::Bank,
             auth_keeper.clone(),
+        )
+        .with_allowed_modules([GaiaModules::Distribution]);
+
+        let blocked_addrs = bank_keeper
+            .blocked_addrs()
+            .iter()
+            .map(|addr| (addr.to_string(), true))
+            .collect::<HashMap<_, _>>();
+
+        // The keepers called from the staking hooks use the staking keeper without hooks.
+        let hookless_staking_keeper: GaiaHooklessStakingKeeper = staking::Keeper::new(
+            GaiaStoreKey::Staking,
+            GaiaParamsStoreKey::Staking,
+            auth_keeper.clone(),
+            bank_keeper.clone(),
+            // The compiler require type for option `None`
+            None::<staking::MockHookKeeper<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>>,
+            GaiaModules::BondedPool,
+            GaiaModules::NotBondedPool,
+        );
+
+        let staking_hooks: GaiaStakingHooks = (
+            distribution::Keeper::new(
+                GaiaStoreKey::Distribution,
+                GaiaParamsStoreKey::Distribution,
+                auth_keeper.clone(),
+                bank_keeper.clone(),
+                hookless_staking_keeper.clone(),
+                GaiaModules::FeeCollector,
+                GaiaModules::Distribution,
+                blocked_addrs.clone(),
+            ),
+            slashing::Keeper::new(
+                GaiaStoreKey::Slashing,
+                GaiaParamsStoreKey::Slashing,
+                hookless_staking_keeper,
+            ),
         );
 
         let staking_keeper = staking::Keeper::new(
@@ -117,24 +232,72 @@ impl GaiaABCIHandler {
             GaiaParamsStoreKey::Staking,
             auth_keeper.clone(),
             bank_keeper.clone(),
-            // NOTE: The variant with instance should have less performance.
-            // Some(staking::MockHookKeeper::new()),
-            // The compiler require type for option `None`
-            None::<
-                staking::MockHookKeeper<
-                    GaiaStoreKey,
-                    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-                    GaiaModules,
-                >,
-            >,
+            Some(staking_hooks),
             GaiaModules::BondedPool,
             GaiaModules::NotBondedPool,
         );
 
+        let distribution_keeper = distribution::Keeper::new(
+            GaiaStoreKey::Distribution,
+            GaiaParamsStoreKey::Distribution,
+            auth_keeper.clone(),
+            bank_keeper.clone(),
+            staking_keeper.clone(),
+            GaiaModules::FeeCollector,
+            GaiaModules::Distribution,
+            blocked_addrs,
+        );
+
+        let slashing_keeper: GaiaAppSlashingKeeper = slashing::Keeper::new(
+            GaiaStoreKey::Slashing,
+            GaiaParamsStoreKey::Slashing,
+            staking_keeper.clone(),
+        );
+
+        let upgrade_keeper = upgrade::Keeper::new(
+            GaiaStoreKey::Upgrade,
+            upgrades::module_versions(),
+            HashMap::new(),
+        );
+
+        let gov_keeper = gov::keeper::GovKeeper::new(
+            GaiaStoreKey::Gov,
+            GaiaParamsStoreKey::Gov,
+            GaiaModules::Gov,
+            bank_keeper.clone(),
+            staking_keeper.clone(),
+            GaiaProposalHandler::new(upgrade_keeper.clone(), distribution_keeper.clone()),
+        );
+
+        let evidence_keeper = evidence::Keeper::new(
+            GaiaStoreKey::Evidence,
+            staking_keeper.clone(),
+            slashing_keeper.clone(),
+            None::<RawEquivocation>,
+        );
+
+        let mint_keeper = mint::Keeper::new(
+            GaiaStoreKey::Mint,
+            GaiaParamsStoreKey::Mint,
+            bank_keeper.clone(),
+            staking_keeper.clone(),
+            GaiaModules::Mint,
+            GaiaModules::FeeCollector,
+        );
+
+        let vesting_keeper = vesting::Keeper::new(auth_keeper.clone(), bank_keeper.clone());
+
+        let feegrant_keeper: GaiaFeeGrantKeeper =
+            feegrant::Keeper::new(GaiaStoreKey::FeeGrant, auth_keeper.clone());
+
+        let authz_keeper: GaiaAuthzKeeper =
+            authz::Keeper::new(GaiaStoreKey::Authz, auth_keeper.clone());
+
         let ibc_keeper = ibc_rs::keeper::Keeper::new(GaiaStoreKey::IBC, GaiaParamsStoreKey::IBC);
         let ante_handler = BaseAnteHandler::new(
             auth_keeper.clone(),
             bank_keeper.clone(),
+            Some(feegrant_keeper.clone()),
             DefaultSignGasConsumer,
             GaiaModules::FeeCollector,
         );
@@ -144,6 +307,15 @@ impl GaiaABCIHandler {
             auth_abci_handler: auth::AuthABCIHandler::new(auth_keeper),
             genutil_handler: GenutilAbciHandler::new(staking_keeper.clone(), ante_handler.clone()),
             staking_abci_handler: staking::StakingABCIHandler::new(staking_keeper),
+            distribution_abci_handler: distribution::ABCIHandler::new(distribution_keeper),
+            slashing_abci_handler: slashing::ABCIHandler::new(slashing_keeper),
+            gov_abci_handler: gov::abci_handler::GovAbciHandler::new(gov_keeper),
+            evidence_abci_handler: evidence::ABCIHandler::new(evidence_keeper),
+            mint_abci_handler: mint::ABCIHandler::new(mint_keeper),
+            upgrade_abci_handler: upgrade::ABCIHandler::new(upgrade_keeper),
+            vesting_abci_handler: vesting::ABCIHandler::new(vesting_keeper),
+            feegrant_abci_handler: feegrant::ABCIHandler::new(feegrant_keeper),
+            authz_abci_handler: authz::ABCIHandler::new(authz_keeper),
             ibc_abci_handler: ibc_rs::ABCIHandler::new(ibc_keeper.clone()),
             ante_handler,
         }
@@ -166,6 +338,29 @@ impl ABCIHandler for GaiaABCIHandler {
             Message::Bank(msg) => self.bank_abci_handler.msg(ctx, msg),
             Message::Staking(msg) => self.staking_abci_handler.msg(ctx, msg),
             Message::IBC(msg) => self.ibc_abci_handler.msg(ctx, msg.clone()),
+            Message::Distribution(msg) => self
+                .distribution_abci_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<DistributionModuleInfo>()),
+            Message::Slashing(msg) => Ok(self.slashing_abci_handler.tx(ctx, msg)?),
+            Message::Gov(msg) => self.gov_abci_handler.msg(ctx, msg),
+            Message::Evidence(msg) => self
+                .evidence_abci_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<EvidenceModuleInfo>()),
+            Message::Vesting(msg) => self
+                .vesting_abci_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<VestingModuleInfo>()),
+            Message::FeeGrant(msg) => self
+                .feegrant_abci_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<FeeGrantModuleInfo>()),
+            // the messages of a `MsgExec` are routed back through `msg` with the granter as signer
+            Message::Authz(msg) => self
+                .authz_abci_handler
+                .tx(ctx, msg, |ctx, msg: &Message| self.msg(ctx, msg))
+                .map_err(|e| e.into::<AuthzModuleInfo>()),
         }
     }
 
@@ -174,6 +369,12 @@ impl ABCIHandler for GaiaABCIHandler {
         ctx: &mut gears::context::block::BlockContext<'_, DB, Self::StoreKey>,
         request: gears::tendermint::request::RequestBeginBlock,
     ) {
+        self.upgrade_abci_handler.begin_block(ctx);
+        self.mint_abci_handler.begin_block(ctx);
+        self.distribution_abci_handler
+            .begin_block(ctx, request.clone());
+        self.slashing_abci_handler.begin_block(ctx, request.clone());
+        self.evidence_abci_handler.begin_block(ctx, request.clone());
         self.staking_abci_handler.begin_block(ctx, request);
     }
 
@@ -182,6 +383,9 @@ impl ABCIHandler for GaiaABCIHandler {
         ctx: &mut gears::context::block::BlockContext<'_, DB, Self::StoreKey>,
         request: gears::tendermint::request::RequestEndBlock,
     ) -> Vec<gears::tendermint::types::proto::validator::ValidatorUpdate> {
+        self.gov_abci_handler.end_block(ctx, request.clone());
+        self.feegrant_abci_handler.end_block(ctx);
+        self.authz_abci_handler.end_block(ctx);
         self.staking_abci_handler.end_block(ctx, request)
     }
 
@@ -191,9 +395,18 @@ impl ABCIHandler for GaiaABCIHandler {
         genesis: GenesisState,
     ) -> Vec<gears::tendermint::types::proto::validator::ValidatorUpdate> {
         self.bank_abci_handler.init_genesis(ctx, genesis.bank);
+        self.distribution_abci_handler
+            .genesis(ctx, genesis.distribution);
         let staking_updates = self.staking_abci_handler.genesis(ctx, genesis.staking);
+        self.slashing_abci_handler.genesis(ctx, genesis.slashing);
+        self.gov_abci_handler.init_genesis(ctx, genesis.gov);
+        self.mint_abci_handler.genesis(ctx, genesis.mint);
         self.ibc_abci_handler.genesis(ctx, genesis.ibc);
         self.auth_abci_handler.init_genesis(ctx, genesis.auth);
+        self.evidence_abci_handler.genesis(ctx, genesis.evidence);
+        self.feegrant_abci_handler.genesis(ctx, genesis.feegrant);
+        self.authz_abci_handler.genesis(ctx, genesis.authz);
+        self.upgrade_abci_handler.init_genesis(ctx);
         let genutil_updates = self.genutil_handler.init_genesis(ctx, genesis.genutil);
 
         match (genutil_updates.is_empty(), staking_updates.is_empty()) {
@@ -206,17 +419,63 @@ impl ABCIHandler for GaiaABCIHandler {
         }
     }
 
+    fn prepare_for_zero_height<DB: Database>(&self, ctx: &mut InitContext<'_, DB, GaiaStoreKey>) {
+        self.distribution_abci_handler.prepare_for_zero_height(ctx);
+    }
+
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, GaiaStoreKey>,
+        for_zero_height: bool,
+    ) -> GenesisState {
+        GenesisState {
+            bank: self.bank_abci_handler.export_genesis(ctx, for_zero_height),
+            auth: self.auth_abci_handler.export_genesis(ctx, for_zero_height),
+            staking: self
+                .staking_abci_handler
+                .export_genesis(ctx, for_zero_height),
+            ibc: self.ibc_abci_handler.export_genesis(ctx),
+            genutil: self.genutil_handler.export_genesis(ctx, for_zero_height),
+            distribution: self.distribution_abci_handler.export_genesis(ctx),
+            slashing: self
+                .slashing_abci_handler
+                .export_genesis(ctx, for_zero_height),
+            gov: self.gov_abci_handler.export_genesis(ctx, for_zero_height),
+            evidence: self.evidence_abci_handler.export_genesis(ctx),
+            mint: self.mint_abci_handler.export_genesis(ctx),
+            feegrant: self.feegrant_abci_handler.export_genesis(ctx),
+            authz: self.authz_abci_handler.export_genesis(ctx),
+        }
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, GaiaStoreKey>,
         query: RequestQuery,
     ) -> Result<Vec<u8>, QueryError> {
-        if query.path.starts_with("/cosmos.auth") {
+        // authz goes first as its path starts with the auth prefix
+        if query.path.starts_with("/cosmos.authz") {
+            self.authz_abci_handler.query(ctx, query).map(Into::into)
+        } else if query.path.starts_with("/cosmos.auth") {
             self.auth_abci_handler.query(ctx, query)
         } else if query.path.starts_with("/cosmos.bank") {
             self.bank_abci_handler.query(ctx, query)
         } else if query.path.starts_with("/cosmos.staking") {
             self.staking_abci_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.distribution") {
+            self.distribution_abci_handler
+                .query(ctx, query)
+                .map(Into::into)
+        } else if query.path.starts_with("/cosmos.slashing") {
+            self.slashing_abci_handler.query(ctx, query).map(Into::into)
+        } else if query.path.starts_with("/cosmos.gov") {
+            self.gov_abci_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.evidence") {
+            self.evidence_abci_handler.query(ctx, query).map(Into::into)
+        } else if query.path.starts_with("/cosmos.mint") {
+            self.mint_abci_handler.query(ctx, query).map(Into::into)
+        } else if query.path.starts_with("/cosmos.feegrant") {
+            self.feegrant_abci_handler.query(ctx, query).map(Into::into)
         } else if query.path.starts_with("/ibc.core.client") {
             self.ibc_abci_handler.query(ctx, query)
         } else {
@@ -228,15 +487,10 @@ impl ABCIHandler for GaiaABCIHandler {
         &self,
         ctx: &mut TxContext<'_, DB, GaiaStoreKey>,
         tx: &TxWithRaw<Message>,
-        is_check: bool,
+        mode: RunTxMode,
     ) -> Result<(), TxError> {
-        self.ante_handler.run(
-            ctx,
-            tx,
-            is_check,
-            ctx.node_opt.clone(),
-            ctx.gas_meter.clone(),
-        )
+        self.ante_handler
+            .run(ctx, tx, mode, ctx.node_opt.clone(), ctx.gas_meter.clone())
     }
 
     fn typed_query<DB: Database + Send + Sync>(
@@ -254,20 +508,27 @@ impl ABCIHandler for GaiaABCIHandler {
             GaiaNodeQueryRequest::Staking(req) => {
                 GaiaNodeQueryResponse::Staking(self.staking_abci_handler.typed_query(ctx, req))
             }
-            // TODO: replace handler
-            GaiaNodeQueryRequest::Slashing(_req) => GaiaNodeQueryResponse::Slashing(
-                slashing::SlashingNodeQueryResponse::Params(slashing::QueryParamsResponse {
-                    params: slashing::SlashingParams::default(),
-                }),
-            ),
-            // TODO: replace handler
-            GaiaNodeQueryRequest::Distribution(_req) => GaiaNodeQueryResponse::Distribution(
-                distribution::DistributionNodeQueryResponse::Params(
-                    distribution::QueryParamsResponse {
-                        params: distribution::DistributionParams::default(),
-                    },
-                ),
+            GaiaNodeQueryRequest::Slashing(req) => {
+                GaiaNodeQueryResponse::Slashing(self.slashing_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::Distribution(req) => GaiaNodeQueryResponse::Distribution(
+                self.distribution_abci_handler.typed_query(ctx, req),
             ),
+            GaiaNodeQueryRequest::Gov(req) => {
+                GaiaNodeQueryResponse::Gov(self.gov_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::Evidence(req) => {
+                GaiaNodeQueryResponse::Evidence(self.evidence_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::Mint(req) => {
+                GaiaNodeQueryResponse::Mint(self.mint_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::FeeGrant(req) => {
+                GaiaNodeQueryResponse::FeeGrant(self.feegrant_abci_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::Authz(req) => {
+                GaiaNodeQueryResponse::Authz(self.authz_abci_handler.typed_query(ctx, req))
+            }
         }
     }
 }
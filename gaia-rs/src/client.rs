@@ -1,23 +1,50 @@
 use anyhow::Result;
 use auth::cli::query::AuthQueryCli;
+use authz::cli::{
+    query::AuthzQueryCli,
+    tx::{run_authz_tx_command, AuthzTxCli},
+};
 use bank::cli::{
     query::BankQueryCli,
     tx::{run_bank_tx_command, BankTxCli},
 };
 use clap::{Args, Subcommand};
+use distribution::cli::{
+    query::DistributionQueryCli,
+    tx::{run_staking_tx_command as run_distribution_tx_command, DistributionTxCli},
+};
+use evidence::cli::{
+    query::EvidenceQueryCli,
+    tx::{run_tx_command as run_evidence_tx_command, EvidenceTxCli},
+};
+use feegrant::cli::{
+    query::FeeGrantQueryCli,
+    tx::{run_feegrant_tx_command, FeeGrantTxCli},
+};
 use gears::{
-    commands::client::tx::ClientTxContext,
-    types::{address::AccAddress, tx::Messages},
+    application::handlers::client::TxHandler, commands::client::tx::ClientTxContext,
+    crypto::public::PublicKey, types::tx::Messages,
+};
+use gov::client::{
+    cli::{query::GovQueryCli, tx::GovTxCli},
+    GovClientHandler,
 };
 use ibc_rs::client::cli::{
     query::IbcQueryCli,
     tx::{run_ibc_tx_command, IbcTxCli},
 };
+use mint::cli::query::MintQueryCli;
+use slashing::cli::{
+    query::SlashingQueryCli,
+    tx::{run_staking_tx_command as run_slashing_tx_command, SlashingTxCli},
+};
 use staking::cli::{
     query::StakingQueryCli,
     tx::{run_staking_tx_command, StakingTxCli},
 };
 
+use vesting::cli::tx::{run_vesting_tx_command, VestingTxCli};
+
 use crate::message::Message;
 
 #[derive(Debug, Clone, Args)]
@@ -34,13 +61,28 @@ pub enum GaiaTxCommands {
     Staking(StakingTxCli),
     /// IBC transaction subcommands
     IBC(IbcTxCli),
+    /// Distribution transaction subcommands
+    Distribution(DistributionTxCli),
+    /// Slashing transaction subcommands
+    Slashing(SlashingTxCli),
+    /// Governance transaction subcommands
+    Gov(GovTxCli),
+    /// Evidence transaction subcommands
+    Evidence(EvidenceTxCli),
+    /// Vesting transaction subcommands
+    Vesting(VestingTxCli),
+    /// Fee grant transaction subcommands
+    FeeGrant(FeeGrantTxCli),
+    /// Authz transaction subcommands
+    Authz(AuthzTxCli),
 }
 
 pub fn tx_command_handler(
-    _ctx: &ClientTxContext,
+    ctx: &mut ClientTxContext,
     command: GaiaTxCommands,
-    from_address: AccAddress,
+    pubkey: PublicKey,
 ) -> Result<Messages<Message>> {
+    let from_address = pubkey.get_address();
     match command {
         GaiaTxCommands::Bank(args) => run_bank_tx_command(args, from_address)
             .map(Message::Bank)
@@ -51,6 +93,26 @@ pub fn tx_command_handler(
         GaiaTxCommands::IBC(args) => run_ibc_tx_command(args, from_address)
             .map(Message::IBC)
             .map(Into::into),
+        GaiaTxCommands::Distribution(args) => run_distribution_tx_command(ctx, args, from_address)
+            .map(|msgs| msgs.map(Message::Distribution)),
+        GaiaTxCommands::Slashing(args) => run_slashing_tx_command(args, from_address)
+            .map(Message::Slashing)
+            .map(Into::into),
+        GaiaTxCommands::Gov(args) => GovClientHandler
+            .prepare_tx(ctx, args, pubkey)
+            .map(|msgs| msgs.map(Message::Gov)),
+        GaiaTxCommands::Evidence(args) => {
+            run_evidence_tx_command(args, from_address).map(|msgs| msgs.map(Message::Evidence))
+        }
+        GaiaTxCommands::Vesting(args) => run_vesting_tx_command(args, from_address)
+            .map(Message::Vesting)
+            .map(Into::into),
+        GaiaTxCommands::FeeGrant(args) => run_feegrant_tx_command(args, from_address)
+            .map(Message::FeeGrant)
+            .map(Into::into),
+        GaiaTxCommands::Authz(args) => run_authz_tx_command(args, from_address)
+            .map(Message::Authz)
+            .map(Into::into),
     }
 }
 
@@ -64,6 +126,20 @@ pub enum GaiaQueryCommands {
     Staking(StakingQueryCli),
     /// Querying commands for the ibc module
     Ibc(IbcQueryCli),
+    /// Querying commands for the distribution module
+    Distribution(DistributionQueryCli),
+    /// Querying commands for the slashing module
+    Slashing(SlashingQueryCli),
+    /// Querying commands for the gov module
+    Gov(GovQueryCli),
+    /// Querying commands for the evidence module
+    Evidence(EvidenceQueryCli),
+    /// Querying commands for the mint module
+    Mint(MintQueryCli),
+    /// Querying commands for the feegrant module
+    FeeGrant(FeeGrantQueryCli),
+    /// Querying commands for the authz module
+    Authz(AuthzQueryCli),
 }
 
 /// Wraps `GaiaTxCommands`. This structure exists to satisfy interface needs of TxHandler
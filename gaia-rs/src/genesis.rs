@@ -1,12 +1,19 @@
 use auth::GenesisState as AuthGenesis;
+use authz::GenesisState as AuthzGenesis;
 use bank::GenesisState as BankGenesis;
+use distribution::GenesisState as DistributionGenesis;
+use evidence::{GenesisState as EvidenceGenesis, RawEquivocation};
+use feegrant::GenesisState as FeeGrantGenesis;
 use gears::{
     baseapp::genesis::GenesisError,
     types::{address::AccAddress, base::coins::UnsignedCoins},
 };
 use genutil::genesis::GenutilGenesis;
+use gov::genesis::GovGenesisState as GovGenesis;
 use ibc_rs::GenesisState as IBCGenesis;
+use mint::GenesisState as MintGenesis;
 use serde::{Deserialize, Serialize};
+use slashing::GenesisState as SlashingGenesis;
 use staking::GenesisState as StakingGenesis;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -17,6 +24,20 @@ pub struct GenesisState {
     pub staking: StakingGenesis,
     pub ibc: IBCGenesis,
     pub genutil: GenutilGenesis,
+    #[serde(default)]
+    pub distribution: DistributionGenesis,
+    #[serde(default)]
+    pub slashing: SlashingGenesis,
+    #[serde(default)]
+    pub gov: GovGenesis,
+    #[serde(default)]
+    pub evidence: EvidenceGenesis<RawEquivocation>,
+    #[serde(default)]
+    pub mint: MintGenesis,
+    #[serde(default)]
+    pub feegrant: FeeGrantGenesis,
+    #[serde(default)]
+    pub authz: AuthzGenesis,
 }
 
 impl gears::baseapp::genesis::Genesis for GenesisState {
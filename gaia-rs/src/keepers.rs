@@ -0,0 +1,56 @@
+use crate::{
+    modules::GaiaModules,
+    store_keys::{GaiaParamsStoreKey, GaiaStoreKey},
+};
+
+pub type GaiaAuthKeeper = auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>;
+
+pub type GaiaBankKeeper =
+    bank::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaAuthKeeper, GaiaModules>;
+
+pub type GaiaFeeGrantKeeper = feegrant::Keeper<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>;
+
+pub type GaiaAuthzKeeper = authz::Keeper<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>;
+
+pub type GaiaStakingKeeper<KH> = staking::Keeper<
+    GaiaStoreKey,
+    GaiaParamsStoreKey,
+    GaiaAuthKeeper,
+    GaiaBankKeeper,
+    KH,
+    GaiaModules,
+>;
+
+/// Staking keeper without hooks. The keepers called by the staking hooks use it to read and
+/// update the staking state, so they don't trigger the hooks again.
+pub type GaiaHooklessStakingKeeper =
+    GaiaStakingKeeper<staking::MockHookKeeper<GaiaStoreKey, GaiaAuthKeeper, GaiaModules>>;
+
+pub type GaiaDistributionKeeper = distribution::Keeper<
+    GaiaStoreKey,
+    GaiaParamsStoreKey,
+    GaiaAuthKeeper,
+    GaiaBankKeeper,
+    GaiaHooklessStakingKeeper,
+    GaiaModules,
+>;
+
+/// Distribution keeper of the application, which goes through the staking keeper with hooks
+pub type GaiaAppDistributionKeeper = distribution::Keeper<
+    GaiaStoreKey,
+    GaiaParamsStoreKey,
+    GaiaAuthKeeper,
+    GaiaBankKeeper,
+    GaiaStakingKeeper<GaiaStakingHooks>,
+    GaiaModules,
+>;
+
+pub type GaiaSlashingKeeper<SSK> =
+    slashing::Keeper<GaiaStoreKey, GaiaParamsStoreKey, SSK, GaiaModules>;
+
+/// Staking hooks of the application. Every hook is passed to the distribution module first and
+/// then to the slashing module.
+pub type GaiaStakingHooks = (
+    GaiaDistributionKeeper,
+    GaiaSlashingKeeper<GaiaHooklessStakingKeeper>,
+);
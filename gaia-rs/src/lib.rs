@@ -8,6 +8,9 @@ use auth::query::QueryAccountRequest;
 use auth::query::QueryAccountResponse;
 use auth::AuthNodeQueryRequest;
 use auth::AuthNodeQueryResponse;
+use authz::cli::query::AuthzQueryHandler;
+use authz::AuthzNodeQueryRequest;
+use authz::AuthzNodeQueryResponse;
 use axum::Router;
 use bank::cli::query::BankQueryHandler;
 use bank::types::query::QueryDenomMetadataRequest;
@@ -18,8 +21,15 @@ use clap::Subcommand;
 use client::tx_command_handler;
 use client::GaiaQueryCommands;
 use client::WrappedGaiaQueryCommands;
+use distribution::cli::query::DistributionQueryHandler;
 use distribution::DistributionNodeQueryRequest;
 use distribution::DistributionNodeQueryResponse;
+use evidence::cli::query::EvidenceQueryHandler;
+use evidence::EvidenceNodeQueryRequest;
+use evidence::EvidenceNodeQueryResponse;
+use feegrant::cli::query::FeeGrantQueryHandler;
+use feegrant::FeeGrantNodeQueryRequest;
+use feegrant::FeeGrantNodeQueryResponse;
 use gears::application::client::Client;
 use gears::application::handlers::client::NodeFetcher;
 use gears::application::handlers::client::{QueryHandler, TxHandler};
@@ -36,13 +46,18 @@ use gears::commands::NilAuxCommand;
 use gears::core::Protobuf;
 use gears::crypto::public::PublicKey;
 use gears::grpc::health::health_server;
-use gears::grpc::tx::tx_server;
 use gears::rest::RestState;
 use gears::types::address::AccAddress;
 use gears::types::tx::Messages;
+use gov::client::GovClientHandler;
+use gov::query::{GovQuery, GovQueryResponse};
 use ibc_rs::client::cli::query::IbcQueryHandler;
+use mint::cli::query::MintQueryHandler;
+use mint::MintNodeQueryRequest;
+use mint::MintNodeQueryResponse;
 use rest::get_router;
 use serde::Serialize;
+use slashing::cli::query::SlashingQueryHandler;
 use slashing::SlashingNodeQueryRequest;
 use slashing::SlashingNodeQueryResponse;
 use staking::cli::query::StakingQueryHandler;
@@ -56,12 +71,14 @@ pub mod abci_handler;
 pub mod client;
 pub mod config;
 pub mod genesis;
+pub mod keepers;
 pub mod message;
 pub mod modules;
 pub mod params;
 pub mod query;
 pub mod rest;
 pub mod store_keys;
+pub mod upgrades;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct GaiaApplication;
@@ -85,7 +102,7 @@ impl TxHandler for GaiaCoreClient {
         command: Self::TxCommands,
         pubkey: PublicKey,
     ) -> Result<Messages<Self::Message>> {
-        tx_command_handler(ctx, command.0, pubkey.get_address())
+        tx_command_handler(ctx, command.0, pubkey)
     }
 }
 
@@ -113,6 +130,27 @@ impl QueryHandler for GaiaCoreClient {
             GaiaQueryCommands::Ibc(command) => {
                 Self::QueryRequest::Ibc(IbcQueryHandler.prepare_query_request(command)?)
             }
+            GaiaQueryCommands::Distribution(command) => Self::QueryRequest::Distribution(
+                DistributionQueryHandler.prepare_query_request(command)?,
+            ),
+            GaiaQueryCommands::Slashing(command) => {
+                Self::QueryRequest::Slashing(SlashingQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Gov(command) => {
+                Self::QueryRequest::Gov(GovClientHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Evidence(command) => {
+                Self::QueryRequest::Evidence(EvidenceQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Mint(command) => {
+                Self::QueryRequest::Mint(MintQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::FeeGrant(command) => {
+                Self::QueryRequest::FeeGrant(FeeGrantQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Authz(command) => {
+                Self::QueryRequest::Authz(AuthzQueryHandler.prepare_query_request(command)?)
+            }
         };
 
         Ok(res)
@@ -136,6 +174,27 @@ impl QueryHandler for GaiaCoreClient {
             GaiaQueryCommands::Ibc(command) => {
                 Self::QueryResponse::Ibc(IbcQueryHandler.handle_raw_response(query_bytes, command)?)
             }
+            GaiaQueryCommands::Distribution(command) => Self::QueryResponse::Distribution(
+                DistributionQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Slashing(command) => Self::QueryResponse::Slashing(
+                SlashingQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Gov(command) => Self::QueryResponse::Gov(
+                GovClientHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Evidence(command) => Self::QueryResponse::Evidence(
+                EvidenceQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Mint(command) => Self::QueryResponse::Mint(
+                MintQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::FeeGrant(command) => Self::QueryResponse::FeeGrant(
+                FeeGrantQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Authz(command) => Self::QueryResponse::Authz(
+                AuthzQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
         };
 
         Ok(res)
@@ -207,6 +266,11 @@ pub enum GaiaNodeQueryRequest {
     Staking(StakingNodeQueryRequest),
     Slashing(SlashingNodeQueryRequest),
     Distribution(DistributionNodeQueryRequest),
+    Gov(GovQuery),
+    Evidence(EvidenceNodeQueryRequest),
+    Mint(MintNodeQueryRequest),
+    FeeGrant(FeeGrantNodeQueryRequest),
+    Authz(AuthzNodeQueryRequest),
 }
 
 impl QueryRequest for GaiaNodeQueryRequest {
@@ -245,6 +309,36 @@ impl From<DistributionNodeQueryRequest> for GaiaNodeQueryRequest {
     }
 }
 
+impl From<GovQuery> for GaiaNodeQueryRequest {
+    fn from(req: GovQuery) -> Self {
+        GaiaNodeQueryRequest::Gov(req)
+    }
+}
+
+impl From<EvidenceNodeQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: EvidenceNodeQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Evidence(req)
+    }
+}
+
+impl From<MintNodeQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: MintNodeQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Mint(req)
+    }
+}
+
+impl From<FeeGrantNodeQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: FeeGrantNodeQueryRequest) -> Self {
+        GaiaNodeQueryRequest::FeeGrant(req)
+    }
+}
+
+impl From<AuthzNodeQueryRequest> for GaiaNodeQueryRequest {
+    fn from(req: AuthzNodeQueryRequest) -> Self {
+        GaiaNodeQueryRequest::Authz(req)
+    }
+}
+
 #[derive(Clone, Serialize)]
 #[serde(untagged)]
 pub enum GaiaNodeQueryResponse {
@@ -253,6 +347,11 @@ pub enum GaiaNodeQueryResponse {
     Staking(StakingNodeQueryResponse),
     Slashing(SlashingNodeQueryResponse),
     Distribution(DistributionNodeQueryResponse),
+    Gov(GovQueryResponse),
+    Evidence(EvidenceNodeQueryResponse),
+    Mint(MintNodeQueryResponse),
+    FeeGrant(FeeGrantNodeQueryResponse),
+    Authz(AuthzNodeQueryResponse),
 }
 
 impl TryFrom<GaiaNodeQueryResponse> for BankNodeQueryResponse {
@@ -320,6 +419,71 @@ impl TryFrom<GaiaNodeQueryResponse> for DistributionNodeQueryResponse {
     }
 }
 
+impl TryFrom<GaiaNodeQueryResponse> for GovQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Gov(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for EvidenceNodeQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Evidence(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for MintNodeQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Mint(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for FeeGrantNodeQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::FeeGrant(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<GaiaNodeQueryResponse> for AuthzNodeQueryResponse {
+    type Error = Status;
+
+    fn try_from(res: GaiaNodeQueryResponse) -> Result<Self, Status> {
+        match res {
+            GaiaNodeQueryResponse::Authz(res) => Ok(res),
+            _ => Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            )),
+        }
+    }
+}
+
 impl QueryResponse for GaiaNodeQueryResponse {
     fn into_bytes(self) -> Vec<u8> {
         todo!()
@@ -352,9 +516,9 @@ impl RouterBuilder<GaiaNodeQueryRequest, GaiaNodeQueryResponse> for GaiaCore {
             .add_service(reflection_service)
             .add_service(staking::grpc::new(app.clone()))
             .add_service(auth::grpc::new(app.clone()))
+            .add_service(gov::client::grpc::new(app.clone()))
             .add_service(bank::grpc::new(app))
             .add_service(health_server())
-            .add_service(tx_server())
     }
 }
 
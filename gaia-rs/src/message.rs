@@ -17,14 +17,35 @@ pub enum Message {
     Staking(staking::Message),
     #[msg(url(string = "/ibc.core.client.v1"))]
     IBC(ibc_rs::message::Message),
+    #[msg(url(string = "/cosmos.distribution.v1beta1"))]
+    Distribution(distribution::Message),
+    #[msg(url(string = "/cosmos.slashing.v1beta1"))]
+    Slashing(slashing::Message),
+    #[msg(url(string = "/cosmos.gov.v1beta1"))]
+    Gov(gov::msg::GovMsg),
+    #[msg(url(string = "/cosmos.evidence.v1beta1"))]
+    Evidence(evidence::Message),
+    #[msg(url(string = "/cosmos.vesting.v1beta1"))]
+    Vesting(vesting::Message),
+    #[msg(url(string = "/cosmos.feegrant.v1beta1"))]
+    FeeGrant(feegrant::Message),
+    #[msg(url(string = "/cosmos.authz.v1beta1"))]
+    Authz(authz::Message),
 }
 
 impl ValueRenderer for Message {
     fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
         match self {
             Message::Bank(msg) => msg.format(get_metadata),
-            Message::Staking(_) => Err(RenderError::NotImplemented),
+            Message::Staking(msg) => msg.format(get_metadata),
             Message::IBC(_) => Err(RenderError::NotImplemented),
+            Message::Distribution(_) => Err(RenderError::NotImplemented),
+            Message::Slashing(_) => Err(RenderError::NotImplemented),
+            Message::Gov(_) => Err(RenderError::NotImplemented),
+            Message::Evidence(_) => Err(RenderError::NotImplemented),
+            Message::Vesting(_) => Err(RenderError::NotImplemented),
+            Message::FeeGrant(_) => Err(RenderError::NotImplemented),
+            Message::Authz(_) => Err(RenderError::NotImplemented),
         }
     }
 }
@@ -5,6 +5,9 @@ pub enum GaiaModules {
     FeeCollector,
     BondedPool,
     NotBondedPool,
+    Distribution,
+    Gov,
+    Mint,
 }
 
 impl Module for GaiaModules {
@@ -13,6 +16,9 @@ impl Module for GaiaModules {
             GaiaModules::FeeCollector => "fee_collector".into(),
             GaiaModules::BondedPool => staking::BONDED_POOL_NAME.into(),
             GaiaModules::NotBondedPool => staking::NOT_BONDED_POOL_NAME.into(),
+            GaiaModules::Distribution => "distribution".into(),
+            GaiaModules::Gov => "gov".into(),
+            GaiaModules::Mint => "mint".into(),
         }
     }
 
@@ -21,6 +27,9 @@ impl Module for GaiaModules {
             GaiaModules::FeeCollector => vec![],
             GaiaModules::BondedPool => vec!["burner".into(), "staking".into()],
             GaiaModules::NotBondedPool => vec!["burner".into(), "staking".into()],
+            GaiaModules::Distribution => vec![],
+            GaiaModules::Gov => vec!["burner".into()],
+            GaiaModules::Mint => vec!["minter".into()],
         }
     }
 }
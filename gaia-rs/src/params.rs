@@ -1,14 +1,17 @@
 use auth::AuthParamsKeeper;
 use bank::BankParamsKeeper;
+use distribution::submission::CommunityPoolSpendProposalHandler;
 use gears::{
     application::keepers::params::ParamsKeeper,
     baseapp::BaseAppParamsKeeper,
     context::InfallibleContextMut,
     params::{ParamsDeserialize, ParamsSerialize},
-    store::{database::Database, StoreKey},
+    store::database::Database,
 };
 use gov::{
+    params::GovParamsKeeper,
     submission::{
+        community_pool::CommunityPoolSpendProposal,
         handler::{ParamChangeSubmissionHandler, SubmissionHandler, SubmissionHandlingError},
         param::ParameterChangeProposal,
         text::{TextProposal, TextSubmissionHandler},
@@ -16,15 +19,53 @@ use gov::{
     types::proposal::Proposal,
     ProposalHandler,
 };
+use mint::MintParamsKeeper;
 use staking::StakingParamsKeeper;
+use upgrade::{
+    submission::UpgradeProposalHandler, CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal,
+};
+
+use crate::{
+    keepers::{
+        GaiaAppDistributionKeeper, GaiaAuthKeeper, GaiaBankKeeper, GaiaStakingHooks,
+        GaiaStakingKeeper,
+    },
+    modules::GaiaModules,
+    store_keys::{GaiaParamsStoreKey, GaiaStoreKey},
+    upgrades::{GaiaUpgradeHandler, GaiaUpgradeKeeper},
+};
 
-use crate::store_keys::GaiaParamsStoreKey;
+type GaiaCommunityPoolSpendProposalHandler = CommunityPoolSpendProposalHandler<
+    GaiaStoreKey,
+    GaiaParamsStoreKey,
+    GaiaAuthKeeper,
+    GaiaBankKeeper,
+    GaiaStakingKeeper<GaiaStakingHooks>,
+    GaiaModules,
+>;
 
-#[derive(Debug)]
-pub struct GaiaProposalHandler;
+#[derive(Debug, Clone)]
+pub struct GaiaProposalHandler {
+    upgrade_handler: UpgradeProposalHandler<GaiaStoreKey, GaiaUpgradeHandler>,
+    community_pool_spend_handler: GaiaCommunityPoolSpendProposalHandler,
+}
+
+impl GaiaProposalHandler {
+    pub fn new(
+        upgrade_keeper: GaiaUpgradeKeeper,
+        distribution_keeper: GaiaAppDistributionKeeper,
+    ) -> Self {
+        Self {
+            upgrade_handler: UpgradeProposalHandler::new(upgrade_keeper),
+            community_pool_spend_handler: CommunityPoolSpendProposalHandler::new(
+                distribution_keeper,
+            ),
+        }
+    }
+}
 
-impl ProposalHandler<GaiaParamsStoreKey, Proposal> for GaiaProposalHandler {
-    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: Database, SK: StoreKey>(
+impl ProposalHandler<GaiaStoreKey, GaiaParamsStoreKey, Proposal> for GaiaProposalHandler {
+    fn handle<CTX: InfallibleContextMut<DB, GaiaStoreKey>, DB: Database>(
         &self,
         proposal: &Proposal,
         ctx: &mut CTX,
@@ -56,8 +97,20 @@ impl ProposalHandler<GaiaParamsStoreKey, Proposal> for GaiaProposalHandler {
                         >::handle(
                             change, ctx, &space
                         ),
+                        space @ GaiaParamsStoreKey::Gov => ParamChangeSubmissionHandler::<
+                            GovParamsKeeper<GaiaParamsStoreKey>,
+                        >::handle(
+                            change, ctx, &space
+                        ),
+                        space @ GaiaParamsStoreKey::Mint => ParamChangeSubmissionHandler::<
+                            MintParamsKeeper<GaiaParamsStoreKey>,
+                        >::handle(
+                            change, ctx, &space
+                        ),
                         GaiaParamsStoreKey::IBC => Err(SubmissionHandlingError::Subspace),
                         GaiaParamsStoreKey::Capability => Err(SubmissionHandlingError::Subspace),
+                        GaiaParamsStoreKey::Distribution => Err(SubmissionHandlingError::Subspace),
+                        GaiaParamsStoreKey::Slashing => Err(SubmissionHandlingError::Subspace),
                     }?;
                 }
 
@@ -68,6 +121,20 @@ impl ProposalHandler<GaiaParamsStoreKey, Proposal> for GaiaProposalHandler {
                 ctx,
                 &DUMMY_PARAMS,
             ),
+            SoftwareUpgradeProposal::TYPE_URL | CancelSoftwareUpgradeProposal::TYPE_URL => {
+                ProposalHandler::<GaiaStoreKey, GaiaParamsStoreKey, Proposal>::handle(
+                    &self.upgrade_handler,
+                    proposal,
+                    ctx,
+                )
+            }
+            CommunityPoolSpendProposal::TYPE_URL => {
+                ProposalHandler::<GaiaStoreKey, GaiaParamsStoreKey, Proposal>::handle(
+                    &self.community_pool_spend_handler,
+                    proposal,
+                    ctx,
+                )
+            }
             _ => Err(SubmissionHandlingError::InvalidProposal),
         }
     }
@@ -112,8 +179,24 @@ impl ProposalHandler<GaiaParamsStoreKey, Proposal> for GaiaProposalHandler {
                                         &change.value,
                                     )
                                 }
+                                GaiaParamsStoreKey::Gov => {
+                                    GovParamsKeeper::<GaiaParamsStoreKey>::check_key(&change.key)
+                                        && GovParamsKeeper::<GaiaParamsStoreKey>::validate(
+                                            &change.key,
+                                            &change.value,
+                                        )
+                                }
+                                GaiaParamsStoreKey::Mint => {
+                                    MintParamsKeeper::<GaiaParamsStoreKey>::check_key(&change.key)
+                                        && MintParamsKeeper::<GaiaParamsStoreKey>::validate(
+                                            &change.key,
+                                            &change.value,
+                                        )
+                                }
                                 GaiaParamsStoreKey::IBC => false,
                                 GaiaParamsStoreKey::Capability => false,
+                                GaiaParamsStoreKey::Distribution => false,
+                                GaiaParamsStoreKey::Slashing => false,
                             } {
                                 return false;
                             }
@@ -125,6 +208,20 @@ impl ProposalHandler<GaiaParamsStoreKey, Proposal> for GaiaProposalHandler {
                 }
             }
             TextProposal::TYPE_URL => true,
+            SoftwareUpgradeProposal::TYPE_URL | CancelSoftwareUpgradeProposal::TYPE_URL => {
+                <UpgradeProposalHandler<GaiaStoreKey, GaiaUpgradeHandler> as ProposalHandler<
+                    GaiaStoreKey,
+                    GaiaParamsStoreKey,
+                    Proposal,
+                >>::check(proposal)
+            }
+            CommunityPoolSpendProposal::TYPE_URL => {
+                <GaiaCommunityPoolSpendProposalHandler as ProposalHandler<
+                    GaiaStoreKey,
+                    GaiaParamsStoreKey,
+                    Proposal,
+                >>::check(proposal)
+            }
             _ => false,
         }
     }
@@ -1,8 +1,15 @@
 use auth::cli::query::{AuthQuery, AuthQueryResponse};
+use authz::cli::query::{AuthzQueryRequest, AuthzQueryResponse};
 use bank::cli::query::{BankQuery, BankQueryResponse};
+use distribution::cli::query::{DistributionQueryRequest, DistributionQueryResponse};
+use evidence::cli::query::{EvidenceQueryRequest, EvidenceQueryResponse};
+use feegrant::cli::query::{FeeGrantQueryRequest, FeeGrantQueryResponse};
 use gears::{baseapp::Query, derive::Query};
+use gov::query::{GovQuery, GovQueryResponse};
 use ibc_rs::client::cli::query::{IbcQuery, IbcQueryResponse};
+use mint::cli::query::{MintQueryRequest, MintQueryResponse};
 use serde::{Deserialize, Serialize};
+use slashing::cli::query::{SlashingQueryRequest, SlashingQueryResponse};
 use staking::cli::query::{StakingQuery, StakingQueryResponse};
 
 #[derive(Clone, PartialEq)]
@@ -11,6 +18,13 @@ pub enum GaiaQuery {
     Bank(BankQuery),
     Staking(StakingQuery),
     Ibc(IbcQuery),
+    Distribution(DistributionQueryRequest),
+    Slashing(SlashingQueryRequest),
+    Gov(GovQuery),
+    Evidence(EvidenceQueryRequest),
+    Mint(MintQueryRequest),
+    FeeGrant(FeeGrantQueryRequest),
+    Authz(AuthzQueryRequest),
 }
 
 impl Query for GaiaQuery {
@@ -20,6 +34,13 @@ impl Query for GaiaQuery {
             GaiaQuery::Bank(var) => var.query_url(),
             GaiaQuery::Staking(var) => var.query_url(),
             GaiaQuery::Ibc(var) => var.query_url(),
+            GaiaQuery::Distribution(var) => var.query_url(),
+            GaiaQuery::Slashing(var) => var.query_url(),
+            GaiaQuery::Gov(var) => var.query_url(),
+            GaiaQuery::Evidence(var) => var.query_url(),
+            GaiaQuery::Mint(var) => var.query_url(),
+            GaiaQuery::FeeGrant(var) => var.query_url(),
+            GaiaQuery::Authz(var) => var.query_url(),
         }
     }
 
@@ -29,6 +50,13 @@ impl Query for GaiaQuery {
             GaiaQuery::Bank(var) => var.into_bytes(),
             GaiaQuery::Staking(var) => var.into_bytes(),
             GaiaQuery::Ibc(var) => var.into_bytes(),
+            GaiaQuery::Distribution(var) => var.into_bytes(),
+            GaiaQuery::Slashing(var) => var.into_bytes(),
+            GaiaQuery::Gov(var) => var.into_bytes(),
+            GaiaQuery::Evidence(var) => var.into_bytes(),
+            GaiaQuery::Mint(var) => var.into_bytes(),
+            GaiaQuery::FeeGrant(var) => var.into_bytes(),
+            GaiaQuery::Authz(var) => var.into_bytes(),
         }
     }
 }
@@ -40,4 +68,11 @@ pub enum GaiaQueryResponse {
     Bank(BankQueryResponse),
     Staking(StakingQueryResponse),
     Ibc(IbcQueryResponse),
+    Distribution(DistributionQueryResponse),
+    Slashing(SlashingQueryResponse),
+    Gov(GovQueryResponse),
+    Evidence(EvidenceQueryResponse),
+    Mint(MintQueryResponse),
+    FeeGrant(FeeGrantQueryResponse),
+    Authz(AuthzQueryResponse),
 }
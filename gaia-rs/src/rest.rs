@@ -1,4 +1,5 @@
 use auth::{AuthNodeQueryRequest, AuthNodeQueryResponse};
+use authz::{AuthzNodeQueryRequest, AuthzNodeQueryResponse};
 use axum::Router;
 use bank::{BankNodeQueryRequest, BankNodeQueryResponse};
 use distribution::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
@@ -7,6 +8,8 @@ use gears::{
     baseapp::{QueryRequest, QueryResponse},
     rest::RestState,
 };
+use gov::query::{GovQuery, GovQueryResponse};
+use mint::{MintNodeQueryRequest, MintNodeQueryResponse};
 use slashing::{SlashingNodeQueryRequest, SlashingNodeQueryResponse};
 use staking::{StakingNodeQueryRequest, StakingNodeQueryResponse};
 
@@ -16,13 +19,19 @@ pub fn get_router<
         + From<BankNodeQueryRequest>
         + From<StakingNodeQueryRequest>
         + From<SlashingNodeQueryRequest>
-        + From<DistributionNodeQueryRequest>,
+        + From<DistributionNodeQueryRequest>
+        + From<GovQuery>
+        + From<MintNodeQueryRequest>
+        + From<AuthzNodeQueryRequest>,
     QRes: QueryResponse
         + TryInto<AuthNodeQueryResponse>
         + TryInto<BankNodeQueryResponse>
         + TryInto<StakingNodeQueryResponse>
         + TryInto<SlashingNodeQueryResponse>
-        + TryInto<DistributionNodeQueryResponse>,
+        + TryInto<DistributionNodeQueryResponse>
+        + TryInto<GovQueryResponse>
+        + TryInto<MintNodeQueryResponse>
+        + TryInto<AuthzNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
     Router::new()
@@ -31,4 +40,7 @@ pub fn get_router<
         .nest("/cosmos/staking", staking::rest::get_router())
         .nest("/cosmos/slashing", slashing::rest::get_router())
         .nest("/cosmos/distribution", distribution::rest::get_router())
+        .nest("/cosmos/gov", gov::client::rest::get_router())
+        .nest("/cosmos/mint", mint::rest::get_router())
+        .nest("/cosmos/authz", authz::rest::get_router())
 }
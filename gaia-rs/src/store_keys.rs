@@ -16,6 +16,22 @@ pub enum GaiaStoreKey {
     IBC,
     #[skey(to_string = "capability")]
     Capability,
+    #[skey(to_string = "distribution")]
+    Distribution,
+    #[skey(to_string = "slashing")]
+    Slashing,
+    #[skey(to_string = "gov")]
+    Gov,
+    #[skey(to_string = "evidence")]
+    Evidence,
+    #[skey(to_string = "mint")]
+    Mint,
+    #[skey(to_string = "upgrade")]
+    Upgrade,
+    #[skey(to_string = "feegrant")]
+    FeeGrant,
+    #[skey(to_string = "authz")]
+    Authz,
 }
 
 #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys)]
@@ -32,4 +48,12 @@ pub enum GaiaParamsStoreKey {
     IBC,
     #[pkey(to_string = "capability/")]
     Capability,
+    #[pkey(to_string = "distribution/")]
+    Distribution,
+    #[pkey(to_string = "slashing/")]
+    Slashing,
+    #[pkey(to_string = "gov/")]
+    Gov,
+    #[pkey(to_string = "mint/")]
+    Mint,
 }
@@ -0,0 +1,47 @@
+use gears::{context::block::BlockContext, store::database::Database};
+use upgrade::{ModuleVersionMap, Plan, UpgradeHandler};
+
+use crate::store_keys::GaiaStoreKey;
+
+pub type GaiaUpgradeKeeper = upgrade::Keeper<GaiaStoreKey, GaiaUpgradeHandler>;
+
+/// Consensus versions of the modules of this binary. Bump the version of a module whenever
+/// its state layout changes and register the migration from the previous version in the
+/// handler of the upgrade.
+pub fn module_versions() -> ModuleVersionMap {
+    [
+        "auth",
+        "authz",
+        "bank",
+        "distribution",
+        "evidence",
+        "feegrant",
+        "genutil",
+        "gov",
+        "ibc",
+        "mint",
+        "slashing",
+        "staking",
+        "upgrade",
+        "vesting",
+    ]
+    .into_iter()
+    .map(|module| (module.to_owned(), 1))
+    .collect()
+}
+
+/// Upgrades which this binary is able to apply. Add a variant for each new upgrade and
+/// register it by name in `GaiaABCIHandler::new`.
+#[derive(Debug, Clone)]
+pub enum GaiaUpgradeHandler {}
+
+impl UpgradeHandler<GaiaStoreKey> for GaiaUpgradeHandler {
+    fn handle<DB: Database>(
+        &self,
+        _ctx: &mut BlockContext<'_, DB, GaiaStoreKey>,
+        _plan: &Plan,
+        _from: &ModuleVersionMap,
+    ) -> anyhow::Result<ModuleVersionMap> {
+        match *self {}
+    }
+}
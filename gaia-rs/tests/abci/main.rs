@@ -18,9 +18,12 @@ use keyring::key::pair::KeyPair;
 use std::fs;
 use std::path::Path;
 
+mod modules;
+mod restart;
 mod scenario_1;
 mod scenario_2;
 mod scenario_3;
+mod simulate;
 #[cfg(test)]
 mod two_tx;
 
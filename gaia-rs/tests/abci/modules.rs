@@ -0,0 +1,64 @@
+use distribution::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
+use gaia_rs::{genesis::GenesisState, GaiaNodeQueryRequest, GaiaNodeQueryResponse};
+use gears::{baseapp::NodeQueryHandler, tendermint::types::time::timestamp::Timestamp};
+use gov::query::{
+    request::QueryAllParamsRequest, response::QueryAllParamsResponse, GovQuery, GovQueryResponse,
+};
+use slashing::{SlashingNodeQueryRequest, SlashingNodeQueryResponse};
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, we start the chain with the distribution, slashing, gov and evidence
+/// modules and run a block through their begin and end blockers. The params of the modules
+/// are queried to check that their genesis was initialized.
+fn modules_run_a_block() {
+    let (mut node, _) = setup_mock_node(None::<&str>);
+    let genesis = GenesisState::default();
+
+    let app_hash = node
+        .step(
+            vec![],
+            Timestamp::try_new(5, 0).expect("hard coded time is valid"),
+        )
+        .app_hash;
+    assert!(!app_hash.is_empty());
+
+    let query = |request: GaiaNodeQueryRequest| {
+        node.app()
+            .typed_query(request)
+            .expect("the latest version can be queried")
+    };
+
+    match query(GaiaNodeQueryRequest::Distribution(
+        DistributionNodeQueryRequest::Params(distribution::QueryParamsRequest {}),
+    )) {
+        GaiaNodeQueryResponse::Distribution(DistributionNodeQueryResponse::Params(response)) => {
+            assert_eq!(response.params, genesis.distribution.params)
+        }
+        _ => panic!("unexpected response to a distribution params query"),
+    }
+
+    match query(GaiaNodeQueryRequest::Slashing(
+        SlashingNodeQueryRequest::Params(slashing::QueryParamsRequest {}),
+    )) {
+        GaiaNodeQueryResponse::Slashing(SlashingNodeQueryResponse::Params(response)) => {
+            assert_eq!(response.params, genesis.slashing.params)
+        }
+        _ => panic!("unexpected response to a slashing params query"),
+    }
+
+    match query(GaiaNodeQueryRequest::Gov(GovQuery::AllParams(
+        QueryAllParamsRequest,
+    ))) {
+        GaiaNodeQueryResponse::Gov(GovQueryResponse::AllParams(response)) => assert_eq!(
+            response,
+            QueryAllParamsResponse {
+                voting_params: genesis.gov.params.voting,
+                deposit_params: genesis.gov.params.deposit,
+                tally_params: genesis.gov.params.tally,
+            }
+        ),
+        _ => panic!("unexpected response to a gov params query"),
+    }
+}
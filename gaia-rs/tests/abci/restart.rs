@@ -0,0 +1,124 @@
+use bank::{
+    types::query::QuerySpendableBalancesRequest, BankNodeQueryRequest, BankNodeQueryResponse,
+};
+use gaia_rs::{
+    abci_handler::GaiaABCIHandler, config::AppConfig, genesis::GenesisState,
+    store_keys::GaiaParamsStoreKey, GaiaApplication, GaiaNodeQueryResponse,
+};
+use gears::{
+    baseapp::{genesis::Genesis, options::NodeOptions, BaseApp, NodeQueryHandler},
+    config::Config,
+    store::database::MemDB,
+    tendermint::types::{
+        chain_id::ChainId,
+        consensus::ConsensusParams,
+        proto::validator::{ValidatorUpdate, VotingPower},
+        time::timestamp::Timestamp,
+    },
+    types::{
+        account::{Account, BaseVestingAccount, DelayedVestingAccount},
+        address::AccAddress,
+        base::coin::UnsignedCoin,
+    },
+    utils::node::{InitState, MockNode},
+};
+
+use crate::{user, USER_0};
+
+type GaiaBaseApp = BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>;
+
+/// The whole balance of the vesting account is locked until this time
+const VESTING_END: i64 = 500;
+
+fn new_app(db: MemDB) -> GaiaBaseApp {
+    BaseApp::new(
+        db,
+        GaiaParamsStoreKey::BaseApp,
+        GaiaABCIHandler::new(Config::<AppConfig>::default()),
+        NodeOptions::default(),
+    )
+}
+
+fn spendable_balances(app: &GaiaBaseApp, address: AccAddress) -> Vec<UnsignedCoin> {
+    let response = app
+        .typed_query(BankNodeQueryRequest::Spendable(
+            QuerySpendableBalancesRequest {
+                address,
+                pagination: None,
+            },
+        ))
+        .expect("the latest version can be queried");
+
+    match response {
+        GaiaNodeQueryResponse::Bank(BankNodeQueryResponse::Spendable(response)) => {
+            response.balances
+        }
+        _ => panic!("unexpected response to a spendable balances query"),
+    }
+}
+
+#[test]
+/// In this scenario, we restart the application and check that queries use the time of the
+/// last committed block rather than the time at which the application was started.
+fn restart_keeps_last_block_time() {
+    let db = MemDB::new();
+    let app = new_app(db.clone());
+    let user = user(2, USER_0);
+    let balance: UnsignedCoin = "34uatom".parse().expect("hard coded coin is valid");
+
+    let mut genesis = GenesisState::default();
+    genesis
+        .add_genesis_account(
+            user.address(),
+            "34uatom".parse().expect("hard coded coins are valid"),
+        )
+        .expect("won't fail since there's no existing account");
+    genesis.auth.accounts = genesis
+        .auth
+        .accounts
+        .into_iter()
+        .map(|account| match account {
+            Account::Base(account) if account.address == user.address() => {
+                Account::DelayedVesting(DelayedVestingAccount::new(BaseVestingAccount::new(
+                    account,
+                    vec![balance.clone()],
+                    VESTING_END,
+                )))
+            }
+            account => account,
+        })
+        .collect();
+
+    let init_state = InitState {
+        time: Timestamp::UNIX_EPOCH,
+        chain_id: ChainId::default(),
+        consensus_params: ConsensusParams::default(),
+        validators: vec![ValidatorUpdate {
+            pub_key: gears::tendermint::crypto::new_private_key()
+                .try_into()
+                .expect("ed25519 key conversion is supported"),
+            power: VotingPower::new(10).expect("hardcoded power is less the max voting power"),
+        }],
+        app_genesis: genesis,
+        initial_height: 1,
+    };
+
+    let mut node = MockNode::new(app.clone(), init_state);
+    node.step(
+        vec![],
+        Timestamp::try_new(VESTING_END * 2, 0).expect("hard coded timestamp is valid"),
+    );
+
+    assert_eq!(
+        spendable_balances(&app, user.address()),
+        vec![balance.clone()]
+    );
+
+    // a new application on the same database loads the committed state
+    let restarted = new_app(db);
+
+    assert_eq!(
+        spendable_balances(&restarted, user.address()),
+        vec![balance]
+    );
+}
@@ -0,0 +1,117 @@
+use gaia_rs::genesis::GenesisState;
+use gears::{
+    baseapp::NodeSimulateHandler,
+    core::Protobuf,
+    tendermint::types::{chain_id::ChainId, time::timestamp::Timestamp},
+    types::{address::AccAddress, base::coins::Coins, msg::send::MsgSend, tx::raw::TxRaw},
+    utils::node::{generate_tx, User},
+};
+
+use crate::setup_mock_node;
+
+/// Size of a secp256k1 signature
+const SIGNATURE_SIZE: i64 = 64;
+
+/// Size charged for every missing signature of a simulated transaction
+const SIMULATED_SIGNATURE_SIZE: i64 = 112;
+
+fn send_tx(user: &User, chain_id: ChainId) -> Vec<u8> {
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let amount = Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+        .expect("hard coded coins are valid");
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address,
+        amount,
+    }));
+
+    generate_tx(vec1::vec1![msg], 0, user, chain_id).to_vec()
+}
+
+/// Replaces the signature of a transaction with a single signer
+fn with_signature(tx: &[u8], signature: Vec<u8>) -> Vec<u8> {
+    let mut raw = TxRaw::decode_vec(tx).expect("generated transaction is valid");
+    raw.signatures = vec![signature];
+
+    raw.encode_vec()
+}
+
+fn tx_size_cost_per_byte() -> i64 {
+    GenesisState::default()
+        .auth
+        .params
+        .tx_size_cost_per_byte
+        .try_into()
+        .expect("default cost fits into i64")
+}
+
+#[test]
+/// Signatures of simulated transactions aren't verified, while check rejects them
+fn simulate_skips_signature_verification() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = with_signature(&send_tx(&user, node.chain_id().clone()), vec![1; 64]);
+
+    assert!(node.app().simulate(tx.clone().into()).is_ok());
+    assert_ne!(node.check_tx(tx.into()).code, 0);
+}
+
+#[test]
+/// A missing signature of a simulated transaction is charged as a full signature
+fn simulate_charges_missing_signatures() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let signed = send_tx(&user, node.chain_id().clone());
+    let unsigned = with_signature(&signed, vec![]);
+
+    let simulate = |tx: Vec<u8>| -> i64 {
+        node.app()
+            .simulate(tx.into())
+            .expect("simulation succeeds")
+            .gas_used
+            .into()
+    };
+    let signed_gas = simulate(signed);
+    let unsigned_gas = simulate(unsigned);
+
+    // the bytes of the removed signature are replaced by the placeholder
+    assert_eq!(
+        unsigned_gas - signed_gas,
+        (SIMULATED_SIGNATURE_SIZE - SIGNATURE_SIZE) * tx_size_cost_per_byte()
+    );
+}
+
+#[test]
+/// Simulation reports the gas of the ante handler and of the messages, while check and
+/// deliver report the gas of the ante handler
+fn simulate_gas_covers_ante_handler_and_messages() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let tx = send_tx(&user, node.chain_id().clone());
+
+    let simulated_gas: i64 = node
+        .app()
+        .simulate(tx.clone().into())
+        .expect("simulation succeeds")
+        .gas_used
+        .into();
+
+    let checked = node.check_tx(tx.clone().into());
+    assert_eq!(checked.code, 0);
+
+    let delivered = node
+        .step(vec![tx.into()], Timestamp::UNIX_EPOCH)
+        .tx_responses
+        .pop()
+        .expect("a response for every transaction");
+    assert_eq!(delivered.code, 0);
+
+    assert_eq!(checked.gas_used, delivered.gas_used);
+    assert!(simulated_gas > checked.gas_used);
+}
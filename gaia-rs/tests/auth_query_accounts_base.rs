@@ -114,7 +114,7 @@ fn base_accounts_query() -> anyhow::Result<()> {
                 .into_iter()
                 .filter(|this| match this {
                     Account::Base(_) => true,
-                    Account::Module(_) => false,
+                    _ => false,
                 })
                 .collect::<Vec<_>>(),
             _ => unreachable!(),
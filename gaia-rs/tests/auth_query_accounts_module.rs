@@ -71,8 +71,8 @@ fn module_accounts_query() -> anyhow::Result<()> {
                 .accounts
                 .into_iter()
                 .filter(|this| match this {
-                    Account::Base(_) => false,
                     Account::Module(_) => true,
+                    _ => false,
                 })
                 .collect::<Vec<_>>(),
             _ => unreachable!(),
@@ -426,7 +426,7 @@ fn redelegate_failed_on_invalid_amount() -> anyhow::Result<()> {
 //     // since validator status is BondStatus::Unbonded, the system doesn't store redelegation
 //     // entries in the queue
 //     let expected =
-//         GaiaQueryResponse::Staking(staking::cli::query::StakingQueryResponse::Redelegation(
+//         GaiaQueryResponse::Staking(staking::cli::query::StakingQueryResponse::Redelegations(
 //             staking::QueryRedelegationResponse {
 //                 redelegation_responses: vec![],
 //                 pagination: None,
@@ -3,7 +3,13 @@ use super::handlers::{
     AuxHandler,
 };
 use crate::{
-    commands::client::{keys::keys, query::run_query, tx::run_tx, ClientCommands},
+    commands::client::{
+        keys::keys,
+        query::run_query,
+        tx::run_tx,
+        tx_file::{run_broadcast, run_multisign, run_sign, run_validate_signatures},
+        ClientCommands,
+    },
     x::query::tx_query::{TxQueryHandler, TxsQueryHandler},
 };
 
@@ -59,6 +65,18 @@ impl<Core: Client, F: NodeFetcher + Clone> ClientApplication<Core, F> {
                 println!("{}", serde_json::to_string_pretty(&query)?);
             }
             ClientCommands::Keys(cmd) => keys(cmd)?,
+            ClientCommands::Sign(cmd) => run_sign::<Core::Message, _>(cmd, &self.fetcher)?,
+            ClientCommands::Multisign(cmd) => {
+                run_multisign::<Core::Message, _>(cmd, &self.fetcher)?
+            }
+            ClientCommands::ValidateSignatures(cmd) => {
+                run_validate_signatures::<Core::Message, _>(cmd, &self.fetcher)?
+            }
+            ClientCommands::Broadcast(cmd) => {
+                let tx = run_broadcast(cmd)?;
+
+                println!("{}", serde_json::to_string_pretty(&tx)?);
+            }
         };
 
         Ok(())
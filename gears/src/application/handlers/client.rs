@@ -2,9 +2,12 @@ use std::path::PathBuf;
 
 use crate::{
     baseapp::Query,
-    commands::client::tx::{broadcast_tx_commit, AccountProvider, ClientTxContext},
+    commands::client::tx::{broadcast_tx_commit, simulate_tx, AccountProvider, ClientTxContext},
     crypto::{
-        info::{create_signed_transaction_direct, create_signed_transaction_textual, SigningInfo},
+        info::{
+            create_signed_transaction_direct, create_signed_transaction_textual,
+            create_simulation_transaction, SigningInfo,
+        },
         keys::{GearsPublicKey, ReadAccAddress, SigningKey},
         public::PublicKey,
     },
@@ -13,6 +16,7 @@ use crate::{
     types::{
         account::{Account, BaseAccount},
         address::AccAddress,
+        auth::gas::Gas,
         denom::Denom,
         tx::{body::TxBody, metadata::Metadata, Messages, Tx, TxMessage},
     },
@@ -125,6 +129,24 @@ pub trait TxHandler {
 
         let tip = None; //TODO: remove hard coded
 
+        if let Some(gas_adjustment) = ctx.gas_adjustment {
+            if let AccountProvider::Offline { .. } = ctx.account {
+                return Err(anyhow!("cannot estimate gas in offline mode"));
+            }
+
+            let simulation_tx = create_simulation_transaction(
+                &signing_infos,
+                ctx.fee.clone(),
+                tip.clone(),
+                tx_body.clone(),
+            );
+            let gas_used = simulate_tx(&ctx.node, (&simulation_tx).into())?;
+            let gas_limit = (gas_used as f64 * gas_adjustment) as u64;
+            tracing::info!("gas estimate: {gas_limit}");
+
+            ctx.fee.gas_limit = Gas::try_from(gas_limit)?;
+        }
+
         match mode {
             SignMode::Direct => create_signed_transaction_direct(
                 signing_infos,
@@ -1,12 +1,12 @@
-use std::{borrow::Cow, num::NonZero};
+use std::{borrow::Cow, collections::BTreeMap, num::NonZero};
 
 use crate::{
-    baseapp::{errors::QueryError, genesis::Genesis, QueryRequest, QueryResponse},
+    baseapp::{errors::QueryError, genesis::Genesis, mode::RunTxMode, QueryRequest, QueryResponse},
     context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
     types::tx::{raw::TxWithRaw, TxMessage},
 };
 use database::Database;
-use kv_store::StoreKey;
+use kv_store::{upgrade::StoreUpgrades, StoreKey};
 use tendermint::types::{
     proto::validator::ValidatorUpdate,
     request::{begin_block::RequestBeginBlock, end_block::RequestEndBlock, query::RequestQuery},
@@ -53,7 +53,7 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         &self,
         ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         tx: &TxWithRaw<Self::Message>,
-        is_check: bool,
+        mode: RunTxMode,
     ) -> Result<(), TxError>;
 
     // TODO: this should return a Result similar to the SDK. See:
@@ -89,9 +89,30 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         genesis: Self::Genesis,
     ) -> Vec<ValidatorUpdate>;
 
+    /// Adjusts the state at the height of the context before it's exported for a chain which
+    /// starts again from the first block, e.g. withdraws all rewards. The changes are only
+    /// exported, they are never committed to the database of the node.
+    #[allow(unused_variables)]
+    fn prepare_for_zero_height<DB: Database>(&self, ctx: &mut InitContext<'_, DB, Self::StoreKey>) {
+    }
+
+    /// Exports the state at the height of the context as genesis. With `for_zero_height`
+    /// the state is adjusted for a chain which starts again from the first block.
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        for_zero_height: bool,
+    ) -> Self::Genesis;
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
         query: RequestQuery,
     ) -> Result<Vec<u8>, QueryError>;
+
+    /// Store upgrades keyed by the height of the software upgrade which introduces them.
+    /// The upgrades of a height are applied when the stores are loaded at the preceding height.
+    fn store_upgrades(&self) -> BTreeMap<u32, StoreUpgrades> {
+        BTreeMap::new()
+    }
 }
@@ -7,6 +7,7 @@ use super::{
     ApplicationInfo,
 };
 use crate::commands::node::{
+    export::export,
     genesis::genesis_account_add,
     init::init,
     run::{run, RouterBuilder},
@@ -83,6 +84,9 @@ impl<
             AppCommands::GenesisAdd(cmd) => {
                 genesis_account_add::<<<Core as Node>::Handler as ABCIHandler>::Genesis>(cmd)?
             }
+            AppCommands::Export(cmd) => {
+                export::<DB, DBO, _, _>(cmd, self.db_builder, self.abci_handler_builder)?
+            }
             AppCommands::Aux(cmd) => {
                 let cmd = self.core.prepare_aux(cmd)?;
                 self.core.handle_aux(cmd)?;
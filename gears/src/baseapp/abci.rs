@@ -1,5 +1,7 @@
 use super::{
     mode::{check::CheckTxMode, deliver::DeliverTxMode},
+    snapshot::ApplyChunk,
+    state::ApplicationState,
     BaseApp,
 };
 use crate::error::POISONED_LOCK;
@@ -17,6 +19,7 @@ use extensions::lock::AcquireRwLock;
 use tendermint::{
     application::ABCIApplication,
     types::{
+        proto::header::Header,
         request::{
             begin_block::RequestBeginBlock,
             check_tx::RequestCheckTx,
@@ -38,11 +41,12 @@ use tendermint::{
             init_chain::ResponseInitChain,
             query::ResponseQuery,
             snapshot::{
-                ResponseApplySnapshotChunk, ResponseListSnapshots, ResponseLoadSnapshotChunk,
-                ResponseOfferSnapshot,
+                ApplySnapshotChunkResult, OfferResult, ResponseApplySnapshotChunk,
+                ResponseListSnapshots, ResponseLoadSnapshotChunk, ResponseOfferSnapshot,
             },
             ResponseCommit, ResponseFlush,
         },
+        time::timestamp::Timestamp,
     },
 };
 use tracing::{debug, error, info};
@@ -110,15 +114,15 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
     fn query(&self, request: RequestQuery) -> ResponseQuery {
         match self.run_query(&request) {
-            Ok(res) => ResponseQuery {
+            Ok((res, proof_ops, height)) => ResponseQuery {
                 code: 0,
                 log: "exists".to_string(),
                 info: "".to_string(),
                 index: 0,
                 key: request.data,
                 value: res,
-                proof_ops: None,
-                height: request.height as u32,
+                proof_ops,
+                height,
                 codespace: "".to_string(),
             },
             Err(e) => ResponseQuery {
@@ -233,9 +237,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
         let mut state = self.state.write().expect(POISONED_LOCK);
 
-        let height = self.get_block_header().height;
+        let header = self.get_block_header();
+        let height = header.height;
 
         let hash = state.commit(&mut multi_store);
+        self.set_last_block_time(header.time);
 
         info!(
             "Committed state, block height: {} app hash: {}",
@@ -243,6 +249,34 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             hex::encode(hash)
         );
 
+        if let Some(snapshot_manager) = &self.snapshot_manager {
+            if snapshot_manager
+                .options()
+                .should_snapshot(multi_store.head_version())
+            {
+                match snapshot_manager.spawn_create(&multi_store) {
+                    Ok(true) => (),
+                    Ok(false) => error!(
+                        "Skipped snapshot at height {}: another snapshot is being created",
+                        height
+                    ),
+                    Err(err) => error!("Failed to create snapshot at height {}: {}", height, err),
+                }
+            }
+        }
+
+        // pruning is postponed until the snapshot which is being created is done,
+        // the next pruning deletes all versions which should've been pruned meanwhile
+        let snapshot_in_progress = self
+            .snapshot_manager
+            .as_ref()
+            .is_some_and(|snapshot_manager| snapshot_manager.is_creating());
+        if !snapshot_in_progress {
+            if let Err(err) = multi_store.prune(&self.options.pruning()) {
+                error!("Failed to prune state at height {}: {}", height, err);
+            }
+        }
+
         ResponseCommit {
             data: hash.to_vec().into(),
             retain_height: 0, // this is the height above which tendermint will retain all blocks // TODO: make this configurable as in Cosmos
@@ -286,6 +320,9 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             consensus_params,
         );
 
+        self.baseapp_params_keeper
+            .set_block_time(&mut ctx, request.header.time);
+
         self.abci_handler.begin_block(&mut ctx, request);
 
         let events = ctx.events;
@@ -345,24 +382,140 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
     /// Used during state sync to discover available snapshots on peers.
     fn list_snapshots(&self) -> ResponseListSnapshots {
-        Default::default()
+        let Some(snapshot_manager) = &self.snapshot_manager else {
+            return Default::default();
+        };
+
+        match snapshot_manager.list() {
+            Ok(snapshots) => ResponseListSnapshots { snapshots },
+            Err(err) => {
+                error!("Failed to list snapshots: {err}");
+                Default::default()
+            }
+        }
     }
 
     /// Called when bootstrapping the node using state sync.
-    fn offer_snapshot(&self, _request: RequestOfferSnapshot) -> ResponseOfferSnapshot {
-        Default::default()
+    fn offer_snapshot(
+        &self,
+        RequestOfferSnapshot { snapshot, app_hash }: RequestOfferSnapshot,
+    ) -> ResponseOfferSnapshot {
+        let result = match (&self.snapshot_manager, snapshot) {
+            (None, _) => OfferResult::Abort,
+            (Some(_), None) => OfferResult::Reject,
+            (Some(snapshot_manager), Some(snapshot)) => {
+                info!(
+                    "Offered snapshot at height {} with format {}",
+                    snapshot.height, snapshot.format
+                );
+                snapshot_manager.offer(snapshot, &app_hash)
+            }
+        };
+
+        ResponseOfferSnapshot {
+            result: result.into(),
+        }
     }
 
     /// Used during state sync to retrieve chunks of snapshots from peers.
-    fn load_snapshot_chunk(&self, _request: RequestLoadSnapshotChunk) -> ResponseLoadSnapshotChunk {
-        Default::default()
+    fn load_snapshot_chunk(
+        &self,
+        RequestLoadSnapshotChunk {
+            height,
+            format,
+            chunk,
+        }: RequestLoadSnapshotChunk,
+    ) -> ResponseLoadSnapshotChunk {
+        let Some(snapshot_manager) = &self.snapshot_manager else {
+            return Default::default();
+        };
+
+        match snapshot_manager.load_chunk(height, format, chunk) {
+            Ok(chunk) => ResponseLoadSnapshotChunk {
+                chunk: chunk.unwrap_or_default().into(),
+            },
+            Err(err) => {
+                error!("Failed to load chunk {chunk} of snapshot at height {height}: {err}");
+                Default::default()
+            }
+        }
     }
 
     /// Apply the given snapshot chunk to the application's state.
     fn apply_snapshot_chunk(
         &self,
-        _request: RequestApplySnapshotChunk,
+        RequestApplySnapshotChunk {
+            index,
+            chunk,
+            sender,
+        }: RequestApplySnapshotChunk,
     ) -> ResponseApplySnapshotChunk {
-        Default::default()
+        let Some(snapshot_manager) = &self.snapshot_manager else {
+            return ResponseApplySnapshotChunk {
+                result: ApplySnapshotChunkResult::Abort.into(),
+                refetch_chunks: vec![],
+                reject_senders: vec![],
+            };
+        };
+
+        let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
+
+        let result = match snapshot_manager.apply_chunk(index, chunk.to_vec(), &mut multi_store) {
+            ApplyChunk::Accepted => ApplySnapshotChunkResult::Accept,
+            ApplyChunk::Rejected(ApplySnapshotChunkResult::Retry) => {
+                error!("Invalid snapshot chunk {index} received from {sender}");
+
+                return ResponseApplySnapshotChunk {
+                    result: ApplySnapshotChunkResult::Retry.into(),
+                    refetch_chunks: vec![index],
+                    reject_senders: vec![sender],
+                };
+            }
+            ApplyChunk::Rejected(result) => result,
+            ApplyChunk::Completed { height, app_hash } => {
+                let mut state = self.state.write().expect(POISONED_LOCK);
+
+                let ctx = SimpleContext::new(
+                    SimpleBackend::Application(&mut multi_store),
+                    height,
+                    Default::default(),
+                );
+
+                let max_gas = self
+                    .baseapp_params_keeper
+                    .block_params(&ctx)
+                    .map(|e| e.max_gas)
+                    .unwrap_or_default();
+
+                let last_block_time = self
+                    .baseapp_params_keeper
+                    .block_time(&ctx)
+                    .unwrap_or(Timestamp::UNIX_EPOCH);
+
+                // the restored state becomes the last committed block
+                *state = ApplicationState::new(Gas::from(max_gas), &multi_store);
+                self.set_block_header(Header {
+                    height,
+                    time: last_block_time,
+                    app_hash: app_hash.to_vec(),
+                    ..Default::default()
+                });
+                self.set_last_block_time(last_block_time);
+
+                info!(
+                    "Restored state from snapshot, height: {} app hash: {}",
+                    height,
+                    hex::encode(app_hash)
+                );
+
+                ApplySnapshotChunkResult::Accept
+            }
+        };
+
+        ResponseApplySnapshotChunk {
+            result: result.into(),
+            refetch_chunks: vec![],
+            reject_senders: vec![],
+        }
     }
 }
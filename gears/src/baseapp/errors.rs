@@ -49,6 +49,8 @@ pub enum QueryError {
     Store(#[from] kv_store::error::KVStoreError),
     #[error("error decoding query: {0}")]
     Proto(String),
+    #[error("simulation failed: {0}")]
+    Simulate(#[from] RunTxError),
     #[error("TODO: {0}")]
     TODO(#[from] anyhow::Error),
 }
@@ -0,0 +1,164 @@
+//! Protobuf encoding of `cosmos.ics23.v1` commitment proofs
+
+use kv_store::proof;
+use prost::Message;
+use tendermint::types::proto::crypto::ProofOp;
+
+pub const PROOF_OP_IAVL_COMMITMENT: &str = "ics23:iavl";
+pub const PROOF_OP_SIMPLE_MERKLE_COMMITMENT: &str = "ics23:simple";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum HashOp {
+    NoHash = 0,
+    Sha256 = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LengthOp {
+    NoPrefix = 0,
+    VarProto = 1,
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct LeafOp {
+    #[prost(enumeration = "HashOp", tag = "1")]
+    pub hash: i32,
+    #[prost(enumeration = "HashOp", tag = "2")]
+    pub prehash_key: i32,
+    #[prost(enumeration = "HashOp", tag = "3")]
+    pub prehash_value: i32,
+    #[prost(enumeration = "LengthOp", tag = "4")]
+    pub length: i32,
+    #[prost(bytes = "vec", tag = "5")]
+    pub prefix: Vec<u8>,
+}
+
+impl From<proof::LeafOp> for LeafOp {
+    fn from(proof::LeafOp { prefix }: proof::LeafOp) -> Self {
+        Self {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct InnerOp {
+    #[prost(enumeration = "HashOp", tag = "1")]
+    pub hash: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub prefix: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    pub suffix: Vec<u8>,
+}
+
+impl From<proof::InnerOp> for InnerOp {
+    fn from(proof::InnerOp { prefix, suffix }: proof::InnerOp) -> Self {
+        Self {
+            hash: HashOp::Sha256.into(),
+            prefix,
+            suffix,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct ExistenceProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub leaf: Option<LeafOp>,
+    #[prost(message, repeated, tag = "4")]
+    pub path: Vec<InnerOp>,
+}
+
+impl From<proof::ExistenceProof> for ExistenceProof {
+    fn from(
+        proof::ExistenceProof {
+            key,
+            value,
+            leaf,
+            path,
+        }: proof::ExistenceProof,
+    ) -> Self {
+        Self {
+            key,
+            value,
+            leaf: Some(leaf.into()),
+            path: path.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct NonExistenceProof {
+    #[prost(bytes = "vec", tag = "1")]
+    pub key: Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub left: Option<ExistenceProof>,
+    #[prost(message, optional, tag = "3")]
+    pub right: Option<ExistenceProof>,
+}
+
+impl From<proof::NonExistenceProof> for NonExistenceProof {
+    fn from(proof::NonExistenceProof { key, left, right }: proof::NonExistenceProof) -> Self {
+        Self {
+            key,
+            left: left.map(Into::into),
+            right: right.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Oneof)]
+pub enum Proof {
+    #[prost(message, tag = "1")]
+    Exist(ExistenceProof),
+    #[prost(message, tag = "2")]
+    Nonexist(NonExistenceProof),
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct CommitmentProof {
+    #[prost(oneof = "Proof", tags = "1, 2")]
+    pub proof: Option<Proof>,
+}
+
+impl From<proof::CommitmentProof> for CommitmentProof {
+    fn from(value: proof::CommitmentProof) -> Self {
+        let proof = match value {
+            proof::CommitmentProof::Exist(proof) => Proof::Exist(proof.into()),
+            proof::CommitmentProof::Nonexist(proof) => Proof::Nonexist(proof.into()),
+        };
+
+        Self { proof: Some(proof) }
+    }
+}
+
+/// Returns proof operations which prove `key` in store `store_name` against the app hash
+pub fn store_proof_ops(
+    store_name: &str,
+    key: Vec<u8>,
+    key_proof: proof::CommitmentProof,
+    store_proof: proof::ExistenceProof,
+) -> Vec<ProofOp> {
+    vec![
+        ProofOp {
+            r#type: PROOF_OP_IAVL_COMMITMENT.to_owned(),
+            key,
+            data: CommitmentProof::from(key_proof).encode_to_vec(),
+        },
+        ProofOp {
+            r#type: PROOF_OP_SIMPLE_MERKLE_COMMITMENT.to_owned(),
+            key: store_name.as_bytes().to_vec(),
+            data: CommitmentProof::from(proof::CommitmentProof::Exist(store_proof)).encode_to_vec(),
+        },
+    ]
+}
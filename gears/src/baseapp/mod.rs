@@ -18,33 +18,45 @@ use crate::{
 use bytes::Bytes;
 use database::Database;
 use errors::QueryError;
+use ibc_proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse};
 use kv_store::{
     bank::multi::{ApplicationMultiBank, TransactionMultiBank},
     query::QueryMultiStore,
+    StoreKey,
 };
-use mode::build_tx_gas_meter;
+use prost::Message;
+use strum::IntoEnumIterator;
 use tendermint::types::{
     chain_id::ChainId,
-    proto::{event::Event, header::Header},
+    proto::{crypto::ProofOps, event::Event, header::Header},
     request::query::RequestQuery,
+    time::timestamp::Timestamp,
 };
 
 use self::{
-    errors::RunTxError, mode::ExecutionMode, options::NodeOptions, state::ApplicationState,
+    errors::RunTxError,
+    mode::{ExecutionMode, RunTxMode},
+    options::NodeOptions,
+    snapshot::SnapshotManager,
+    state::ApplicationState,
 };
 
 mod abci;
 pub mod errors;
 pub mod genesis;
+pub mod ics23;
 pub mod mode;
 mod params;
 mod query;
+mod simulate;
+pub mod snapshot;
 pub mod state;
 pub use params::{
     BaseAppParamsKeeper, BlockParams, ConsensusParams, EvidenceParams, ValidatorParams,
 };
 
 pub use query::*;
+pub use simulate::*;
 
 #[derive(Debug, Clone)]
 pub struct BaseApp<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo> {
@@ -52,8 +64,10 @@ pub struct BaseApp<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: App
     multi_store: Arc<RwLock<ApplicationMultiBank<DB, H::StoreKey>>>,
     abci_handler: H,
     block_header: Arc<RwLock<Header>>, // passed by Tendermint in call to begin_block
+    last_block_time: Arc<RwLock<Timestamp>>, // time of the last committed block
     baseapp_params_keeper: BaseAppParamsKeeper<PSK>,
     options: NodeOptions,
+    snapshot_manager: Option<Arc<SnapshotManager>>,
     _info_marker: PhantomData<AI>,
 }
 
@@ -61,7 +75,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     BaseApp<DB, PSK, H, AI>
 {
     pub fn new(db: DB, params_subspace_key: PSK, abci_handler: H, options: NodeOptions) -> Self {
-        let multi_store = ApplicationMultiBank::new(Arc::new(db));
+        let multi_store =
+            ApplicationMultiBank::new_with_upgrades(Arc::new(db), &abci_handler.store_upgrades());
         let mut multi_store = match multi_store {
             Ok(ms) => ms,
             Err(err) => panic!("Failed to init MultiStore with err: {err}"),
@@ -79,9 +94,14 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             .map(|e| e.max_gas)
             .unwrap_or_default();
 
+        let last_block_time = baseapp_params_keeper
+            .block_time(&ctx)
+            .unwrap_or(Timestamp::UNIX_EPOCH);
+
         Self {
             abci_handler,
             block_header: Arc::new(RwLock::new(Default::default())),
+            last_block_time: Arc::new(RwLock::new(last_block_time)),
             baseapp_params_keeper,
             state: Arc::new(RwLock::new(ApplicationState::new(
                 Gas::from(max_gas),
@@ -89,20 +109,50 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             ))),
             multi_store: Arc::new(RwLock::new(multi_store)),
             options,
+            snapshot_manager: None,
             _info_marker: PhantomData,
         }
     }
 
+    /// Enable creation of state-sync snapshots and restoring state from them
+    pub fn with_snapshot_manager(mut self, snapshot_manager: SnapshotManager) -> Self {
+        self.snapshot_manager = Some(Arc::new(snapshot_manager));
+        self
+    }
+
     fn get_block_header(&self) -> Header {
         self.block_header.read().expect(POISONED_LOCK).clone()
     }
 
+    /// Time of the block in which the latest version of the state was committed. The
+    /// times of older blocks aren't kept, so queries of older versions use it as well,
+    /// the same as the Cosmos SDK does.
+    fn get_last_block_time(&self) -> Timestamp {
+        *self.last_block_time.read().expect(POISONED_LOCK)
+    }
+
+    fn set_last_block_time(&self, time: Timestamp) {
+        *self.last_block_time.write().expect(POISONED_LOCK) = time;
+    }
+
     fn set_block_header(&self, header: Header) {
         let mut current_header = self.block_header.write().expect(POISONED_LOCK);
         *current_header = header;
     }
 
-    fn run_query(&self, request: &RequestQuery) -> Result<Bytes, QueryError> {
+    /// Runs the query and returns the response with the version of the state it was run against
+    fn run_query(
+        &self,
+        request: &RequestQuery,
+    ) -> Result<(Bytes, Option<ProofOps>, u32), QueryError> {
+        if request.path == SIMULATE_QUERY_PATH {
+            let version = self.multi_store.read().expect(POISONED_LOCK).head_version();
+            let tx_bytes = SimulateRequest::decode(request.data.clone())?.tx_bytes;
+            let response = SimulateResponse::from(self.simulate(tx_bytes.into())?);
+
+            return Ok((response.encode_to_vec().into(), None, version));
+        }
+
         //TODO: request height u32
         let version: u32 = request
             .height
@@ -110,11 +160,56 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             .map_err(|_| QueryError::InvalidHeight)?;
 
         let store = self.multi_store.read().expect(POISONED_LOCK);
-        let ctx = QueryContext::new(QueryMultiStore::new(&*store, version)?, version)?;
+        // height 0 means the latest committed version
+        let version = match version {
+            0 => store.head_version(),
+            version => version,
+        };
+        let multi_store = QueryMultiStore::new(&*store, version)?;
+
+        if let Some(store_path) = request.path.strip_prefix("/store/") {
+            let (value, proof_ops) = Self::run_store_query(&multi_store, store_path, request)?;
+
+            return Ok((value, proof_ops, version));
+        }
+
+        let ctx = QueryContext::new(multi_store, version, self.get_last_block_time())?;
 
         self.abci_handler
             .query(&ctx, request.clone())
-            .map(Into::into)
+            .map(|res| (res.into(), None, version))
+    }
+
+    /// Query raw value of a key with path `/store/<store name>/key`. Proofs are only
+    /// available for these queries.
+    fn run_store_query(
+        multi_store: &QueryMultiStore<DB, H::StoreKey>,
+        path: &str,
+        request: &RequestQuery,
+    ) -> Result<(Bytes, Option<ProofOps>), QueryError> {
+        let (store_name, "key") = path.split_once('/').ok_or(QueryError::PathNotFound)? else {
+            return Err(QueryError::PathNotFound);
+        };
+
+        let store_key = H::StoreKey::iter()
+            .find(|sk| sk.name() == store_name)
+            .ok_or(QueryError::PathNotFound)?;
+
+        let key = request.data.to_vec();
+        let value = multi_store
+            .kv_store(&store_key)
+            .get(&key)
+            .unwrap_or_default();
+
+        let proof_ops = request.prove.then(|| {
+            let (key_proof, store_proof) = multi_store.get_proof(&store_key, &key);
+
+            ProofOps {
+                ops: ics23::store_proof_ops(store_name, key, key_proof, store_proof),
+            }
+        });
+
+        Ok((value.into(), proof_ops))
     }
 
     fn run_tx<MD: ExecutionMode<DB, H>>(
@@ -145,7 +240,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             height,
             header,
             consensus_params,
-            build_tx_gas_meter(height, Some(&tx_with_raw.tx.auth_info.fee)),
+            MD::build_tx_gas_meter(height, Some(&tx_with_raw.tx.auth_info.fee)),
             gas_meter,
             self.options.clone(),
         );
@@ -156,7 +251,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         ctx.multi_store_mut().upgrade_cache();
 
         let gas_wanted = ctx.gas_meter.borrow().limit();
-        let gas_used = ctx.gas_meter.borrow().consumed_or_limit();
+        let mut gas_used = ctx.gas_meter.borrow().consumed_or_limit();
 
         let events = MD::run_msg(
             &mut ctx,
@@ -164,6 +259,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             tx_with_raw.tx.get_msgs().iter(),
         )?;
 
+        if MD::MODE == RunTxMode::Simulate {
+            // gas estimate has to include the gas consumed by the messages
+            gas_used = ctx.gas_meter.borrow().consumed_or_limit();
+        }
+
         ctx.block_gas_meter
             .consume_gas(gas_used, BLOCK_GAS_DESCRIPTOR)?;
 
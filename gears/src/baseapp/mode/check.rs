@@ -2,7 +2,7 @@ use database::Database;
 use kv_store::bank::multi::TransactionMultiBank;
 use tendermint::types::proto::event::Event;
 
-use super::ExecutionMode;
+use super::{ExecutionMode, RunTxMode};
 use crate::{
     application::handlers::node::ABCIHandler,
     baseapp::errors::RunTxError,
@@ -35,6 +35,8 @@ impl<DB, AH: ABCIHandler> CheckTxMode<DB, AH> {
 }
 
 impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for CheckTxMode<DB, AH> {
+    const MODE: RunTxMode = RunTxMode::Check;
+
     fn run_msg<'m>(
         ctx: &mut TxContext<'_, DB, AH::StoreKey>,
         _handler: &AH,
@@ -49,7 +51,7 @@ impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for CheckTxMode<DB, AH
         tx_with_raw: &TxWithRaw<AH::Message>,
     ) -> Result<(), RunTxError> {
         handler
-            .run_ante_checks(ctx, tx_with_raw, true)
+            .run_ante_checks(ctx, tx_with_raw, RunTxMode::Check)
             .inspect_err(|_| ctx.multi_store_mut().clear_cache())
             .map_err(RunTxError::from)
     }
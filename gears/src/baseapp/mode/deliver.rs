@@ -2,7 +2,7 @@ use database::Database;
 use kv_store::bank::multi::TransactionMultiBank;
 use tendermint::types::proto::event::Event;
 
-use super::ExecutionMode;
+use super::{ExecutionMode, RunTxMode};
 use crate::types::gas::basic_meter::BasicGasMeter;
 use crate::types::gas::infinite_meter::InfiniteGasMeter;
 use crate::types::gas::kind::BlockKind;
@@ -33,6 +33,8 @@ impl<DB, AH: ABCIHandler> DeliverTxMode<DB, AH> {
 }
 
 impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for DeliverTxMode<DB, AH> {
+    const MODE: RunTxMode = RunTxMode::Deliver;
+
     fn run_msg<'m>(
         ctx: &mut TxContext<'_, DB, AH::StoreKey>,
         handler: &AH,
@@ -53,7 +55,7 @@ impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for DeliverTxMode<DB,
         tx_with_raw: &TxWithRaw<AH::Message>,
     ) -> Result<(), RunTxError> {
         handler
-            .run_ante_checks(ctx, tx_with_raw, false)
+            .run_ante_checks(ctx, tx_with_raw, RunTxMode::Deliver)
             .inspect_err(|_| ctx.multi_store_mut().clear_cache())
             .map_err(RunTxError::from)
     }
@@ -16,8 +16,27 @@ use crate::{
 
 pub mod check;
 pub mod deliver;
+pub mod simulate;
+
+/// Mode in which a transaction is run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunTxMode {
+    /// Validate a transaction before it gets into the mempool
+    Check,
+    /// Execute a transaction included in a block
+    Deliver,
+    /// Execute a transaction on a throwaway copy of the state to estimate gas.
+    /// Signatures aren't verified in this mode.
+    Simulate,
+}
 
 pub trait ExecutionMode<DB, AH: ABCIHandler>: Sealed {
+    const MODE: RunTxMode;
+
+    fn build_tx_gas_meter(block_height: u32, fee: Option<&Fee>) -> GasMeter<TxKind> {
+        build_tx_gas_meter(block_height, fee)
+    }
+
     fn runnable(ctx: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError>;
 
     fn run_ante_checks(
@@ -36,12 +55,13 @@ pub trait ExecutionMode<DB, AH: ABCIHandler>: Sealed {
 mod sealed {
     use crate::application::handlers::node::ABCIHandler;
 
-    use super::{check::CheckTxMode, deliver::DeliverTxMode};
+    use super::{check::CheckTxMode, deliver::DeliverTxMode, simulate::SimulateTxMode};
 
     pub trait Sealed {}
 
     impl<DB, AH: ABCIHandler> Sealed for CheckTxMode<DB, AH> {}
     impl<DB, AH: ABCIHandler> Sealed for DeliverTxMode<DB, AH> {}
+    impl<DB, AH: ABCIHandler> Sealed for SimulateTxMode<DB, AH> {}
 }
 
 pub(crate) fn build_tx_gas_meter(block_height: u32, fee: Option<&Fee>) -> GasMeter<TxKind> {
@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+
+use database::Database;
+use tendermint::types::proto::event::Event;
+
+use super::{ExecutionMode, RunTxMode};
+use crate::{
+    application::handlers::node::ABCIHandler,
+    baseapp::errors::RunTxError,
+    context::{tx::TxContext, TransactionalContext},
+    types::{
+        auth::fee::Fee,
+        gas::{kind::TxKind, GasMeter},
+        tx::raw::TxWithRaw,
+    },
+};
+
+/// Runs transactions on a branch of the check state. Unlike other modes it doesn't
+/// own a state, changes are dropped together with the branch.
+#[derive(Debug)]
+pub struct SimulateTxMode<DB, AH: ABCIHandler>(PhantomData<(DB, AH)>);
+
+impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for SimulateTxMode<DB, AH> {
+    const MODE: RunTxMode = RunTxMode::Simulate;
+
+    fn build_tx_gas_meter(_block_height: u32, _fee: Option<&Fee>) -> GasMeter<TxKind> {
+        // gas limit of the simulated transaction is unknown
+        GasMeter::infinite()
+    }
+
+    fn run_msg<'m>(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        msgs: impl Iterator<Item = &'m AH::Message>,
+    ) -> Result<Vec<Event>, RunTxError> {
+        for msg in msgs {
+            handler
+                .msg(ctx, msg)
+                .inspect_err(|_| ctx.multi_store_mut().clear_cache())?
+        }
+
+        Ok(ctx.events_drain())
+    }
+
+    fn run_ante_checks(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(), RunTxError> {
+        handler
+            .run_ante_checks(ctx, tx_with_raw, RunTxMode::Simulate)
+            .inspect_err(|_| ctx.multi_store_mut().clear_cache())
+            .map_err(RunTxError::from)
+    }
+
+    fn runnable(_: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError> {
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use std::sync::{Arc, RwLock};
 
+use kv_store::PruningOptions;
+
 use crate::{error::POISONED_LOCK, types::base::min_gas::MinGasPrices};
 
 #[derive(Debug, Clone, Default)]
@@ -8,11 +10,15 @@ pub struct NodeOptions(Arc<RwLock<InnerOptions>>);
 #[derive(Debug, Default)]
 struct InnerOptions {
     pub min_gas_prices: MinGasPrices,
+    pub pruning: PruningOptions,
 }
 
 impl NodeOptions {
-    pub fn new(min_gas_prices: MinGasPrices) -> Self {
-        Self(Arc::new(RwLock::new(InnerOptions { min_gas_prices })))
+    pub fn new(min_gas_prices: MinGasPrices, pruning: PruningOptions) -> Self {
+        Self(Arc::new(RwLock::new(InnerOptions {
+            min_gas_prices,
+            pruning,
+        })))
     }
 
     pub fn min_gas_prices(&self) -> MinGasPrices {
@@ -22,4 +28,8 @@ impl NodeOptions {
             .min_gas_prices
             .to_owned()
     }
+
+    pub fn pruning(&self) -> PruningOptions {
+        self.0.read().expect(POISONED_LOCK).pruning
+    }
 }
@@ -4,11 +4,14 @@ use kv_store::StoreKey;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use std::collections::{HashMap, HashSet};
-use tendermint::types::time::duration::{
-    serde_with::{
-        deserialize_duration_opt_from_nanos_string, serialize_duration_opt_to_nanos_string,
+use tendermint::types::time::{
+    duration::{
+        serde_with::{
+            deserialize_duration_opt_from_nanos_string, serialize_duration_opt_to_nanos_string,
+        },
+        Duration,
     },
-    Duration,
+    timestamp::Timestamp,
 };
 
 use crate::{
@@ -30,6 +33,7 @@ mod inner {
 const KEY_BLOCK_PARAMS: &str = "BlockParams";
 const KEY_EVIDENCE_PARAMS: &str = "EvidenceParams";
 const KEY_VALIDATOR_PARAMS: &str = "ValidatorParams";
+const KEY_BLOCK_TIME: &str = "BlockTime";
 
 const _SUBSPACE_NAME: &str = "baseapp/";
 
@@ -273,6 +277,35 @@ impl<PSK: ParamsSubspaceKey> BaseAppParamsKeeper<PSK> {
         )
         .ok()
     }
+
+    /// Stores the time of the block which is being executed. It's committed together with the
+    /// block, so the time of the last committed block is known after a restart.
+    pub fn set_block_time<DB: Database, SK: StoreKey, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        time: Timestamp,
+    ) {
+        let mut store = infallible_subspace_mut(ctx, &self.params_subspace_key);
+
+        let time = serde_json::to_vec(&time).expect("conversion to json won't fail");
+        store.raw_key_set(KEY_BLOCK_TIME.as_bytes().iter().cloned(), time);
+    }
+
+    /// Returns the time of the block in which the state was committed
+    pub fn block_time<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        store: &CTX,
+    ) -> Option<Timestamp> {
+        let sub_store = infallible_subspace(store, &self.params_subspace_key);
+
+        serde_json::from_slice(
+            &sub_store
+                .params_field(KEY_BLOCK_TIME, ParamKind::Bytes)?
+                .bytes()
+                .expect("We sure that this is bytes"),
+        )
+        .ok()
+    }
 }
 
 #[cfg(test)]
@@ -373,4 +406,31 @@ mod tests {
 
         assert_eq!(ConsensusParams::default(), params);
     }
+
+    #[test]
+    fn block_time_set_read_works() {
+        let keeper = BaseAppParamsKeeper {
+            params_subspace_key: SubspaceKey::Params,
+        };
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+
+        let mut ctx = InitContext::new(
+            &mut multi_store,
+            0,
+            Timestamp::UNIX_EPOCH,
+            tendermint::types::chain_id::ChainId::default(),
+            ConsensusParams::default(),
+        );
+
+        assert_eq!(keeper.block_time(&ctx), None);
+
+        let time = Timestamp::try_new(1_000, 5).unwrap_test();
+        keeper.set_block_time(&mut ctx, time);
+
+        assert_eq!(keeper.block_time(&ctx), Some(time));
+        // the block time isn't part of the consensus params
+        assert_eq!(keeper.consensus_params(&ctx), ConsensusParams::default());
+    }
 }
@@ -37,7 +37,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let version = request.height();
 
         let store = self.multi_store.read().expect(POISONED_LOCK);
-        let ctx = QueryContext::new(QueryMultiStore::new(&*store, version)?, version)?;
+        let ctx = QueryContext::new(
+            QueryMultiStore::new(&*store, version)?,
+            version,
+            self.get_last_block_time(),
+        )?;
         Ok(self.abci_handler.typed_query(&ctx, request))
     }
 }
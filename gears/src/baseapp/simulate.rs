@@ -0,0 +1,72 @@
+use bytes::Bytes;
+use database::Database;
+use ibc_proto::cosmos::{
+    base::abci::v1beta1::{GasInfo, Result as AbciResult},
+    tx::v1beta1::SimulateResponse,
+};
+use prost::Message;
+
+use crate::{
+    application::{handlers::node::ABCIHandler, ApplicationInfo},
+    error::POISONED_LOCK,
+    params::ParamsSubspaceKey,
+    types::gas::{Gas, GasMeter},
+};
+
+use super::{errors::RunTxError, mode::simulate::SimulateTxMode, BaseApp, RunTxInfo};
+
+/// ABCI query path of the transaction simulation
+pub const SIMULATE_QUERY_PATH: &str = "/cosmos.tx.v1beta1.Service/Simulate";
+
+pub trait NodeSimulateHandler: Clone + Send + Sync + 'static {
+    /// Run transaction on top of the current check state without persisting any changes
+    fn simulate(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError>;
+}
+
+impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo> NodeSimulateHandler
+    for BaseApp<DB, PSK, H, AI>
+{
+    fn simulate(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError> {
+        let mut multi_store = self
+            .state
+            .read()
+            .expect(POISONED_LOCK)
+            .check_mode
+            .multi_store
+            .branch();
+        let mut block_gas_meter = GasMeter::infinite();
+
+        self.run_tx::<SimulateTxMode<_, _>>(raw, &mut multi_store, &mut block_gas_meter)
+    }
+}
+
+impl From<RunTxInfo> for SimulateResponse {
+    fn from(
+        RunTxInfo {
+            events,
+            gas_wanted,
+            gas_used,
+        }: RunTxInfo,
+    ) -> Self {
+        Self {
+            gas_info: Some(GasInfo {
+                gas_wanted: match gas_wanted {
+                    Gas::Infinite => u64::MAX,
+                    Gas::Finite(gas) => gas.into(),
+                },
+                gas_used: gas_used.into(),
+            }),
+            result: Some(AbciResult {
+                // events of `ibc_proto` come from another version of the tendermint proto crate
+                events: events
+                    .into_iter()
+                    .map(|event| {
+                        Message::decode(event.encode_to_vec().as_slice())
+                            .expect("events have the same encoding")
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+        }
+    }
+}
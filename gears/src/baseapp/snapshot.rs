@@ -0,0 +1,667 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use database::Database;
+use kv_store::{
+    bank::multi::ApplicationMultiBank,
+    query::QueryMultiStore,
+    snapshot::{SnapshotItem, SnapshotRestore},
+    StoreKey,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tendermint::types::{
+    proto::info::Snapshot,
+    response::snapshot::{ApplySnapshotChunkResult, OfferResult},
+};
+
+use crate::error::POISONED_LOCK;
+
+/// Format of snapshots created by [`SnapshotManager`]. A change in the encoding
+/// of snapshot items requires a new format.
+pub const SNAPSHOT_FORMAT: u32 = 1;
+
+/// Max size of a snapshot chunk in bytes
+const CHUNK_SIZE: usize = 10 * 1024 * 1024;
+const METADATA_FILE: &str = "metadata.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("snapshot io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid snapshot metadata: {0}")]
+    Metadata(#[from] serde_json::Error),
+    #[error(transparent)]
+    Store(#[from] kv_store::error::SnapshotError),
+    #[error(transparent)]
+    View(#[from] kv_store::error::KVStoreError),
+}
+
+/// Snapshot creation options
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SnapshotOptions {
+    /// Number of blocks between snapshots, zero disables snapshots
+    pub interval: u32,
+    /// Number of recent snapshots to keep, zero keeps all snapshots
+    pub keep_recent: u32,
+}
+
+impl SnapshotOptions {
+    pub fn should_snapshot(&self, height: u32) -> bool {
+        self.interval != 0 && height != 0 && height.is_multiple_of(self.interval)
+    }
+}
+
+/// Metadata advertised with every snapshot. Allows to verify each chunk as it's received.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SnapshotMetadata {
+    chunk_hashes: Vec<[u8; 32]>,
+}
+
+impl SnapshotMetadata {
+    /// Hash of the whole snapshot is the hash of all chunk hashes
+    fn snapshot_hash(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        for hash in &self.chunk_hashes {
+            hasher.update(hash);
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+/// Snapshot which is being restored from chunks received from peers. Every chunk is
+/// decoded and written into the multi store as soon as it's applied, only the tail of
+/// an item which continues in the next chunk is buffered.
+#[derive(Debug)]
+struct PendingRestore {
+    height: u32,
+    app_hash: [u8; 32],
+    metadata: SnapshotMetadata,
+    applied: usize,
+    tail: Vec<u8>,
+    restore: SnapshotRestore,
+}
+
+/// Result of applying a chunk of a pending restore
+#[derive(Debug)]
+pub enum ApplyChunk {
+    /// Chunk accepted, more chunks are expected
+    Accepted,
+    /// Last chunk accepted, the multi store is restored at `height`
+    Completed { height: u32, app_hash: [u8; 32] },
+    /// Chunk was rejected
+    Rejected(ApplySnapshotChunkResult),
+}
+
+/// Creates snapshots of the multi store in `<dir>/<height>/<format>/`
+/// and keeps the state of snapshot restoration.
+#[derive(Debug)]
+pub struct SnapshotManager {
+    dir: PathBuf,
+    options: SnapshotOptions,
+    creating: AtomicBool,
+    restore: Mutex<Option<PendingRestore>>,
+}
+
+impl SnapshotManager {
+    pub fn new(dir: impl Into<PathBuf>, options: SnapshotOptions) -> Self {
+        Self {
+            dir: dir.into(),
+            options,
+            creating: AtomicBool::new(false),
+            restore: Mutex::new(None),
+        }
+    }
+
+    pub fn options(&self) -> SnapshotOptions {
+        self.options
+    }
+
+    fn snapshot_dir(&self, height: u64, format: u32) -> PathBuf {
+        self.dir.join(height.to_string()).join(format.to_string())
+    }
+
+    /// Returns true while a snapshot is being created. The version which is being
+    /// exported must not be pruned until then.
+    pub fn is_creating(&self) -> bool {
+        self.creating.load(Ordering::Acquire)
+    }
+
+    /// Start creating a snapshot of the multi store at its head version. The snapshot is
+    /// created from a read-only view of the version in a background thread, so the multi
+    /// store is only borrowed to open the view. Returns `false` if another snapshot is
+    /// still being created.
+    pub fn spawn_create<DB: Database, SK: StoreKey>(
+        self: &Arc<Self>,
+        multi_store: &ApplicationMultiBank<DB, SK>,
+    ) -> Result<bool, SnapshotError> {
+        if self.creating.swap(true, Ordering::AcqRel) {
+            return Ok(false);
+        }
+
+        let height = multi_store.head_version();
+        let view = match QueryMultiStore::new(multi_store, height) {
+            Ok(view) => view,
+            Err(err) => {
+                self.creating.store(false, Ordering::Release);
+                return Err(err.into());
+            }
+        };
+
+        let manager = Arc::clone(self);
+        std::thread::spawn(move || {
+            let _creating = CreatingGuard(&manager.creating);
+
+            match manager.create(height, &view) {
+                Ok(snapshot) => tracing::info!(
+                    "Created state snapshot at height {} with {} chunks",
+                    snapshot.height,
+                    snapshot.chunks
+                ),
+                Err(err) => {
+                    tracing::error!("Failed to create snapshot at height {}: {}", height, err)
+                }
+            }
+        });
+
+        Ok(true)
+    }
+
+    /// Export the multi store view of `height` into chunk files
+    pub fn create<DB: Database, SK: StoreKey>(
+        &self,
+        height: u32,
+        multi_store: &QueryMultiStore<DB, SK>,
+    ) -> Result<Snapshot, SnapshotError> {
+        let dir = self.snapshot_dir(height.into(), SNAPSHOT_FORMAT);
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+
+        let mut metadata = SnapshotMetadata {
+            chunk_hashes: Vec::new(),
+        };
+        let mut buf = Vec::with_capacity(CHUNK_SIZE);
+        let mut write_err = None;
+
+        multi_store.export(|item| {
+            if write_err.is_some() {
+                return;
+            }
+
+            item.encode(&mut buf);
+            if buf.len() >= CHUNK_SIZE {
+                if let Err(err) = write_chunk(&dir, &mut metadata, &buf) {
+                    write_err = Some(err);
+                }
+                buf.clear();
+            }
+        });
+
+        if let Some(err) = write_err {
+            return Err(err.into());
+        }
+        if !buf.is_empty() {
+            write_chunk(&dir, &mut metadata, &buf)?;
+        }
+
+        fs::write(dir.join(METADATA_FILE), serde_json::to_vec(&metadata)?)?;
+
+        self.prune()?;
+
+        snapshot_from_metadata(height.into(), metadata)
+    }
+
+    /// Delete old snapshots which shouldn't be kept
+    fn prune(&self) -> Result<(), SnapshotError> {
+        if self.options.keep_recent == 0 {
+            return Ok(());
+        }
+
+        let heights = self.heights()?;
+        let keep = self.options.keep_recent as usize;
+        if heights.len() > keep {
+            for height in &heights[..heights.len() - keep] {
+                fs::remove_dir_all(self.dir.join(height.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Heights of stored snapshots in ascending order
+    fn heights(&self) -> Result<Vec<u64>, SnapshotError> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut heights = fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u64>().ok())
+            .collect::<Vec<_>>();
+        heights.sort();
+
+        Ok(heights)
+    }
+
+    /// List complete snapshots, most recent first
+    pub fn list(&self) -> Result<Vec<Snapshot>, SnapshotError> {
+        let mut snapshots = Vec::new();
+        for height in self.heights()?.into_iter().rev() {
+            let metadata_path = self
+                .snapshot_dir(height, SNAPSHOT_FORMAT)
+                .join(METADATA_FILE);
+
+            // snapshot is either of other format or it's still being created
+            if !metadata_path.exists() {
+                continue;
+            }
+
+            let metadata = serde_json::from_slice(&fs::read(metadata_path)?)?;
+            snapshots.push(snapshot_from_metadata(height, metadata)?);
+        }
+
+        Ok(snapshots)
+    }
+
+    pub fn load_chunk(
+        &self,
+        height: u64,
+        format: u32,
+        chunk: u32,
+    ) -> Result<Option<Vec<u8>>, SnapshotError> {
+        let path = self.snapshot_dir(height, format).join(chunk.to_string());
+
+        match fs::read(path) {
+            Ok(chunk) => Ok(Some(chunk)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Start restoration of an offered snapshot
+    pub fn offer(&self, snapshot: Snapshot, app_hash: &[u8]) -> OfferResult {
+        if snapshot.format != SNAPSHOT_FORMAT {
+            return OfferResult::RejectFormat;
+        }
+
+        if snapshot.chunks == 0 {
+            return OfferResult::Reject;
+        }
+
+        let (Ok(height), Ok(app_hash)) = (u32::try_from(snapshot.height), app_hash.try_into())
+        else {
+            return OfferResult::Reject;
+        };
+
+        let metadata: SnapshotMetadata = match serde_json::from_slice(&snapshot.metadata) {
+            Ok(metadata) => metadata,
+            Err(_) => return OfferResult::Reject,
+        };
+
+        if metadata.chunk_hashes.len() != snapshot.chunks as usize
+            || metadata.snapshot_hash().as_slice() != snapshot.hash
+        {
+            return OfferResult::Reject;
+        }
+
+        *self.restore.lock().expect(POISONED_LOCK) = Some(PendingRestore {
+            height,
+            app_hash,
+            metadata,
+            applied: 0,
+            tail: Vec::new(),
+            restore: SnapshotRestore::default(),
+        });
+
+        OfferResult::Accept
+    }
+
+    /// Apply chunk of the snapshot which is being restored. Chunks are applied in order,
+    /// the restored state is verified and saved once the last chunk is applied.
+    pub fn apply_chunk<DB: Database, SK: StoreKey>(
+        &self,
+        index: u32,
+        chunk: Vec<u8>,
+        multi_store: &mut ApplicationMultiBank<DB, SK>,
+    ) -> ApplyChunk {
+        let mut guard = self.restore.lock().expect(POISONED_LOCK);
+        let Some(restore) = guard.as_mut() else {
+            return ApplyChunk::Rejected(ApplySnapshotChunkResult::Abort);
+        };
+
+        let index = index as usize;
+        if index != restore.applied {
+            return ApplyChunk::Rejected(ApplySnapshotChunkResult::Retry);
+        }
+
+        let hash: [u8; 32] = Sha256::digest(&chunk).into();
+        if restore.metadata.chunk_hashes.get(index) != Some(&hash) {
+            return ApplyChunk::Rejected(ApplySnapshotChunkResult::Retry);
+        }
+
+        let mut bytes = if restore.tail.is_empty() {
+            chunk
+        } else {
+            [std::mem::take(&mut restore.tail), chunk].concat()
+        };
+        let mut items = Vec::new();
+        let decoded = decode_items(&bytes, &mut items);
+        restore.tail = bytes.split_off(decoded);
+        restore.applied += 1;
+
+        if let Err(err) = multi_store.restore_items(&mut restore.restore, items) {
+            tracing::error!("Failed to restore snapshot chunk {index}: {err}");
+            *guard = None;
+
+            return ApplyChunk::Rejected(ApplySnapshotChunkResult::RejectSnapshot);
+        }
+
+        if restore.applied < restore.metadata.chunk_hashes.len() {
+            return ApplyChunk::Accepted;
+        }
+
+        let PendingRestore {
+            height,
+            app_hash,
+            tail,
+            restore,
+            ..
+        } = guard.take().expect("restore is checked above");
+
+        // the last chunk must end with a complete item
+        if !tail.is_empty() {
+            return ApplyChunk::Rejected(ApplySnapshotChunkResult::RejectSnapshot);
+        }
+
+        if let Err(err) = multi_store.finish_restore(restore, height, app_hash) {
+            tracing::error!("Failed to restore snapshot at height {height}: {err}");
+
+            return ApplyChunk::Rejected(ApplySnapshotChunkResult::RejectSnapshot);
+        }
+
+        ApplyChunk::Completed { height, app_hash }
+    }
+}
+
+/// Resets the flag of a snapshot creation even if the creation panics
+struct CreatingGuard<'a>(&'a AtomicBool);
+
+impl Drop for CreatingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+/// Decode complete items from the start of `bytes` into `items`. Returns the number of
+/// decoded bytes, the rest is either a truncated item or invalid.
+fn decode_items(bytes: &[u8], items: &mut Vec<SnapshotItem>) -> usize {
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        let mut next = rest;
+        match SnapshotItem::decode(&mut next) {
+            Ok(item) => {
+                items.push(item);
+                rest = next;
+            }
+            Err(_) => break,
+        }
+    }
+
+    bytes.len() - rest.len()
+}
+
+fn write_chunk(
+    dir: &Path,
+    metadata: &mut SnapshotMetadata,
+    chunk: &[u8],
+) -> Result<(), std::io::Error> {
+    fs::write(dir.join(metadata.chunk_hashes.len().to_string()), chunk)?;
+    metadata.chunk_hashes.push(Sha256::digest(chunk).into());
+
+    Ok(())
+}
+
+fn snapshot_from_metadata(
+    height: u64,
+    metadata: SnapshotMetadata,
+) -> Result<Snapshot, SnapshotError> {
+    Ok(Snapshot {
+        height,
+        format: SNAPSHOT_FORMAT,
+        chunks: metadata.chunk_hashes.len() as u32,
+        hash: metadata.snapshot_hash().to_vec().into(),
+        metadata: serde_json::to_vec(&metadata)?.into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+    use strum::EnumIter;
+
+    use super::*;
+
+    #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+    enum TestStoreKey {
+        Bank,
+        Auth,
+    }
+
+    impl StoreKey for TestStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                TestStoreKey::Bank => "bank",
+                TestStoreKey::Auth => "acc",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &TestStoreKey::Auth
+        }
+    }
+
+    fn multi_store() -> ApplicationMultiBank<MemDB, TestStoreKey> {
+        ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test()
+    }
+
+    /// Returns the app hash of a committed multi store and its snapshot with an item per chunk
+    fn encoded_chunks() -> ([u8; 32], Vec<Vec<u8>>) {
+        let mut multi_store = multi_store();
+        for i in 1..=3 {
+            multi_store
+                .kv_store_mut(&TestStoreKey::Bank)
+                .set(vec![i], vec![i]);
+        }
+        let app_hash = multi_store.commit();
+
+        let mut chunks = Vec::new();
+        QueryMultiStore::new(&multi_store, 1)
+            .unwrap_test()
+            .export(|item| {
+                let mut buf = Vec::new();
+                item.encode(&mut buf);
+                chunks.push(buf);
+            });
+
+        (app_hash, chunks)
+    }
+
+    fn offered_manager(chunks: &[Vec<u8>], app_hash: [u8; 32]) -> SnapshotManager {
+        let metadata = SnapshotMetadata {
+            chunk_hashes: chunks
+                .iter()
+                .map(|chunk| Sha256::digest(chunk).into())
+                .collect(),
+        };
+        let snapshot = snapshot_from_metadata(1, metadata).expect("hardcoded is valid");
+
+        let manager = SnapshotManager::new("snapshots", SnapshotOptions::default());
+        assert_eq!(manager.offer(snapshot, &app_hash), OfferResult::Accept);
+
+        manager
+    }
+
+    /// Applies all chunks but the last one, which must be applied by the caller
+    fn apply_all_but_last(
+        manager: &SnapshotManager,
+        chunks: &[Vec<u8>],
+        multi_store: &mut ApplicationMultiBank<MemDB, TestStoreKey>,
+    ) {
+        for (index, chunk) in chunks[..chunks.len() - 1].iter().enumerate() {
+            assert!(matches!(
+                manager.apply_chunk(index as u32, chunk.clone(), multi_store),
+                ApplyChunk::Accepted
+            ));
+        }
+    }
+
+    #[test]
+    fn apply_chunks_completes_restore() {
+        let (app_hash, chunks) = encoded_chunks();
+        let manager = offered_manager(&chunks, app_hash);
+        let mut multi_store = multi_store();
+
+        apply_all_but_last(&manager, &chunks, &mut multi_store);
+        // every applied chunk is written, but not reachable until the restore is completed
+        assert_eq!(multi_store.head_version(), 0);
+        assert_eq!(multi_store.kv_store(&TestStoreKey::Bank).get(&[1]), None);
+
+        let last = chunks.len() - 1;
+        match manager.apply_chunk(last as u32, chunks[last].clone(), &mut multi_store) {
+            ApplyChunk::Completed {
+                height,
+                app_hash: restored_hash,
+            } => {
+                assert_eq!(height, 1);
+                assert_eq!(restored_hash, app_hash);
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+
+        assert_eq!(multi_store.head_version(), 1);
+        assert_eq!(multi_store.head_commit_hash(), app_hash);
+        assert_eq!(
+            multi_store.kv_store(&TestStoreKey::Bank).get(&[2]),
+            Some(vec![2])
+        );
+    }
+
+    #[test]
+    fn apply_invalid_chunk_retries() {
+        let (app_hash, chunks) = encoded_chunks();
+        let manager = offered_manager(&chunks, app_hash);
+        let mut multi_store = multi_store();
+
+        assert!(matches!(
+            manager.apply_chunk(0, chunks[1].clone(), &mut multi_store),
+            ApplyChunk::Rejected(ApplySnapshotChunkResult::Retry)
+        ));
+        assert!(matches!(
+            manager.apply_chunk(0, chunks[0].clone(), &mut multi_store),
+            ApplyChunk::Accepted
+        ));
+    }
+
+    #[test]
+    fn apply_chunks_with_split_item() {
+        let (app_hash, chunks) = encoded_chunks();
+        let bytes = chunks.concat();
+        // the second chunk starts in the middle of the first item
+        let chunks = vec![bytes[..2].to_vec(), bytes[2..].to_vec()];
+        let manager = offered_manager(&chunks, app_hash);
+        let mut multi_store = multi_store();
+
+        apply_all_but_last(&manager, &chunks, &mut multi_store);
+
+        assert!(matches!(
+            manager.apply_chunk(1, chunks[1].clone(), &mut multi_store),
+            ApplyChunk::Completed { height: 1, .. }
+        ));
+        assert_eq!(multi_store.head_commit_hash(), app_hash);
+    }
+
+    #[test]
+    fn apply_truncated_snapshot_rejects() {
+        let (app_hash, chunks) = encoded_chunks();
+        let mut chunk = chunks.concat();
+        chunk.pop();
+        let manager = offered_manager(&[chunk.clone()], app_hash);
+        let mut multi_store = multi_store();
+
+        assert!(matches!(
+            manager.apply_chunk(0, chunk, &mut multi_store),
+            ApplyChunk::Rejected(ApplySnapshotChunkResult::RejectSnapshot)
+        ));
+        assert_eq!(multi_store.head_version(), 0);
+    }
+
+    #[test]
+    fn apply_snapshot_with_wrong_hash_rejects() {
+        let (_, chunks) = encoded_chunks();
+        let manager = offered_manager(&chunks, [1; 32]);
+        let mut multi_store = multi_store();
+
+        apply_all_but_last(&manager, &chunks, &mut multi_store);
+
+        let last = chunks.len() - 1;
+        assert!(matches!(
+            manager.apply_chunk(last as u32, chunks[last].clone(), &mut multi_store),
+            ApplyChunk::Rejected(ApplySnapshotChunkResult::RejectSnapshot)
+        ));
+        assert_eq!(multi_store.head_version(), 0);
+    }
+
+    #[test]
+    fn apply_unknown_store_rejects() {
+        let mut chunk = Vec::new();
+        SnapshotItem::Store("unknown".to_owned()).encode(&mut chunk);
+        let manager = offered_manager(&[chunk.clone(), chunk.clone()], [1; 32]);
+
+        assert!(matches!(
+            manager.apply_chunk(0, chunk.clone(), &mut multi_store()),
+            ApplyChunk::Rejected(ApplySnapshotChunkResult::RejectSnapshot)
+        ));
+        // the restore is aborted
+        assert!(matches!(
+            manager.apply_chunk(1, chunk, &mut multi_store()),
+            ApplyChunk::Rejected(ApplySnapshotChunkResult::Abort)
+        ));
+    }
+
+    #[test]
+    fn offer_rejects_empty_snapshot() {
+        let manager = SnapshotManager::new("snapshots", SnapshotOptions::default());
+        let snapshot = snapshot_from_metadata(
+            10,
+            SnapshotMetadata {
+                chunk_hashes: Vec::new(),
+            },
+        )
+        .expect("hardcoded is valid");
+
+        assert_eq!(manager.offer(snapshot, &[1; 32]), OfferResult::Reject);
+    }
+
+    #[test]
+    fn offer_rejects_unknown_format() {
+        let manager = SnapshotManager::new("snapshots", SnapshotOptions::default());
+        let snapshot = Snapshot {
+            height: 10,
+            format: SNAPSHOT_FORMAT + 1,
+            chunks: 0,
+            hash: Default::default(),
+            metadata: Default::default(),
+        };
+
+        assert_eq!(manager.offer(snapshot, &[1; 32]), OfferResult::RejectFormat);
+    }
+}
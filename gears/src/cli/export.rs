@@ -0,0 +1,38 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{application::ApplicationInfo, commands::node::export::ExportCommand};
+
+/// Export state to JSON
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliExportCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    pub home: PathBuf,
+    /// Export state from a particular height (latest height by default)
+    #[arg(long, action = ArgAction::Set)]
+    pub height: Option<u32>,
+    /// Export state to start at height zero (perform preproccessing)
+    #[arg(long, default_value_t = false)]
+    pub for_zero_height: bool,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliExportCommand<T>> for ExportCommand {
+    fn from(
+        CliExportCommand {
+            home,
+            height,
+            for_zero_height,
+            _marker,
+        }: CliExportCommand<T>,
+    ) -> Self {
+        Self {
+            home,
+            height,
+            for_zero_height,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::client::keys::{ImportKeyCommand, KeyringBackend},
+};
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(
+    about = "Import an ASCII-armored encrypted private key from <KEYFILE> saving it to <NAME> file"
+)]
+pub struct CliImportKeyCommand<T: ApplicationInfo> {
+    #[arg(required = true)]
+    name: String,
+    #[arg(required = true, value_hint = ValueHint::FilePath)]
+    keyfile: PathBuf,
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// select keyring's backend
+    #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
+    keyring_backend: KeyringBackend,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliImportKeyCommand<T>> for ImportKeyCommand {
+    fn from(value: CliImportKeyCommand<T>) -> Self {
+        let CliImportKeyCommand {
+            name,
+            keyfile,
+            home,
+            keyring_backend,
+            _marker,
+        } = value;
+
+        Self {
+            name,
+            keyfile,
+            home,
+            keyring_backend,
+        }
+    }
+}
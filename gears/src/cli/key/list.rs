@@ -0,0 +1,37 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::client::keys::{KeyringBackend, ListKeyCommand},
+};
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(about = "List all keys with their addresses and public keys")]
+pub struct CliListKeyCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// select keyring's backend
+    #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
+    keyring_backend: KeyringBackend,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliListKeyCommand<T>> for ListKeyCommand {
+    fn from(value: CliListKeyCommand<T>) -> Self {
+        let CliListKeyCommand {
+            home,
+            keyring_backend,
+            _marker,
+        } = value;
+
+        Self {
+            home,
+            keyring_backend,
+        }
+    }
+}
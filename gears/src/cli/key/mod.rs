@@ -1,19 +1,41 @@
 use crate::{application::ApplicationInfo, commands::client::keys::KeyCommand};
 
-use self::add::CliAddKeyCommand;
+use self::{
+    add::CliAddKeyCommand, delete::CliDeleteKeyCommand, export::CliExportKeyCommand,
+    import::CliImportKeyCommand, list::CliListKeyCommand, rename::CliRenameKeyCommand,
+    show::CliShowKeyCommand,
+};
 
 pub mod add;
+pub mod delete;
+pub mod export;
+pub mod import;
+pub mod list;
+pub mod rename;
+pub mod show;
 
 #[derive(Debug, Clone, ::clap::Subcommand)]
 #[command(about = "Manage your application's keys")]
 pub enum CliKeyCommand<T: ApplicationInfo> {
     Add(CliAddKeyCommand<T>),
+    List(CliListKeyCommand<T>),
+    Show(CliShowKeyCommand<T>),
+    Delete(CliDeleteKeyCommand<T>),
+    Export(CliExportKeyCommand<T>),
+    Import(CliImportKeyCommand<T>),
+    Rename(CliRenameKeyCommand<T>),
 }
 
 impl<T: ApplicationInfo> From<CliKeyCommand<T>> for KeyCommand {
     fn from(value: CliKeyCommand<T>) -> Self {
         match value {
             CliKeyCommand::Add(cmd) => KeyCommand::Add(cmd.into()),
+            CliKeyCommand::List(cmd) => KeyCommand::List(cmd.into()),
+            CliKeyCommand::Show(cmd) => KeyCommand::Show(cmd.into()),
+            CliKeyCommand::Delete(cmd) => KeyCommand::Delete(cmd.into()),
+            CliKeyCommand::Export(cmd) => KeyCommand::Export(cmd.into()),
+            CliKeyCommand::Import(cmd) => KeyCommand::Import(cmd.into()),
+            CliKeyCommand::Rename(cmd) => KeyCommand::Rename(cmd.into()),
         }
     }
 }
@@ -0,0 +1,49 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::client::keys::{KeyringBackend, RenameKeyCommand},
+};
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(about = "Rename the key with the given <OLD_NAME> to <NEW_NAME>")]
+pub struct CliRenameKeyCommand<T: ApplicationInfo> {
+    #[arg(required = true)]
+    old_name: String,
+    #[arg(required = true)]
+    new_name: String,
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// select keyring's backend
+    #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
+    keyring_backend: KeyringBackend,
+    #[arg(short, long, action = ArgAction::SetTrue, help = "Skip confirmation prompt when renaming the key")]
+    yes: bool,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliRenameKeyCommand<T>> for RenameKeyCommand {
+    fn from(value: CliRenameKeyCommand<T>) -> Self {
+        let CliRenameKeyCommand {
+            old_name,
+            new_name,
+            home,
+            keyring_backend,
+            yes,
+            _marker,
+        } = value;
+
+        Self {
+            old_name,
+            new_name,
+            home,
+            keyring_backend,
+            yes,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::client::keys::{Bech32Type, KeyringBackend, ShowKeyCommand},
+};
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(
+    about = "Display key details of the given <NAMES>, multiple names are combined into a multisig key"
+)]
+pub struct CliShowKeyCommand<T: ApplicationInfo> {
+    #[arg(required = true, num_args = 1..)]
+    names: Vec<String>,
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// select keyring's backend
+    #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
+    keyring_backend: KeyringBackend,
+    /// the bech32 prefix format of the displayed address
+    #[arg(long, action = ArgAction::Set, default_value_t = Bech32Type::Acc)]
+    bech: Bech32Type,
+    #[arg(short, long, action = ArgAction::SetTrue, help = "Output the address only")]
+    address: bool,
+    #[arg(long, action = ArgAction::Set, default_value_t = 1, help = "K out of N required signatures. For use in conjunction with multiple names")]
+    multisig_threshold: u32,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliShowKeyCommand<T>> for ShowKeyCommand {
+    fn from(value: CliShowKeyCommand<T>) -> Self {
+        let CliShowKeyCommand {
+            names,
+            home,
+            keyring_backend,
+            bech,
+            address,
+            multisig_threshold,
+            _marker,
+        } = value;
+
+        Self {
+            names,
+            home,
+            keyring_backend,
+            bech,
+            address,
+            multisig_threshold,
+        }
+    }
+}
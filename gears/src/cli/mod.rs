@@ -10,6 +10,7 @@ use crate::{
 };
 
 use self::{
+    export::CliExportCommand,
     genesis::CliGenesisCommand,
     init::CliInitCommand,
     key::CliKeyCommand,
@@ -17,10 +18,14 @@ use self::{
     query_txs::{CliQueryTxCommand, CliQueryTxsCommand},
     run::CliRunCommand,
     tx::CliTxCommand,
+    tx_file::{
+        CliBroadcastCommand, CliMultisignCommand, CliSignCommand, CliValidateSignaturesCommand,
+    },
 };
 
 pub mod aux;
 mod config;
+pub mod export;
 pub mod genesis;
 pub mod init;
 pub mod key;
@@ -29,6 +34,7 @@ pub mod query;
 pub mod query_txs;
 pub mod run;
 pub mod tx;
+pub mod tx_file;
 
 fn write_completions<G: Generator>(gen: G, cmd: &mut Command, buf: &mut dyn Write) {
     generate(gen, cmd, cmd.get_name().to_string(), buf);
@@ -172,6 +178,10 @@ where
     Query(CliQueryCommand<T, CliQue>),
     QueryTx(CliQueryTxCommand<T>),
     QueryTxs(CliQueryTxsCommand<T>),
+    Sign(CliSignCommand<T>),
+    Multisign(CliMultisignCommand<T>),
+    ValidateSignatures(CliValidateSignaturesCommand<T>),
+    Broadcast(CliBroadcastCommand<T>),
     #[command(subcommand)]
     Keys(CliKeyCommand<T>),
 }
@@ -195,6 +205,10 @@ where
             CliClientCommands::Query(cmd) => Self::Query(cmd.try_into()?),
             CliClientCommands::QueryTx(cmd) => Self::QueryTx(cmd.into()),
             CliClientCommands::QueryTxs(cmd) => Self::QueryTxs(cmd.into()),
+            CliClientCommands::Sign(cmd) => Self::Sign(cmd.try_into()?),
+            CliClientCommands::Multisign(cmd) => Self::Multisign(cmd.try_into()?),
+            CliClientCommands::ValidateSignatures(cmd) => Self::ValidateSignatures(cmd.into()),
+            CliClientCommands::Broadcast(cmd) => Self::Broadcast(cmd.into()),
             CliClientCommands::Keys(cmd) => Self::Keys(cmd.into()),
         };
 
@@ -208,6 +222,7 @@ pub enum CliAppCommands<T: ApplicationInfo, CliAUX: Subcommand> {
     Run(CliRunCommand<T>),
     #[command(name = "add-genesis-account")]
     GenesisAdd(CliGenesisCommand<T>),
+    Export(CliExportCommand<T>),
     #[command(flatten)]
     Aux(CliAUX),
 }
@@ -225,6 +240,7 @@ where
             CliAppCommands::Init(cmd) => Self::Init(cmd.into()),
             CliAppCommands::Run(cmd) => Self::Run(cmd.into()),
             CliAppCommands::GenesisAdd(cmd) => Self::GenesisAdd(cmd.into()),
+            CliAppCommands::Export(cmd) => Self::Export(cmd.into()),
             CliAppCommands::Aux(cmd) => Self::Aux(cmd.try_into()?),
         };
 
@@ -1,4 +1,4 @@
-use std::{marker::PhantomData, path::PathBuf};
+use std::{marker::PhantomData, path::PathBuf, str::FromStr};
 
 use address::AccAddress;
 use clap::{ArgAction, Args, Subcommand, ValueEnum, ValueHint};
@@ -12,6 +12,7 @@ use crate::{
         keys::KeyringBackend,
         tx::{AccountProvider, ClientTxContext, Keyring as TxKeyring, LocalInfo, TxCommand},
     },
+    crypto::public::PublicKey,
     types::{
         auth::{fee::Fee, gas::Gas},
         base::coins::UnsignedCoins,
@@ -52,6 +53,14 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     #[arg(long, global = true, action = ArgAction::Set, required = false )]
     pub timeout_height: Option<u32>,
 
+    /// Print an unsigned transaction to be signed by the `sign` and `multisign` commands
+    #[arg(long, global = true, default_value_t = false)]
+    pub generate_only: bool,
+
+    /// Public key of the signer in JSON or a path to a file with it, replaces the keyring in generate only mode
+    #[arg(long, global = true, requires = "generate_only", value_parser = parse_public_key)]
+    pub from_pubkey: Option<PublicKey>,
+
     #[command(flatten)]
     pub command: C,
 
@@ -59,12 +68,43 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     _marker: PhantomData<T>,
 }
 
+const DEFAULT_GAS_LIMIT: u64 = 200_000;
+
+/// Gas limit of a transaction or `auto` to estimate it by simulation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasLimitCli {
+    Auto,
+    Limit(u64),
+}
+
+impl FromStr for GasLimitCli {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            _ => Ok(Self::Limit(s.parse()?)),
+        }
+    }
+}
+
+impl std::fmt::Display for GasLimitCli {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::Limit(limit) => write!(f, "{limit}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct FeeCli {
-    // TODO: Cosmos has "auto" feature to calculate gas price if needed
-    /// gas limit to set per-transaction
-    #[arg(long, short, global = true, action = ArgAction::Set, default_value_t = 200_000)]
-    pub gas_limit: u64,
+    /// gas limit to set per-transaction; set to "auto" to calculate sufficient gas automatically
+    #[arg(long = "gas", alias = "gas-limit", short, global = true, action = ArgAction::Set, default_value_t = GasLimitCli::Limit(DEFAULT_GAS_LIMIT))]
+    pub gas_limit: GasLimitCli,
+    /// adjustment factor to be multiplied against the estimate returned by the tx simulation
+    #[arg(long, global = true, action = ArgAction::Set, default_value_t = 1.0)]
+    pub gas_adjustment: f64,
     /// Fees to pay along with transaction; eg: 10uatom
     #[arg(long, global = true, action = ArgAction::Set)]
     pub fees: Option<UnsignedCoins>,
@@ -82,12 +122,17 @@ impl TryFrom<FeeCli> for Fee {
     fn try_from(
         FeeCli {
             gas_limit,
+            gas_adjustment: _,
             fees,
             payer,
             granter,
         }: FeeCli,
     ) -> Result<Self, Self::Error> {
-        let gas_limit = Gas::try_from(gas_limit)?;
+        // the limit is replaced by the estimate before signing
+        let gas_limit = match gas_limit {
+            GasLimitCli::Auto => Gas::try_from(DEFAULT_GAS_LIMIT)?,
+            GasLimitCli::Limit(limit) => Gas::try_from(limit)?,
+        };
 
         if granter.as_ref().is_some_and(|this| this.is_empty()) {
             Err(anyhow::anyhow!("`fee-granter` can't be empty"))?
@@ -105,6 +150,16 @@ impl TryFrom<FeeCli> for Fee {
     }
 }
 
+/// Parses a public key in JSON format, the key is read from a file if the value is a path to one
+pub(crate) fn parse_public_key(value: &str) -> anyhow::Result<PublicKey> {
+    let json = match std::path::Path::new(value).is_file() {
+        true => std::fs::read_to_string(value)?,
+        false => value.to_owned(),
+    };
+
+    Ok(serde_json::from_str(&json)?)
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct Mode {
     /// makes sure that the client will not reach out to full node.
@@ -121,6 +176,38 @@ pub struct Mode {
     pub account_number: Option<u64>,
 }
 
+impl From<Mode> for AccountProvider {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode {
+                offline: true,
+                sequence,
+                account_number,
+            } => AccountProvider::Offline {
+                sequence: sequence.unwrap_or_default(),
+                account_number: account_number.unwrap_or_default(),
+            },
+            Mode {
+                offline: false,
+                sequence: Some(sequence),
+                account_number,
+            } => AccountProvider::Offline {
+                sequence,
+                account_number: account_number.unwrap_or_default(),
+            },
+            Mode {
+                offline: false,
+                sequence,
+                account_number: Some(account_number),
+            } => AccountProvider::Offline {
+                sequence: sequence.unwrap_or_default(),
+                account_number,
+            },
+            _ => AccountProvider::Online,
+        }
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone, Display)]
 pub enum Keyring {
     /// Use a Ledger device to sign the transaction
@@ -131,6 +218,31 @@ pub enum Keyring {
     Local,
 }
 
+impl Keyring {
+    pub(crate) fn into_tx_keyring<T: ApplicationInfo>(
+        self,
+        local: Option<Local<T>>,
+    ) -> Result<TxKeyring, MissingCliOptions> {
+        match self {
+            Keyring::Ledger => Ok(TxKeyring::Ledger),
+            Keyring::Local => {
+                let Local {
+                    from_key,
+                    keyring_backend,
+                    ..
+                } = local.ok_or(MissingCliOptions(
+                    "local signing options: from-key".to_owned(),
+                ))?;
+
+                Ok(TxKeyring::Local(LocalInfo {
+                    keyring_backend,
+                    from_key,
+                }))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct Local<T: ApplicationInfo> {
     /// from key
@@ -176,54 +288,22 @@ where
             mode,
             note,
             timeout_height,
+            generate_only,
+            from_pubkey,
             fee,
             command,
         } = value;
 
-        let keyring = match keyring {
-            Keyring::Ledger => TxKeyring::Ledger,
-            Keyring::Local => {
-                let Local {
-                    from_key,
-                    keyring_backend,
-                    ..
-                } = local.ok_or(MissingCliOptions(
-                    "local signing options: from-key".to_owned(),
-                ))?;
-
-                TxKeyring::Local(LocalInfo {
-                    keyring_backend,
-                    from_key,
-                })
-            }
+        let keyring = match from_pubkey {
+            Some(public_key) => TxKeyring::PublicKey(public_key),
+            None => keyring.into_tx_keyring(local)?,
         };
+        let account = mode.into();
 
-        let account = match mode {
-            Mode {
-                offline: true,
-                sequence,
-                account_number,
-            } => AccountProvider::Offline {
-                sequence: sequence.unwrap_or_default(),
-                account_number: account_number.unwrap_or_default(),
-            },
-            Mode {
-                offline: false,
-                sequence: Some(sequence),
-                account_number,
-            } => AccountProvider::Offline {
-                sequence,
-                account_number: account_number.unwrap_or_default(),
-            },
-            Mode {
-                offline: false,
-                sequence,
-                account_number: Some(account_number),
-            } => AccountProvider::Offline {
-                sequence: sequence.unwrap_or_default(),
-                account_number,
-            },
-            _ => AccountProvider::Online,
+        let gas_adjustment = match fee.gas_limit {
+            GasLimitCli::Auto if fee.gas_adjustment > 0.0 => Some(fee.gas_adjustment),
+            GasLimitCli::Auto => Err(anyhow::anyhow!("`gas-adjustment` must be positive"))?,
+            GasLimitCli::Limit(_) => None,
         };
 
         Ok(Self {
@@ -237,6 +317,8 @@ where
                 memo: note,
                 timeout_height,
                 fee: fee.try_into()?,
+                gas_adjustment,
+                generate_only,
             },
         })
     }
@@ -0,0 +1,238 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use address::AccAddress;
+use clap::{ArgAction, ValueEnum, ValueHint};
+use core_types::tx::mode_info::SignMode;
+use strum::Display;
+use tendermint::types::chain_id::ChainId;
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::client::tx_file::{
+        BroadcastCommand, MultisignCommand, SignCommand, ValidateSignaturesCommand,
+    },
+    crypto::public::PublicKey,
+};
+
+use super::tx::{parse_public_key, Keyring, Local, Mode};
+
+#[derive(ValueEnum, Debug, Clone, Display)]
+pub enum CliSignMode {
+    /// Sign bytes of the transaction, signer infos of all signers have to be set beforehand
+    #[strum(to_string = "direct")]
+    Direct,
+    /// Sign the legacy amino JSON representation of the transaction
+    #[strum(to_string = "amino-json")]
+    AminoJson,
+}
+
+impl From<CliSignMode> for SignMode {
+    fn from(mode: CliSignMode) -> Self {
+        match mode {
+            CliSignMode::Direct => SignMode::Direct,
+            CliSignMode::AminoJson => SignMode::LegacyAminoJson,
+        }
+    }
+}
+
+/// Sign a transaction file created with `--generate-only`
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliSignCommand<T: ApplicationInfo> {
+    #[arg(value_hint = ValueHint::FilePath)]
+    file: PathBuf,
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    node: url::Url,
+    /// the network chain-id
+    #[arg(long =  "chain-id", action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).chain_id())]
+    chain_id: ChainId,
+
+    #[arg(long, short, default_value_t = Keyring::Local)]
+    keyring: Keyring,
+
+    #[command(flatten)]
+    #[group(id = "local", conflicts_with = Keyring::Ledger)]
+    local: Option<Local<T>>,
+
+    #[command(flatten)]
+    #[group(id = "Broadcast mode")]
+    mode: Mode,
+
+    /// sign mode of the signature, multisig signatures use amino-json
+    #[arg(long, action = ArgAction::Set)]
+    sign_mode: Option<CliSignMode>,
+    /// address of the multisig account, the signature is written separately to be combined by `multisign`
+    #[arg(long, action = ArgAction::Set)]
+    multisig: Option<AccAddress>,
+    /// write the result to the given file instead of the standard output
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::FilePath)]
+    output_document: Option<PathBuf>,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> TryFrom<CliSignCommand<T>> for SignCommand {
+    type Error = anyhow::Error;
+
+    fn try_from(value: CliSignCommand<T>) -> Result<Self, Self::Error> {
+        let CliSignCommand {
+            file,
+            home,
+            node,
+            chain_id,
+            keyring,
+            local,
+            mode,
+            sign_mode,
+            multisig,
+            output_document,
+            _marker,
+        } = value;
+
+        let sign_mode = match (sign_mode, &multisig) {
+            (Some(sign_mode), _) => sign_mode.into(),
+            (None, Some(_)) => SignMode::LegacyAminoJson,
+            (None, None) => SignMode::Direct,
+        };
+
+        Ok(Self {
+            file,
+            home,
+            node,
+            chain_id,
+            keyring: keyring.into_tx_keyring(local)?,
+            account: mode.into(),
+            sign_mode,
+            multisig,
+            output_document,
+        })
+    }
+}
+
+/// Combine signatures of multisig members into a multisignature of the transaction file
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliMultisignCommand<T: ApplicationInfo> {
+    #[arg(value_hint = ValueHint::FilePath)]
+    file: PathBuf,
+    /// multisig public key in JSON or a path to a file with it, as printed by `keys show` with multiple names
+    #[arg(value_parser = parse_public_key)]
+    multisig_key: PublicKey,
+    /// signature files created by `sign --multisig`
+    #[arg(required = true, num_args = 1.., value_hint = ValueHint::FilePath)]
+    signature_files: Vec<PathBuf>,
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    node: url::Url,
+    /// the network chain-id
+    #[arg(long =  "chain-id", action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).chain_id())]
+    chain_id: ChainId,
+
+    #[command(flatten)]
+    #[group(id = "Broadcast mode")]
+    mode: Mode,
+
+    /// write the result to the given file instead of the standard output
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::FilePath)]
+    output_document: Option<PathBuf>,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> TryFrom<CliMultisignCommand<T>> for MultisignCommand {
+    type Error = anyhow::Error;
+
+    fn try_from(value: CliMultisignCommand<T>) -> Result<Self, Self::Error> {
+        let CliMultisignCommand {
+            file,
+            multisig_key,
+            signature_files,
+            node,
+            chain_id,
+            mode,
+            output_document,
+            _marker,
+        } = value;
+
+        let PublicKey::Multisig(multisig_key) = multisig_key else {
+            Err(anyhow::anyhow!("{multisig_key:?} is not a multisig key"))?
+        };
+
+        Ok(Self {
+            file,
+            multisig_key,
+            signature_files,
+            node,
+            chain_id,
+            account: mode.into(),
+            output_document,
+        })
+    }
+}
+
+/// Validate that the transaction file is signed by the required signers
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliValidateSignaturesCommand<T: ApplicationInfo> {
+    #[arg(value_hint = ValueHint::FilePath)]
+    file: PathBuf,
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    node: url::Url,
+    /// the network chain-id
+    #[arg(long =  "chain-id", action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).chain_id())]
+    chain_id: ChainId,
+    /// check signers only, signatures can't be verified without account numbers from a node
+    #[arg(long, default_value_t = false)]
+    offline: bool,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliValidateSignaturesCommand<T>> for ValidateSignaturesCommand {
+    fn from(value: CliValidateSignaturesCommand<T>) -> Self {
+        let CliValidateSignaturesCommand {
+            file,
+            node,
+            chain_id,
+            offline,
+            _marker,
+        } = value;
+
+        Self {
+            file,
+            node,
+            chain_id,
+            offline,
+        }
+    }
+}
+
+/// Broadcast a signed transaction file
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliBroadcastCommand<T: ApplicationInfo> {
+    #[arg(value_hint = ValueHint::FilePath)]
+    file: PathBuf,
+    /// <host>:<port> to Tendermint RPC interface for this chain
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::Url, env = "GEARS_NODE", default_value_t = client_config(&T::home_dir()).node())]
+    node: url::Url,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliBroadcastCommand<T>> for BroadcastCommand {
+    fn from(value: CliBroadcastCommand<T>) -> Self {
+        let CliBroadcastCommand {
+            file,
+            node,
+            _marker,
+        } = value;
+
+        Self { file, node }
+    }
+}
@@ -1,11 +1,19 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use bip32::Mnemonic;
+use keyring::key::pair::KeyPair;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{io::Write, path::PathBuf};
 use strum::Display;
 use text_io::read;
 
-use crate::crypto::keys::ReadAccAddress;
+use crate::{
+    crypto::{
+        keys::{GearsPublicKey, ReadAccAddress},
+        multisig::LegacyAminoPubKey,
+        public::PublicKey,
+    },
+    types::address::{ConsAddress, ValAddress},
+};
 
 const KEYRING_SUB_DIR_FILE: &str = "keyring-file";
 const KEYRING_SUB_DIR_TEST: &str = "keyring-test";
@@ -36,9 +44,28 @@ impl KeyringBackend {
     }
 }
 
+/// Type of the bech32 prefix used to display an address
+#[derive(Clone, Copy, Default, Debug, Display, Deserialize, Serialize)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Bech32Type {
+    #[default]
+    #[strum(to_string = "acc")]
+    Acc,
+    #[strum(to_string = "val")]
+    Val,
+    #[strum(to_string = "cons")]
+    Cons,
+}
+
 #[derive(Debug, Clone)]
 pub enum KeyCommand {
     Add(AddKeyCommand),
+    List(ListKeyCommand),
+    Show(ShowKeyCommand),
+    Delete(DeleteKeyCommand),
+    Export(ExportKeyCommand),
+    Import(ImportKeyCommand),
+    Rename(RenameKeyCommand),
 }
 
 #[derive(Debug, Clone, former::Former)]
@@ -50,6 +77,91 @@ pub struct AddKeyCommand {
     pub bip39_mnemonic: Option<String>,
 }
 
+#[derive(Debug, Clone, former::Former)]
+pub struct ListKeyCommand {
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ShowKeyCommand {
+    pub names: Vec<String>,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+    pub bech: Bech32Type,
+    pub address: bool,
+    pub multisig_threshold: u32,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct DeleteKeyCommand {
+    pub name: String,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+    pub yes: bool,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ExportKeyCommand {
+    pub name: String,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ImportKeyCommand {
+    pub name: String,
+    pub keyfile: PathBuf,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct RenameKeyCommand {
+    pub old_name: String,
+    pub new_name: String,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+    pub yes: bool,
+}
+
+/// Key information displayed by `list` and `show` commands
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyOutput {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub key_type: String,
+    pub address: String,
+    pub pubkey: String,
+}
+
+impl KeyOutput {
+    fn new(name: String, key_type: &str, public_key: &PublicKey, bech: Bech32Type) -> Result<Self> {
+        let address = public_key.get_address();
+        let address = match bech {
+            Bech32Type::Acc => address.to_string(),
+            Bech32Type::Val => ValAddress::from(address).to_string(),
+            Bech32Type::Cons => ConsAddress::from(ValAddress::from(address)).to_string(),
+        };
+
+        Ok(Self {
+            name,
+            key_type: key_type.to_owned(),
+            address,
+            pubkey: serde_json::to_string(public_key)?,
+        })
+    }
+}
+
+/// Asks user to confirm an action, anything except `y` or `yes` is treated as a refusal
+fn confirm(prompt: &str) -> Result<bool> {
+    print!("{prompt} [y/N]: ");
+    std::io::stdout().flush()?;
+    let answer: String = read!("{}\n");
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 // TODO: remove this cli code
 pub fn keys(command: KeyCommand) -> Result<()> {
     match command {
@@ -88,6 +200,162 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 println!("{}", mnemonic.phrase());
             }
         }
+        KeyCommand::List(ListKeyCommand {
+            home,
+            keyring_backend,
+        }) => {
+            let keyring_home = home.join(keyring_backend.get_sub_dir());
+            let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+            let keys = keyring::keys(backend)?
+                .into_iter()
+                .map(|(name, key_pair)| {
+                    KeyOutput::new(
+                        name,
+                        "local",
+                        &key_pair.get_gears_public_key(),
+                        Bech32Type::Acc,
+                    )
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            println!("{}", serde_json::to_string_pretty(&keys)?);
+        }
+        KeyCommand::Show(ShowKeyCommand {
+            names,
+            home,
+            keyring_backend,
+            bech,
+            address,
+            multisig_threshold,
+        }) => {
+            let keyring_home = home.join(keyring_backend.get_sub_dir());
+
+            let output = match names.as_slice() {
+                [] => return Err(anyhow!("at least one key name is required")),
+                [name] => {
+                    let key_pair = keyring::key_by_name(
+                        name,
+                        keyring_backend.to_keyring_backend(&keyring_home),
+                    )?;
+
+                    KeyOutput::new(
+                        name.clone(),
+                        "local",
+                        &key_pair.get_gears_public_key(),
+                        bech,
+                    )?
+                }
+                _ => {
+                    if multisig_threshold == 0 || multisig_threshold as usize > names.len() {
+                        return Err(anyhow!(
+                            "threshold must be a positive number not greater than the number of keys"
+                        ));
+                    }
+
+                    let public_keys = names
+                        .iter()
+                        .map(|name| {
+                            keyring::key_by_name(
+                                name,
+                                keyring_backend.to_keyring_backend(&keyring_home),
+                            )
+                            .map(|key_pair| key_pair.get_gears_public_key())
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let multisig = LegacyAminoPubKey::new(multisig_threshold, public_keys)?;
+
+                    KeyOutput::new(
+                        "multi".to_owned(),
+                        "multi",
+                        &PublicKey::Multisig(multisig),
+                        bech,
+                    )?
+                }
+            };
+
+            if address {
+                println!("{}", output.address);
+            } else {
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            }
+        }
+        KeyCommand::Delete(DeleteKeyCommand {
+            name,
+            home,
+            keyring_backend,
+            yes,
+        }) => {
+            let keyring_home = home.join(keyring_backend.get_sub_dir());
+            let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+            if !yes && !confirm("Key reference will be deleted. Continue?")? {
+                return Err(anyhow!("aborted"));
+            }
+
+            keyring::delete_key_by_name(&name, backend)?;
+
+            println!("Key deleted forever (uh oh!)");
+        }
+        KeyCommand::Export(ExportKeyCommand {
+            name,
+            home,
+            keyring_backend,
+        }) => {
+            let keyring_home = home.join(keyring_backend.get_sub_dir());
+            let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+            let key_pair = keyring::key_by_name(&name, backend)?;
+
+            let passphrase =
+                rpassword::prompt_password("Enter passphrase to encrypt the exported key: ")?;
+            if passphrase.is_empty() {
+                return Err(anyhow!("passphrase must not be empty"));
+            }
+            if passphrase != rpassword::prompt_password("Repeat the passphrase: ")? {
+                return Err(anyhow!("passphrases don't match"));
+            }
+
+            print!("{}", key_pair.to_pkcs8_encrypted_pem(passphrase).as_str());
+        }
+        KeyCommand::Import(ImportKeyCommand {
+            name,
+            keyfile,
+            home,
+            keyring_backend,
+        }) => {
+            let keyring_home = home.join(keyring_backend.get_sub_dir());
+            let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+            let armor = std::fs::read_to_string(&keyfile)?;
+            let passphrase = rpassword::prompt_password("Enter passphrase to decrypt your key: ")?;
+            let key_pair = KeyPair::from_pkcs8_encrypted_pem(&armor, passphrase)
+                .map_err(|e| anyhow!("failed to decrypt private key: {e}"))?;
+
+            keyring::import_key(&name, &key_pair, backend)?;
+        }
+        KeyCommand::Rename(RenameKeyCommand {
+            old_name,
+            new_name,
+            home,
+            keyring_backend,
+            yes,
+        }) => {
+            let keyring_home = home.join(keyring_backend.get_sub_dir());
+            let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+            if !yes
+                && !confirm(&format!(
+                    "Key reference will be renamed from {old_name} to {new_name}. Continue?"
+                ))?
+            {
+                return Err(anyhow!("aborted"));
+            }
+
+            keyring::rename_key(&old_name, &new_name, backend)?;
+
+            println!("Key {old_name} was renamed to {new_name}");
+        }
     }
 
     Ok(())
@@ -1,9 +1,15 @@
-use self::{keys::KeyCommand, query::QueryCommand, tx::TxCommand};
+use self::{
+    keys::KeyCommand,
+    query::QueryCommand,
+    tx::TxCommand,
+    tx_file::{BroadcastCommand, MultisignCommand, SignCommand, ValidateSignaturesCommand},
+};
 use crate::cli::query_txs::{TxQueryCli, TxsQueryCli};
 
 pub mod keys;
 pub mod query;
 pub mod tx;
+pub mod tx_file;
 
 #[derive(Debug, Clone)]
 pub enum ClientCommands<AUX, TX, QUE> {
@@ -13,4 +19,8 @@ pub enum ClientCommands<AUX, TX, QUE> {
     QueryTx(QueryCommand<TxQueryCli>),
     QueryTxs(QueryCommand<TxsQueryCli>),
     Keys(KeyCommand),
+    Sign(SignCommand),
+    Multisign(MultisignCommand),
+    ValidateSignatures(ValidateSignaturesCommand),
+    Broadcast(BroadcastCommand),
 }
@@ -1,20 +1,27 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use core_types::tx::mode_info::SignMode;
+use core_types::Protobuf;
+use ibc_proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse};
 use prost::Message;
 use tendermint::rpc::client::{Client, HttpClient};
 use tendermint::rpc::response::tx::broadcast::Response;
 use tendermint::types::chain_id::ChainId;
 
 use crate::application::handlers::client::{NodeFetcher, TxExecutionResult, TxHandler};
+use crate::baseapp::SIMULATE_QUERY_PATH;
 use crate::commands::client::query::execute_query;
 use crate::crypto::any_key::AnyKey;
 use crate::crypto::keys::GearsPublicKey;
 use crate::crypto::ledger::LedgerProxyKey;
+use crate::crypto::public::PublicKey;
 use crate::runtime::runtime;
 use crate::types::auth::fee::Fee;
 use crate::types::auth::gas::Gas;
+use crate::types::auth::info::AuthInfo;
+use crate::types::tx::body::TxBody;
 use crate::types::tx::raw::TxRaw;
+use crate::types::tx::{Messages, TxMessage};
 
 use super::keys::KeyringBackend;
 
@@ -41,6 +48,11 @@ pub struct ClientTxContext {
     pub timeout_height: Option<u32>,
 
     pub fee: Fee,
+    /// Estimate the gas limit by simulating the transaction. The estimate is
+    /// multiplied by this adjustment factor.
+    pub gas_adjustment: Option<f64>,
+    /// Print an unsigned transaction instead of signing and broadcasting it
+    pub generate_only: bool,
 }
 
 impl ClientTxContext {
@@ -79,6 +91,8 @@ impl ClientTxContext {
                 payer: None,
                 granter: "".to_owned(),
             },
+            gas_adjustment: None,
+            generate_only: false,
         }
     }
 }
@@ -87,6 +101,9 @@ impl ClientTxContext {
 pub enum Keyring {
     Ledger,
     Local(LocalInfo),
+    /// Public key of the signer without a private key, it's only enough to generate an
+    /// unsigned transaction
+    PublicKey(PublicKey),
 }
 
 #[derive(Debug, Clone)]
@@ -130,13 +147,11 @@ impl From<TxExecutionResult> for RuntxResult {
     }
 }
 
-fn handle_key(client_tx_context: &ClientTxContext) -> anyhow::Result<AnyKey> {
-    match client_tx_context.keyring {
+pub(crate) fn handle_key(keyring: &Keyring, home: &Path) -> anyhow::Result<AnyKey> {
+    match keyring {
         Keyring::Ledger => Ok(AnyKey::Ledger(LedgerProxyKey::new()?)),
-        Keyring::Local(ref local) => {
-            let keyring_home = client_tx_context
-                .home
-                .join(local.keyring_backend.get_sub_dir());
+        Keyring::Local(local) => {
+            let keyring_home = home.join(local.keyring_backend.get_sub_dir());
             let key = keyring::key_by_name(
                 &local.from_key,
                 local.keyring_backend.to_keyring_backend(&keyring_home),
@@ -144,6 +159,9 @@ fn handle_key(client_tx_context: &ClientTxContext) -> anyhow::Result<AnyKey> {
 
             Ok(AnyKey::Local(key))
         }
+        Keyring::PublicKey(_) => Err(anyhow::anyhow!(
+            "transaction can't be signed by a public key, use generate only mode"
+        )),
     }
 }
 
@@ -152,7 +170,20 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
     handler: &H,
     fetcher: &F,
 ) -> anyhow::Result<RuntxResult> {
-    let key = handle_key(&mut ctx)?;
+    if ctx.generate_only {
+        let public_key = match &ctx.keyring {
+            Keyring::PublicKey(public_key) => public_key.clone(),
+            keyring => handle_key(keyring, &ctx.home)?.get_gears_public_key(),
+        };
+
+        let messages = handler.prepare_tx(&mut ctx, inner, public_key)?;
+        let raw_tx = generate_tx(messages, &ctx)?;
+        println!("{}", serde_json::to_string_pretty(&raw_tx)?);
+
+        return Ok(RuntxResult::None);
+    }
+
+    let key = handle_key(&ctx.keyring, &ctx.home)?;
 
     let messages = handler.prepare_tx(&mut ctx, inner, key.get_gears_public_key())?;
 
@@ -195,6 +226,65 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
     }
 }
 
+/// Creates an unsigned transaction with all messages, signer infos and signatures are added
+/// later by the `sign` and `multisign` commands
+fn generate_tx<M: TxMessage>(
+    messages: Messages<M>,
+    ctx: &ClientTxContext,
+) -> anyhow::Result<TxRaw> {
+    if ctx.gas_adjustment.is_some() {
+        return Err(anyhow::anyhow!(
+            "cannot estimate gas of a transaction in generate only mode"
+        ));
+    }
+
+    let body = TxBody {
+        messages: messages.into_msgs(),
+        memo: ctx.memo.clone().unwrap_or_default(),
+        timeout_height: ctx.timeout_height.unwrap_or_default(),
+        extension_options: vec![],
+        non_critical_extension_options: vec![],
+    };
+
+    let auth_info = AuthInfo {
+        signer_infos: vec![],
+        fee: ctx.fee.clone(),
+        tip: None,
+    };
+
+    Ok(TxRaw {
+        body_bytes: body.encode_vec(),
+        auth_info_bytes: auth_info.encode_vec(),
+        signatures: vec![],
+    })
+}
+
+/// Simulates the transaction on the node and returns the amount of used gas
+pub fn simulate_tx(node: &url::Url, raw_tx: TxRaw) -> anyhow::Result<u64> {
+    let client = HttpClient::new(node.as_str())?;
+
+    let request = SimulateRequest {
+        tx_bytes: core_types::tx::raw::TxRaw::from(raw_tx).encode_to_vec(),
+        ..Default::default()
+    };
+
+    let res = runtime().block_on(client.abci_query(
+        Some(SIMULATE_QUERY_PATH.to_owned()),
+        request.encode_to_vec(),
+        None,
+        false,
+    ))?;
+
+    if res.code.is_err() {
+        return Err(anyhow::anyhow!("node returned an error: {}", res.log));
+    }
+
+    SimulateResponse::decode(res.value.as_slice())?
+        .gas_info
+        .map(|gas_info| gas_info.gas_used)
+        .ok_or_else(|| anyhow::anyhow!("simulation response doesn't contain gas info"))
+}
+
 pub fn broadcast_tx_commit(client: HttpClient, raw_tx: TxRaw) -> anyhow::Result<Response> {
     let res = runtime().block_on(
         client.broadcast_tx_commit(core_types::tx::raw::TxRaw::from(raw_tx).encode_to_vec()),
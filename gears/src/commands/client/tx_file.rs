@@ -0,0 +1,821 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use core_types::{
+    tx::mode_info::{CompactBitArray, ModeInfo, Multi, SignMode},
+    Protobuf,
+};
+use ibc_proto::cosmos::crypto::multisig::v1beta1::MultiSignature;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tendermint::{
+    rpc::{client::HttpClient, response::tx::broadcast::Response},
+    types::chain_id::ChainId,
+};
+
+use crate::{
+    application::handlers::client::NodeFetcher,
+    crypto::{
+        info::sign_bytes,
+        keys::{GearsPublicKey, ReadAccAddress, SigningKey},
+        multisig::LegacyAminoPubKey,
+        public::PublicKey,
+    },
+    types::{
+        address::AccAddress,
+        auth::info::AuthInfo,
+        signing::SignerInfo,
+        tx::{body::TxBody, raw::TxRaw, Tx, TxMessage},
+    },
+};
+
+use super::tx::{broadcast_tx_commit, handle_key, AccountProvider, Keyring};
+
+/// Signs a transaction file. The signature of a multisig member is written to a separate
+/// file which is combined with signatures of other members by the multisign command.
+#[derive(Debug, Clone, former::Former)]
+pub struct SignCommand {
+    pub file: PathBuf,
+    pub home: PathBuf,
+    pub node: url::Url,
+    pub chain_id: ChainId,
+    pub keyring: Keyring,
+    pub account: AccountProvider,
+    pub sign_mode: SignMode,
+    /// Address of the multisig account on behalf of which the transaction is signed
+    pub multisig: Option<AccAddress>,
+    pub output_document: Option<PathBuf>,
+}
+
+/// Combines signatures of multisig members into a multisignature of the transaction file
+#[derive(Debug, Clone, former::Former)]
+pub struct MultisignCommand {
+    pub file: PathBuf,
+    pub multisig_key: LegacyAminoPubKey,
+    pub signature_files: Vec<PathBuf>,
+    pub node: url::Url,
+    pub chain_id: ChainId,
+    pub account: AccountProvider,
+    pub output_document: Option<PathBuf>,
+}
+
+/// Checks that the transaction file is signed by the expected signers. Signatures are
+/// verified only in online mode since account numbers are queried from the node.
+#[derive(Debug, Clone, former::Former)]
+pub struct ValidateSignaturesCommand {
+    pub file: PathBuf,
+    pub node: url::Url,
+    pub chain_id: ChainId,
+    pub offline: bool,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct BroadcastCommand {
+    pub file: PathBuf,
+    pub node: url::Url,
+}
+
+/// Signature of a single member of a multisig account
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureDescriptor {
+    pub public_key: PublicKey,
+    #[serde(with = "core_types::serializers::Base64Standard")]
+    pub signature: Vec<u8>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub sequence: u64,
+}
+
+pub fn run_sign<M: TxMessage, F: NodeFetcher>(
+    SignCommand {
+        file,
+        home,
+        node,
+        chain_id,
+        keyring,
+        account,
+        sign_mode,
+        multisig,
+        output_document,
+    }: SignCommand,
+    fetcher: &F,
+) -> anyhow::Result<()> {
+    let mut raw_tx = read_tx(&file)?;
+    let body = TxBody::<M>::decode_vec(&raw_tx.body_bytes)?;
+    let mut auth_info = AuthInfo::decode_vec(&raw_tx.auth_info_bytes)?;
+
+    let key = handle_key(&keyring, &home)?;
+
+    match multisig {
+        Some(multisig) => {
+            // members sign independently, so their signatures can't cover the signer infos
+            if sign_mode != SignMode::LegacyAminoJson {
+                return Err(anyhow!(
+                    "multisig transactions can be signed only in legacy amino json mode"
+                ));
+            }
+
+            let (account_number, sequence) = account_info(multisig, &account, &node, fetcher)?;
+            let sign_bytes = sign_bytes(
+                &raw_tx,
+                &body,
+                auth_info.fee,
+                &sign_mode,
+                &chain_id,
+                account_number,
+                sequence,
+            )?;
+
+            let descriptor = SignatureDescriptor {
+                public_key: key.get_gears_public_key(),
+                signature: key.sign(&sign_bytes).map_err(|e| anyhow!(e.to_string()))?,
+                sequence,
+            };
+
+            write_output(&descriptor, output_document)
+        }
+        None => {
+            let signers = signers(&body, &auth_info);
+
+            // direct signatures cover the signer infos, so adding the signer info of another
+            // signer later would invalidate the signature
+            if sign_mode == SignMode::Direct
+                && signers.len() > 1
+                && !signers.iter().all(|signer| {
+                    auth_info
+                        .signer_infos
+                        .iter()
+                        .any(|info| signer_address(info).as_ref() == Some(signer))
+                })
+            {
+                return Err(anyhow!(
+                    "transaction with {} signers can be signed in direct mode only when signer infos of all signers are set",
+                    signers.len()
+                ));
+            }
+
+            let (account_number, sequence) =
+                account_info(key.get_address(), &account, &node, fetcher)?;
+
+            let position = set_signer_info(
+                &mut raw_tx,
+                &mut auth_info,
+                &signers,
+                SignerInfo {
+                    public_key: Some(key.get_gears_public_key()),
+                    mode_info: ModeInfo::Single(sign_mode.clone()),
+                    sequence,
+                },
+            )?;
+
+            let sign_bytes = sign_bytes(
+                &raw_tx,
+                &body,
+                auth_info.fee,
+                &sign_mode,
+                &chain_id,
+                account_number,
+                sequence,
+            )?;
+            raw_tx.signatures[position] =
+                key.sign(&sign_bytes).map_err(|e| anyhow!(e.to_string()))?;
+
+            write_output(&raw_tx, output_document)
+        }
+    }
+}
+
+pub fn run_multisign<M: TxMessage, F: NodeFetcher>(
+    MultisignCommand {
+        file,
+        multisig_key,
+        signature_files,
+        node,
+        chain_id,
+        account,
+        output_document,
+    }: MultisignCommand,
+    fetcher: &F,
+) -> anyhow::Result<()> {
+    let mut raw_tx = read_tx(&file)?;
+    let body = TxBody::<M>::decode_vec(&raw_tx.body_bytes)?;
+    let mut auth_info = AuthInfo::decode_vec(&raw_tx.auth_info_bytes)?;
+
+    let address = multisig_key.get_address();
+    let (account_number, sequence) = account_info(address, &account, &node, fetcher)?;
+    let sign_bytes = sign_bytes(
+        &raw_tx,
+        &body,
+        auth_info.fee.clone(),
+        &SignMode::LegacyAminoJson,
+        &chain_id,
+        account_number,
+        sequence,
+    )?;
+
+    let public_keys = multisig_key.public_keys();
+    let mut bitarray = CompactBitArray::new(public_keys.len());
+    let mut signatures = Vec::with_capacity(signature_files.len());
+
+    for path in signature_files {
+        let descriptor: SignatureDescriptor = serde_json::from_slice(&std::fs::read(&path)?)?;
+
+        let index = public_keys
+            .iter()
+            .position(|key| key == &descriptor.public_key)
+            .ok_or_else(|| {
+                anyhow!(
+                    "{}: signer is not a member of the multisig key",
+                    path.display()
+                )
+            })?;
+
+        if descriptor.sequence != sequence {
+            return Err(anyhow!(
+                "{}: signature sequence {} doesn't match the account sequence {sequence}",
+                path.display(),
+                descriptor.sequence
+            ));
+        }
+
+        descriptor
+            .public_key
+            .verify_signature(&sign_bytes, &descriptor.signature)
+            .map_err(|e| anyhow!("{}: invalid signature: {e}", path.display()))?;
+
+        if bitarray.get_index(index) {
+            return Err(anyhow!(
+                "{}: the member has already signed the transaction",
+                path.display()
+            ));
+        }
+
+        bitarray.set_index(index, true);
+        signatures.push((index, descriptor.signature));
+    }
+
+    if signatures.len() < multisig_key.threshold() as usize {
+        return Err(anyhow!(
+            "not enough signatures, have {}, threshold is {}",
+            signatures.len(),
+            multisig_key.threshold()
+        ));
+    }
+
+    // signatures are ordered as their keys within the multisig key
+    signatures.sort_by_key(|(index, _)| *index);
+    let mode_infos = vec![ModeInfo::Single(SignMode::LegacyAminoJson); signatures.len()];
+    let signature = MultiSignature {
+        signatures: signatures
+            .into_iter()
+            .map(|(_, signature)| signature)
+            .collect(),
+    }
+    .encode_to_vec();
+
+    let signers = signers(&body, &auth_info);
+    let position = set_signer_info(
+        &mut raw_tx,
+        &mut auth_info,
+        &signers,
+        SignerInfo {
+            public_key: Some(PublicKey::Multisig(multisig_key)),
+            mode_info: ModeInfo::Multi(Multi {
+                bitarray: Some(bitarray),
+                mode_infos,
+            }),
+            sequence,
+        },
+    )?;
+    raw_tx.signatures[position] = signature;
+
+    write_output(&raw_tx, output_document)
+}
+
+pub fn run_validate_signatures<M: TxMessage, F: NodeFetcher>(
+    ValidateSignaturesCommand {
+        file,
+        node,
+        chain_id,
+        offline,
+    }: ValidateSignaturesCommand,
+    fetcher: &F,
+) -> anyhow::Result<()> {
+    let raw_tx = read_tx(&file)?;
+    let tx =
+        Tx::<M>::decode_vec(&core_types::tx::raw::TxRaw::from(raw_tx.clone()).encode_to_vec())?;
+
+    let signers = tx.get_signers();
+    println!("Signers:");
+    for (i, signer) in signers.iter().enumerate() {
+        println!("  {i}: {signer}");
+    }
+
+    println!("Signatures:");
+    let mut valid = signers.len() == tx.get_signatures_data().len();
+    for (i, (signer_info, signature_data)) in tx
+        .auth_info
+        .signer_infos
+        .iter()
+        .zip(tx.get_signatures_data())
+        .enumerate()
+    {
+        let public_key = match &signer_info.public_key {
+            Some(public_key) => public_key,
+            None => {
+                valid = false;
+                println!("  {i}: [ERR] public key is not set");
+                continue;
+            }
+        };
+
+        let address = public_key.get_address();
+        if signers.get(i) != Some(&&address) {
+            valid = false;
+            println!("  {i}: {address} [ERR] signer doesn't match the expected one");
+            continue;
+        }
+
+        if offline {
+            println!("  {i}: {address} [OK]");
+            continue;
+        }
+
+        let result = account_info(address.clone(), &AccountProvider::Online, &node, fetcher)
+            .and_then(|(account_number, sequence)| {
+                if sequence != signature_data.sequence {
+                    return Err(anyhow!(
+                        "sequence {} doesn't match the account sequence {sequence}",
+                        signature_data.sequence
+                    ));
+                }
+
+                public_key.verify_signature_data(signature_data, &|mode: &SignMode| {
+                    Ok::<_, anyhow::Error>(sign_bytes(
+                        &raw_tx,
+                        &tx.body,
+                        tx.auth_info.fee.clone(),
+                        mode,
+                        &chain_id,
+                        account_number,
+                        sequence,
+                    )?)
+                })
+            });
+
+        match result {
+            Ok(()) => println!("  {i}: {address} [OK]"),
+            Err(e) => {
+                valid = false;
+                println!("  {i}: {address} [ERR] {e}");
+            }
+        }
+    }
+
+    match valid {
+        true => Ok(()),
+        false => Err(anyhow!("signatures validation failed")),
+    }
+}
+
+pub fn run_broadcast(
+    BroadcastCommand { file, node }: BroadcastCommand,
+) -> anyhow::Result<Response> {
+    let raw_tx = read_tx(&file)?;
+    let client = HttpClient::new(tendermint::rpc::url::Url::try_from(node)?)?;
+
+    broadcast_tx_commit(client, raw_tx)
+}
+
+/// Returns account number and sequence of the account
+fn account_info<F: NodeFetcher>(
+    address: AccAddress,
+    account: &AccountProvider,
+    node: &url::Url,
+    fetcher: &F,
+) -> anyhow::Result<(u64, u64)> {
+    match account {
+        AccountProvider::Offline {
+            sequence,
+            account_number,
+        } => Ok((*account_number, *sequence)),
+        AccountProvider::Online => {
+            let account = fetcher
+                .latest_account(address.clone(), node.as_str())?
+                .ok_or_else(|| anyhow!("account not found: {}", address))?;
+
+            Ok((account.get_account_number(), account.get_sequence()))
+        }
+    }
+}
+
+/// Returns the signers of the transaction in the order of their signer infos
+fn signers<M: TxMessage>(body: &TxBody<M>, auth_info: &AuthInfo) -> Vec<AccAddress> {
+    body.signers(auth_info.fee.payer.as_ref())
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+fn signer_address(signer_info: &SignerInfo) -> Option<AccAddress> {
+    signer_info
+        .public_key
+        .as_ref()
+        .map(|public_key| public_key.get_address())
+}
+
+/// Sets signer info of the transaction replacing the one with the same public key. A new
+/// signer info is inserted at the position of its signer among the signers which already
+/// have signer infos, so the transaction can be signed in any order. Returns position of
+/// the signer info which matches position of its signature.
+fn set_signer_info(
+    raw_tx: &mut TxRaw,
+    auth_info: &mut AuthInfo,
+    signers: &[AccAddress],
+    signer_info: SignerInfo,
+) -> anyhow::Result<usize> {
+    if raw_tx.signatures.len() != auth_info.signer_infos.len() {
+        return Err(anyhow!(
+            "signatures list does not match signer_infos length"
+        ));
+    }
+
+    let signer_index = |info: &SignerInfo| -> anyhow::Result<usize> {
+        let address = signer_address(info)
+            .ok_or_else(|| anyhow!("public key of the signer info is not set"))?;
+
+        signers
+            .iter()
+            .position(|signer| signer == &address)
+            .ok_or_else(|| anyhow!("{address} is not a signer of the transaction"))
+    };
+
+    let index = signer_index(&signer_info)?;
+
+    let position = match auth_info
+        .signer_infos
+        .iter()
+        .position(|info| info.public_key == signer_info.public_key)
+    {
+        Some(position) => {
+            auth_info.signer_infos[position] = signer_info;
+            position
+        }
+        None => {
+            let mut position = 0;
+            for info in &auth_info.signer_infos {
+                if signer_index(info)? < index {
+                    position += 1;
+                }
+            }
+
+            auth_info.signer_infos.insert(position, signer_info);
+            raw_tx.signatures.insert(position, Vec::new());
+            position
+        }
+    };
+
+    raw_tx.auth_info_bytes = auth_info.encode_vec();
+
+    Ok(position)
+}
+
+fn read_tx(path: &Path) -> anyhow::Result<TxRaw> {
+    Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+}
+
+fn write_output(value: &impl Serialize, output_document: Option<PathBuf>) -> anyhow::Result<()> {
+    let json = serde_json::to_string_pretty(value)?;
+
+    match output_document {
+        Some(path) => std::fs::write(path, json)?,
+        None => println!("{json}"),
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_fs::TempDir;
+    use cosmwasm_std::Uint256;
+    use extensions::testing::UnwrapTesting;
+    use keyring::key::pair::KeyPair;
+    use vec1::vec1;
+
+    use crate::{
+        commands::client::{keys::KeyringBackend, tx::LocalInfo},
+        types::{
+            account::{Account, BaseAccount},
+            auth::fee::Fee,
+            base::{coin::UnsignedCoin, coins::UnsignedCoins},
+            denom::Denom,
+            msg::send::MsgSend,
+            tx::metadata::Metadata,
+        },
+    };
+
+    use super::*;
+
+    const MULTISIG_ACCOUNT_NUMBER: u64 = 5;
+    const MULTISIG_SEQUENCE: u64 = 2;
+    const SINGLE_ACCOUNT_NUMBER: u64 = 7;
+    const SINGLE_SEQUENCE: u64 = 0;
+
+    #[test]
+    fn multisign_after_single_signature_keeps_signers_order() {
+        let setup = Setup::new();
+
+        setup.sign("single", None, "unsigned.json", "single.json");
+        setup.sign("a", Some(setup.multisig_address()), "single.json", "a.json");
+        setup.sign("c", Some(setup.multisig_address()), "single.json", "c.json");
+        // signature files are passed out of the order of the member keys
+        setup
+            .multisign("single.json", &["c.json", "a.json"], "signed.json")
+            .unwrap_test();
+
+        setup.assert_signers_order("signed.json");
+        setup.validate("signed.json").unwrap_test();
+    }
+
+    #[test]
+    fn single_signature_after_multisign_keeps_signers_order() {
+        let setup = Setup::new();
+
+        setup.sign(
+            "b",
+            Some(setup.multisig_address()),
+            "unsigned.json",
+            "b.json",
+        );
+        setup.sign(
+            "c",
+            Some(setup.multisig_address()),
+            "unsigned.json",
+            "c.json",
+        );
+        setup
+            .multisign("unsigned.json", &["b.json", "c.json"], "multisigned.json")
+            .unwrap_test();
+        setup.sign("single", None, "multisigned.json", "signed.json");
+
+        setup.assert_signers_order("signed.json");
+        setup.validate("signed.json").unwrap_test();
+    }
+
+    #[test]
+    fn multisign_below_threshold_fails() {
+        let setup = Setup::new();
+
+        setup.sign(
+            "a",
+            Some(setup.multisig_address()),
+            "unsigned.json",
+            "a.json",
+        );
+
+        assert!(setup
+            .multisign("unsigned.json", &["a.json"], "signed.json")
+            .is_err());
+    }
+
+    #[test]
+    fn validate_signatures_fails_for_invalid_multisignature() {
+        let setup = Setup::new();
+
+        setup.sign("single", None, "unsigned.json", "single.json");
+        setup.sign("a", Some(setup.multisig_address()), "single.json", "a.json");
+        setup.sign("b", Some(setup.multisig_address()), "single.json", "b.json");
+        setup
+            .multisign("single.json", &["a.json", "b.json"], "signed.json")
+            .unwrap_test();
+
+        let mut raw_tx = read_tx(&setup.path("signed.json")).unwrap_test();
+        *raw_tx.signatures[0].last_mut().unwrap_test() ^= 1;
+        write_output(&raw_tx, Some(setup.path("invalid.json"))).unwrap_test();
+
+        assert!(setup.validate("invalid.json").is_err());
+    }
+
+    struct TestFetcher(Vec<Account>);
+
+    impl NodeFetcher for TestFetcher {
+        fn latest_account(
+            &self,
+            address: AccAddress,
+            _node: impl AsRef<str>,
+        ) -> anyhow::Result<Option<Account>> {
+            Ok(self
+                .0
+                .iter()
+                .find(|account| account.get_address() == &address)
+                .cloned())
+        }
+
+        fn denom_metadata(
+            &self,
+            _base: Denom,
+            _node: impl AsRef<str>,
+        ) -> anyhow::Result<Option<Metadata>> {
+            Ok(None)
+        }
+    }
+
+    /// Home with a 2 of 3 multisig key of members `a`, `b`, `c` and a `single` key, and an
+    /// unsigned transaction which has to be signed by the multisig account and the single key
+    struct Setup {
+        home: TempDir,
+        multisig_key: LegacyAminoPubKey,
+        single_key: KeyPair,
+        fetcher: TestFetcher,
+    }
+
+    impl Setup {
+        fn new() -> Self {
+            let home = TempDir::new().unwrap_test();
+
+            let members = [("a", 1), ("b", 2), ("c", 3)]
+                .into_iter()
+                .map(|(name, secret)| import_key(&home, name, secret).get_gears_public_key())
+                .collect();
+            let multisig_key = LegacyAminoPubKey::new(2, members).unwrap_test();
+            let single_key = import_key(&home, "single", 4);
+
+            let fetcher = TestFetcher(vec![
+                Account::Base(BaseAccount {
+                    address: multisig_key.get_address(),
+                    pub_key: None,
+                    account_number: MULTISIG_ACCOUNT_NUMBER,
+                    sequence: MULTISIG_SEQUENCE,
+                }),
+                Account::Base(BaseAccount {
+                    address: single_key.get_address(),
+                    pub_key: None,
+                    account_number: SINGLE_ACCOUNT_NUMBER,
+                    sequence: SINGLE_SEQUENCE,
+                }),
+            ]);
+
+            let setup = Self {
+                home,
+                multisig_key,
+                single_key,
+                fetcher,
+            };
+
+            // the single key signs the second message, but the multisig account is the
+            // first signer
+            let body = TxBody::new_with_defaults(vec1![
+                send(setup.multisig_address()),
+                send(setup.single_key.get_address())
+            ]);
+            let auth_info = AuthInfo {
+                signer_infos: Vec::new(),
+                fee: Fee {
+                    amount: None,
+                    gas_limit: 200_000_u64.try_into().unwrap_test(),
+                    payer: None,
+                    granter: String::new(),
+                },
+                tip: None,
+            };
+            let raw_tx = TxRaw {
+                body_bytes: body.encode_vec(),
+                auth_info_bytes: auth_info.encode_vec(),
+                signatures: Vec::new(),
+            };
+            write_output(&raw_tx, Some(setup.path("unsigned.json"))).unwrap_test();
+
+            setup
+        }
+
+        fn path(&self, name: &str) -> PathBuf {
+            self.home.path().join(name)
+        }
+
+        fn multisig_address(&self) -> AccAddress {
+            self.multisig_key.get_address()
+        }
+
+        fn sign(&self, from_key: &str, multisig: Option<AccAddress>, file: &str, output: &str) {
+            let (account_number, sequence) = match multisig {
+                Some(_) => (MULTISIG_ACCOUNT_NUMBER, MULTISIG_SEQUENCE),
+                None => (SINGLE_ACCOUNT_NUMBER, SINGLE_SEQUENCE),
+            };
+
+            run_sign::<MsgSend, _>(
+                SignCommand {
+                    file: self.path(file),
+                    home: self.home.path().to_path_buf(),
+                    node: node(),
+                    chain_id: chain_id(),
+                    keyring: Keyring::Local(LocalInfo {
+                        keyring_backend: KeyringBackend::Test,
+                        from_key: from_key.to_owned(),
+                    }),
+                    account: AccountProvider::Offline {
+                        sequence,
+                        account_number,
+                    },
+                    sign_mode: SignMode::LegacyAminoJson,
+                    multisig,
+                    output_document: Some(self.path(output)),
+                },
+                &self.fetcher,
+            )
+            .unwrap_test();
+        }
+
+        fn multisign(
+            &self,
+            file: &str,
+            signature_files: &[&str],
+            output: &str,
+        ) -> anyhow::Result<()> {
+            run_multisign::<MsgSend, _>(
+                MultisignCommand {
+                    file: self.path(file),
+                    multisig_key: self.multisig_key.clone(),
+                    signature_files: signature_files.iter().map(|name| self.path(name)).collect(),
+                    node: node(),
+                    chain_id: chain_id(),
+                    account: AccountProvider::Offline {
+                        sequence: MULTISIG_SEQUENCE,
+                        account_number: MULTISIG_ACCOUNT_NUMBER,
+                    },
+                    output_document: Some(self.path(output)),
+                },
+                &self.fetcher,
+            )
+        }
+
+        fn validate(&self, file: &str) -> anyhow::Result<()> {
+            run_validate_signatures::<MsgSend, _>(
+                ValidateSignaturesCommand {
+                    file: self.path(file),
+                    node: node(),
+                    chain_id: chain_id(),
+                    offline: false,
+                },
+                &self.fetcher,
+            )
+        }
+
+        fn assert_signers_order(&self, file: &str) {
+            let raw_tx = read_tx(&self.path(file)).unwrap_test();
+            let auth_info = AuthInfo::decode_vec(&raw_tx.auth_info_bytes).unwrap_test();
+
+            let public_keys = auth_info
+                .signer_infos
+                .into_iter()
+                .map(|info| info.public_key)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                public_keys,
+                vec![
+                    Some(PublicKey::Multisig(self.multisig_key.clone())),
+                    Some(self.single_key.get_gears_public_key()),
+                ]
+            );
+            assert_eq!(raw_tx.signatures.len(), 2);
+        }
+    }
+
+    fn import_key(home: &TempDir, name: &str, secret: u8) -> KeyPair {
+        let key_pair: KeyPair = serde_json::from_value(serde_json::json!({
+            "type": "secp256k1",
+            "secret_key": hex::encode([secret; 32]),
+        }))
+        .unwrap_test();
+
+        let keyring_home = home.path().join(KeyringBackend::Test.get_sub_dir());
+        keyring::import_key(name, &key_pair, keyring::Backend::Test(&keyring_home)).unwrap_test();
+
+        key_pair
+    }
+
+    fn send(from_address: AccAddress) -> MsgSend {
+        MsgSend {
+            from_address,
+            to_address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")
+                .unwrap_test(),
+            amount: UnsignedCoins::new(vec![UnsignedCoin {
+                denom: Denom::try_from("uatom".to_string()).unwrap_test(),
+                amount: Uint256::from(10u8),
+            }])
+            .unwrap_test(),
+        }
+    }
+
+    fn node() -> url::Url {
+        url::Url::parse("http://localhost:26657").unwrap_test()
+    }
+
+    fn chain_id() -> ChainId {
+        ChainId::from_str("test-chain").unwrap_test()
+    }
+}
@@ -0,0 +1,123 @@
+use std::{path::PathBuf, sync::Arc};
+
+use database::{Database, DatabaseBuilder, OverlayDB};
+use kv_store::{bank::multi::ApplicationMultiBank, query::QueryMultiStore};
+use tendermint::{informal::genesis::Genesis, types::time::timestamp::Timestamp};
+
+use crate::{
+    application::handlers::node::ABCIHandler,
+    baseapp::ConsensusParams,
+    config::{ApplicationConfig, Config, ConfigDirectory},
+    context::{init::InitContext, query::QueryContext},
+};
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ExportCommand {
+    pub home: PathBuf,
+    pub height: Option<u32>,
+    pub for_zero_height: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("{0}")]
+    Database(String),
+    #[error("{0}")]
+    Config(String),
+    #[error("{0}")]
+    Store(String),
+    #[error("height {0} is higher than the latest height {1}")]
+    InvalidHeight(u32, u32),
+    #[error("state for zero height can only be exported at the latest height {0}")]
+    ZeroHeightNotLatest(u32),
+    #[error("invalid genesis time: {0}")]
+    GenesisTime(String),
+}
+
+/// Exports the application state as genesis, which is printed to stdout. Chain
+/// parameters which aren't part of the application state are taken from the
+/// genesis file of the node.
+pub fn export<DB: Database, DBO: DatabaseBuilder<DB>, H: ABCIHandler, AC: ApplicationConfig>(
+    cmd: ExportCommand,
+    db_builder: DBO,
+    abci_handler_builder: impl FnOnce(Config<AC>) -> H,
+) -> Result<(), ExportError> {
+    let ExportCommand {
+        home,
+        height,
+        for_zero_height,
+    } = cmd;
+
+    let db = db_builder
+        .build(home.join("data").join("application.db"))
+        .map_err(|e| ExportError::Database(format!("{e:?}")))?;
+
+    let cfg_file_path = ConfigDirectory::ConfigFile.path_from_home(&home);
+    let config: Config<AC> = Config::from_file(cfg_file_path)
+        .map_err(|e| ExportError::Config(format!("Error reading config file: {:?}", e)))?;
+
+    let abci_handler = abci_handler_builder(config);
+
+    let genesis_file_path = ConfigDirectory::GenesisFile.path_from_home(&home);
+    let genesis: Genesis<serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string(genesis_file_path)?)?;
+
+    // changes made to export the state for zero height stay in memory
+    let mut multi_store =
+        ApplicationMultiBank::<OverlayDB<DB>, H::StoreKey>::new(Arc::new(OverlayDB::new(db)))
+            .map_err(|e| ExportError::Store(e.to_string()))?;
+    let latest_height = multi_store.head_version();
+    let height = height.unwrap_or(latest_height);
+    if height > latest_height {
+        return Err(ExportError::InvalidHeight(height, latest_height));
+    }
+
+    // the time of the exported block isn't stored in the application state
+    let time = Timestamp::try_new(genesis.genesis_time.unix_timestamp(), 0)
+        .map_err(|e| ExportError::GenesisTime(e.to_string()))?;
+
+    if for_zero_height {
+        if height != latest_height {
+            return Err(ExportError::ZeroHeightNotLatest(latest_height));
+        }
+
+        let mut ctx = InitContext::new(
+            &mut multi_store,
+            height,
+            time,
+            genesis.chain_id.clone().into(),
+            ConsensusParams::default(),
+        );
+        abci_handler.prepare_for_zero_height(&mut ctx);
+
+        multi_store.commit();
+    }
+
+    let ctx = QueryMultiStore::new(&multi_store, multi_store.head_version())
+        .and_then(|multi_store| QueryContext::new(multi_store, height, time))
+        .map_err(|e| ExportError::Store(e.to_string()))?;
+    let app_state = abci_handler.export_genesis(&ctx, for_zero_height);
+
+    let genesis = Genesis {
+        genesis_time: genesis.genesis_time,
+        chain_id: genesis.chain_id,
+        // a chain restarted from zero height starts from the first block
+        initial_height: match for_zero_height {
+            true => 1,
+            false => i64::from(height) + 1,
+        },
+        consensus_params: genesis.consensus_params,
+        // the validator set is restored from the staking state in init chain
+        validators: Vec::new(),
+        app_hash: Default::default(),
+        app_state,
+    };
+
+    println!("{}", serde_json::to_string_pretty(&genesis)?);
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+pub mod export;
 pub mod genesis;
 pub mod init;
 pub mod run;
@@ -7,5 +8,6 @@ pub enum AppCommands<AUX> {
     Init(init::InitCommand),
     Run(run::RunCommand),
     GenesisAdd(genesis::GenesisCommand),
+    Export(export::ExportCommand),
     Aux(AUX),
 }
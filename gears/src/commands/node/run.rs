@@ -1,9 +1,10 @@
 use crate::application::handlers::node::ABCIHandler;
 use crate::application::ApplicationInfo;
 use crate::baseapp::options::NodeOptions;
+use crate::baseapp::snapshot::SnapshotManager;
 use crate::baseapp::{BaseApp, NodeQueryHandler};
 use crate::config::{ApplicationConfig, Config, ConfigDirectory};
-use crate::grpc::run_grpc_server;
+use crate::grpc::{run_grpc_server, tx::tx_server};
 use crate::params::ParamsSubspaceKey;
 use crate::rest::{run_rest_server, RestState};
 use crate::types::base::min_gas::MinGasPrices;
@@ -13,6 +14,7 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use tendermint::abci::ServerBuilder;
 use tendermint::application::ABCI;
+use tendermint::rpc::client::HttpClientUrl;
 use tower_layer::Identity;
 use tracing::metadata::LevelFilter;
 use tracing::{error, info};
@@ -126,25 +128,36 @@ pub fn run<
 
     let abci_handler = abci_handler_builder(config.clone());
 
-    let options = NodeOptions::new(min_gas_prices.or(config.min_gas_prices).ok_or(
-        RunError::HomeDirectory(
-            "Failed to get `min_gas_prices` set it via cli or in config file".to_owned(),
-        ),
-    )?);
+    let options = NodeOptions::new(
+        min_gas_prices
+            .or(config.min_gas_prices.clone())
+            .ok_or(RunError::HomeDirectory(
+                "Failed to get `min_gas_prices` set it via cli or in config file".to_owned(),
+            ))?,
+        config.pruning_options(),
+    );
+
+    let snapshot_manager =
+        SnapshotManager::new(db_dir.join("snapshots"), config.snapshot_options());
+
+    let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(db, params_subspace_key, abci_handler, options)
+        .with_snapshot_manager(snapshot_manager);
 
-    let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(db, params_subspace_key, abci_handler, options);
+    let tendermint_rpc_address: HttpClientUrl = tendermint_addr
+        .unwrap_or(config.tendermint_rpc_address)
+        .try_into()?;
 
     run_rest_server::<H::Message, H::QReq, H::QRes, _>(
         app.clone(),
         rest_listen_addr.unwrap_or(config.rest_listen_addr),
         router_builder.build_router::<BaseApp<DB, PSK, H, AI>>(),
-        tendermint_addr
-            .unwrap_or(config.tendermint_rpc_address)
-            .try_into()?,
+        tendermint_rpc_address.clone(),
     );
 
     run_grpc_server(
-        router_builder.build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone()),
+        router_builder
+            .build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone())
+            .add_service(tx_server(app.clone(), tendermint_rpc_address)),
         grpc_listen_addr.unwrap_or(config.grpc_listen_addr),
     );
 
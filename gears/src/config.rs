@@ -5,10 +5,12 @@ use std::net::{Ipv4Addr, SocketAddr};
 use std::path::{Path, PathBuf};
 
 use extensions::socket_addr;
+use kv_store::PruningOptions;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tendermint::rpc::url::Url;
 
+use crate::baseapp::snapshot::SnapshotOptions;
 use crate::defaults::{CLIENT_CONFIG_FILE_NAME, CONFIG_DIR, CONFIG_FILE_NAME, GENESIS_FILE_NAME};
 use crate::types::base::min_gas::MinGasPrices;
 
@@ -18,6 +20,7 @@ pub const DEFAULT_REST_LISTEN_ADDR: SocketAddr =
 pub const DEFAULT_ADDRESS: SocketAddr =
     SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 26658);
 pub const DEFAULT_TENDERMINT_RPC_ADDRESS: &str = "http://localhost:26657";
+pub const DEFAULT_SNAPSHOT_KEEP_RECENT: u32 = 2;
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub enum ConfigDirectory {
@@ -42,6 +45,21 @@ impl ConfigDirectory {
 
 pub trait ApplicationConfig: Serialize + DeserializeOwned + Default + Clone {}
 
+/// Defines which historic states of the application are deleted
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PruningStrategy {
+    /// The last 362880 states are kept, pruning at 10 block intervals
+    #[default]
+    Default,
+    /// All historic states are kept, nothing is deleted (i.e. archiving node)
+    Nothing,
+    /// Only the current and previous states are kept, pruning at 10 block intervals
+    Everything,
+    /// Options are taken from `pruning_keep_recent`, `pruning_keep_every` and `pruning_interval`
+    Custom,
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -51,6 +69,12 @@ pub struct Config<AC: Default + Clone> {
     pub grpc_listen_addr: SocketAddr,
     pub address: SocketAddr,
     pub min_gas_prices: Option<MinGasPrices>,
+    pub pruning: PruningStrategy,
+    pub pruning_keep_recent: u32,
+    pub pruning_keep_every: u32,
+    pub pruning_interval: u32,
+    pub snapshot_interval: u32,
+    pub snapshot_keep_recent: u32,
     pub app_config: AC,
 }
 
@@ -92,9 +116,35 @@ impl<AC: ApplicationConfig> Config<AC> {
             grpc_listen_addr: self.grpc_listen_addr.to_owned(),
             address: self.address.to_owned(),
             min_gas_prices: self.min_gas_prices.to_owned(),
+            pruning: self.pruning,
+            pruning_keep_recent: self.pruning_keep_recent,
+            pruning_keep_every: self.pruning_keep_every,
+            pruning_interval: self.pruning_interval,
+            snapshot_interval: self.snapshot_interval,
+            snapshot_keep_recent: self.snapshot_keep_recent,
             app_config: AC::default(),
         }
     }
+
+    pub fn pruning_options(&self) -> PruningOptions {
+        match self.pruning {
+            PruningStrategy::Default => PruningOptions::default(),
+            PruningStrategy::Nothing => PruningOptions::nothing(),
+            PruningStrategy::Everything => PruningOptions::everything(),
+            PruningStrategy::Custom => PruningOptions::new(
+                self.pruning_keep_recent,
+                self.pruning_keep_every,
+                self.pruning_interval,
+            ),
+        }
+    }
+
+    pub fn snapshot_options(&self) -> SnapshotOptions {
+        SnapshotOptions {
+            interval: self.snapshot_interval,
+            keep_recent: self.snapshot_keep_recent,
+        }
+    }
 }
 
 impl<AC: ApplicationConfig> Default for Config<AC> {
@@ -108,6 +158,12 @@ impl<AC: ApplicationConfig> Default for Config<AC> {
             app_config: AC::default(),
             min_gas_prices: None,
             grpc_listen_addr: DEFAULT_GRPC_LISTEN_ADDR,
+            pruning: PruningStrategy::default(),
+            pruning_keep_recent: 0,
+            pruning_keep_every: 0,
+            pruning_interval: 0,
+            snapshot_interval: 0,
+            snapshot_keep_recent: DEFAULT_SNAPSHOT_KEEP_RECENT,
         }
     }
 }
@@ -132,4 +188,29 @@ grpc_listen_addr = "{{grpc_listen_addr}}"
 tendermint_rpc_address = "{{tendermint_rpc_address}}"
 
 min_gas_prices = "{{min_gas_prices}}"
+
+# default: the last 362880 states are kept, pruning at 10 block intervals
+# nothing: all historic states will be saved, nothing will be deleted (i.e. archiving node)
+# everything: only the current and previous states are kept, pruning at 10 block intervals
+# custom: allow pruning options to be manually specified through 'pruning_keep_recent', 'pruning_keep_every', and 'pruning_interval'
+pruning = "{{pruning}}"
+
+# These are applied if and only if the pruning strategy is custom.
+pruning_keep_recent = {{pruning_keep_recent}}
+pruning_keep_every = {{pruning_keep_every}}
+pruning_interval = {{pruning_interval}}
+
+#######################################################################
+###                        State Sync Configuration                 ###
+#######################################################################
+
+# State sync snapshots allow other nodes to rapidly join the network without replaying historical
+# blocks, instead downloading and applying a snapshot of the application state at a given height.
+
+# snapshot_interval specifies the block interval at which local state sync snapshots are
+# taken (0 to disable).
+snapshot_interval = {{snapshot_interval}}
+
+# snapshot_keep_recent specifies the number of recent snapshots to keep and serve (0 to keep all).
+snapshot_keep_recent = {{snapshot_keep_recent}}
 "#;
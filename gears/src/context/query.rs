@@ -4,7 +4,7 @@ use database::Database;
 use kv_store::{
     error::KVStoreError, query::QueryMultiStore, store::kv::immutable::KVStore, StoreKey,
 };
-use tendermint::types::chain_id::ChainId;
+use tendermint::types::{chain_id::ChainId, time::timestamp::Timestamp};
 
 use crate::types::store::kv::Store;
 
@@ -14,18 +14,21 @@ pub struct QueryContext<DB, SK> {
     multi_store: QueryMultiStore<DB, SK>,
     pub(crate) height: u32,
     pub(crate) chain_id: ChainId,
+    time: Timestamp,
 }
 
 impl<DB: Database, SK: StoreKey> QueryContext<DB, SK> {
     pub fn new(
         multi_store: QueryMultiStore<DB, SK>,
         version: u32,
+        time: Timestamp,
         // chain_id: ChainId,
     ) -> Result<Self, KVStoreError> {
         Ok(QueryContext {
             multi_store,
             height: version,
             chain_id: ChainId::new("todo-900").expect("default should be valid"),
+            time,
         })
     }
 }
@@ -35,6 +38,11 @@ impl<DB: Database, SK: StoreKey> QueryContext<DB, SK> {
         &self.chain_id
     }
 
+    /// Time of the block the queried state was committed in
+    pub fn get_time(&self) -> Timestamp {
+        self.time
+    }
+
     pub fn kv_store(&self, store_key: &SK) -> KVStore<'_, PrefixDB<DB>> {
         self.multi_store.kv_store(store_key)
     }
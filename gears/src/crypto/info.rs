@@ -15,11 +15,12 @@ use crate::{
     application::handlers::client::{MetadataViaRPC, NodeFetcher},
     signing::{
         errors::SigningErrors, handler::SignModeHandler, renderer::value_renderer::ValueRenderer,
+        std_sign_doc::StdSignDoc,
     },
     types::{
         auth::{fee::Fee, info::AuthInfo, tip::Tip},
         signing::SignerInfo,
-        tx::{body::TxBody, signer::SignerData, Tx, TxMessage},
+        tx::{body::TxBody, raw::TxRaw, signer::SignerData, Tx, TxMessage},
     },
 };
 
@@ -66,6 +67,55 @@ pub fn create_signed_transaction_direct<M: TxMessage, K: SigningKey + GearsPubli
     // Ok()
 }
 
+/// Creates a transaction with empty signatures, which is used to estimate gas by simulation
+pub fn create_simulation_transaction<M: TxMessage, K: GearsPublicKey>(
+    signing_infos: &[SigningInfo<K>],
+    fee: Fee,
+    tip: Option<Tip>,
+    body: TxBody<M>,
+) -> Tx<M> {
+    let auth_info = auth_info(signing_infos, fee, tip, Mode::Direct);
+
+    Tx {
+        body,
+        auth_info,
+        signatures: vec![Vec::new(); signing_infos.len()],
+        signatures_data: Vec::new(),
+    }
+}
+
+/// Returns bytes of the raw transaction which are signed in the given sign mode. Only modes
+/// which don't need metadata from a node are supported.
+pub fn sign_bytes<M: TxMessage>(
+    raw: &TxRaw,
+    body: &TxBody<M>,
+    fee: Fee,
+    mode: &SignMode,
+    chain_id: &ChainId,
+    account_number: u64,
+    sequence: u64,
+) -> Result<Vec<u8>, SigningErrors> {
+    match mode {
+        SignMode::Direct => Ok(SignDoc {
+            body_bytes: raw.body_bytes.clone(),
+            auth_info_bytes: raw.auth_info_bytes.clone(),
+            chain_id: chain_id.to_string(),
+            account_number,
+        }
+        .encode_to_vec()),
+        SignMode::LegacyAminoJson => {
+            StdSignDoc::new(body, fee, chain_id.to_string(), account_number, sequence)
+                .map_err(|e| SigningErrors::CustomError(e.to_string()))?
+                .to_sign_bytes()
+                .map_err(|e| SigningErrors::CustomError(e.to_string()))
+        }
+        mode => Err(SigningErrors::CustomError(format!(
+            "sign mode not supported: {:?}",
+            mode
+        ))),
+    }
+}
+
 // NOTE: we can't implement From<K::Error> for this type
 #[derive(Debug)]
 pub enum TextualSigningError<K: SigningKey> {
@@ -154,7 +204,7 @@ impl From<Mode> for SignMode {
 }
 
 fn auth_info<K: GearsPublicKey>(
-    signing_infos: &Vec<SigningInfo<K>>,
+    signing_infos: &[SigningInfo<K>],
     fee: Fee,
     tip: Option<Tip>,
     mode: Mode,
@@ -0,0 +1,360 @@
+use address::AccAddress;
+use core_types::any::google::Any;
+use core_types::tx::mode_info::Multi;
+use core_types::tx::signature::SignatureData;
+use core_types::Protobuf;
+use ibc_proto::cosmos::crypto::multisig::v1beta1::MultiSignature;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::public::{DecodeError, PublicKey};
+
+/// Amino prefixes of the key types, they are needed to derive the address of a multisig key
+const AMINO_PREFIX_MULTISIG_THRESHOLD: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+const AMINO_PREFIX_SECP256K1: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+const AMINO_PREFIX_ED25519: [u8; 4] = [0x16, 0x24, 0xde, 0x64];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MultisigError {
+    #[error("threshold k of n multisignature: k <= 0")]
+    ZeroThreshold,
+    #[error("threshold k of n multisignature: len(pubKeys) < k")]
+    NotEnoughKeys,
+    #[error("bit array is missing from multisig mode info")]
+    MissingBitArray,
+    #[error("bit array size is incorrect, expecting: {expected}, got: {got}")]
+    BitArraySize { expected: usize, got: usize },
+    #[error("failed to decode multisignature: {0}")]
+    Decode(String),
+    #[error("mismatch between number of signatures ({signatures}) and mode infos ({mode_infos})")]
+    ModeInfos {
+        signatures: usize,
+        mode_infos: usize,
+    },
+    #[error("not enough signatures set, have {got}, expected {expected}")]
+    NotEnoughSignatures { expected: usize, got: usize },
+    #[error("number of set bits ({bits}) doesn't match the number of signatures ({signatures})")]
+    SignersMismatch { bits: usize, signatures: usize },
+}
+
+/// LegacyAminoPubKey specifies a public key type which nests multiple public keys and a
+/// threshold, it uses legacy amino address rules.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(try_from = "RawLegacyAminoPubKey")]
+pub struct LegacyAminoPubKey {
+    threshold: u32,
+    public_keys: Vec<PublicKey>,
+}
+
+/// Unvalidated representation of a multisig key used for deserialization
+#[derive(Deserialize)]
+struct RawLegacyAminoPubKey {
+    threshold: u32,
+    public_keys: Vec<PublicKey>,
+}
+
+impl TryFrom<RawLegacyAminoPubKey> for LegacyAminoPubKey {
+    type Error = MultisigError;
+
+    fn try_from(raw: RawLegacyAminoPubKey) -> Result<Self, Self::Error> {
+        Self::new(raw.threshold, raw.public_keys)
+    }
+}
+
+impl LegacyAminoPubKey {
+    pub fn new(threshold: u32, public_keys: Vec<PublicKey>) -> Result<Self, MultisigError> {
+        if threshold == 0 {
+            return Err(MultisigError::ZeroThreshold);
+        }
+
+        if public_keys.len() < threshold as usize {
+            return Err(MultisigError::NotEnoughKeys);
+        }
+
+        Ok(Self {
+            threshold,
+            public_keys,
+        })
+    }
+
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.public_keys
+    }
+
+    /// Returns the address of the key which is the first 20 bytes of the sha256 hash of its
+    /// amino encoding
+    pub fn get_address(&self) -> AccAddress {
+        let hash = Sha256::digest(self.amino_bytes());
+
+        hash[..20]
+            .try_into()
+            .expect("the slice is 20 bytes long which is less than AccAddress::MAX_ADDR_LEN")
+    }
+
+    /// Splits the multisignature into the signature data of every key which signed it. The
+    /// keys are returned in the order of the bit array together with their signature data,
+    /// nested multisig keys are returned as is and should be split by the caller.
+    pub fn signers(
+        &self,
+        multi: &Multi,
+        signature: &[u8],
+        sequence: u64,
+    ) -> Result<Vec<(&PublicKey, SignatureData)>, MultisigError> {
+        let bitarray = multi
+            .bitarray
+            .as_ref()
+            .ok_or(MultisigError::MissingBitArray)?;
+
+        let size = bitarray.count();
+        if size != self.public_keys.len() {
+            return Err(MultisigError::BitArraySize {
+                expected: self.public_keys.len(),
+                got: size,
+            });
+        }
+
+        let signatures = MultiSignature::decode(signature)
+            .map_err(|e| MultisigError::Decode(e.to_string()))?
+            .signatures;
+
+        if signatures.len() != multi.mode_infos.len() {
+            return Err(MultisigError::ModeInfos {
+                signatures: signatures.len(),
+                mode_infos: multi.mode_infos.len(),
+            });
+        }
+
+        if signatures.len() < self.threshold as usize {
+            return Err(MultisigError::NotEnoughSignatures {
+                expected: self.threshold as usize,
+                got: signatures.len(),
+            });
+        }
+
+        let signers = self
+            .public_keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| bitarray.get_index(*i))
+            .map(|(_, key)| key)
+            .collect::<Vec<_>>();
+
+        if signers.len() != signatures.len() {
+            return Err(MultisigError::SignersMismatch {
+                bits: signers.len(),
+                signatures: signatures.len(),
+            });
+        }
+
+        Ok(signers
+            .into_iter()
+            .zip(signatures.into_iter().zip(multi.mode_infos.iter().cloned()))
+            .map(|(key, (signature, mode_info))| {
+                (
+                    key,
+                    SignatureData {
+                        signature,
+                        sequence,
+                        mode_info,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    fn amino_bytes(&self) -> Vec<u8> {
+        let mut bytes = AMINO_PREFIX_MULTISIG_THRESHOLD.to_vec();
+
+        // field 1, varint
+        bytes.push(0x08);
+        prost::encoding::encode_varint(self.threshold.into(), &mut bytes);
+
+        for key in &self.public_keys {
+            let encoded = match key {
+                PublicKey::Secp256k1(key) => amino_key_bytes(AMINO_PREFIX_SECP256K1, key.clone()),
+                PublicKey::Ed25519(key) => amino_key_bytes(AMINO_PREFIX_ED25519, key.clone()),
+                PublicKey::Multisig(key) => key.amino_bytes(),
+            };
+
+            // field 2, length delimited
+            bytes.push(0x12);
+            prost::encoding::encode_varint(encoded.len() as u64, &mut bytes);
+            bytes.extend(encoded);
+        }
+
+        bytes
+    }
+}
+
+fn amino_key_bytes(prefix: [u8; 4], key: impl Into<Vec<u8>>) -> Vec<u8> {
+    let key = key.into();
+
+    let mut bytes = prefix.to_vec();
+    prost::encoding::encode_varint(key.len() as u64, &mut bytes);
+    bytes.extend(key);
+
+    bytes
+}
+
+mod inner {
+    use core_types::any::google::Any;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct LegacyAminoPubKey {
+        #[prost(uint32, tag = "1")]
+        pub threshold: u32,
+        #[prost(message, repeated, tag = "2")]
+        pub public_keys: Vec<Any>,
+    }
+}
+
+impl TryFrom<inner::LegacyAminoPubKey> for LegacyAminoPubKey {
+    type Error = DecodeError;
+
+    fn try_from(raw: inner::LegacyAminoPubKey) -> Result<Self, Self::Error> {
+        let public_keys = raw
+            .public_keys
+            .into_iter()
+            .map(PublicKey::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(raw.threshold, public_keys).map_err(|e| DecodeError(e.to_string()))
+    }
+}
+
+impl From<LegacyAminoPubKey> for inner::LegacyAminoPubKey {
+    fn from(key: LegacyAminoPubKey) -> inner::LegacyAminoPubKey {
+        inner::LegacyAminoPubKey {
+            threshold: key.threshold,
+            public_keys: key.public_keys.into_iter().map(Any::from).collect(),
+        }
+    }
+}
+
+impl Protobuf<inner::LegacyAminoPubKey> for LegacyAminoPubKey {}
+
+#[cfg(test)]
+mod tests {
+    use core_types::tx::mode_info::{CompactBitArray, ModeInfo, SignMode};
+
+    use super::*;
+
+    fn secp256k1_key(key: &str) -> PublicKey {
+        PublicKey::Secp256k1(
+            data_encoding::BASE64
+                .decode(key.as_bytes())
+                .expect("hard coded hex is valid")
+                .try_into()
+                .expect("hard coded key is valid"),
+        )
+    }
+
+    fn keys() -> Vec<PublicKey> {
+        vec![
+            secp256k1_key("AvUEsFHbsr40nTSmWh7CWYRZHGwf4cpRLtJlaRO4VAoq"),
+            secp256k1_key("A7Jg0Wg+RHwI7CAkSbCjpfWFROGtYYkUlaBVxCT6UXJ4"),
+        ]
+    }
+
+    #[test]
+    fn new_validates_threshold() {
+        assert_eq!(
+            LegacyAminoPubKey::new(0, keys()),
+            Err(MultisigError::ZeroThreshold)
+        );
+        assert_eq!(
+            LegacyAminoPubKey::new(3, keys()),
+            Err(MultisigError::NotEnoughKeys)
+        );
+        assert!(LegacyAminoPubKey::new(2, keys()).is_ok());
+    }
+
+    #[test]
+    fn amino_bytes_encoding() {
+        let key = LegacyAminoPubKey::new(1, keys()[..1].to_vec()).expect("valid threshold");
+
+        let mut expected = vec![0x22, 0xc1, 0xf7, 0xe2, 0x08, 0x01, 0x12, 0x26];
+        expected.extend([0xeb, 0x5a, 0xe9, 0x87, 0x21]);
+        expected.extend(Vec::<u8>::from(match &keys()[0] {
+            PublicKey::Secp256k1(key) => key.clone(),
+            _ => unreachable!(),
+        }));
+
+        assert_eq!(key.amino_bytes(), expected);
+        assert_ne!(key.get_address(), keys()[0].get_address());
+    }
+
+    #[test]
+    fn proto_and_json_round_trip() {
+        let key = PublicKey::Multisig(LegacyAminoPubKey::new(2, keys()).expect("valid threshold"));
+
+        let any = Any::from(key.clone());
+        assert_eq!(any.type_url, "/cosmos.crypto.multisig.LegacyAminoPubKey");
+        assert_eq!(PublicKey::try_from(any), Ok(key.clone()));
+
+        let json = serde_json::to_value(&key).expect("serialization is infallible");
+        assert_eq!(json["@type"], "/cosmos.crypto.multisig.LegacyAminoPubKey");
+        assert_eq!(json["threshold"], 2);
+        assert_eq!(
+            serde_json::from_value::<PublicKey>(json).expect("valid json"),
+            key
+        );
+    }
+
+    #[test]
+    fn signers_checks_bit_array_and_threshold() {
+        let key = LegacyAminoPubKey::new(1, keys()).expect("valid threshold");
+        let signature = MultiSignature {
+            signatures: vec![vec![1; 64]],
+        }
+        .encode_to_vec();
+        let mode_infos = vec![ModeInfo::Single(SignMode::Direct)];
+
+        let multi = Multi {
+            bitarray: Some(CompactBitArray {
+                extra_bits_stored: 2,
+                elems: vec![0b0100_0000],
+            }),
+            mode_infos: mode_infos.clone(),
+        };
+        let signers = key.signers(&multi, &signature, 7).expect("valid multisig");
+        assert_eq!(signers.len(), 1);
+        assert_eq!(signers[0].0, &keys()[1]);
+        assert_eq!(signers[0].1.sequence, 7);
+
+        let multi = Multi {
+            bitarray: Some(CompactBitArray {
+                extra_bits_stored: 3,
+                elems: vec![0b0100_0000],
+            }),
+            mode_infos: mode_infos.clone(),
+        };
+        assert_eq!(
+            key.signers(&multi, &signature, 7),
+            Err(MultisigError::BitArraySize {
+                expected: 2,
+                got: 3
+            })
+        );
+
+        let multi = Multi {
+            bitarray: Some(CompactBitArray {
+                extra_bits_stored: 2,
+                elems: vec![0b1100_0000],
+            }),
+            mode_infos,
+        };
+        assert_eq!(
+            key.signers(&multi, &signature, 7),
+            Err(MultisigError::SignersMismatch {
+                bits: 2,
+                signatures: 1
+            })
+        );
+    }
+}
@@ -1,10 +1,18 @@
 use address::AccAddress;
 use bytes::Bytes;
 use core_types::any::google::Any;
+use core_types::tx::{
+    mode_info::{ModeInfo, SignMode},
+    signature::SignatureData,
+};
 use core_types::Protobuf;
 use serde::{Deserialize, Serialize};
 
-use super::{ed25519::Ed25519PubKey, secp256k1::Secp256k1PubKey};
+use super::{
+    ed25519::Ed25519PubKey,
+    multisig::{LegacyAminoPubKey, MultisigError},
+    secp256k1::Secp256k1PubKey,
+};
 
 pub type SigningError = secp256k1::Error;
 
@@ -12,6 +20,18 @@ pub type SigningError = secp256k1::Error;
 #[error("invalid key: {0}")]
 pub struct DecodeError(pub String);
 
+#[derive(Debug, thiserror::Error)]
+pub enum SignatureVerificationError {
+    #[error("{0}")]
+    Multisig(#[from] MultisigError),
+    #[error("multisig public key requires multi mode info")]
+    SingleModeInfo,
+    #[error("multi mode info requires a multisig public key")]
+    MultiModeInfo,
+    #[error("{0}")]
+    Signing(#[from] SigningError),
+}
+
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum PublicKey {
@@ -20,7 +40,8 @@ pub enum PublicKey {
     //Secp256r1(Vec<u8>),
     #[serde(rename = "/cosmos.crypto.ed25519.PubKey")]
     Ed25519(Ed25519PubKey),
-    //Multisig(Vec<u8>),
+    #[serde(rename = "/cosmos.crypto.multisig.LegacyAminoPubKey")]
+    Multisig(LegacyAminoPubKey),
 }
 
 impl PublicKey {
@@ -32,6 +53,39 @@ impl PublicKey {
         match self {
             PublicKey::Secp256k1(key) => key.verify_signature(message, signature),
             PublicKey::Ed25519(key) => key.verify_signature(message, signature),
+            // a multisignature can't be checked against a single message since every signer
+            // may use a different sign mode, see `PublicKey::verify_signature_data`
+            PublicKey::Multisig(_) => Err(secp256k1::Error::InvalidSignature),
+        }
+    }
+
+    /// Verifies the signature of a single key or, recursively, the signatures of every key which
+    /// signed a multisignature. Sign bytes are requested per sign mode since every signer of a
+    /// multisignature may use its own mode.
+    pub fn verify_signature_data<E: From<SignatureVerificationError>>(
+        &self,
+        signature_data: &SignatureData,
+        sign_bytes: &impl Fn(&SignMode) -> Result<Vec<u8>, E>,
+    ) -> Result<(), E> {
+        match (self, &signature_data.mode_info) {
+            (PublicKey::Multisig(key), ModeInfo::Multi(multi)) => {
+                let signers = key
+                    .signers(multi, &signature_data.signature, signature_data.sequence)
+                    .map_err(SignatureVerificationError::from)?;
+
+                for (key, data) in signers {
+                    key.verify_signature_data(&data, sign_bytes)?;
+                }
+
+                Ok(())
+            }
+            (PublicKey::Multisig(_), ModeInfo::Single(_)) => {
+                Err(SignatureVerificationError::SingleModeInfo.into())
+            }
+            (_, ModeInfo::Multi(_)) => Err(SignatureVerificationError::MultiModeInfo.into()),
+            (key, ModeInfo::Single(mode)) => key
+                .verify_signature(sign_bytes(mode)?, &signature_data.signature)
+                .map_err(|e| SignatureVerificationError::from(e).into()),
         }
     }
 
@@ -39,6 +93,7 @@ impl PublicKey {
         match self {
             PublicKey::Secp256k1(key) => key.get_address(),
             PublicKey::Ed25519(key) => key.get_address(),
+            PublicKey::Multisig(key) => key.get_address(),
         }
     }
 }
@@ -58,6 +113,11 @@ impl TryFrom<Any> for PublicKey {
                     .map_err(|e| DecodeError(e.to_string()))?;
                 Ok(Self::Ed25519(key))
             }
+            "/cosmos.crypto.multisig.LegacyAminoPubKey" => {
+                let key = LegacyAminoPubKey::decode::<Bytes>(any.value.into())
+                    .map_err(|e| DecodeError(e.to_string()))?;
+                Ok(Self::Multisig(key))
+            }
 
             _ => Err(DecodeError(format!(
                 "Key type not recognized: {}",
@@ -78,6 +138,10 @@ impl From<PublicKey> for Any {
                 type_url: "/cosmos.crypto.ed25519.PubKey".to_string(),
                 value: key.encode_vec(),
             },
+            PublicKey::Multisig(key) => Any {
+                type_url: "/cosmos.crypto.multisig.LegacyAminoPubKey".to_string(),
+                value: key.encode_vec(),
+            },
         }
     }
 }
@@ -112,11 +176,16 @@ impl From<InformalPublicKey> for PublicKey {
     }
 }
 
-impl From<PublicKey> for TendermintPublicKey {
-    fn from(key: PublicKey) -> Self {
+impl TryFrom<PublicKey> for TendermintPublicKey {
+    type Error = DecodeError;
+
+    fn try_from(key: PublicKey) -> Result<Self, Self::Error> {
         match key {
-            PublicKey::Ed25519(value) => TendermintPublicKey::Ed25519(value.into()),
-            PublicKey::Secp256k1(value) => TendermintPublicKey::Secp256k1(value.into()),
+            PublicKey::Ed25519(value) => Ok(TendermintPublicKey::Ed25519(value.into())),
+            PublicKey::Secp256k1(value) => Ok(TendermintPublicKey::Secp256k1(value.into())),
+            PublicKey::Multisig(_) => Err(DecodeError(
+                "multisig keys can't be used as consensus keys".to_string(),
+            )),
         }
     }
 }
@@ -1,62 +1,327 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use core_types::any::google::Any;
+use ibc_proto::cosmos::base::query::v1beta1::PageResponse;
 use ibc_proto::cosmos::tx::v1beta1::service_server::Service;
 use ibc_proto::cosmos::tx::v1beta1::service_server::ServiceServer as TxServer;
 use ibc_proto::cosmos::tx::v1beta1::{
-    BroadcastTxRequest, BroadcastTxResponse, GetBlockWithTxsRequest, GetBlockWithTxsResponse,
-    GetTxRequest, GetTxResponse, GetTxsEventRequest, GetTxsEventResponse, SimulateRequest,
-    SimulateResponse,
+    BroadcastMode, BroadcastTxRequest, BroadcastTxResponse, GetBlockWithTxsRequest,
+    GetBlockWithTxsResponse, GetTxRequest, GetTxResponse, GetTxsEventRequest, GetTxsEventResponse,
+    OrderBy, SimulateRequest, SimulateResponse, Tx,
 };
+use prost::Message;
+use tendermint::informal::{encode_block, Hash};
+use tendermint::rpc::client::{Client, HttpClient, HttpClientUrl};
+use tendermint::rpc::query::Query;
+use tendermint::rpc::response::tx::Response as RpcTxResponse;
+use tendermint::rpc::url::Url;
+use tendermint::rpc::Order;
+use tendermint::types::proto::block::{BlockId, Height};
 use tonic::{Request, Response, Status};
 use tracing::info;
 
-pub struct TxService;
+use crate::baseapp::NodeSimulateHandler;
+use crate::types::pagination::request::QUERY_DEFAULT_LIMIT;
+use crate::types::response::tx::TxResponseRaw;
+
+const TX_TYPE_URL: &str = "/cosmos.tx.v1beta1.Tx";
+
+#[derive(Clone)]
+pub struct TxService<App> {
+    app: App,
+    tendermint_rpc_address: HttpClientUrl,
+}
+
+impl<App> TxService<App> {
+    fn client(&self) -> HttpClient {
+        HttpClient::new::<Url>(self.tendermint_rpc_address.clone().into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed")
+    }
+}
 
 #[tonic::async_trait]
-impl Service for TxService {
+impl<App: NodeSimulateHandler> Service for TxService<App> {
     async fn simulate(
         &self,
-        _request: Request<SimulateRequest>,
+        request: Request<SimulateRequest>,
     ) -> Result<Response<SimulateResponse>, Status> {
         info!("Received a gRPC request tx::simulate");
-        // TODO: run simulation once implemented
-        Ok(Response::new(SimulateResponse {
-            gas_info: None,
-            result: None,
-        }))
+        let tx_bytes = request.into_inner().tx_bytes;
+        if tx_bytes.is_empty() {
+            return Err(Status::invalid_argument("empty txBytes is not allowed"));
+        }
+
+        let response = self
+            .app
+            .simulate(tx_bytes.into())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        Ok(Response::new(response.into()))
     }
 
     async fn get_tx(
         &self,
-        _request: Request<GetTxRequest>,
+        request: Request<GetTxRequest>,
     ) -> Result<Response<GetTxResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::get_tx");
+        let hash = Hash::from_str(&request.into_inner().hash.to_uppercase())
+            .map_err(|e| Status::invalid_argument(format!("invalid tx hash: {e}")))?;
+
+        let client = self.client();
+        let tx = client
+            .tx(hash, false)
+            .await
+            .map_err(|e| Status::not_found(format!("tx not found: {e}")))?;
+        let timestamp = client
+            .block(tx.height)
+            .await
+            .map_err(rpc_error)?
+            .block
+            .header
+            .time
+            .to_string();
+
+        Ok(Response::new(GetTxResponse {
+            tx: Some(decode_tx(&tx.tx)?),
+            tx_response: Some(transcode(&tx_response(tx, timestamp))?),
+        }))
     }
 
     async fn broadcast_tx(
         &self,
-        _request: Request<BroadcastTxRequest>,
+        request: Request<BroadcastTxRequest>,
     ) -> Result<Response<BroadcastTxResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::broadcast_tx");
+        let BroadcastTxRequest { tx_bytes, mode } = request.into_inner();
+        if tx_bytes.is_empty() {
+            return Err(Status::invalid_argument("invalid empty tx"));
+        }
+
+        let client = self.client();
+        let tx_response = match BroadcastMode::try_from(mode) {
+            Ok(BroadcastMode::Sync) => {
+                let res = client
+                    .broadcast_tx_sync(tx_bytes)
+                    .await
+                    .map_err(rpc_error)?;
+                TxResponseRaw {
+                    txhash: res.hash.to_string(),
+                    code: res.code.into(),
+                    data: hex::encode(res.data),
+                    raw_log: res.log,
+                    ..Default::default()
+                }
+            }
+            Ok(BroadcastMode::Async) => {
+                let res = client
+                    .broadcast_tx_async(tx_bytes)
+                    .await
+                    .map_err(rpc_error)?;
+                TxResponseRaw {
+                    txhash: res.hash.to_string(),
+                    code: res.code.into(),
+                    data: hex::encode(res.data),
+                    raw_log: res.log,
+                    ..Default::default()
+                }
+            }
+            Ok(BroadcastMode::Block) => {
+                let res = client
+                    .broadcast_tx_commit(tx_bytes)
+                    .await
+                    .map_err(rpc_error)?;
+
+                // the transaction didn't get into the block if it failed the check
+                if res.check_tx.code.is_err() {
+                    TxResponseRaw {
+                        txhash: res.hash.to_string(),
+                        codespace: res.check_tx.codespace,
+                        code: res.check_tx.code.into(),
+                        raw_log: res.check_tx.log,
+                        gas_wanted: res.check_tx.gas_wanted,
+                        gas_used: res.check_tx.gas_used,
+                        ..Default::default()
+                    }
+                } else {
+                    TxResponseRaw {
+                        height: res.height.into(),
+                        txhash: res.hash.to_string(),
+                        codespace: res.deliver_tx.codespace,
+                        code: res.deliver_tx.code.into(),
+                        data: hex::encode(res.deliver_tx.data),
+                        raw_log: res.deliver_tx.log,
+                        info: res.deliver_tx.info,
+                        gas_wanted: res.deliver_tx.gas_wanted,
+                        gas_used: res.deliver_tx.gas_used,
+                        events: res.deliver_tx.events.into_iter().map(Into::into).collect(),
+                        ..Default::default()
+                    }
+                }
+            }
+            Ok(BroadcastMode::Unspecified) | Err(_) => {
+                return Err(Status::invalid_argument("invalid broadcast mode"))
+            }
+        };
+
+        Ok(Response::new(BroadcastTxResponse {
+            tx_response: Some(transcode(&tx_response)?),
+        }))
     }
 
     async fn get_txs_event(
         &self,
-        _request: Request<GetTxsEventRequest>,
+        request: Request<GetTxsEventRequest>,
     ) -> Result<Response<GetTxsEventResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::get_txs_event");
+        let GetTxsEventRequest {
+            events,
+            order_by,
+            page,
+            limit,
+            ..
+        } = request.into_inner();
+
+        if events.is_empty() {
+            return Err(Status::invalid_argument(
+                "must declare at least one event to search",
+            ));
+        }
+
+        let query = Query::from_str(&events.join(" AND "))
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let order = match OrderBy::try_from(order_by) {
+            Ok(OrderBy::Desc) => Order::Descending,
+            _ => Order::Ascending,
+        };
+        let page = match page {
+            0 => 1,
+            page => u32::try_from(page)
+                .map_err(|_| Status::invalid_argument("page number is too big"))?,
+        };
+        let limit = match limit {
+            0 => QUERY_DEFAULT_LIMIT,
+            limit => u8::try_from(limit).unwrap_or(u8::MAX),
+        };
+
+        let client = self.client();
+        let res = client
+            .tx_search(query, false, page, limit, order)
+            .await
+            .map_err(rpc_error)?;
+
+        let mut timestamps: HashMap<Height, String> = HashMap::with_capacity(res.txs.len());
+        let mut txs = Vec::with_capacity(res.txs.len());
+        let mut tx_responses = Vec::with_capacity(res.txs.len());
+        for tx in res.txs {
+            if !timestamps.contains_key(&tx.height) {
+                let block = client.block(tx.height).await.map_err(rpc_error)?;
+                timestamps.insert(tx.height, block.block.header.time.to_string());
+            }
+            let timestamp = timestamps[&tx.height].clone();
+
+            txs.push(decode_tx(&tx.tx)?);
+            tx_responses.push(transcode(&tx_response(tx, timestamp))?);
+        }
+
+        Ok(Response::new(GetTxsEventResponse {
+            txs,
+            tx_responses,
+            pagination: None,
+            total: res.total_count.into(),
+        }))
     }
 
     async fn get_block_with_txs(
         &self,
-        _request: Request<GetBlockWithTxsRequest>,
+        request: Request<GetBlockWithTxsRequest>,
     ) -> Result<Response<GetBlockWithTxsResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::get_block_with_txs");
+        let GetBlockWithTxsRequest { height, pagination } = request.into_inner();
+        let height = u32::try_from(height)
+            .map_err(|_| Status::invalid_argument(format!("invalid height {height}")))?;
+
+        let res = self.client().block(height).await.map_err(rpc_error)?;
+
+        let (offset, limit) = match pagination {
+            Some(pagination) if pagination.limit > 0 => (pagination.offset, pagination.limit),
+            Some(pagination) => (pagination.offset, QUERY_DEFAULT_LIMIT.into()),
+            None => (0, QUERY_DEFAULT_LIMIT.into()),
+        };
+        let total = res.block.data.len() as u64;
+        if offset > 0 && offset >= total {
+            return Err(Status::invalid_argument(format!(
+                "out of range: cannot paginate {total} txs with offset {offset} and limit {limit}"
+            )));
+        }
+
+        let txs = res
+            .block
+            .data
+            .iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|tx| decode_tx(tx))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Response::new(GetBlockWithTxsResponse {
+            txs,
+            block_id: Some(transcode(&BlockId::from(res.block_id))?),
+            block: Some(
+                Message::decode(encode_block(res.block).as_slice())
+                    .map_err(|e| Status::internal(e.to_string()))?,
+            ),
+            pagination: Some(PageResponse {
+                next_key: Vec::new(),
+                total,
+            }),
+        }))
     }
 }
 
-pub fn tx_server() -> TxServer<TxService> {
-    TxServer::new(TxService)
+pub fn tx_server<App: NodeSimulateHandler>(
+    app: App,
+    tendermint_rpc_address: HttpClientUrl,
+) -> TxServer<TxService<App>> {
+    TxServer::new(TxService {
+        app,
+        tendermint_rpc_address,
+    })
+}
+
+fn rpc_error(e: tendermint::rpc::error::Error) -> Status {
+    tracing::error!("Error connecting to Tendermint: {e}");
+    Status::unavailable("failed to connect to Tendermint")
+}
+
+/// Tendermint types of `ibc_proto` come from another version of the proto crate,
+/// so types which are equal on the wire are converted through their encoding
+fn transcode<T: Message, U: Message + Default>(value: &T) -> Result<U, Status> {
+    U::decode(value.encode_to_vec().as_slice()).map_err(|e| Status::internal(e.to_string()))
+}
+
+fn decode_tx(raw: &[u8]) -> Result<Tx, Status> {
+    // `TxRaw` and `Tx` have the same encoding
+    Tx::decode(raw).map_err(|e| Status::internal(format!("failed to decode tx: {e}")))
+}
+
+fn tx_response(tx: RpcTxResponse, timestamp: String) -> TxResponseRaw {
+    TxResponseRaw {
+        height: tx.height.into(),
+        txhash: tx.hash.to_string(),
+        codespace: tx.tx_result.codespace,
+        code: tx.tx_result.code.value(),
+        data: hex::encode(tx.tx_result.data),
+        raw_log: tx.tx_result.log,
+        // logs are typed in the proto definition, but aren't structured here
+        logs: String::new(),
+        info: tx.tx_result.info,
+        gas_wanted: tx.tx_result.gas_wanted,
+        gas_used: tx.tx_result.gas_used,
+        tx: Some(Any {
+            type_url: TX_TYPE_URL.to_owned(),
+            value: tx.tx,
+        }),
+        timestamp,
+        events: tx.tx_result.events.into_iter().map(Into::into).collect(),
+    }
 }
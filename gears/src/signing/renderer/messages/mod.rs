@@ -1,3 +1,4 @@
 //! Implementation of formatting messages into `Screen`
 
+pub mod msg_multi_send;
 pub mod msg_send;
@@ -0,0 +1,146 @@
+use crate::{
+    signing::handler::MetadataGetter,
+    types::{
+        address::AccAddress,
+        base::coins::UnsignedCoins,
+        msg::multi_send::MsgMultiSend,
+        rendering::screen::{Indent, Screen},
+    },
+};
+
+use crate::signing::renderer::value_renderer::{
+    DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError, TryPrimitiveValueRenderer,
+    TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+};
+
+impl ValueRenderer for MsgMultiSend {
+    /// Format `MsgMultiSend`. Inputs and outputs are rendered as repeated fields.
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens_vec = Vec::new();
+
+        screens_vec.append(&mut format_repeated(
+            "Input",
+            self.inputs
+                .iter()
+                .map(|input| (&input.address, &input.coins)),
+            get_metadata,
+        )?);
+
+        screens_vec.append(&mut format_repeated(
+            "Output",
+            self.outputs
+                .iter()
+                .map(|output| (&output.address, &output.coins)),
+            get_metadata,
+        )?);
+
+        Ok(screens_vec)
+    }
+}
+
+fn format_repeated<'a, MG: MetadataGetter>(
+    name: &str,
+    items: impl ExactSizeIterator<Item = (&'a AccAddress, &'a UnsignedCoins)>,
+    get_metadata: &MG,
+) -> Result<Vec<Screen>, RenderError> {
+    let count = items.len();
+    let mut screens_vec = Vec::new();
+
+    screens_vec.push(Screen {
+        title: format!("{name}s"),
+        content: DefaultPrimitiveRenderer::try_format(match count {
+            1 => format!("1 {name}"),
+            _ => format!("{count} {name}s"),
+        })?,
+        indent: Some(Indent::two()),
+        expert: false,
+    });
+
+    for (i, (address, coins)) in items.enumerate() {
+        screens_vec.push(Screen {
+            title: format!("{name}s ({}/{count})", i + 1),
+            content: DefaultPrimitiveRenderer::try_format(format!("{name} object"))?,
+            indent: Some(indent(3)),
+            expert: false,
+        });
+
+        screens_vec.push(Screen {
+            title: "Address".to_string(),
+            content: DefaultPrimitiveRenderer::format(address.clone()),
+            indent: Some(indent(4)),
+            expert: false,
+        });
+
+        screens_vec.push(Screen {
+            title: "Coins".to_string(),
+            content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                coins.to_owned(),
+                get_metadata,
+            )?,
+            indent: Some(indent(4)),
+            expert: false,
+        });
+    }
+
+    screens_vec.push(Screen {
+        title: String::new(),
+        content: DefaultPrimitiveRenderer::try_format(format!("End of {name}s"))?,
+        indent: Some(Indent::two()),
+        expert: false,
+    });
+
+    Ok(screens_vec)
+}
+
+fn indent(level: u8) -> Indent {
+    Indent::try_new(level).expect("indent is less than 16")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::types::{msg::multi_send::MsgMultiSend, rendering::screen::Screen};
+
+    use crate::signing::renderer::value_renderer::ValueRenderer;
+
+    #[test]
+    fn msg_multi_send_works() -> anyhow::Result<()> {
+        const MESSAGE: &str = r#"{
+            "inputs": [
+                { "address": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "coins": [{ "denom": "uatom", "amount": "20000000" }] }
+            ],
+            "outputs": [
+                { "address": "cosmos1ejrf4cur2wy6kfurg9f2jppp2h3afe5h6pkh5t", "coins": [{ "denom": "uatom", "amount": "10000000" }] },
+                { "address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "coins": [{ "denom": "uatom", "amount": "10000000" }] }
+            ]
+        }"#;
+
+        let msg: MsgMultiSend = serde_json::from_str(MESSAGE)?;
+
+        const SCREENS: &str = r#"[
+            { "title": "Inputs", "content": "1 Input", "indent": 2 },
+            { "title": "Inputs (1/1)", "content": "Input object", "indent": 3 },
+            { "title": "Address", "content": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "indent": 4 },
+            { "title": "Coins", "content": "20 ATOM", "indent": 4 },
+            { "content": "End of Inputs", "indent": 2 },
+            { "title": "Outputs", "content": "2 Outputs", "indent": 2 },
+            { "title": "Outputs (1/2)", "content": "Output object", "indent": 3 },
+            { "title": "Address", "content": "cosmos1ejrf4cur2wy6kfurg9f2jppp2h3afe5h6pkh5t", "indent": 4 },
+            { "title": "Coins", "content": "10 ATOM", "indent": 4 },
+            { "title": "Outputs (2/2)", "content": "Output object", "indent": 3 },
+            { "title": "Address", "content": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "indent": 4 },
+            { "title": "Coins", "content": "10 ATOM", "indent": 4 },
+            { "content": "End of Outputs", "indent": 2 }
+        ]"#;
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(SCREENS)?;
+
+        let actual_screens = ValueRenderer::format(&msg, &TestMetadataGetter);
+
+        assert!(actual_screens.is_ok(), "Failed to retrieve screens");
+        assert_eq!(expected_screens, actual_screens.expect("Unreachable"));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-use crate::types::auth::fee::Fee;
+use crate::{
+    signing::renderer::amino_renderer::{AminoRenderer, RenderError},
+    types::{
+        auth::fee::Fee,
+        tx::{body::TxBody, TxMessage},
+    },
+};
 
 // gears::core::base::coin::Coin has wrong order of fields
 // It is better to create a struct with correct order than
@@ -79,6 +85,38 @@ pub struct StdSignDoc {
 }
 
 impl StdSignDoc {
+    /// Creates a sign doc of the transaction for a signer with the given account number and sequence
+    pub fn new<M: TxMessage>(
+        body: &TxBody<M>,
+        fee: Fee,
+        chain_id: impl Into<String>,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Self, RenderError> {
+        let msgs = body
+            .messages
+            .iter()
+            .map(|msg| {
+                Ok(Msg {
+                    kind: msg.amino_url().to_string(),
+                    value: msg.render()?,
+                })
+            })
+            .collect::<Result<Vec<_>, RenderError>>()?;
+
+        Ok(Self {
+            account_number: account_number.to_string(),
+            chain_id: chain_id.into(),
+            fee: fee.into(),
+            memo: body.memo.clone(),
+            msgs,
+            sequence: sequence.to_string(),
+            // TODO: check impl
+            // timeout_height: Some(u64::from(body.timeout_height).to_string()),
+            timeout_height: None,
+        })
+    }
+
     pub fn to_sign_bytes(&self) -> Result<Vec<u8>, serde_json::Error> {
         serde_json::to_vec(self)
     }
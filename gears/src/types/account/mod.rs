@@ -9,6 +9,10 @@ use crate::crypto::public::{DecodeError, PublicKey};
 
 use super::address::AccAddress;
 
+mod vesting;
+
+pub use vesting::*;
+
 pub mod inner {
     pub use core_types::account::BaseAccount;
     pub use core_types::account::ModuleAccount;
@@ -119,6 +123,14 @@ pub enum Account {
     Base(BaseAccount),
     #[serde(rename = "/cosmos.auth.v1beta1.ModuleAccount")]
     Module(ModuleAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.ContinuousVestingAccount")]
+    ContinuousVesting(ContinuousVestingAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.DelayedVestingAccount")]
+    DelayedVesting(DelayedVestingAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.PeriodicVestingAccount")]
+    PeriodicVesting(PeriodicVestingAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.PermanentLockedAccount")]
+    PermanentLocked(PermanentLockedAccount),
 }
 
 impl Account {
@@ -133,59 +145,81 @@ impl Account {
         Self::Base(acc)
     }
 
-    pub fn get_public_key(&self) -> Option<&PublicKey> {
+    pub fn base_account(&self) -> &BaseAccount {
         match self {
-            Account::Base(acct) => acct.pub_key.as_ref(),
-            Account::Module(acct) => acct.base_account.pub_key.as_ref(),
+            Account::Base(acct) => acct,
+            Account::Module(acct) => &acct.base_account,
+            Account::ContinuousVesting(acct) => &acct.base_vesting_account.base_account,
+            Account::DelayedVesting(acct) => &acct.base_vesting_account.base_account,
+            Account::PeriodicVesting(acct) => &acct.base_vesting_account.base_account,
+            Account::PermanentLocked(acct) => &acct.base_vesting_account.base_account,
         }
     }
 
-    pub fn get_address(&self) -> &AccAddress {
+    pub fn base_account_mut(&mut self) -> &mut BaseAccount {
         match self {
-            Account::Base(base) => &base.address,
-            Account::Module(module) => &module.base_account.address,
+            Account::Base(acct) => acct,
+            Account::Module(acct) => &mut acct.base_account,
+            Account::ContinuousVesting(acct) => &mut acct.base_vesting_account.base_account,
+            Account::DelayedVesting(acct) => &mut acct.base_vesting_account.base_account,
+            Account::PeriodicVesting(acct) => &mut acct.base_vesting_account.base_account,
+            Account::PermanentLocked(acct) => &mut acct.base_vesting_account.base_account,
         }
     }
 
-    pub fn set_public_key(&mut self, key: PublicKey) {
+    /// Returns the vesting account if the account vests coins
+    pub fn vesting_account(&self) -> Option<&dyn VestingAccount> {
         match self {
-            Account::Base(acct) => acct.pub_key = Some(key),
-            Account::Module(acct) => acct.base_account.pub_key = Some(key),
+            Account::Base(_) | Account::Module(_) => None,
+            Account::ContinuousVesting(acct) => Some(acct),
+            Account::DelayedVesting(acct) => Some(acct),
+            Account::PeriodicVesting(acct) => Some(acct),
+            Account::PermanentLocked(acct) => Some(acct),
         }
     }
 
-    pub fn set_account_number(&mut self, number: u64) {
+    pub fn vesting_account_mut(&mut self) -> Option<&mut dyn VestingAccount> {
         match self {
-            Account::Base(acct) => acct.account_number = number,
-            Account::Module(acct) => acct.base_account.account_number = number,
+            Account::Base(_) | Account::Module(_) => None,
+            Account::ContinuousVesting(acct) => Some(acct),
+            Account::DelayedVesting(acct) => Some(acct),
+            Account::PeriodicVesting(acct) => Some(acct),
+            Account::PermanentLocked(acct) => Some(acct),
         }
     }
 
+    pub fn get_public_key(&self) -> Option<&PublicKey> {
+        self.base_account().pub_key.as_ref()
+    }
+
+    pub fn get_address(&self) -> &AccAddress {
+        &self.base_account().address
+    }
+
+    pub fn set_public_key(&mut self, key: PublicKey) {
+        self.base_account_mut().pub_key = Some(key)
+    }
+
+    pub fn set_account_number(&mut self, number: u64) {
+        self.base_account_mut().account_number = number
+    }
+
     pub fn increment_sequence(&mut self) {
-        match self {
-            Account::Base(acct) => acct.sequence += 1,
-            Account::Module(acct) => acct.base_account.sequence += 1,
-        }
+        self.base_account_mut().sequence += 1
     }
 
     pub fn get_sequence(&self) -> u64 {
-        match self {
-            Account::Base(acct) => acct.sequence,
-            Account::Module(acct) => acct.base_account.sequence,
-        }
+        self.base_account().sequence
     }
 
     pub fn get_account_number(&self) -> u64 {
-        match self {
-            Account::Base(acct) => acct.account_number,
-            Account::Module(acct) => acct.base_account.account_number,
-        }
+        self.base_account().account_number
     }
 
     pub fn has_permissions(&self, perm: impl AsRef<str>) -> bool {
         match self {
-            Account::Base(_) => false, // TODO:NOW
             Account::Module(var) => var.permissions.iter().any(|this| this == perm.as_ref()),
+            _ => false, // TODO:NOW
         }
     }
 }
@@ -205,6 +239,26 @@ impl TryFrom<Any> for Account {
                     .map_err(|e| core_types::errors::CoreError::DecodeGeneral(e.to_string()))?;
                 Ok(Account::Module(module))
             }
+            "/cosmos.vesting.v1beta1.ContinuousVestingAccount" => {
+                let acct = ContinuousVestingAccount::decode::<Bytes>(any.value.into())
+                    .map_err(|e| core_types::errors::CoreError::DecodeGeneral(e.to_string()))?;
+                Ok(Account::ContinuousVesting(acct))
+            }
+            "/cosmos.vesting.v1beta1.DelayedVestingAccount" => {
+                let acct = DelayedVestingAccount::decode::<Bytes>(any.value.into())
+                    .map_err(|e| core_types::errors::CoreError::DecodeGeneral(e.to_string()))?;
+                Ok(Account::DelayedVesting(acct))
+            }
+            "/cosmos.vesting.v1beta1.PeriodicVestingAccount" => {
+                let acct = PeriodicVestingAccount::decode::<Bytes>(any.value.into())
+                    .map_err(|e| core_types::errors::CoreError::DecodeGeneral(e.to_string()))?;
+                Ok(Account::PeriodicVesting(acct))
+            }
+            "/cosmos.vesting.v1beta1.PermanentLockedAccount" => {
+                let acct = PermanentLockedAccount::decode::<Bytes>(any.value.into())
+                    .map_err(|e| core_types::errors::CoreError::DecodeGeneral(e.to_string()))?;
+                Ok(Account::PermanentLocked(acct))
+            }
             _ => Err(core_types::errors::CoreError::DecodeAny(format!(
                 "account type not recognized: {}",
                 any.type_url
@@ -224,6 +278,22 @@ impl From<Account> for Any {
                 type_url: "/cosmos.auth.v1beta1.ModuleAccount".to_string(),
                 value: module.encode_vec(),
             },
+            Account::ContinuousVesting(acct) => Any {
+                type_url: "/cosmos.vesting.v1beta1.ContinuousVestingAccount".to_string(),
+                value: acct.encode_vec(),
+            },
+            Account::DelayedVesting(acct) => Any {
+                type_url: "/cosmos.vesting.v1beta1.DelayedVestingAccount".to_string(),
+                value: acct.encode_vec(),
+            },
+            Account::PeriodicVesting(acct) => Any {
+                type_url: "/cosmos.vesting.v1beta1.PeriodicVestingAccount".to_string(),
+                value: acct.encode_vec(),
+            },
+            Account::PermanentLocked(acct) => Any {
+                type_url: "/cosmos.vesting.v1beta1.PermanentLockedAccount".to_string(),
+                value: acct.encode_vec(),
+            },
         }
     }
 }
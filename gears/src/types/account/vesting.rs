@@ -0,0 +1,839 @@
+use core_types::errors::CoreError;
+use core_types::serializers::serialize_number_to_string;
+use core_types::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_number_from_string;
+use tendermint::types::time::timestamp::Timestamp;
+
+use crate::types::{
+    base::{
+        coin::{inner::Coin as RawCoin, UnsignedCoin},
+        errors::CoinError,
+    },
+    denom::Denom,
+    uint::Uint256,
+};
+
+use super::{inner, BaseAccount};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VestingError {
+    #[error("delegation attempt with zero coins or insufficient funds")]
+    InsufficientFunds,
+    #[error("undelegation attempt with zero coins")]
+    ZeroUndelegation,
+    #[error("vesting start-time cannot be before end-time")]
+    InvalidStartTime,
+    #[error("vesting end time does not match length of all vesting periods")]
+    PeriodsEndTime,
+    #[error("original vesting coins does not match the sum of all coins in vesting periods")]
+    PeriodsOriginalVesting,
+}
+
+/// VestingAccount defines an account type that vests coins via a vesting schedule.
+/// Vesting accounts only differ in the way they compute the vested coins, the rest
+/// of the logic is shared through the base vesting account.
+pub trait VestingAccount {
+    fn base_vesting_account(&self) -> &BaseVestingAccount;
+
+    fn base_vesting_account_mut(&mut self) -> &mut BaseVestingAccount;
+
+    /// vested_coins returns the total amount of vested coins for the account at the
+    /// given block time
+    fn vested_coins(&self, time: &Timestamp) -> Vec<UnsignedCoin>;
+
+    /// vesting_coins returns the total amount of vesting coins for the account at the
+    /// given block time
+    fn vesting_coins(&self, time: &Timestamp) -> Vec<UnsignedCoin> {
+        saturating_sub(
+            &self.base_vesting_account().original_vesting,
+            &self.vested_coins(time),
+        )
+    }
+
+    /// locked_coins returns the set of coins that are not spendable (i.e. locked)
+    /// at the given block time
+    fn locked_coins(&self, time: &Timestamp) -> Vec<UnsignedCoin> {
+        self.base_vesting_account()
+            .locked_coins_from_vesting(&self.vesting_coins(time))
+    }
+
+    /// track_delegation tracks a desired delegation amount by setting the appropriate
+    /// values for the amount of delegated vesting, delegated free, and reducing the
+    /// overall amount of base coins
+    fn track_delegation(
+        &mut self,
+        time: &Timestamp,
+        balance: &[UnsignedCoin],
+        amount: &[UnsignedCoin],
+    ) -> Result<(), VestingError> {
+        let vesting_coins = self.vesting_coins(time);
+        self.base_vesting_account_mut()
+            .track_delegation(balance, &vesting_coins, amount)
+    }
+
+    /// track_undelegation tracks an undelegation amount by setting the necessary
+    /// values by which delegated vesting and delegated vesting need to decrease and
+    /// by which amount the base coins need to increase
+    fn track_undelegation(&mut self, amount: &[UnsignedCoin]) -> Result<(), VestingError> {
+        self.base_vesting_account_mut().track_undelegation(amount)
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct BaseVestingAccountRaw {
+    #[prost(message, optional, tag = "1")]
+    pub base_account: Option<inner::BaseAccount>,
+    #[prost(message, repeated, tag = "2")]
+    pub original_vesting: Vec<RawCoin>,
+    #[prost(message, repeated, tag = "3")]
+    pub delegated_free: Vec<RawCoin>,
+    #[prost(message, repeated, tag = "4")]
+    pub delegated_vesting: Vec<RawCoin>,
+    #[prost(int64, tag = "5")]
+    pub end_time: i64,
+}
+
+/// BaseVestingAccount implements the VestingAccount interface. It contains all
+/// the necessary fields needed for any vesting account implementation.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BaseVestingAccount {
+    pub base_account: BaseAccount,
+    pub original_vesting: Vec<UnsignedCoin>,
+    #[serde(default)]
+    pub delegated_free: Vec<UnsignedCoin>,
+    #[serde(default)]
+    pub delegated_vesting: Vec<UnsignedCoin>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_number_to_string")]
+    pub end_time: i64,
+}
+
+impl BaseVestingAccount {
+    pub fn new(
+        base_account: BaseAccount,
+        original_vesting: Vec<UnsignedCoin>,
+        end_time: i64,
+    ) -> Self {
+        Self {
+            base_account,
+            original_vesting,
+            delegated_free: vec![],
+            delegated_vesting: vec![],
+            end_time,
+        }
+    }
+
+    /// locked_coins_from_vesting returns all the coins that are not spendable (i.e. locked)
+    /// from the vesting coins. The delegated vesting coins are already removed from the
+    /// balance, so only the rest of the vesting coins is locked.
+    pub fn locked_coins_from_vesting(&self, vesting_coins: &[UnsignedCoin]) -> Vec<UnsignedCoin> {
+        saturating_sub(vesting_coins, &self.delegated_vesting)
+    }
+
+    /// track_delegation splits the delegated amount into the delegated vesting part,
+    /// which is bounded by the vesting coins which are not delegated yet, and the
+    /// delegated free part
+    pub fn track_delegation(
+        &mut self,
+        balance: &[UnsignedCoin],
+        vesting_coins: &[UnsignedCoin],
+        amount: &[UnsignedCoin],
+    ) -> Result<(), VestingError> {
+        if amount
+            .iter()
+            .any(|coin| coin.amount.is_zero() || amount_of(balance, &coin.denom) < coin.amount)
+        {
+            return Err(VestingError::InsufficientFunds);
+        }
+
+        for coin in amount {
+            let vesting_amount = amount_of(vesting_coins, &coin.denom);
+            let delegated_vesting_amount = amount_of(&self.delegated_vesting, &coin.denom);
+
+            // x := min(max(V - DV, 0), D)
+            // y := D - x
+            let x = vesting_amount
+                .saturating_sub(delegated_vesting_amount)
+                .min(coin.amount);
+            let y = coin.amount - x;
+
+            add_amount(&mut self.delegated_vesting, &coin.denom, x);
+            add_amount(&mut self.delegated_free, &coin.denom, y);
+        }
+
+        Ok(())
+    }
+
+    /// track_undelegation removes the undelegated amount from the delegated free coins
+    /// first, and the rest from the delegated vesting coins
+    pub fn track_undelegation(&mut self, amount: &[UnsignedCoin]) -> Result<(), VestingError> {
+        if amount.iter().any(|coin| coin.amount.is_zero()) {
+            return Err(VestingError::ZeroUndelegation);
+        }
+
+        for coin in amount {
+            // x := min(DF, D)
+            // y := min(DV, D - x)
+            let x = amount_of(&self.delegated_free, &coin.denom).min(coin.amount);
+            let y = amount_of(&self.delegated_vesting, &coin.denom).min(coin.amount - x);
+
+            sub_amount(&mut self.delegated_free, &coin.denom, x);
+            sub_amount(&mut self.delegated_vesting, &coin.denom, y);
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<BaseVestingAccountRaw> for BaseVestingAccount {
+    type Error = CoreError;
+
+    fn try_from(raw: BaseVestingAccountRaw) -> Result<Self, Self::Error> {
+        let base_account = raw
+            .base_account
+            .ok_or(CoreError::DecodeGeneral(
+                "missing base account field".into(),
+            ))?
+            .try_into()?;
+
+        Ok(BaseVestingAccount {
+            base_account,
+            original_vesting: coins_from_raw(raw.original_vesting)?,
+            delegated_free: coins_from_raw(raw.delegated_free)?,
+            delegated_vesting: coins_from_raw(raw.delegated_vesting)?,
+            end_time: raw.end_time,
+        })
+    }
+}
+
+impl From<BaseVestingAccount> for BaseVestingAccountRaw {
+    fn from(
+        BaseVestingAccount {
+            base_account,
+            original_vesting,
+            delegated_free,
+            delegated_vesting,
+            end_time,
+        }: BaseVestingAccount,
+    ) -> Self {
+        Self {
+            base_account: Some(base_account.into()),
+            original_vesting: coins_into_raw(original_vesting),
+            delegated_free: coins_into_raw(delegated_free),
+            delegated_vesting: coins_into_raw(delegated_vesting),
+            end_time,
+        }
+    }
+}
+
+impl Protobuf<BaseVestingAccountRaw> for BaseVestingAccount {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ContinuousVestingAccountRaw {
+    #[prost(message, optional, tag = "1")]
+    pub base_vesting_account: Option<BaseVestingAccountRaw>,
+    #[prost(int64, tag = "2")]
+    pub start_time: i64,
+}
+
+/// ContinuousVestingAccount implements the VestingAccount interface. It
+/// continuously vests by unlocking coins linearly with respect to time.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ContinuousVestingAccount {
+    pub base_vesting_account: BaseVestingAccount,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_number_to_string")]
+    pub start_time: i64,
+}
+
+impl ContinuousVestingAccount {
+    pub fn new(base_vesting_account: BaseVestingAccount, start_time: i64) -> Self {
+        Self {
+            base_vesting_account,
+            start_time,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), VestingError> {
+        if self.start_time >= self.base_vesting_account.end_time {
+            return Err(VestingError::InvalidStartTime);
+        }
+
+        Ok(())
+    }
+}
+
+impl VestingAccount for ContinuousVestingAccount {
+    fn base_vesting_account(&self) -> &BaseVestingAccount {
+        &self.base_vesting_account
+    }
+
+    fn base_vesting_account_mut(&mut self) -> &mut BaseVestingAccount {
+        &mut self.base_vesting_account
+    }
+
+    /// The coins vest linearly between the start and the end time. Vested amounts are
+    /// rounded down.
+    fn vested_coins(&self, time: &Timestamp) -> Vec<UnsignedCoin> {
+        let time = i64::from(time.timestamp_seconds());
+        let end_time = self.base_vesting_account.end_time;
+
+        if time <= self.start_time {
+            return vec![];
+        } else if time >= end_time {
+            return self.base_vesting_account.original_vesting.clone();
+        }
+
+        // both values are positive because start_time < time < end_time
+        let elapsed = Uint256::from((time - self.start_time) as u64);
+        let duration = Uint256::from((end_time - self.start_time) as u64);
+
+        self.base_vesting_account
+            .original_vesting
+            .iter()
+            .map(|coin| UnsignedCoin {
+                denom: coin.denom.clone(),
+                amount: coin.amount.multiply_ratio(elapsed, duration),
+            })
+            .filter(|coin| !coin.amount.is_zero())
+            .collect()
+    }
+}
+
+impl TryFrom<ContinuousVestingAccountRaw> for ContinuousVestingAccount {
+    type Error = CoreError;
+
+    fn try_from(raw: ContinuousVestingAccountRaw) -> Result<Self, Self::Error> {
+        Ok(ContinuousVestingAccount {
+            base_vesting_account: base_vesting_account_from_raw(raw.base_vesting_account)?,
+            start_time: raw.start_time,
+        })
+    }
+}
+
+impl From<ContinuousVestingAccount> for ContinuousVestingAccountRaw {
+    fn from(acct: ContinuousVestingAccount) -> Self {
+        Self {
+            base_vesting_account: Some(acct.base_vesting_account.into()),
+            start_time: acct.start_time,
+        }
+    }
+}
+
+impl Protobuf<ContinuousVestingAccountRaw> for ContinuousVestingAccount {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct DelayedVestingAccountRaw {
+    #[prost(message, optional, tag = "1")]
+    pub base_vesting_account: Option<BaseVestingAccountRaw>,
+}
+
+/// DelayedVestingAccount implements the VestingAccount interface. It vests all
+/// coins after a specific time, but non prior. In other words, it keeps them
+/// locked until a specified time.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DelayedVestingAccount {
+    pub base_vesting_account: BaseVestingAccount,
+}
+
+impl DelayedVestingAccount {
+    pub fn new(base_vesting_account: BaseVestingAccount) -> Self {
+        Self {
+            base_vesting_account,
+        }
+    }
+}
+
+impl VestingAccount for DelayedVestingAccount {
+    fn base_vesting_account(&self) -> &BaseVestingAccount {
+        &self.base_vesting_account
+    }
+
+    fn base_vesting_account_mut(&mut self) -> &mut BaseVestingAccount {
+        &mut self.base_vesting_account
+    }
+
+    fn vested_coins(&self, time: &Timestamp) -> Vec<UnsignedCoin> {
+        if i64::from(time.timestamp_seconds()) >= self.base_vesting_account.end_time {
+            self.base_vesting_account.original_vesting.clone()
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl TryFrom<DelayedVestingAccountRaw> for DelayedVestingAccount {
+    type Error = CoreError;
+
+    fn try_from(raw: DelayedVestingAccountRaw) -> Result<Self, Self::Error> {
+        Ok(DelayedVestingAccount {
+            base_vesting_account: base_vesting_account_from_raw(raw.base_vesting_account)?,
+        })
+    }
+}
+
+impl From<DelayedVestingAccount> for DelayedVestingAccountRaw {
+    fn from(acct: DelayedVestingAccount) -> Self {
+        Self {
+            base_vesting_account: Some(acct.base_vesting_account.into()),
+        }
+    }
+}
+
+impl Protobuf<DelayedVestingAccountRaw> for DelayedVestingAccount {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PeriodRaw {
+    #[prost(int64, tag = "1")]
+    pub length: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub amount: Vec<RawCoin>,
+}
+
+/// Period defines a length of time and amount of coins that will vest.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Period {
+    /// Period duration in seconds
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_number_to_string")]
+    pub length: i64,
+    pub amount: Vec<UnsignedCoin>,
+}
+
+impl TryFrom<PeriodRaw> for Period {
+    type Error = CoreError;
+
+    fn try_from(raw: PeriodRaw) -> Result<Self, Self::Error> {
+        Ok(Period {
+            length: raw.length,
+            amount: coins_from_raw(raw.amount)?,
+        })
+    }
+}
+
+impl From<Period> for PeriodRaw {
+    fn from(period: Period) -> Self {
+        Self {
+            length: period.length,
+            amount: coins_into_raw(period.amount),
+        }
+    }
+}
+
+impl Protobuf<PeriodRaw> for Period {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PeriodicVestingAccountRaw {
+    #[prost(message, optional, tag = "1")]
+    pub base_vesting_account: Option<BaseVestingAccountRaw>,
+    #[prost(int64, tag = "2")]
+    pub start_time: i64,
+    #[prost(message, repeated, tag = "3")]
+    pub vesting_periods: Vec<PeriodRaw>,
+}
+
+/// PeriodicVestingAccount implements the VestingAccount interface. It
+/// periodically vests by unlocking coins during each specified period.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PeriodicVestingAccount {
+    pub base_vesting_account: BaseVestingAccount,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_number_to_string")]
+    pub start_time: i64,
+    pub vesting_periods: Vec<Period>,
+}
+
+impl PeriodicVestingAccount {
+    /// Creates the account from the periods. The original vesting coins and the end time
+    /// are computed from the periods.
+    pub fn new(base_account: BaseAccount, start_time: i64, vesting_periods: Vec<Period>) -> Self {
+        let mut end_time = start_time;
+        let mut original_vesting = vec![];
+        for period in &vesting_periods {
+            end_time += period.length;
+            for coin in &period.amount {
+                add_amount(&mut original_vesting, &coin.denom, coin.amount);
+            }
+        }
+
+        Self {
+            base_vesting_account: BaseVestingAccount::new(base_account, original_vesting, end_time),
+            start_time,
+            vesting_periods,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), VestingError> {
+        if self.start_time >= self.base_vesting_account.end_time {
+            return Err(VestingError::InvalidStartTime);
+        }
+
+        let mut end_time = self.start_time;
+        let mut original_vesting = vec![];
+        for period in &self.vesting_periods {
+            end_time += period.length;
+            for coin in &period.amount {
+                add_amount(&mut original_vesting, &coin.denom, coin.amount);
+            }
+        }
+
+        if end_time != self.base_vesting_account.end_time {
+            return Err(VestingError::PeriodsEndTime);
+        }
+
+        if original_vesting != self.base_vesting_account.original_vesting {
+            return Err(VestingError::PeriodsOriginalVesting);
+        }
+
+        Ok(())
+    }
+}
+
+impl VestingAccount for PeriodicVestingAccount {
+    fn base_vesting_account(&self) -> &BaseVestingAccount {
+        &self.base_vesting_account
+    }
+
+    fn base_vesting_account_mut(&mut self) -> &mut BaseVestingAccount {
+        &mut self.base_vesting_account
+    }
+
+    /// The coins of a period vest once the whole period has elapsed
+    fn vested_coins(&self, time: &Timestamp) -> Vec<UnsignedCoin> {
+        let time = i64::from(time.timestamp_seconds());
+
+        if time <= self.start_time {
+            return vec![];
+        } else if time >= self.base_vesting_account.end_time {
+            return self.base_vesting_account.original_vesting.clone();
+        }
+
+        let mut vested = vec![];
+        let mut period_start_time = self.start_time;
+        for period in &self.vesting_periods {
+            if time - period_start_time < period.length {
+                break;
+            }
+
+            for coin in &period.amount {
+                add_amount(&mut vested, &coin.denom, coin.amount);
+            }
+
+            period_start_time += period.length;
+        }
+
+        vested
+    }
+}
+
+impl TryFrom<PeriodicVestingAccountRaw> for PeriodicVestingAccount {
+    type Error = CoreError;
+
+    fn try_from(raw: PeriodicVestingAccountRaw) -> Result<Self, Self::Error> {
+        Ok(PeriodicVestingAccount {
+            base_vesting_account: base_vesting_account_from_raw(raw.base_vesting_account)?,
+            start_time: raw.start_time,
+            vesting_periods: raw
+                .vesting_periods
+                .into_iter()
+                .map(Period::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<PeriodicVestingAccount> for PeriodicVestingAccountRaw {
+    fn from(acct: PeriodicVestingAccount) -> Self {
+        Self {
+            base_vesting_account: Some(acct.base_vesting_account.into()),
+            start_time: acct.start_time,
+            vesting_periods: acct.vesting_periods.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<PeriodicVestingAccountRaw> for PeriodicVestingAccount {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PermanentLockedAccountRaw {
+    #[prost(message, optional, tag = "1")]
+    pub base_vesting_account: Option<BaseVestingAccountRaw>,
+}
+
+/// PermanentLockedAccount implements the VestingAccount interface. It does
+/// not ever release coins from being locked, but the coins can still be
+/// delegated.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PermanentLockedAccount {
+    pub base_vesting_account: BaseVestingAccount,
+}
+
+impl PermanentLockedAccount {
+    pub fn new(base_account: BaseAccount, original_vesting: Vec<UnsignedCoin>) -> Self {
+        Self {
+            // the end time is never reached
+            base_vesting_account: BaseVestingAccount::new(base_account, original_vesting, 0),
+        }
+    }
+}
+
+impl VestingAccount for PermanentLockedAccount {
+    fn base_vesting_account(&self) -> &BaseVestingAccount {
+        &self.base_vesting_account
+    }
+
+    fn base_vesting_account_mut(&mut self) -> &mut BaseVestingAccount {
+        &mut self.base_vesting_account
+    }
+
+    fn vested_coins(&self, _time: &Timestamp) -> Vec<UnsignedCoin> {
+        vec![]
+    }
+}
+
+impl TryFrom<PermanentLockedAccountRaw> for PermanentLockedAccount {
+    type Error = CoreError;
+
+    fn try_from(raw: PermanentLockedAccountRaw) -> Result<Self, Self::Error> {
+        Ok(PermanentLockedAccount {
+            base_vesting_account: base_vesting_account_from_raw(raw.base_vesting_account)?,
+        })
+    }
+}
+
+impl From<PermanentLockedAccount> for PermanentLockedAccountRaw {
+    fn from(acct: PermanentLockedAccount) -> Self {
+        Self {
+            base_vesting_account: Some(acct.base_vesting_account.into()),
+        }
+    }
+}
+
+impl Protobuf<PermanentLockedAccountRaw> for PermanentLockedAccount {}
+
+fn base_vesting_account_from_raw(
+    raw: Option<BaseVestingAccountRaw>,
+) -> Result<BaseVestingAccount, CoreError> {
+    raw.ok_or(CoreError::DecodeGeneral(
+        "missing base vesting account field".into(),
+    ))?
+    .try_into()
+}
+
+fn coins_from_raw(coins: Vec<RawCoin>) -> Result<Vec<UnsignedCoin>, CoreError> {
+    coins
+        .into_iter()
+        .map(|coin| {
+            UnsignedCoin::try_from(coin).map_err(|e: CoinError| CoreError::Coin(e.to_string()))
+        })
+        .collect()
+}
+
+fn coins_into_raw(coins: Vec<UnsignedCoin>) -> Vec<RawCoin> {
+    coins.into_iter().map(Into::into).collect()
+}
+
+fn amount_of(coins: &[UnsignedCoin], denom: &Denom) -> Uint256 {
+    coins
+        .iter()
+        .find(|coin| &coin.denom == denom)
+        .map(|coin| coin.amount)
+        .unwrap_or_default()
+}
+
+/// Adds the amount to the coin of the denom, keeping the coins sorted by denom
+fn add_amount(coins: &mut Vec<UnsignedCoin>, denom: &Denom, amount: Uint256) {
+    if amount.is_zero() {
+        return;
+    }
+
+    match coins.binary_search_by(|coin| coin.denom.cmp(denom)) {
+        Ok(i) => coins[i].amount += amount,
+        Err(i) => coins.insert(
+            i,
+            UnsignedCoin {
+                denom: denom.clone(),
+                amount,
+            },
+        ),
+    }
+}
+
+/// Subtracts the amount from the coin of the denom, the coin is removed once it reaches zero
+fn sub_amount(coins: &mut Vec<UnsignedCoin>, denom: &Denom, amount: Uint256) {
+    if let Ok(i) = coins.binary_search_by(|coin| coin.denom.cmp(denom)) {
+        coins[i].amount = coins[i].amount.saturating_sub(amount);
+        if coins[i].amount.is_zero() {
+            coins.remove(i);
+        }
+    }
+}
+
+/// Subtracts the coins denom by denom, the amounts which would be negative are dropped
+fn saturating_sub(coins: &[UnsignedCoin], other: &[UnsignedCoin]) -> Vec<UnsignedCoin> {
+    coins
+        .iter()
+        .map(|coin| UnsignedCoin {
+            denom: coin.denom.clone(),
+            amount: coin.amount.saturating_sub(amount_of(other, &coin.denom)),
+        })
+        .filter(|coin| !coin.amount.is_zero())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+    use crate::types::address::AccAddress;
+
+    fn coins(coins: &str) -> Vec<UnsignedCoin> {
+        coins
+            .split(',')
+            .map(|coin| coin.parse().unwrap_test())
+            .collect()
+    }
+
+    fn time(seconds: i64) -> Timestamp {
+        Timestamp::try_new(seconds, 0).unwrap_test()
+    }
+
+    fn base_vesting_account(original_vesting: &str, end_time: i64) -> BaseVestingAccount {
+        BaseVestingAccount::new(
+            BaseAccount {
+                address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")
+                    .unwrap_test(),
+                pub_key: None,
+                account_number: 0,
+                sequence: 0,
+            },
+            coins(original_vesting),
+            end_time,
+        )
+    }
+
+    #[test]
+    fn continuous_vesting_coins() {
+        let acct =
+            ContinuousVestingAccount::new(base_vesting_account("100fee,50stake", 2000), 1000);
+
+        assert_eq!(acct.vested_coins(&time(1000)), vec![]);
+        assert_eq!(acct.vested_coins(&time(1500)), coins("50fee,25stake"));
+        assert_eq!(acct.vesting_coins(&time(1500)), coins("50fee,25stake"));
+        assert_eq!(acct.vested_coins(&time(2000)), coins("100fee,50stake"));
+        assert_eq!(acct.vesting_coins(&time(2000)), vec![]);
+    }
+
+    #[test]
+    fn delayed_vesting_coins() {
+        let acct = DelayedVestingAccount::new(base_vesting_account("100stake", 2000));
+
+        assert_eq!(acct.vested_coins(&time(1999)), vec![]);
+        assert_eq!(acct.locked_coins(&time(1999)), coins("100stake"));
+        assert_eq!(acct.vested_coins(&time(2000)), coins("100stake"));
+        assert_eq!(acct.locked_coins(&time(2000)), vec![]);
+    }
+
+    #[test]
+    fn periodic_vesting_coins() {
+        let acct = PeriodicVestingAccount::new(
+            base_vesting_account("1stake", 0).base_account,
+            1000,
+            vec![
+                Period {
+                    length: 100,
+                    amount: coins("10stake"),
+                },
+                Period {
+                    length: 200,
+                    amount: coins("20fee,20stake"),
+                },
+            ],
+        );
+
+        acct.validate().unwrap_test();
+        assert_eq!(acct.base_vesting_account.end_time, 1300);
+        assert_eq!(
+            acct.base_vesting_account.original_vesting,
+            coins("20fee,30stake")
+        );
+        assert_eq!(acct.vested_coins(&time(1099)), vec![]);
+        assert_eq!(acct.vested_coins(&time(1100)), coins("10stake"));
+        assert_eq!(acct.vested_coins(&time(1299)), coins("10stake"));
+        assert_eq!(acct.vested_coins(&time(1300)), coins("20fee,30stake"));
+    }
+
+    #[test]
+    fn track_delegation_and_undelegation() {
+        let mut acct = PermanentLockedAccount::new(
+            base_vesting_account("1stake", 0).base_account,
+            coins("100stake"),
+        );
+
+        // delegate more than the vesting coins
+        acct.track_delegation(&time(0), &coins("150stake"), &coins("120stake"))
+            .unwrap_test();
+        assert_eq!(
+            acct.base_vesting_account.delegated_vesting,
+            coins("100stake")
+        );
+        assert_eq!(acct.base_vesting_account.delegated_free, coins("20stake"));
+        assert_eq!(acct.locked_coins(&time(0)), vec![]);
+
+        // the free coins are undelegated first
+        acct.track_undelegation(&coins("50stake")).unwrap_test();
+        assert_eq!(
+            acct.base_vesting_account.delegated_vesting,
+            coins("70stake")
+        );
+        assert_eq!(acct.base_vesting_account.delegated_free, vec![]);
+        assert_eq!(acct.locked_coins(&time(0)), coins("30stake"));
+
+        assert_eq!(
+            acct.track_delegation(&time(0), &coins("10stake"), &coins("20stake")),
+            Err(VestingError::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn vesting_account_protobuf_roundtrip() {
+        let acct =
+            ContinuousVestingAccount::new(base_vesting_account("100fee,50stake", 2000), 1000);
+
+        let decoded =
+            ContinuousVestingAccount::decode_vec(&acct.clone().encode_vec()).unwrap_test();
+
+        assert_eq!(acct, decoded);
+    }
+
+    #[test]
+    fn vesting_account_genesis_json() {
+        let json = r#"{
+            "@type": "/cosmos.vesting.v1beta1.DelayedVestingAccount",
+            "base_vesting_account": {
+                "base_account": {
+                    "address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+                    "pub_key": null,
+                    "account_number": "0",
+                    "sequence": "0"
+                },
+                "original_vesting": [{ "denom": "stake", "amount": "100" }],
+                "delegated_free": [],
+                "delegated_vesting": [],
+                "end_time": "2000"
+            }
+        }"#;
+
+        let account: super::super::Account = serde_json::from_str(json).unwrap_test();
+
+        assert_eq!(
+            account,
+            super::super::Account::DelayedVesting(DelayedVestingAccount::new(
+                base_vesting_account("100stake", 2000)
+            ))
+        );
+    }
+}
@@ -1 +1,2 @@
+pub mod multi_send;
 pub mod send;
@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+
+use core_types::Protobuf;
+use serde::{Deserialize, Serialize};
+use tx_derive::AppMessage;
+
+use crate::types::{
+    address::AccAddress,
+    base::{coin::UnsignedCoin, coins::UnsignedCoins, errors::CoinError},
+    denom::Denom,
+    tx::MsgSigners,
+    uint::Uint256,
+};
+
+mod inner {
+    pub use core_types::base::coin::Coin;
+    pub use core_types::msg::Input;
+    pub use core_types::msg::MsgMultiSend;
+    pub use core_types::msg::Output;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[error("MsgMultiSend parse error: {0}")]
+pub struct MsgMultiSendParseError(pub String);
+
+/// Input models transaction input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    pub address: AccAddress,
+    pub coins: UnsignedCoins,
+}
+
+impl MsgSigners for Input {
+    fn signers(&self) -> Vec<&AccAddress> {
+        vec![&self.address]
+    }
+}
+
+impl TryFrom<inner::Input> for Input {
+    type Error = MsgMultiSendParseError;
+
+    fn try_from(raw: inner::Input) -> Result<Self, Self::Error> {
+        Ok(Input {
+            address: AccAddress::from_bech32(&raw.address)
+                .map_err(|e| MsgMultiSendParseError(e.to_string()))?,
+            coins: coins_from_raw(raw.coins)?,
+        })
+    }
+}
+
+impl From<Input> for inner::Input {
+    fn from(Input { address, coins }: Input) -> Self {
+        Self {
+            address: address.into(),
+            coins: coins_into_raw(coins),
+        }
+    }
+}
+
+/// Output models transaction outputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Output {
+    pub address: AccAddress,
+    pub coins: UnsignedCoins,
+}
+
+impl TryFrom<inner::Output> for Output {
+    type Error = MsgMultiSendParseError;
+
+    fn try_from(raw: inner::Output) -> Result<Self, Self::Error> {
+        Ok(Output {
+            address: AccAddress::from_bech32(&raw.address)
+                .map_err(|e| MsgMultiSendParseError(e.to_string()))?,
+            coins: coins_from_raw(raw.coins)?,
+        })
+    }
+}
+
+impl From<Output> for inner::Output {
+    fn from(Output { address, coins }: Output) -> Self {
+        Self {
+            address: address.into(),
+            coins: coins_into_raw(coins),
+        }
+    }
+}
+
+/// MsgMultiSend represents an arbitrary multi-in, multi-out send message.
+/// Only a single input is allowed, so the message has exactly one signer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[serde(try_from = "inner::MsgMultiSend", into = "inner::MsgMultiSend")]
+#[msg(
+    url = "/cosmos.bank.v1beta1.MsgMultiSend",
+    amino_url = "cosmos-sdk/MsgMultiSend",
+    gears
+)]
+pub struct MsgMultiSend {
+    #[msg(signer)]
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+}
+
+impl MsgMultiSend {
+    /// Creates the message checking that the sum of the inputs equals the sum of the outputs
+    pub fn new(inputs: Vec<Input>, outputs: Vec<Output>) -> Result<Self, MsgMultiSendParseError> {
+        match inputs.len() {
+            0 => Err(MsgMultiSendParseError(
+                "no inputs to send transaction".into(),
+            ))?,
+            1 => (),
+            _ => Err(MsgMultiSendParseError(
+                "multiple senders not allowed".into(),
+            ))?,
+        }
+
+        if outputs.is_empty() {
+            Err(MsgMultiSendParseError(
+                "no outputs to send transaction".into(),
+            ))?
+        }
+
+        let total_in = sum_coins(inputs.iter().map(|input| &input.coins))?;
+        let total_out = sum_coins(outputs.iter().map(|output| &output.coins))?;
+
+        if total_in != total_out {
+            Err(MsgMultiSendParseError("sum inputs != sum outputs".into()))?
+        }
+
+        Ok(Self { inputs, outputs })
+    }
+}
+
+impl TryFrom<inner::MsgMultiSend> for MsgMultiSend {
+    type Error = MsgMultiSendParseError;
+
+    fn try_from(raw: inner::MsgMultiSend) -> Result<Self, Self::Error> {
+        let inputs = raw
+            .inputs
+            .into_iter()
+            .map(Input::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let outputs = raw
+            .outputs
+            .into_iter()
+            .map(Output::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        MsgMultiSend::new(inputs, outputs)
+    }
+}
+
+impl From<MsgMultiSend> for inner::MsgMultiSend {
+    fn from(MsgMultiSend { inputs, outputs }: MsgMultiSend) -> Self {
+        Self {
+            inputs: inputs.into_iter().map(Into::into).collect(),
+            outputs: outputs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<inner::MsgMultiSend> for MsgMultiSend {}
+
+fn coins_from_raw(coins: Vec<inner::Coin>) -> Result<UnsignedCoins, MsgMultiSendParseError> {
+    let coins = coins
+        .into_iter()
+        .map(UnsignedCoin::try_from)
+        .collect::<Result<Vec<_>, CoinError>>()
+        .map_err(|e| MsgMultiSendParseError(e.to_string()))?;
+
+    UnsignedCoins::new(coins).map_err(|e| MsgMultiSendParseError(e.to_string()))
+}
+
+fn coins_into_raw(coins: UnsignedCoins) -> Vec<inner::Coin> {
+    coins.into_inner().into_iter().map(Into::into).collect()
+}
+
+fn sum_coins<'a>(
+    coins: impl Iterator<Item = &'a UnsignedCoins>,
+) -> Result<BTreeMap<&'a Denom, Uint256>, MsgMultiSendParseError> {
+    let mut total = BTreeMap::<&Denom, Uint256>::new();
+    for coin in coins.flat_map(|coins| coins.inner()) {
+        let amount = total.entry(&coin.denom).or_default();
+        *amount = amount
+            .checked_add(coin.amount)
+            .map_err(|e| MsgMultiSendParseError(e.to_string()))?;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+    use crate::types::tx::TxMessage;
+
+    const MESSAGE: &str = r#"{
+        "inputs": [
+            { "address": "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs", "coins": [{ "denom": "uatom", "amount": "30" }] }
+        ],
+        "outputs": [
+            { "address": "cosmos1ejrf4cur2wy6kfurg9f2jppp2h3afe5h6pkh5t", "coins": [{ "denom": "uatom", "amount": "10" }] },
+            { "address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux", "coins": [{ "denom": "uatom", "amount": "20" }] }
+        ]
+    }"#;
+
+    #[test]
+    fn multi_send_signer_is_input() {
+        let msg: MsgMultiSend = serde_json::from_str(MESSAGE).unwrap_test();
+
+        assert_eq!(
+            msg.get_signers(),
+            vec![
+                &AccAddress::from_bech32("cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs")
+                    .unwrap_test()
+            ]
+        );
+        assert_eq!(
+            msg.clone(),
+            MsgMultiSend::decode_vec(&msg.encode_vec()).unwrap_test()
+        );
+    }
+
+    #[test]
+    fn multi_send_inputs_outputs_mismatch_fails() {
+        let message = MESSAGE.replace(r#""amount": "30""#, r#""amount": "31""#);
+
+        assert!(serde_json::from_str::<MsgMultiSend>(&message).is_err());
+    }
+
+    #[test]
+    fn multi_send_multiple_inputs_fails() {
+        let msg: MsgMultiSend = serde_json::from_str(MESSAGE).unwrap_test();
+
+        let result = MsgMultiSend::new(
+            vec![msg.inputs[0].clone(), msg.inputs[0].clone()],
+            msg.outputs,
+        );
+
+        assert_eq!(
+            result,
+            Err(MsgMultiSendParseError(
+                "multiple senders not allowed".into()
+            ))
+        );
+    }
+}
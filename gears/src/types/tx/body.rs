@@ -1,10 +1,12 @@
 use super::TxMessage;
+use crate::types::address::AccAddress;
 use core_types::any::google::Any;
 use core_types::errors::CoreError;
 use core_types::Protobuf;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
+use std::collections::HashSet;
 use vec1::Vec1;
 
 mod inner {
@@ -53,6 +55,32 @@ impl<M> TxBody<M> {
     }
 }
 
+impl<M: TxMessage> TxBody<M> {
+    /// Returns the required signers of the messages, each only the first time it occurs,
+    /// followed by the fee payer if it isn't one of them
+    pub fn signers<'a>(&'a self, fee_payer: Option<&'a AccAddress>) -> Vec<&'a AccAddress> {
+        let mut signers = vec![];
+        let mut seen = HashSet::new();
+
+        for msg in &self.messages {
+            for addr in msg.get_signers() {
+                if seen.insert(addr.to_string()) {
+                    signers.push(addr);
+                }
+            }
+        }
+
+        // ensure any specified fee payer is included in the required signers (at the end)
+        if let Some(addr) = fee_payer {
+            if seen.insert(addr.to_string()) {
+                signers.push(addr);
+            }
+        }
+
+        signers
+    }
+}
+
 impl<M: TxMessage> TryFrom<inner::TxBody> for TxBody<M> {
     type Error = CoreError;
 
@@ -4,7 +4,6 @@ pub mod metadata;
 pub mod signer;
 use core_types::{any::google::Any, errors::CoreError, tx::signature::SignatureData, Protobuf};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
 use vec1::{vec1, Vec1};
 
 use crate::crypto::public::PublicKey;
@@ -33,6 +32,24 @@ pub trait TxMessage:
     }
 }
 
+/// Signer addresses held by a field of a message. Fields marked with `#[msg(signer)]`
+/// in the `AppMessage` derive should implement it.
+pub trait MsgSigners {
+    fn signers(&self) -> Vec<&AccAddress>;
+}
+
+impl MsgSigners for AccAddress {
+    fn signers(&self) -> Vec<&AccAddress> {
+        vec![self]
+    }
+}
+
+impl<T: MsgSigners> MsgSigners for Vec<T> {
+    fn signers(&self) -> Vec<&AccAddress> {
+        self.iter().flat_map(MsgSigners::signers).collect()
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub enum NullTxMsg {}
 
@@ -86,6 +103,14 @@ impl<T: TxMessage> Messages<T> {
     pub fn chunk_size(&self) -> usize {
         self.chunk_size
     }
+
+    /// Converts every message keeping the chunk size
+    pub fn map<U: TxMessage>(self, f: impl FnMut(T) -> U) -> Messages<U> {
+        Messages {
+            messages: self.messages.mapped(f),
+            chunk_size: self.chunk_size,
+        }
+    }
 }
 
 impl<T: TxMessage> From<T> for Messages<T> {
@@ -141,27 +166,7 @@ impl<M: TxMessage> Tx<M> {
     }
 
     pub fn get_signers(&self) -> Vec<&AccAddress> {
-        let mut signers = vec![];
-        let mut seen = HashSet::new();
-
-        for msg in &self.body.messages {
-            for addr in msg.get_signers() {
-                if seen.insert(addr.to_string()) {
-                    signers.push(addr);
-                }
-            }
-        }
-
-        // ensure any specified fee payer is included in the required signers (at the end)
-        let fee_payer = &self.auth_info.fee.payer;
-
-        if let Some(addr) = fee_payer {
-            if seen.insert(addr.to_string()) {
-                signers.push(addr);
-            }
-        }
-
-        signers
+        self.body.signers(self.auth_info.fee.payer.as_ref())
     }
 
     // TODO: Remove this method and read valid structure
@@ -199,6 +204,10 @@ impl<M: TxMessage> Tx<M> {
         self.auth_info.fee.amount.as_ref()
     }
 
+    pub fn get_fee_granter(&self) -> &str {
+        &self.auth_info.fee.granter
+    }
+
     pub fn get_fee_payer(&self) -> &AccAddress {
         if let Some(payer) = &self.auth_info.fee.payer {
             payer
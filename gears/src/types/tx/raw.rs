@@ -11,8 +11,14 @@ mod inner {
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TxRaw {
+    #[serde(with = "core_types::serializers::Base64Standard")]
     pub body_bytes: Vec<u8>,
+    #[serde(with = "core_types::serializers::Base64Standard")]
     pub auth_info_bytes: Vec<u8>,
+    #[serde(
+        serialize_with = "core_types::serializers::serialize_vec_of_vec_to_vec_of_base64",
+        deserialize_with = "core_types::serializers::deserialize_vec_of_base64_to_vec_of_vec"
+    )]
     pub signatures: Vec<Vec<u8>>,
 }
 
@@ -65,6 +71,8 @@ impl From<TxRaw> for inner::TxRaw {
     }
 }
 
+impl Protobuf<inner::TxRaw> for TxRaw {}
+
 /// Tx is the standard type used for broadcasting transactions.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TxWithRaw<M> {
@@ -12,10 +12,16 @@ use tendermint::{
             validator::ValidatorUpdate,
         },
         request::{
-            begin_block::RequestBeginBlock, deliver_tx::RequestDeliverTx,
-            end_block::RequestEndBlock, init_chain::RequestInitChain, query::RequestQuery,
+            begin_block::RequestBeginBlock,
+            check_tx::{CheckTxType, RequestCheckTx},
+            deliver_tx::RequestDeliverTx,
+            end_block::RequestEndBlock,
+            init_chain::RequestInitChain,
+            query::RequestQuery,
+        },
+        response::{
+            check_tx::ResponseCheckTx, deliver_tx::ResponseDeliverTx, query::ResponseQuery,
         },
-        response::{deliver_tx::ResponseDeliverTx, query::ResponseQuery},
         time::timestamp::Timestamp,
     },
 };
@@ -129,6 +135,18 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         self.app.query(req)
     }
 
+    /// Checks a new transaction against the state of the last committed block
+    pub fn check_tx(&self, tx: Bytes) -> ResponseCheckTx {
+        self.app.check_tx(RequestCheckTx {
+            tx,
+            r#type: CheckTxType::New as i32,
+        })
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
     fn calculate_header(&self) -> Header {
         Header {
             version: Consensus { block: 11, app: 10 },
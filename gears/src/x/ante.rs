@@ -1,4 +1,5 @@
 use crate::application::handlers::node::TxError;
+use crate::baseapp::mode::RunTxMode;
 use crate::baseapp::options::NodeOptions;
 use crate::context::TransactionalContext;
 use crate::crypto::public::PublicKey;
@@ -6,6 +7,7 @@ use crate::signing::handler::MetadataGetter;
 use crate::signing::renderer::amino_renderer::{AminoRenderer, RenderError as AminoRendererError};
 use crate::signing::std_sign_doc;
 use crate::signing::{handler::SignModeHandler, renderer::value_renderer::ValueRenderer};
+use crate::types::address::AccAddress;
 use crate::types::auth::gas::Gas;
 use crate::types::base::coin::UnsignedCoin;
 use crate::types::base::coins::UnsignedCoins;
@@ -18,6 +20,7 @@ use crate::x::errors::{AnteError, AnteGasError};
 use crate::x::keepers::auth::AuthKeeper;
 use crate::x::keepers::auth::AuthParams;
 use crate::x::keepers::bank::BankKeeper;
+use crate::x::keepers::feegrant::FeeGrantKeeper;
 use crate::{
     context::QueryableContext,
     types::tx::{raw::TxWithRaw, signer::SignerData, Tx, TxMessage},
@@ -38,6 +41,20 @@ use std::sync::Arc;
 use super::errors::AccountNotFound;
 use super::module::Module;
 
+/// Size of a secp256k1 signature
+const SECP256K1_SIGNATURE_SIZE: u64 = 64;
+
+/// Size of an amino encoded secp256k1 public key: a 4 byte type prefix, a length byte and
+/// the 33 bytes of the compressed key
+const AMINO_SECP256K1_PUBLIC_KEY_SIZE: u64 = 4 + 1 + 33;
+
+/// Size charged for a missing signature of a simulated transaction, which is usually sent
+/// without signatures. As in the SDK, it's the size of an amino encoded `StdSignature` holding
+/// a secp256k1 public key and signature (a tag and a length byte for each field) plus 6 bytes,
+/// which adds up to 112 bytes.
+const SIMULATED_SIGNATURE_SIZE: u64 =
+    (2 + AMINO_SECP256K1_PUBLIC_KEY_SIZE) + (2 + SECP256K1_SIGNATURE_SIZE) + 6;
+
 pub trait SignGasConsumer: Clone + Sync + Send + 'static {
     fn consume<AP: AuthParams>(
         &self,
@@ -56,10 +73,9 @@ impl SignGasConsumer for DefaultSignGasConsumer {
         &self,
         gas_meter: &mut GasMeter<TxKind>,
         pub_key: PublicKey,
-        _data: &SignatureData,
+        data: &SignatureData,
         params: &AP,
     ) -> Result<(), GasStoreErrors> {
-        // TODO I'm unsure that this is 100% correct due multisig mode see: https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/auth/ante/sigverify.go#L401
         match pub_key {
             PublicKey::Secp256k1(_key) => {
                 let amount = params
@@ -71,6 +87,17 @@ impl SignGasConsumer for DefaultSignGasConsumer {
                     .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
             }
             PublicKey::Ed25519(_) => todo!(), //TODO: implement
+            PublicKey::Multisig(key) => {
+                // gas is consumed for every key which signed the multisignature, a malformed
+                // multisignature is rejected later by the signature verification
+                if let ModeInfo::Multi(multi) = &data.mode_info {
+                    if let Ok(signers) = key.signers(multi, &data.signature, data.sequence) {
+                        for (key, data) in signers {
+                            self.consume(gas_meter, key.to_owned(), &data, params)?;
+                        }
+                    }
+                }
+            }
         }
 
         Ok(())
@@ -81,12 +108,14 @@ impl SignGasConsumer for DefaultSignGasConsumer {
 pub struct BaseAnteHandler<
     BK: BankKeeper<SK, M>,
     AK: AuthKeeper<SK, M>,
+    FK: FeeGrantKeeper<SK>,
     SK: StoreKey,
     GC,
     M: Module,
 > {
     bank_keeper: BK,
     auth_keeper: AK,
+    fee_grant_keeper: Option<FK>,
     sign_gas_consumer: GC,
     fee_collector_module: M,
     sk: PhantomData<SK>,
@@ -95,20 +124,24 @@ pub struct BaseAnteHandler<
 impl<
         AK: AuthKeeper<SK, MOD>,
         BK: BankKeeper<SK, MOD>,
+        FK: FeeGrantKeeper<SK>,
         SK: StoreKey,
         GC: SignGasConsumer,
         MOD: Module,
-    > BaseAnteHandler<BK, AK, SK, GC, MOD>
+    > BaseAnteHandler<BK, AK, FK, SK, GC, MOD>
 {
+    /// Creates a new ante handler. Fee grants are rejected if no `fee_grant_keeper` is set.
     pub fn new(
         auth_keeper: AK,
         bank_keeper: BK,
+        fee_grant_keeper: Option<FK>,
         sign_gas_consumer: GC,
         fee_collector_module: MOD,
-    ) -> BaseAnteHandler<BK, AK, SK, GC, MOD> {
+    ) -> BaseAnteHandler<BK, AK, FK, SK, GC, MOD> {
         BaseAnteHandler {
             bank_keeper,
             auth_keeper,
+            fee_grant_keeper,
             sign_gas_consumer,
             fee_collector_module,
             sk: PhantomData,
@@ -122,22 +155,21 @@ impl<
         &self,
         ctx: &mut CTX,
         tx: &TxWithRaw<M>,
-        is_check: bool,
+        mode: RunTxMode,
         node_opt: NodeOptions,
         gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
     ) -> Result<(), TxError> {
-        // Note: we currently don't have simulate mode at all, so some methods receive hardcoded values for this mode
         // ante.NewSetUpContextDecorator(), // WE not going to implement this in ante. Some logic should be in application
-        self.mempool_fee(tx, is_check, node_opt)?;
+        self.mempool_fee(tx, mode, node_opt)?;
         self.validate_basic_ante_handler(&tx.tx)?;
         self.tx_timeout_height_ante_handler(ctx, &tx.tx)?;
         self.validate_memo_ante_handler(ctx, &tx.tx)?;
-        self.consume_gas_for_tx_size(ctx, tx, gas_meter.clone())?;
+        self.consume_gas_for_tx_size(ctx, tx, mode, gas_meter.clone())?;
         self.deduct_fee_ante_handler(ctx, &tx.tx)?;
         self.set_pub_key_ante_handler(ctx, &tx.tx)?;
         //  ** ante.NewValidateSigCountDecorator(opts.AccountKeeper),
-        self.sign_gas_consume(ctx, &tx.tx, gas_meter.clone())?;
-        self.sig_verification_handler(ctx, tx)?;
+        self.sign_gas_consume(ctx, &tx.tx, mode, gas_meter.clone())?;
+        self.sig_verification_handler(ctx, tx, mode)?;
         self.increment_sequence_ante_handler(ctx, &tx.tx)?;
         //  ** ibcante.NewAnteDecorator(opts.IBCkeeper),
 
@@ -166,10 +198,10 @@ impl<
             raw: _,
             tx_len: _,
         }: &TxWithRaw<M>,
-        is_check: bool,
+        mode: RunTxMode,
         node_opt: NodeOptions,
     ) -> Result<(), AnteError> {
-        if !is_check {
+        if mode != RunTxMode::Check {
             return Ok(());
         }
 
@@ -232,15 +264,23 @@ impl<
     fn consume_gas_for_tx_size<M: TxMessage, DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
-        TxWithRaw {
-            tx: _,
-            raw: _,
-            tx_len,
-        }: &TxWithRaw<M>,
+        TxWithRaw { tx, raw: _, tx_len }: &TxWithRaw<M>,
+        mode: RunTxMode,
         gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
     ) -> Result<(), AnteError> {
         let params = self.auth_keeper.get_auth_params(ctx)?;
-        let tx_len: Gas = (*tx_len as u64).try_into().map_err(|_| AnteError::TxLen)?;
+
+        let mut tx_len = *tx_len as u64;
+        if mode == RunTxMode::Simulate {
+            let missing_signatures = tx
+                .get_signatures_data()
+                .iter()
+                .filter(|data| data.signature.is_empty())
+                .count() as u64;
+            tx_len += missing_signatures * SIMULATED_SIGNATURE_SIZE;
+        }
+
+        let tx_len: Gas = tx_len.try_into().map_err(|_| AnteError::TxLen)?;
         let cost_per_byte: Gas = params.tx_cost_per_byte().try_into().map_err(|_| {
             AnteGasError::Overflow("overflow converting tx cost per byte to gas".to_string())
         })?;
@@ -262,6 +302,7 @@ impl<
         &self,
         ctx: &mut CTX,
         tx: &Tx<M>,
+        mode: RunTxMode,
         gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
     ) -> Result<(), AnteError> {
         let auth_params = self.auth_keeper.get_auth_params(ctx)?;
@@ -275,13 +316,26 @@ impl<
                 .get_account(ctx, signer_addr)?
                 .ok_or(AccountNotFound::from(signer_addr.to_owned()))?;
 
-            let pub_key = acct
-                .get_public_key()
-                .expect("account pub keys are set in set_pub_key_ante_handler")
-                .to_owned();
-
             let sig = signatures.get(i).expect("TODO"); //TODO: expect message
 
+            let pub_key = match acct.get_public_key() {
+                Some(pub_key) => pub_key.to_owned(),
+                // a simulated transaction may omit public keys, secp256k1 is assumed then
+                None if mode == RunTxMode::Simulate => {
+                    let amount = auth_params
+                        .sig_verify_cost_secp256k1()
+                        .try_into()
+                        .map_err(|e| AnteGasError::from(GasStoreErrors::new(&[], e)))?;
+                    gas_meter
+                        .borrow_mut()
+                        .consume_gas(amount, ANTE_SECKP251K1_DESCRIPTOR)
+                        .map_err(Into::<AnteGasError>::into)?;
+
+                    continue;
+                }
+                None => panic!("account pub keys are set in set_pub_key_ante_handler"),
+            };
+
             self.sign_gas_consumer
                 .consume(&mut gas_meter.borrow_mut(), pub_key, sig, &auth_params)
                 .map_err(Into::<AnteGasError>::into)?;
@@ -359,14 +413,58 @@ impl<
         let fee = tx.get_fee();
         let fee_payer = tx.get_fee_payer();
 
-        if !self.auth_keeper.has_account(ctx, fee_payer)? {
-            Err(AccountNotFound::from(fee_payer.clone()))?
+        let fee_granter =
+            match tx.get_fee_granter() {
+                "" => None,
+                granter => Some(AccAddress::from_bech32(granter).map_err(|e| {
+                    AnteError::Validation(format!("invalid fee granter address: {e}"))
+                })?),
+            };
+
+        // if a fee granter is set the fees are deducted from the granter using an allowance
+        // given to the fee payer, unless the granter pays the fees on its own
+        let deduct_fees_from = match &fee_granter {
+            Some(granter) => {
+                let fee_grant_keeper = self
+                    .fee_grant_keeper
+                    .as_ref()
+                    .ok_or(AnteError::FeeGrantDisabled)?;
+
+                if granter != fee_payer {
+                    let msgs = tx
+                        .get_msgs()
+                        .iter()
+                        .map(|msg| msg.type_url())
+                        .collect::<Vec<_>>();
+
+                    fee_grant_keeper
+                        .use_granted_fees(
+                            ctx,
+                            granter,
+                            fee_payer,
+                            fee.map(|fee| fee.inner().as_slice()).unwrap_or_default(),
+                            &msgs,
+                        )
+                        .map_err(|reason| AnteError::FeeGrant {
+                            granter: granter.clone(),
+                            grantee: fee_payer.clone(),
+                            reason,
+                        })?;
+                }
+
+                granter
+            }
+            None => fee_payer,
+        };
+
+        if !self.auth_keeper.has_account(ctx, deduct_fees_from)? {
+            Err(AccountNotFound::from(deduct_fees_from.clone()))?
         }
 
         if let Some(fee) = fee {
             self.bank_keeper.send_coins_from_account_to_module(
                 ctx,
-                fee_payer.to_owned(),
+                deduct_fees_from.to_owned(),
                 &self.fee_collector_module,
                 fee.to_owned(),
             )?;
@@ -428,6 +526,7 @@ impl<
         &self,
         ctx: &mut CTX,
         tx: &TxWithRaw<M>,
+        mode: RunTxMode,
     ) -> Result<(), AnteError> {
         let signers = tx.tx.get_signers();
         let signature_data = tx.tx.get_signatures_data();
@@ -457,6 +556,11 @@ impl<
                 )));
             }
 
+            // signatures of simulated transactions are allowed to be empty or invalid
+            if mode == RunTxMode::Simulate {
+                continue;
+            }
+
             let public_key = acct
                 .get_public_key()
                 .expect("account pub keys are set in set_pub_key_ante_handler"); //TODO: but can't they be set to None?
@@ -468,8 +572,10 @@ impl<
                 acct.get_account_number()
             };
 
-            let sign_bytes = match &signature_data.mode_info {
-                ModeInfo::Single(mode) => match mode {
+            // every signer of a multisignature may use its own sign mode, so sign bytes are
+            // computed per mode
+            let sign_bytes = |mode: &SignMode| -> Result<Vec<u8>, AnteError> {
+                Ok(match mode {
                     SignMode::Direct => SignDoc {
                         body_bytes: tx.raw.body_bytes.clone(),
                         auth_info_bytes: tx.raw.auth_info_bytes.clone(),
@@ -477,30 +583,17 @@ impl<
                         account_number,
                     }
                     .encode_to_vec(),
-                    SignMode::LegacyAminoJson => {
-                        let mut msgs = vec![];
-                        for msg in tx.tx.get_msgs() {
-                            msgs.push(std_sign_doc::Msg {
-                                kind: msg.amino_url().to_string(),
-                                value: msg.render()?,
-                            })
-                        }
-                        let doc = std_sign_doc::StdSignDoc {
-                            account_number: account_number.to_string(),
-                            chain_id: ctx.chain_id().to_string(),
-                            fee: tx.tx.auth_info.fee.clone().into(),
-                            memo: tx.tx.get_memo().to_string(),
-                            msgs,
-                            sequence: account_seq.to_string(),
-                            // TODO: check impl
-                            // timeout_height: Some(u64::from(tx.tx.get_timeout_height()).to_string()),
-                            timeout_height: None,
-                        };
-
-                        doc.to_sign_bytes().map_err(|e| {
-                            AnteError::LegacyAminoJson(AminoRendererError::Rendering(e.to_string()))
-                        })?
-                    }
+                    SignMode::LegacyAminoJson => std_sign_doc::StdSignDoc::new(
+                        &tx.tx.body,
+                        tx.tx.auth_info.fee.clone(),
+                        ctx.chain_id().to_string(),
+                        account_number,
+                        account_seq,
+                    )?
+                    .to_sign_bytes()
+                    .map_err(|e| {
+                        AnteError::LegacyAminoJson(AminoRendererError::Rendering(e.to_string()))
+                    })?,
                     SignMode::Textual => {
                         let handler = SignModeHandler;
 
@@ -514,7 +607,7 @@ impl<
 
                         let f = MetadataFromState {
                             bank_keeper: &self.bank_keeper,
-                            ctx,
+                            ctx: &*ctx,
                             _phantom: PhantomData,
                         };
 
@@ -526,15 +619,10 @@ impl<
                             mode
                         )))
                     }
-                },
-                ModeInfo::Multi(_) => {
-                    return Err(AnteError::Validation("multi sig not supported".to_string()));
-                }
+                })
             };
 
-            public_key
-                .verify_signature(&sign_bytes, &signature_data.signature)
-                .map_err(|e| AnteError::Validation(format!("invalid signature: {}", e)))?;
+            public_key.verify_signature_data(signature_data, &sign_bytes)?;
         }
 
         Ok(())
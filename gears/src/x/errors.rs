@@ -6,8 +6,10 @@ use thiserror::Error;
 
 use crate::{
     application::handlers::node::TxError,
+    crypto::public::SignatureVerificationError,
     signing::{errors::SigningErrors, renderer::amino_renderer::RenderError},
     types::{
+        account::VestingError,
         base::errors::CoinsError,
         denom::Denom,
         gas::GasMeteringErrors,
@@ -106,6 +108,20 @@ pub(crate) enum AnteError {
     LegacyAminoJson(#[from] RenderError),
     #[error("failed get sign bytes from tx: {0}")]
     Signing(#[from] SigningErrors),
+    #[error("fee grants are not enabled")]
+    FeeGrantDisabled,
+    #[error("{granter} does not allow to pay fees for {grantee}: {reason}")]
+    FeeGrant {
+        granter: AccAddress,
+        grantee: AccAddress,
+        reason: FeeGrantKeeperError,
+    },
+}
+
+impl From<SignatureVerificationError> for AnteError {
+    fn from(error: SignatureVerificationError) -> Self {
+        AnteError::Validation(format!("invalid signature: {}", error))
+    }
 }
 
 impl From<AnteError> for TxError {
@@ -130,6 +146,12 @@ impl From<AnteError> for TxError {
             AnteError::Gas(_) => 10,
             AnteError::LegacyAminoJson(_) => 11,
             AnteError::Signing(_) => 12,
+            AnteError::FeeGrantDisabled => 13,
+            AnteError::FeeGrant {
+                granter: _,
+                grantee: _,
+                reason: _,
+            } => 14,
         };
 
         TxError {
@@ -146,6 +168,14 @@ pub enum AuthKeeperError {
     GasError(#[from] GasStoreErrors),
 }
 
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum StakingHooksError {
+    #[error("{0}")]
+    GasError(#[from] GasStoreErrors),
+    #[error("{0}")]
+    Other(String),
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum InsufficientFundsError {
     #[error("account: {account} doesn't have sufficient funds: {funds}")]
@@ -178,6 +208,24 @@ pub enum BankKeeperError {
     AccountPermission,
     #[error("{0} is not allowed to receive funds. Probably tried send to module as account")]
     Blocked(AccAddress),
+    #[error("{0} transfers are currently disabled")]
+    SendDisabled(Denom),
+    #[error(transparent)]
+    Vesting(#[from] VestingError),
+    #[error("{0}")]
+    GasError(#[from] GasStoreErrors),
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum FeeGrantKeeperError {
+    #[error("fee-grant not found")]
+    NotFound,
+    #[error("fee allowance expired")]
+    Expired,
+    #[error("fee limit exceeded: {0}")]
+    LimitExceeded(String),
+    #[error("message does not exist in allowed messages: {0}")]
+    MessageNotAllowed(String),
     #[error("{0}")]
     GasError(#[from] GasStoreErrors),
 }
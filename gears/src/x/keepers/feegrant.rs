@@ -0,0 +1,22 @@
+use database::Database;
+use kv_store::StoreKey;
+
+use crate::{
+    context::TransactionalContext,
+    types::{address::AccAddress, base::coin::UnsignedCoin},
+    x::errors::FeeGrantKeeperError,
+};
+
+/// FeeGrantKeeper defines the fee grant module interface contract needed by the ante handler.
+pub trait FeeGrantKeeper<SK: StoreKey>: Clone + Send + Sync + 'static {
+    /// use_granted_fees deducts the fee from the allowance given by the granter to the grantee.
+    /// An error is returned if the allowance doesn't exist or doesn't cover the fee or messages.
+    fn use_granted_fees<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        granter: &AccAddress,
+        grantee: &AccAddress,
+        fee: &[UnsignedCoin],
+        msgs: &[&str],
+    ) -> Result<(), FeeGrantKeeperError>;
+}
@@ -0,0 +1,33 @@
+use database::Database;
+use kv_store::StoreKey;
+
+use crate::{
+    context::{QueryableContext, TransactionalContext},
+    types::{
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        denom::Denom,
+        store::gas::errors::GasStoreErrors,
+    },
+    x::{errors::BankKeeperError, module::Module},
+};
+
+use super::staking::StakingBankKeeper;
+
+/// Bank keeper which used in mint xmod
+pub trait MintingBankKeeper<SK: StoreKey, M: Module>: StakingBankKeeper<SK, M> {
+    /// Returns the total supply of the denom. The amount is zero when nothing of the denom exists.
+    fn supply<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        denom: &Denom,
+    ) -> Result<UnsignedCoin, GasStoreErrors>;
+
+    /// Creates new coins and adds them to the module account. The module account
+    /// must have the `minter` permission.
+    fn mint_coins<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        module: &M,
+        amount: UnsignedCoins,
+    ) -> Result<(), BankKeeperError>;
+}
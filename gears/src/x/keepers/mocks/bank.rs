@@ -1,7 +1,13 @@
+use std::sync::{Arc, Mutex};
+
 use kv_store::StoreKey;
 
 use crate::{
-    types::{base::coin::UnsignedCoin, store::gas::errors::GasStoreErrors, tx::metadata::Metadata},
+    types::{
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        store::gas::errors::GasStoreErrors,
+        tx::metadata::Metadata,
+    },
     x::{
         errors::BankKeeperError,
         keepers::bank::{BalancesKeeper, BankKeeper},
@@ -14,6 +20,10 @@ pub struct MockBankKeeper {
     pub get_denom_metadata: Option<Metadata>,
     pub balance_all: Vec<UnsignedCoin>,
     pub balance: UnsignedCoin,
+    /// Coins burned by the keeper, with the name of the module they were burned from
+    pub burned: Arc<Mutex<Vec<(String, UnsignedCoins)>>>,
+    /// Coins sent from a module to another, with the names of the sender and the recipient
+    pub module_transfers: Arc<Mutex<Vec<(String, String, UnsignedCoins)>>>,
 }
 
 impl<SK: StoreKey, M: Module> BalancesKeeper<SK, M> for MockBankKeeper {
@@ -71,9 +81,13 @@ impl<SK: StoreKey, M: Module> BankKeeper<SK, M> for MockBankKeeper {
     fn coins_burn<DB: database::Database, CTX: crate::context::TransactionalContext<DB, SK>>(
         &self,
         _: &mut CTX,
-        _: &M,
-        _: &crate::types::base::coins::UnsignedCoins,
+        module: &M,
+        deposit: &UnsignedCoins,
     ) -> Result<(), crate::x::errors::BankKeeperError> {
+        self.burned
+            .lock()
+            .expect("poisoned lock")
+            .push((module.name(), deposit.clone()));
         Ok(())
     }
 }
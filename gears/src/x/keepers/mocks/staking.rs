@@ -1,9 +1,26 @@
+use cosmwasm_std::{Decimal256, Uint256};
 use kv_store::StoreKey;
+use tendermint::types::proto::{crypto::PublicKey, validator::VotingPower};
 
 use crate::{
-    context::TransactionalContext,
-    types::base::coins::UnsignedCoins,
-    x::{errors::BankKeeperError, keepers::staking::StakingBankKeeper, module::Module},
+    context::{QueryableContext, TransactionalContext},
+    error::{MathOperation, NumericError},
+    types::{
+        address::{AccAddress, ConsAddress, ValAddress},
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        store::gas::errors::GasStoreErrors,
+    },
+    x::{
+        errors::BankKeeperError,
+        keepers::staking::{
+            DistributionStakingKeeper, GovStakingKeeper, SlashingStakingKeeper, StakingBankKeeper,
+        },
+        module::Module,
+        types::{
+            delegation::StakingDelegation,
+            validator::{BondStatus, StakingValidator},
+        },
+    },
 };
 
 use super::bank::MockBankKeeper;
@@ -15,10 +32,15 @@ impl<SK: StoreKey, M: Module> StakingBankKeeper<SK, M> for MockBankKeeper {
     >(
         &self,
         _: &mut CTX,
-        _: &M,
-        _: &M,
-        _: UnsignedCoins,
+        sender_pool: &M,
+        recipient_pool: &M,
+        amount: UnsignedCoins,
     ) -> Result<(), BankKeeperError> {
+        self.module_transfers.lock().expect("poisoned lock").push((
+            sender_pool.name(),
+            recipient_pool.name(),
+            amount,
+        ));
         Ok(())
     }
 
@@ -29,7 +51,7 @@ impl<SK: StoreKey, M: Module> StakingBankKeeper<SK, M> for MockBankKeeper {
         &self,
         _: &mut CTX,
         _: &M,
-        _: address::AccAddress,
+        _: AccAddress,
         _: UnsignedCoins,
     ) -> Result<(), BankKeeperError> {
         Ok(())
@@ -41,10 +63,226 @@ impl<SK: StoreKey, M: Module> StakingBankKeeper<SK, M> for MockBankKeeper {
     >(
         &self,
         _: &mut CTX,
-        _: address::AccAddress,
+        _: AccAddress,
         _: &M,
         _: UnsignedCoins,
     ) -> Result<(), BankKeeperError> {
         Ok(())
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct MockValidator {
+    pub operator: ValAddress,
+    pub tokens: Uint256,
+    pub delegator_shares: Decimal256,
+    pub cons_pub_key: PublicKey,
+    pub jailed: bool,
+    pub min_self_delegation: Uint256,
+    pub commission: Decimal256,
+    pub status: BondStatus,
+}
+
+impl StakingValidator for MockValidator {
+    fn operator(&self) -> &ValAddress {
+        &self.operator
+    }
+
+    fn tokens(&self) -> Uint256 {
+        self.tokens
+    }
+
+    fn bonded_tokens(&self) -> Uint256 {
+        match self.status {
+            BondStatus::Bonded => self.tokens,
+            _ => Uint256::zero(),
+        }
+    }
+
+    fn delegator_shares(&self) -> Decimal256 {
+        self.delegator_shares
+    }
+
+    fn cons_pub_key(&self) -> &PublicKey {
+        &self.cons_pub_key
+    }
+
+    fn is_jailed(&self) -> bool {
+        self.jailed
+    }
+
+    fn min_self_delegation(&self) -> Uint256 {
+        self.min_self_delegation
+    }
+
+    fn commission(&self) -> Decimal256 {
+        self.commission
+    }
+
+    fn status(&self) -> BondStatus {
+        self.status
+    }
+
+    fn tokens_from_shares(&self, shares: Decimal256) -> Result<Decimal256, NumericError> {
+        shares
+            .checked_mul(Decimal256::from_atomics(self.tokens, 0)?)
+            .map_err(|_| NumericError::Overflow(MathOperation::Mul))?
+            .checked_div(self.delegator_shares)
+            .map_err(|_| NumericError::Overflow(MathOperation::Div))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MockDelegation {
+    pub delegator: AccAddress,
+    pub validator: ValAddress,
+    pub shares: Decimal256,
+}
+
+impl StakingDelegation for MockDelegation {
+    fn delegator(&self) -> &AccAddress {
+        &self.delegator
+    }
+
+    fn validator(&self) -> &ValAddress {
+        &self.validator
+    }
+
+    fn shares(&self) -> &Decimal256 {
+        &self.shares
+    }
+}
+
+/// Staking keeper which serves the validators and delegations it's built with
+#[derive(Clone, Debug)]
+pub struct MockStakingKeeper {
+    pub validators: Vec<MockValidator>,
+    pub delegations: Vec<MockDelegation>,
+    pub total_bonded_tokens: UnsignedCoin,
+    pub max_validators: u32,
+}
+
+impl<SK: StoreKey, M: Module> GovStakingKeeper<SK, M> for MockStakingKeeper {
+    type Validator = MockValidator;
+    type Delegation = MockDelegation;
+
+    fn bonded_validators_by_power_iter<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+    ) -> Result<impl Iterator<Item = Result<Self::Validator, GasStoreErrors>>, GasStoreErrors> {
+        Ok(self
+            .validators
+            .clone()
+            .into_iter()
+            .filter(|validator| validator.status == BondStatus::Bonded)
+            .map(Ok))
+    }
+
+    fn delegations_iter<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+        voter: &AccAddress,
+    ) -> impl Iterator<Item = Result<Self::Delegation, GasStoreErrors>> {
+        let voter = voter.clone();
+        self.delegations
+            .clone()
+            .into_iter()
+            .filter(move |delegation| delegation.delegator == voter)
+            .map(Ok)
+    }
+
+    fn total_bonded_tokens<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+    ) -> Result<UnsignedCoin, GasStoreErrors> {
+        Ok(self.total_bonded_tokens.clone())
+    }
+}
+
+impl<SK: StoreKey, M: Module> SlashingStakingKeeper<SK, M> for MockStakingKeeper {
+    type Validator = MockValidator;
+    type Delegation = MockDelegation;
+
+    fn validators_iter<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+    ) -> Result<impl Iterator<Item = Result<Self::Validator, GasStoreErrors>>, GasStoreErrors> {
+        Ok(self.validators.clone().into_iter().map(Ok))
+    }
+
+    fn validator<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+        addr: &ValAddress,
+    ) -> Result<Option<Self::Validator>, GasStoreErrors> {
+        Ok(self
+            .validators
+            .iter()
+            .find(|validator| &validator.operator == addr)
+            .cloned())
+    }
+
+    fn validator_by_cons_addr<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<Option<Self::Validator>, GasStoreErrors> {
+        Ok(self
+            .validators
+            .iter()
+            .find(|validator| &ConsAddress::from(validator.cons_pub_key.clone()) == addr)
+            .cloned())
+    }
+
+    fn slash<DB: database::Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _: &mut CTX,
+        _: &ConsAddress,
+        _: u32,
+        _: VotingPower,
+        _: Decimal256,
+    ) -> Result<(), GasStoreErrors> {
+        Ok(())
+    }
+
+    fn jail<DB: database::Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _: &mut CTX,
+        _: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        Ok(())
+    }
+
+    fn unjail<DB: database::Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _: &mut CTX,
+        _: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        Ok(())
+    }
+
+    fn delegation<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+        delegator_address: &AccAddress,
+        validator_address: &ValAddress,
+    ) -> Result<Option<Self::Delegation>, GasStoreErrors> {
+        Ok(self
+            .delegations
+            .iter()
+            .find(|delegation| {
+                &delegation.delegator == delegator_address
+                    && &delegation.validator == validator_address
+            })
+            .cloned())
+    }
+
+    fn max_validators<DB: database::Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        _: &CTX,
+    ) -> Result<u32, GasStoreErrors> {
+        Ok(self.max_validators)
+    }
+}
+
+impl<SK: StoreKey, M: Module> DistributionStakingKeeper<SK, M> for MockStakingKeeper {}
@@ -1,7 +1,10 @@
 pub mod auth;
 pub mod bank;
+pub mod feegrant;
 pub mod gov;
+pub mod mint;
 #[cfg(feature = "mocks")]
 pub mod mocks;
 pub mod slashing;
 pub mod staking;
+pub mod vesting;
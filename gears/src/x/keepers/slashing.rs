@@ -29,13 +29,13 @@ pub trait EvidenceSlashingKeeper<SK: StoreKey, M: Module>: Clone + Send + Sync +
         ctx: &CTX,
         addr: &ConsAddress,
     ) -> Result<bool, GasStoreErrors>;
-    fn slash_fraction_double_sign<DB: Database, CTX: TransactionalContext<DB, SK>>(
+    fn slash_fraction_double_sign<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
         ctx: &CTX,
     ) -> Result<Decimal256, GasStoreErrors>;
     fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &CTX,
+        ctx: &mut CTX,
         addr: &ConsAddress,
         amount: Decimal256,
         validator_power: i64,
@@ -43,18 +43,18 @@ pub trait EvidenceSlashingKeeper<SK: StoreKey, M: Module>: Clone + Send + Sync +
     ) -> Result<(), GasStoreErrors>;
     fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &CTX,
+        ctx: &mut CTX,
         addr: &ConsAddress,
     ) -> Result<(), GasStoreErrors>;
     fn jail_until<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &CTX,
+        ctx: &mut CTX,
         addr: &ConsAddress,
         time: Timestamp,
     ) -> Result<(), GasStoreErrors>;
     fn tombstone<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &CTX,
+        ctx: &mut CTX,
         addr: &ConsAddress,
     ) -> Result<(), GasStoreErrors>;
 }
@@ -11,7 +11,7 @@ use crate::{
         store::gas::errors::GasStoreErrors,
     },
     x::{
-        errors::BankKeeperError,
+        errors::{BankKeeperError, StakingHooksError},
         module::Module,
         types::{delegation::StakingDelegation, validator::StakingValidator},
     },
@@ -38,6 +38,9 @@ pub const VALIDATOR_UPDATE_DELAY: u32 = 1;
 /// keeper which must take particular actions when validators/delegators change
 /// state. The second keeper must implement this interface, which then the
 /// staking keeper can call.
+///
+/// A tuple of hooks is hooks too. It calls every element in the tuple order and
+/// stops at the first error, so several keepers can listen to the staking keeper.
 pub trait KeeperHooks<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module>:
     Clone + Send + Sync + 'static
 {
@@ -45,71 +48,197 @@ pub trait KeeperHooks<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module>:
         &self,
         ctx: &mut CTX,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         cons_addr: ConsAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         cons_addr: ConsAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         cons_addr: ConsAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         del_addr: AccAddress,
         val_addr: ValAddress,
-    );
+    ) -> Result<(), StakingHooksError>;
 
     fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         val_addr: ValAddress,
         fraction: Decimal256,
-    );
+    ) -> Result<(), StakingHooksError>;
+}
+
+macro_rules! impl_keeper_hooks_for_tuple {
+    ($($hooks:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module, $($hooks: KeeperHooks<SK, AK, M>),+>
+            KeeperHooks<SK, AK, M> for ($($hooks,)+)
+        {
+            fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.after_validator_created(ctx, val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.before_validator_modified(ctx, val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                cons_addr: ConsAddress,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.after_validator_removed(ctx, cons_addr.clone(), val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                cons_addr: ConsAddress,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.after_validator_bonded(ctx, cons_addr.clone(), val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                cons_addr: ConsAddress,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.after_validator_begin_unbonding(ctx, cons_addr.clone(), val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                del_addr: AccAddress,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.before_delegation_created(ctx, del_addr.clone(), val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                del_addr: AccAddress,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.before_delegation_shares_modified(ctx, del_addr.clone(), val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                del_addr: AccAddress,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.before_delegation_removed(ctx, del_addr.clone(), val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                del_addr: AccAddress,
+                val_addr: ValAddress,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.after_delegation_modified(ctx, del_addr.clone(), val_addr.clone())?;)+
+                Ok(())
+            }
+
+            fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+                &self,
+                ctx: &mut CTX,
+                val_addr: ValAddress,
+                fraction: Decimal256,
+            ) -> Result<(), StakingHooksError> {
+                let ($($hooks,)+) = self;
+                $($hooks.before_validator_slashed(ctx, val_addr.clone(), fraction)?;)+
+                Ok(())
+            }
+        }
+    };
 }
 
+impl_keeper_hooks_for_tuple!(A);
+impl_keeper_hooks_for_tuple!(A, B);
+impl_keeper_hooks_for_tuple!(A, B, C);
+impl_keeper_hooks_for_tuple!(A, B, C, D);
+impl_keeper_hooks_for_tuple!(A, B, C, D, E);
+impl_keeper_hooks_for_tuple!(A, B, C, D, E, F);
+impl_keeper_hooks_for_tuple!(A, B, C, D, E, F, G);
+impl_keeper_hooks_for_tuple!(A, B, C, D, E, F, G, H);
+
 /// Staking keeper which used in gov xmod
 pub trait GovStakingKeeper<SK: StoreKey, M: Module>: Clone + Send + Sync + 'static {
     type Validator: StakingValidator;
@@ -132,6 +261,21 @@ pub trait GovStakingKeeper<SK: StoreKey, M: Module>: Clone + Send + Sync + 'stat
     ) -> Result<UnsignedCoin, GasStoreErrors>;
 }
 
+/// Staking keeper which used in mint xmod
+pub trait MintingStakingKeeper<SK: StoreKey, M: Module>: Clone + Send + Sync + 'static {
+    /// staking_token_supply returns the total supply of the bond denom
+    fn staking_token_supply<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<UnsignedCoin, GasStoreErrors>;
+
+    /// bonded_ratio returns the fraction of the staking token supply that is bonded
+    fn bonded_ratio<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Decimal256, GasStoreErrors>;
+}
+
 /// Staking keeper which used in slashing xmod
 pub trait SlashingStakingKeeper<SK: StoreKey, M: Module>: Clone + Send + Sync + 'static {
     type Validator: StakingValidator;
@@ -246,3 +390,243 @@ pub trait StakingBankKeeper<SK: StoreKey, M: Module>:
         amount: UnsignedCoins,
     ) -> Result<(), BankKeeperError>;
 }
+
+#[cfg(all(test, feature = "mocks"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use extensions::testing::UnwrapTesting;
+    use strum::EnumIter;
+
+    use super::*;
+    use crate::{
+        baseapp::ConsensusParams,
+        utils::node::{build_init_ctx, build_store},
+        x::keepers::mocks::auth::MockAuthKeeper,
+    };
+
+    type Calls = Arc<Mutex<Vec<(&'static str, u8)>>>;
+
+    type Hooks = (Recorder, Recorder, Recorder);
+
+    #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+    enum TestStoreKey {
+        Staking,
+    }
+
+    impl StoreKey for TestStoreKey {
+        fn name(&self) -> &'static str {
+            "staking"
+        }
+
+        fn params() -> &'static Self {
+            &TestStoreKey::Staking
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct TestModule;
+
+    impl Module for TestModule {
+        fn name(&self) -> String {
+            "staking".to_owned()
+        }
+    }
+
+    /// Records the called hook together with its id and fails if it should
+    #[derive(Debug, Clone)]
+    struct Recorder {
+        id: u8,
+        fails: bool,
+        calls: Calls,
+    }
+
+    impl Recorder {
+        fn record(&self, hook: &'static str) -> Result<(), StakingHooksError> {
+            self.calls
+                .lock()
+                .expect("poisoned lock")
+                .push((hook, self.id));
+
+            match self.fails {
+                true => Err(StakingHooksError::Other(format!(
+                    "hooks {} failed",
+                    self.id
+                ))),
+                false => Ok(()),
+            }
+        }
+    }
+
+    impl KeeperHooks<TestStoreKey, MockAuthKeeper, TestModule> for Recorder {
+        fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("after_validator_created")
+        }
+
+        fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("before_validator_modified")
+        }
+
+        fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _cons_addr: ConsAddress,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("after_validator_removed")
+        }
+
+        fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _cons_addr: ConsAddress,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("after_validator_bonded")
+        }
+
+        fn after_validator_begin_unbonding<
+            DB: Database,
+            CTX: TransactionalContext<DB, TestStoreKey>,
+        >(
+            &self,
+            _ctx: &mut CTX,
+            _cons_addr: ConsAddress,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("after_validator_begin_unbonding")
+        }
+
+        fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _del_addr: AccAddress,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("before_delegation_created")
+        }
+
+        fn before_delegation_shares_modified<
+            DB: Database,
+            CTX: TransactionalContext<DB, TestStoreKey>,
+        >(
+            &self,
+            _ctx: &mut CTX,
+            _del_addr: AccAddress,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("before_delegation_shares_modified")
+        }
+
+        fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _del_addr: AccAddress,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("before_delegation_removed")
+        }
+
+        fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _del_addr: AccAddress,
+            _val_addr: ValAddress,
+        ) -> Result<(), StakingHooksError> {
+            self.record("after_delegation_modified")
+        }
+
+        fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, TestStoreKey>>(
+            &self,
+            _ctx: &mut CTX,
+            _val_addr: ValAddress,
+            _fraction: Decimal256,
+        ) -> Result<(), StakingHooksError> {
+            self.record("before_validator_slashed")
+        }
+    }
+
+    fn recorders(failing: Option<u8>) -> (Hooks, Calls) {
+        let calls = Calls::default();
+        let recorder = |id| Recorder {
+            id,
+            fails: failing == Some(id),
+            calls: calls.clone(),
+        };
+
+        ((recorder(1), recorder(2), recorder(3)), calls)
+    }
+
+    fn val_addr() -> ValAddress {
+        ValAddress::try_from(vec![1; 20]).unwrap_test()
+    }
+
+    fn acc_addr() -> AccAddress {
+        AccAddress::try_from(vec![2; 20]).unwrap_test()
+    }
+
+    #[test]
+    fn tuple_calls_hooks_in_order() {
+        let (hooks, calls) = recorders(None);
+        let mut multi_store = build_store::<TestStoreKey>();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        <Hooks as KeeperHooks<TestStoreKey, MockAuthKeeper, TestModule>>::after_validator_created(
+            &hooks,
+            &mut ctx,
+            val_addr(),
+        )
+        .unwrap_test();
+        <Hooks as KeeperHooks<TestStoreKey, MockAuthKeeper, TestModule>>::before_validator_slashed(
+            &hooks,
+            &mut ctx,
+            val_addr(),
+            Decimal256::percent(10),
+        )
+        .unwrap_test();
+
+        assert_eq!(
+            *calls.lock().expect("poisoned lock"),
+            vec![
+                ("after_validator_created", 1),
+                ("after_validator_created", 2),
+                ("after_validator_created", 3),
+                ("before_validator_slashed", 1),
+                ("before_validator_slashed", 2),
+                ("before_validator_slashed", 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn tuple_stops_at_first_error() {
+        let (hooks, calls) = recorders(Some(2));
+        let mut multi_store = build_store::<TestStoreKey>();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let result =
+            <Hooks as KeeperHooks<TestStoreKey, MockAuthKeeper, TestModule>>::after_delegation_modified(
+                &hooks,
+                &mut ctx,
+                acc_addr(),
+                val_addr(),
+            );
+
+        assert!(matches!(result, Err(StakingHooksError::Other(msg)) if msg == "hooks 2 failed"));
+        assert_eq!(
+            *calls.lock().expect("poisoned lock"),
+            vec![
+                ("after_delegation_modified", 1),
+                ("after_delegation_modified", 2)
+            ]
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use database::Database;
+use kv_store::StoreKey;
+
+use crate::{
+    context::{QueryableContext, TransactionalContext},
+    types::{address::AccAddress, base::coin::UnsignedCoin, msg::send::MsgSend},
+    x::{errors::BankKeeperError, module::Module},
+};
+
+/// Bank keeper which used in vesting xmod
+pub trait VestingBankKeeper<SK: StoreKey, M: Module>: Clone + Send + Sync + 'static {
+    /// Returns an error if any of the coins isn't enabled for sending
+    fn is_send_enabled_coins<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        coins: &[UnsignedCoin],
+    ) -> Result<(), BankKeeperError>;
+
+    /// Checks if the address is restricted from receiving funds
+    fn blocked_addr(&self, addr: &AccAddress) -> bool;
+
+    /// Moves the coins of the message from one account to another
+    fn send_coins_from_account_to_account<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        msg: &MsgSend,
+    ) -> Result<(), BankKeeperError>;
+}
@@ -201,6 +201,56 @@ where
     S: AsRef<str> + ?Sized,
 {
     let password = open(&path, false, backend)?;
+    read_key(name, path, password.as_deref())
+}
+
+/// Gets all entries sorted by name.
+pub fn get_all_keys(
+    path: impl AsRef<Path>,
+    backend: Backend,
+) -> Result<Vec<(String, KeyPair)>, Error> {
+    let password = open(&path, false, backend)?;
+
+    let entries = fs::read_dir(&path).map_err(|e| Error::FileIO {
+        msg: e.to_string(),
+        source: e,
+        path: path.as_ref().display().to_string(),
+    })?;
+
+    let mut names = vec![];
+    for entry in entries {
+        let entry_path = entry
+            .map_err(|e| Error::FileIO {
+                msg: e.to_string(),
+                source: e,
+                path: path.as_ref().display().to_string(),
+            })?
+            .path();
+
+        if entry_path.extension().and_then(|ext| ext.to_str()) != Some(JSON_EXTENSION) {
+            continue;
+        }
+
+        if let Some(name) = entry_path.file_stem().and_then(|name| name.to_str()) {
+            names.push(name.to_owned());
+        }
+    }
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let key_pair = read_key(&name, &path, password.as_deref())?;
+            Ok((name, key_pair))
+        })
+        .collect()
+}
+
+/// Reads and decrypts the entry with the given name from an opened keyring.
+fn read_key<S>(name: &S, path: impl AsRef<Path>, password: Option<&str>) -> Result<KeyPair, Error>
+where
+    S: AsRef<str> + ?Sized,
+{
     let mut path = path.as_ref().join(name.as_ref());
     path.set_extension(JSON_EXTENSION);
 
@@ -324,6 +374,46 @@ where
     })
 }
 
+/// Renames the entry with the given name.
+/// Returns [`Error`] if no entry with the old name can be found or an entry with the new name
+/// already exists.
+pub fn rename_key<S>(
+    old_name: S,
+    new_name: S,
+    path: impl AsRef<Path>,
+    backend: Backend,
+) -> Result<(), Error>
+where
+    S: AsRef<str>,
+{
+    open(&path, false, backend)?;
+
+    let mut old_path = path.as_ref().join(old_name.as_ref());
+    old_path.set_extension(JSON_EXTENSION);
+    let mut new_path = path.as_ref().join(new_name.as_ref());
+    new_path.set_extension(JSON_EXTENSION);
+
+    if !old_path.exists() {
+        return Err(Error::DoesNotExist {
+            name: old_name.as_ref().into(),
+            location: old_path.display().to_string(),
+        });
+    }
+
+    if new_path.exists() {
+        return Err(Error::AlreadyExists {
+            name: new_name.as_ref().into(),
+            location: new_path.display().to_string(),
+        });
+    }
+
+    fs::rename(&old_path, &new_path).map_err(|e| Error::FileIO {
+        msg: e.to_string(),
+        source: e,
+        path: old_path.display().to_string(),
+    })
+}
+
 #[derive(PartialEq)]
 pub enum Backend {
     Test,
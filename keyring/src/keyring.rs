@@ -46,6 +46,21 @@ where
     Ok(key_pair)
 }
 
+/// Stores an existing key pair, e.g. one imported from a PKCS8 PEM file.
+pub fn import_key<S>(name: S, key_pair: &KeyPair, backend: Backend) -> Result<(), Error>
+where
+    S: AsRef<str>,
+{
+    match backend {
+        Backend::File(path) => {
+            file_store::set_key_pair(name, key_pair, path, file_store::Backend::Encrypted)
+        }
+        Backend::Test(path) => {
+            file_store::set_key_pair(name, key_pair, path, file_store::Backend::Test)
+        }
+    }
+}
+
 /// Generates a new random mnemonic and key pair, stores the new key pair and
 /// returns the generated mnemonic.
 pub fn create_key<S>(
@@ -90,6 +105,29 @@ where
     }
 }
 
+/// Get all keys with their names sorted by name.
+pub fn keys(backend: Backend) -> Result<Vec<(String, KeyPair)>, Error> {
+    match backend {
+        Backend::File(path) => file_store::get_all_keys(path, file_store::Backend::Encrypted),
+        Backend::Test(path) => file_store::get_all_keys(path, file_store::Backend::Test),
+    }
+}
+
+/// Rename a key.
+pub fn rename_key<S>(old_name: S, new_name: S, backend: Backend) -> Result<(), Error>
+where
+    S: AsRef<str>,
+{
+    match backend {
+        Backend::File(path) => {
+            file_store::rename_key(old_name, new_name, path, file_store::Backend::Encrypted)
+        }
+        Backend::Test(path) => {
+            file_store::rename_key(old_name, new_name, path, file_store::Backend::Test)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -144,4 +182,46 @@ mod tests {
             key_by_name("bob", Backend::Test(&path)).expect_err("keyring should fail to open");
         assert!(matches!(error, Error::KeyringDoesNotExist(_)));
     }
+
+    #[test]
+    fn keyring_list_rename_import_works() {
+        let path = PathBuf::from("./tmp/keyring/src/keyring/keyring_list_rename_import_works");
+        let _ = std::fs::remove_dir_all(&path);
+
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+        let bob = add_key("bob", &mnemonic, KeyType::Secp256k1, Backend::Test(&path))
+            .expect("key should be added");
+        create_key("alice", KeyType::Secp256k1, Backend::Test(&path))
+            .expect("key should be created");
+
+        // keys should be sorted by name
+        let names = keys(Backend::Test(&path))
+            .expect("keys should be listed")
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["alice", "bob"]);
+
+        // rename to an existing name should fail
+        let error = rename_key("bob", "alice", Backend::Test(&path))
+            .expect_err("key should not be renamed");
+        assert!(matches!(error, Error::AlreadyExists { .. }));
+
+        // rename should succeed
+        rename_key("bob", "carol", Backend::Test(&path)).expect("key should be renamed");
+        let error = key_by_name("bob", Backend::Test(&path)).expect_err("key should be renamed");
+        assert!(matches!(error, Error::DoesNotExist { .. }));
+        let carol = key_by_name("carol", Backend::Test(&path)).expect("key should be retrieved");
+        assert_eq!(carol.to_pkcs8_pem(), bob.to_pkcs8_pem());
+
+        // import of an exported key should succeed
+        let pem = bob.to_pkcs8_encrypted_pem("password");
+        let imported = KeyPair::from_pkcs8_encrypted_pem(&pem, "password").unwrap_test();
+        import_key("dave", &imported, Backend::Test(&path)).expect("key should be imported");
+        let dave = key_by_name("dave", Backend::Test(&path)).expect("key should be retrieved");
+        assert_eq!(dave.to_pkcs8_pem(), bob.to_pkcs8_pem());
+
+        std::fs::remove_dir_all(path).expect("tmp directory should be deleted");
+    }
 }
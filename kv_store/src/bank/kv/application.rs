@@ -6,7 +6,7 @@ use std::{
 };
 
 use database::Database;
-use trees::iavl::Tree;
+use trees::iavl::{PruningOptions, Tree};
 
 use crate::{
     cache::KVCache,
@@ -169,6 +169,15 @@ impl<DB: Database> ApplicationKVBank<DB> {
         //TODO: is it safe to assume this won't ever error?
         persistent.save_version().ok().unwrap_or_default().0
     }
+
+    /// Delete persisted versions which shouldn't be retained according to `options`
+    pub fn prune(&mut self, options: &PruningOptions) -> Result<Vec<u32>, KVStoreError> {
+        Ok(self
+            .persistent
+            .write()
+            .expect(POISONED_LOCK)
+            .prune(options)?)
+    }
 }
 
 #[cfg(test)]
@@ -35,6 +35,16 @@ impl<DB: Database> TransactionKVBank<DB> {
         self.persistent.read().expect(POISONED_LOCK)
     }
 
+    /// Copy of the store which shares persistent storage, but changes of which
+    /// don't affect this store
+    pub fn branch(&self) -> Self {
+        Self {
+            persistent: Arc::clone(&self.persistent),
+            tx: self.tx.clone(),
+            block: self.block.clone(),
+        }
+    }
+
     /// Clear uncommitted cache for tx
     #[inline]
     pub fn tx_cache_clear(&mut self) {
@@ -853,6 +863,21 @@ mod tests {
         assert_eq!(expected_range, result_range);
     }
 
+    #[test]
+    fn branch_changes_do_not_affect_origin() {
+        let mut store = build_store(build_tree([(1, 11)]), None);
+        store.set([2], [22]);
+
+        let mut branch = store.branch();
+        branch.set([3], [33]);
+        branch.delete(&[1]);
+
+        assert_eq!(branch.get(&[2_u8]), Some(vec![22]));
+        assert_eq!(branch.get(&[1_u8]), None);
+        assert_eq!(store.get(&[1_u8]), Some(vec![11]));
+        assert_eq!(store.get(&[3_u8]), None);
+    }
+
     fn build_tree(values: impl IntoIterator<Item = (u8, u8)>) -> Tree<MemDB> {
         let mut tree = Tree::new(
             MemDB::new(),
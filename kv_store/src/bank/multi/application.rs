@@ -1,16 +1,29 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
-use database::{prefix::PrefixDB, Database};
+use database::{prefix::PrefixDB, Database, WriteBatch};
 
 use crate::{
-    bank::kv::application::ApplicationKVBank, build_prefixed_stores, error::MultiStoreError,
-    hash::StoreInfo, StoreKey,
+    bank::kv::application::ApplicationKVBank,
+    build_prefixed_stores,
+    error::{KVStoreError, MultiStoreError, SnapshotError, StoreUpgradeError, POISONED_LOCK},
+    hash::StoreInfo,
+    snapshot::{SnapshotItem, SnapshotRestore},
+    upgrade::StoreUpgrades,
+    PruningOptions, StoreKey,
 };
 
 use super::*;
 
+/// Stores of the application. The second field holds the stores which are removed by a store
+/// upgrade, their data is deleted once the first version after the upgrade is committed.
 #[derive(Debug)]
-pub struct ApplicationStore<DB, SK>(pub(crate) HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>);
+pub struct ApplicationStore<DB, SK>(
+    pub(crate) HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>,
+    pub(crate) Vec<PrefixDB<DB>>,
+);
 
 impl<SK, DB> MultiBankBackend<DB, SK> for ApplicationStore<DB, SK> {
     type Bank = ApplicationKVBank<PrefixDB<DB>>;
@@ -26,33 +39,138 @@ impl<SK, DB> MultiBankBackend<DB, SK> for ApplicationStore<DB, SK> {
 
 impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
     pub fn new(db: Arc<DB>) -> Result<Self, MultiStoreError<SK>> {
-        let mut store_infos = Vec::new();
-        let mut head_version = 0;
+        Self::from_stores(load_stores(db)?)
+    }
 
-        let map = build_prefixed_stores::<_, SK>(db);
-        let mut stores = HashMap::with_capacity(map.len());
-        for (store_key, store) in map {
-            let kv_store = ApplicationKVBank::new(store, None, Some(store_key.name().to_owned()))
-                .map_err(|err| MultiStoreError {
-                sk: store_key.clone(),
+    fn from_stores(
+        stores: HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>,
+    ) -> Result<Self, MultiStoreError<SK>> {
+        let head_version = head_version(&stores);
+
+        let mut store_infos = Vec::with_capacity(stores.len());
+        for (store_key, kv_store) in &stores {
+            check_version(store_key, kv_store, head_version)?;
+
+            store_infos.push(StoreInfo {
+                name: store_key.name().into(),
+                hash: kv_store.persistent().root_hash(),
+            });
+        }
+
+        Ok(MultiBank {
+            head_version,
+            head_commit_hash: crate::hash::hash_store_infos(store_infos),
+            backend: ApplicationStore(stores, Vec::new()),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Loads the stores and applies the store upgrades of the next height, if there are any.
+    /// `upgrades` maps the heights of software upgrades to their store upgrades.
+    pub fn new_with_upgrades(
+        db: Arc<DB>,
+        upgrades: &BTreeMap<u32, StoreUpgrades>,
+    ) -> Result<Self, StoreUpgradeError<SK>> {
+        let mut stores = load_stores(Arc::clone(&db))?;
+        let head_version = head_version(&stores);
+
+        let upgrades = match head_version
+            .checked_add(1)
+            .and_then(|height| upgrades.get(&height))
+        {
+            Some(upgrades) => upgrades,
+            None => return Ok(Self::from_stores(stores)?),
+        };
+
+        for name in upgrades
+            .added
+            .iter()
+            .chain(upgrades.renamed.iter().map(|rename| &rename.new_key))
+        {
+            if !stores.keys().any(|sk| sk.name() == name) {
+                return Err(StoreUpgradeError::UnknownStore(name.to_owned()));
+            }
+        }
+
+        // The last commit includes the removed stores, but not the new ones
+        let mut store_infos = Vec::with_capacity(stores.len());
+        for (store_key, kv_store) in &stores {
+            if upgrades.is_new(store_key.name()) {
+                if !kv_store.persistent().versions().is_empty() {
+                    return Err(StoreUpgradeError::NotEmpty(store_key.name().to_owned()));
+                }
+
+                continue;
+            }
+
+            check_version(store_key, kv_store, head_version)?;
+
+            store_infos.push(StoreInfo {
+                name: store_key.name().into(),
+                hash: kv_store.persistent().root_hash(),
+            });
+        }
+
+        let mut removed = HashMap::new();
+        for name in upgrades.removed() {
+            if stores.keys().any(|sk| sk.name() == name) {
+                return Err(StoreUpgradeError::NotRemoved(name.to_owned()));
+            }
+
+            let prefix_db = PrefixDB::new(Arc::clone(&db), name.as_bytes().to_vec());
+            let kv_store = ApplicationKVBank::new(
+                PrefixDB::new(Arc::clone(&db), name.as_bytes().to_vec()),
+                None,
+                Some(name.to_owned()),
+            )
+            .map_err(|err| StoreUpgradeError::RemovedStore {
+                name: name.to_owned(),
                 err,
             })?;
 
-            let store_info = StoreInfo {
-                name: store_key.name().into(),
+            store_infos.push(StoreInfo {
+                name: name.to_owned(),
                 hash: kv_store.persistent().root_hash(),
-            };
+            });
 
-            store_infos.push(store_info);
-            head_version = kv_store.persistent().loaded_version();
+            removed.insert(name, (prefix_db, kv_store));
+        }
 
-            stores.insert(store_key, kv_store);
+        for (store_key, kv_store) in &mut stores {
+            if !upgrades.is_new(store_key.name()) {
+                continue;
+            }
+
+            kv_store
+                .persistent
+                .write()
+                .expect(POISONED_LOCK)
+                .set_initial_version(head_version)
+                .map_err(|err| MultiStoreError {
+                    sk: store_key.clone(),
+                    err: err.into(),
+                })?;
+
+            if let Some((_, old_store)) = upgrades
+                .renamed_from(store_key.name())
+                .and_then(|old_key| removed.get(old_key))
+            {
+                for (key, value) in old_store.persistent().range(..) {
+                    kv_store.set(key, value);
+                }
+            }
         }
 
         Ok(MultiBank {
             head_version,
             head_commit_hash: crate::hash::hash_store_infos(store_infos),
-            backend: ApplicationStore(stores),
+            backend: ApplicationStore(
+                stores,
+                removed
+                    .into_values()
+                    .map(|(prefix_db, _)| prefix_db)
+                    .collect(),
+            ),
             _marker: PhantomData,
         })
     }
@@ -91,6 +209,15 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
 
         let hash = crate::hash::hash_store_infos(store_infos);
 
+        // the data of the stores removed by an upgrade isn't needed once the upgrade is committed
+        for prefix_db in self.backend.1.drain(..) {
+            let mut batch = WriteBatch::new();
+            for (key, _) in prefix_db.iterator() {
+                batch.delete(key.into_vec());
+            }
+            prefix_db.write(batch);
+        }
+
         self.head_commit_hash = hash;
         self.head_version = match self.head_version.checked_add(1) {
             Some(head_version) => head_version,
@@ -99,9 +226,364 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         hash
     }
 
+    /// Prune all stores if pruning is due at the head version
+    pub fn prune(&mut self, options: &PruningOptions) -> Result<(), MultiStoreError<SK>> {
+        if !options.should_prune(self.head_version) {
+            return Ok(());
+        }
+
+        for (sk, store) in &mut self.backend.0 {
+            store.prune(options).map_err(|err| MultiStoreError {
+                sk: sk.clone(),
+                err,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Restore all stores from snapshot items at `version`. The snapshot is
+    /// verified against `app_hash` before the version is saved.
+    pub fn restore(
+        &mut self,
+        version: u32,
+        items: impl IntoIterator<Item = SnapshotItem>,
+        app_hash: [u8; 32],
+    ) -> Result<(), SnapshotError> {
+        let mut restore = SnapshotRestore::default();
+        self.restore_items(&mut restore, items)?;
+
+        self.finish_restore(restore, version, app_hash)
+    }
+
+    /// Write snapshot items into the stores as soon as they're received. Restored
+    /// data isn't reachable until the restore is finished with [`Self::finish_restore`].
+    pub fn restore_items(
+        &mut self,
+        restore: &mut SnapshotRestore,
+        items: impl IntoIterator<Item = SnapshotItem>,
+    ) -> Result<(), SnapshotError> {
+        for item in items {
+            match item {
+                SnapshotItem::Store(name) => {
+                    if !self.backend.0.keys().any(|sk| sk.name() == name) {
+                        return Err(SnapshotError::UnknownStore(name));
+                    }
+
+                    restore.importers.entry(name.clone()).or_default();
+                    restore.current = Some(name);
+                }
+                SnapshotItem::Node(node) => {
+                    let name = restore
+                        .current
+                        .as_ref()
+                        .ok_or(SnapshotError::NodeWithoutStore)?;
+                    let store = self
+                        .backend
+                        .0
+                        .iter()
+                        .find_map(|(sk, store)| (sk.name() == name).then_some(store))
+                        .expect("store was checked on store item");
+
+                    restore
+                        .importers
+                        .get_mut(name)
+                        .expect("importer was inserted on store item")
+                        .add(&mut store.persistent.write().expect(POISONED_LOCK), node)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify the restored stores against `app_hash` and save them at `version`
+    pub fn finish_restore(
+        &mut self,
+        restore: SnapshotRestore,
+        version: u32,
+        app_hash: [u8; 32],
+    ) -> Result<(), SnapshotError> {
+        let mut importers = restore.importers;
+
+        let mut store_infos = Vec::with_capacity(importers.len());
+        for sk in self.backend.0.keys() {
+            let importer = importers
+                .get(sk.name())
+                .ok_or_else(|| SnapshotError::MissingStore(sk.name().to_owned()))?;
+
+            store_infos.push(StoreInfo {
+                name: sk.name().to_owned(),
+                hash: importer.root_hash()?,
+            });
+        }
+
+        let hash = crate::hash::hash_store_infos(store_infos);
+        if hash != app_hash {
+            return Err(SnapshotError::HashMismatch {
+                expected: app_hash,
+                actual: hash,
+            });
+        }
+
+        for (sk, store) in &mut self.backend.0 {
+            importers
+                .remove(sk.name())
+                .expect("all stores are checked above")
+                .commit(&mut store.persistent.write().expect(POISONED_LOCK), version)?;
+        }
+
+        self.head_version = version;
+        self.head_commit_hash = hash;
+
+        Ok(())
+    }
+
     pub fn clear_cache(&mut self) {
         for store in self.backend.0.values_mut() {
             store.cache_clear();
         }
     }
 }
+
+fn load_stores<DB: Database, SK: StoreKey>(
+    db: Arc<DB>,
+) -> Result<HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>, MultiStoreError<SK>> {
+    let map = build_prefixed_stores::<_, SK>(db);
+    let mut stores = HashMap::with_capacity(map.len());
+    for (store_key, store) in map {
+        let kv_store = ApplicationKVBank::new(store, None, Some(store_key.name().to_owned()))
+            .map_err(|err| MultiStoreError {
+                sk: store_key.clone(),
+                err,
+            })?;
+
+        stores.insert(store_key, kv_store);
+    }
+
+    Ok(stores)
+}
+
+fn head_version<DB: Database, SK>(stores: &HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>) -> u32 {
+    stores
+        .values()
+        .map(|kv_store| kv_store.persistent().loaded_version())
+        .max()
+        .unwrap_or_default()
+}
+
+/// All stores are committed together, so a store at another version is either corrupted or
+/// added without a store upgrade
+fn check_version<DB: Database, SK: StoreKey>(
+    store_key: &SK,
+    kv_store: &ApplicationKVBank<PrefixDB<DB>>,
+    head_version: u32,
+) -> Result<(), MultiStoreError<SK>> {
+    let version = kv_store.persistent().loaded_version();
+    if version != head_version {
+        return Err(MultiStoreError {
+            sk: store_key.clone(),
+            err: KVStoreError::VersionMismatch {
+                version,
+                head_version,
+            },
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+    use strum::EnumIter;
+
+    use super::*;
+    use crate::{query::QueryMultiStore, upgrade::StoreRename};
+
+    #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+    enum TestStoreKey {
+        Bank,
+        Auth,
+    }
+
+    impl StoreKey for TestStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                TestStoreKey::Bank => "bank",
+                TestStoreKey::Auth => "acc",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &TestStoreKey::Auth
+        }
+    }
+
+    fn committed_multi_bank() -> ApplicationMultiBank<MemDB, TestStoreKey> {
+        let mut multi_bank = ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test();
+        for i in 1..=10 {
+            multi_bank
+                .kv_store_mut(&TestStoreKey::Bank)
+                .set(vec![i], vec![i]);
+            multi_bank.commit();
+        }
+        multi_bank
+            .kv_store_mut(&TestStoreKey::Auth)
+            .set(vec![1], vec![1]);
+        multi_bank.commit();
+
+        multi_bank
+    }
+
+    #[test]
+    fn export_restore_round_trip() {
+        let multi_bank = committed_multi_bank();
+        let mut items = Vec::new();
+        QueryMultiStore::new(&multi_bank, 11)
+            .unwrap_test()
+            .export(|item| items.push(item));
+
+        let mut restored: ApplicationMultiBank<MemDB, TestStoreKey> =
+            ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test();
+        restored
+            .restore(11, items, multi_bank.head_commit_hash())
+            .unwrap_test();
+
+        assert_eq!(restored.head_version(), 11);
+        assert_eq!(restored.head_commit_hash(), multi_bank.head_commit_hash());
+        assert_eq!(
+            restored.kv_store(&TestStoreKey::Bank).get(&[5]),
+            Some(vec![5])
+        );
+    }
+
+    #[test]
+    fn restore_items_in_parts() {
+        let multi_bank = committed_multi_bank();
+        let mut items = Vec::new();
+        QueryMultiStore::new(&multi_bank, 11)
+            .unwrap_test()
+            .export(|item| items.push(item));
+
+        let mut restored: ApplicationMultiBank<MemDB, TestStoreKey> =
+            ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut restore = SnapshotRestore::default();
+        for part in items.chunks(3) {
+            restored
+                .restore_items(&mut restore, part.to_vec())
+                .unwrap_test();
+        }
+        assert_eq!(restored.head_version(), 0);
+
+        restored
+            .finish_restore(restore, 11, multi_bank.head_commit_hash())
+            .unwrap_test();
+
+        assert_eq!(restored.head_version(), 11);
+        assert_eq!(
+            restored.kv_store(&TestStoreKey::Bank).get(&[5]),
+            Some(vec![5])
+        );
+    }
+
+    #[test]
+    fn restore_rejects_wrong_hash() {
+        let multi_bank = committed_multi_bank();
+        let mut items = Vec::new();
+        QueryMultiStore::new(&multi_bank, 11)
+            .unwrap_test()
+            .export(|item| items.push(item));
+
+        let mut restored: ApplicationMultiBank<MemDB, TestStoreKey> =
+            ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test();
+        let result = restored.restore(11, items, [0; 32]);
+
+        assert!(matches!(result, Err(SnapshotError::HashMismatch { .. })));
+        assert_eq!(restored.head_version(), 0);
+    }
+
+    #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+    enum OldStoreKey {
+        Bank,
+        Old,
+    }
+
+    impl StoreKey for OldStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                OldStoreKey::Bank => "bank",
+                OldStoreKey::Old => "old",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &OldStoreKey::Bank
+        }
+    }
+
+    #[test]
+    fn store_upgrades_rename_store() {
+        let db = Arc::new(MemDB::new());
+        let mut multi_bank: ApplicationMultiBank<MemDB, OldStoreKey> =
+            ApplicationMultiBank::new(Arc::clone(&db)).unwrap_test();
+        for i in 1..=3 {
+            multi_bank
+                .kv_store_mut(&OldStoreKey::Old)
+                .set(vec![i], vec![i]);
+            multi_bank.commit();
+        }
+        let hash = multi_bank.head_commit_hash();
+
+        let upgrades = BTreeMap::from([(
+            4,
+            StoreUpgrades {
+                renamed: vec![StoreRename {
+                    old_key: "old".to_owned(),
+                    new_key: "acc".to_owned(),
+                }],
+                ..Default::default()
+            },
+        )]);
+
+        // without the upgrade the new store doesn't match the version of the others
+        assert!(ApplicationMultiBank::<MemDB, TestStoreKey>::new(Arc::clone(&db)).is_err());
+
+        let mut upgraded: ApplicationMultiBank<MemDB, TestStoreKey> =
+            ApplicationMultiBank::new_with_upgrades(Arc::clone(&db), &upgrades).unwrap_test();
+        assert_eq!(upgraded.head_version(), 3);
+        assert_eq!(upgraded.head_commit_hash(), hash);
+        assert_eq!(
+            upgraded.kv_store(&TestStoreKey::Auth).get(&[2]),
+            Some(vec![2])
+        );
+
+        upgraded.commit();
+        assert_eq!(upgraded.head_version(), 4);
+        assert_eq!(db.prefix_iterator(b"old".to_vec()).count(), 0);
+
+        let reloaded: ApplicationMultiBank<MemDB, TestStoreKey> =
+            ApplicationMultiBank::new_with_upgrades(db, &upgrades).unwrap_test();
+        assert_eq!(reloaded.head_version(), 4);
+        assert_eq!(
+            reloaded.kv_store(&TestStoreKey::Auth).get(&[3]),
+            Some(vec![3])
+        );
+    }
+
+    #[test]
+    fn store_upgrades_reject_unknown_store() {
+        let db = Arc::new(MemDB::new());
+        let upgrades = BTreeMap::from([(
+            1,
+            StoreUpgrades {
+                added: vec!["unknown".to_owned()],
+                ..Default::default()
+            },
+        )]);
+
+        let result = ApplicationMultiBank::<MemDB, TestStoreKey>::new_with_upgrades(db, &upgrades);
+        assert!(matches!(result, Err(StoreUpgradeError::UnknownStore(name)) if name == "unknown"));
+    }
+}
@@ -22,6 +22,23 @@ impl<SK, DB> MultiBankBackend<DB, SK> for TransactionStore<DB, SK> {
 }
 
 impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, TransactionStore<DB, SK>> {
+    /// Copy of the bank with the same uncommitted changes. Changes of the copy
+    /// are discarded when it is dropped.
+    pub fn branch(&self) -> Self {
+        Self {
+            head_version: self.head_version,
+            head_commit_hash: self.head_commit_hash,
+            backend: TransactionStore(
+                self.backend
+                    .0
+                    .iter()
+                    .map(|(sk, store)| (sk.to_owned(), store.branch()))
+                    .collect(),
+            ),
+            _marker: PhantomData,
+        }
+    }
+
     pub fn tx_cache_clear(&mut self) {
         for store in self.backend.0.values_mut() {
             store.tx_cache_clear()
@@ -6,6 +6,8 @@ use crate::StoreKey;
 pub enum KVStoreError {
     #[error(transparent)]
     Tree(#[from] trees::Error),
+    #[error("store is at version {version}, but the multi store is at version {head_version}")]
+    VersionMismatch { version: u32, head_version: u32 },
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -26,5 +28,40 @@ impl<SK: StoreKey> std::fmt::Display for MultiStoreError<SK> {
     }
 }
 
+impl<SK: StoreKey> std::error::Error for MultiStoreError<SK> {}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum StoreUpgradeError<SK: StoreKey> {
+    #[error(transparent)]
+    Store(#[from] MultiStoreError<SK>),
+    #[error("store {0} of the upgrade is not a store of the application")]
+    UnknownStore(String),
+    #[error("store {0} can't be added by the upgrade, it already has saved versions")]
+    NotEmpty(String),
+    #[error("store {0} removed by the upgrade is still a store of the application")]
+    NotRemoved(String),
+    #[error("failed to load removed store {name} with error: {err}")]
+    RemovedStore { name: String, err: KVStoreError },
+}
+
 pub const KEY_EXISTS_MSG: &str = "a store for every key is guaranteed to exist";
 pub const POISONED_LOCK: &str = "poisoned lock";
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SnapshotError {
+    #[error("failed to decode snapshot item: {0}")]
+    Decode(String),
+    #[error("snapshot contains unknown store {0}")]
+    UnknownStore(String),
+    #[error("snapshot is missing store {0}")]
+    MissingStore(String),
+    #[error("snapshot node without a store")]
+    NodeWithoutStore,
+    #[error("snapshot hash {actual:?} doesn't match expected hash {expected:?}")]
+    HashMismatch {
+        expected: [u8; 32],
+        actual: [u8; 32],
+    },
+    #[error(transparent)]
+    Tree(#[from] trees::Error),
+}
@@ -1,5 +1,6 @@
 use integer_encoding::VarInt;
 use sha2::{Digest, Sha256};
+use trees::proof::{ExistenceProof, LeafOp};
 
 #[derive(Debug)]
 pub struct StoreInfo {
@@ -49,6 +50,27 @@ pub fn hash_store_infos(store_infos: Vec<StoreInfo>) -> [u8; 32] {
     trees::merkle::root_hash(&byte_pairs)
 }
 
+/// Returns the proof of the store hash of store `name` in the commit hash of `store_infos`
+pub fn store_info_proof(store_infos: Vec<StoreInfo>, name: &str) -> Option<ExistenceProof> {
+    let value = store_infos
+        .iter()
+        .find(|info| info.name == name)?
+        .hash
+        .to_vec();
+
+    let mut pairs: Vec<Pair> = store_infos.into_iter().map(|info| info.into()).collect();
+    pairs.sort();
+    let index = pairs.iter().position(|pair| pair.key == name.as_bytes())?;
+    let byte_pairs: Vec<Vec<u8>> = pairs.into_iter().map(|pair| pair.to_bytes()).collect();
+
+    Some(ExistenceProof {
+        key: name.as_bytes().to_vec(),
+        value,
+        leaf: LeafOp { prefix: vec![0] },
+        path: trees::merkle::proof_path(&byte_pairs, index)?,
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -95,4 +117,24 @@ mod tests {
             "9328960b097a043bd62b6d22075084251688dff84d004743d0666f4ecdd5b86d"
         );
     }
+
+    #[test]
+    fn store_info_proof_works() {
+        let store_infos = || {
+            ["acc", "bank", "params", "staking", "gov"]
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| StoreInfo {
+                    name: name.to_owned(),
+                    hash: [i as u8; 32],
+                })
+                .collect::<Vec<_>>()
+        };
+        let root = hash_store_infos(store_infos());
+
+        let proof = store_info_proof(store_infos(), "params").unwrap_test();
+        assert!(proof.verify(&root, b"params", &[2; 32]));
+
+        assert!(store_info_proof(store_infos(), "mint").is_none());
+    }
 }
@@ -7,11 +7,15 @@ pub mod cache;
 pub mod error;
 pub mod query;
 pub mod range;
+pub mod snapshot;
 pub mod store;
+pub mod upgrade;
 
 mod hash;
 mod utils;
 
+pub use trees::{iavl::PruningOptions, proof};
+
 pub(crate) const TREE_CACHE_SIZE: usize = 100_000;
 
 #[derive(Debug, Clone, Hash, Default, PartialEq, Eq, PartialOrd, Ord)]
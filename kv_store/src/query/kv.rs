@@ -1,7 +1,10 @@
 use std::ops::RangeBounds;
 
 use database::Database;
-use trees::iavl::QueryTree;
+use trees::{
+    iavl::{Exporter, QueryTree},
+    proof::CommitmentProof,
+};
 
 use crate::store::prefix::immutable::ImmutablePrefixStore;
 
@@ -23,6 +26,19 @@ impl<DB: Database> QueryKVStore<DB> {
         self.0.get(k.as_ref())
     }
 
+    /// Returns a proof of existence or non-existence of `k`
+    pub fn get_proof<R: AsRef<[u8]> + ?Sized>(&self, k: &R) -> CommitmentProof {
+        self.0.get_proof(k.as_ref())
+    }
+
+    pub fn root_hash(&self) -> [u8; 32] {
+        self.0.root_hash()
+    }
+
+    pub fn export(&self) -> Exporter<'_, DB> {
+        self.0.export()
+    }
+
     pub fn prefix_store<I: IntoIterator<Item = u8>>(
         &self,
         prefix: I,
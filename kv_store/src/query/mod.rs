@@ -1,12 +1,17 @@
 use std::collections::HashMap;
 
 use database::{prefix::PrefixDB, Database};
-use trees::iavl::QueryTree;
+use trees::{
+    iavl::QueryTree,
+    proof::{CommitmentProof, ExistenceProof},
+};
 
 use crate::{
     bank::kv::application::ApplicationKVBank,
     bank::multi::{ApplicationMultiBank, MultiBankBackend},
     error::{KVStoreError, KEY_EXISTS_MSG, POISONED_LOCK},
+    hash::{store_info_proof, StoreInfo},
+    snapshot::SnapshotItem,
     StoreKey,
 };
 
@@ -81,4 +86,39 @@ impl<DB: Database, SK: StoreKey> QueryMultiStore<DB, SK> {
     pub fn head_commit_hash(&self) -> [u8; 32] {
         self.head_commit_hash
     }
+
+    /// Export all stores at the queried version. Stores are exported in order of their names.
+    pub fn export(&self, mut on_item: impl FnMut(SnapshotItem)) {
+        let mut stores = self.inner.iter().collect::<Vec<_>>();
+        stores.sort_by_key(|(sk, _)| sk.name());
+
+        for (sk, store) in stores {
+            on_item(SnapshotItem::Store(sk.name().to_owned()));
+
+            for node in store.export() {
+                on_item(SnapshotItem::Node(node));
+            }
+        }
+    }
+
+    /// Returns the proof of `key` in the store and the proof of the store hash in the commit hash
+    pub fn get_proof(&self, store_key: &SK, key: &[u8]) -> (CommitmentProof, ExistenceProof) {
+        let store_infos = self
+            .inner
+            .iter()
+            .map(|(sk, store)| StoreInfo {
+                name: sk.name().into(),
+                hash: store.root_hash(),
+            })
+            .collect();
+
+        let store_proof = store_info_proof(store_infos, store_key.name()).expect(KEY_EXISTS_MSG);
+        let proof = self
+            .inner
+            .get(store_key)
+            .expect(KEY_EXISTS_MSG)
+            .get_proof(key);
+
+        (proof, store_proof)
+    }
 }
@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+
+use integer_encoding::VarInt;
+use trees::iavl::{ExportedNode, Importer};
+
+use crate::error::SnapshotError;
+
+const STORE_TAG: u8 = 0;
+const NODE_TAG: u8 = 1;
+
+/// Item of a multi store snapshot. Every store is exported as a `Store` item
+/// followed by the nodes of its tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotItem {
+    Store(String),
+    Node(ExportedNode),
+}
+
+impl SnapshotItem {
+    /// Append encoded item to `buf`
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            SnapshotItem::Store(name) => {
+                buf.push(STORE_TAG);
+                encode_bytes(name.as_bytes(), buf);
+            }
+            SnapshotItem::Node(node) => {
+                buf.push(NODE_TAG);
+                buf.push(node.height);
+                buf.extend(node.version.encode_var_vec());
+                encode_bytes(&node.key, buf);
+                if let Some(value) = &node.value {
+                    encode_bytes(value, buf);
+                }
+            }
+        }
+    }
+
+    /// Decode item from the start of `buf` and advance it past the item
+    pub fn decode(buf: &mut &[u8]) -> Result<Self, SnapshotError> {
+        match take(buf, 1)?[0] {
+            STORE_TAG => {
+                let name = String::from_utf8(decode_bytes(buf)?.to_vec())
+                    .map_err(|e| SnapshotError::Decode(e.to_string()))?;
+
+                Ok(SnapshotItem::Store(name))
+            }
+            NODE_TAG => {
+                let height = take(buf, 1)?[0];
+                let version = decode_var(buf)?;
+                let key = decode_bytes(buf)?.to_vec();
+                let value = match height {
+                    0 => Some(decode_bytes(buf)?.to_vec()),
+                    _ => None,
+                };
+
+                Ok(SnapshotItem::Node(ExportedNode {
+                    key,
+                    value,
+                    version,
+                    height,
+                }))
+            }
+            tag => Err(SnapshotError::Decode(format!("unknown item tag {tag}"))),
+        }
+    }
+}
+
+/// State of a multi store restore from snapshot items which are received in several parts,
+/// see [`crate::bank::multi::ApplicationMultiBank::restore_items`]
+#[derive(Debug, Default)]
+pub struct SnapshotRestore {
+    pub(crate) importers: BTreeMap<String, Importer>,
+    /// Store which the following nodes belong to
+    pub(crate) current: Option<String>,
+}
+
+fn encode_bytes(bytes: &[u8], buf: &mut Vec<u8>) {
+    buf.extend(bytes.len().encode_var_vec());
+    buf.extend_from_slice(bytes);
+}
+
+fn decode_bytes<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], SnapshotError> {
+    let len = decode_var(buf)?;
+    take(buf, len)
+}
+
+fn decode_var<T: VarInt>(buf: &mut &[u8]) -> Result<T, SnapshotError> {
+    let (value, read) =
+        T::decode_var(buf).ok_or_else(|| SnapshotError::Decode("invalid varint".to_owned()))?;
+    *buf = &buf[read..];
+
+    Ok(value)
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], SnapshotError> {
+    if buf.len() < len {
+        return Err(SnapshotError::Decode("unexpected end of input".to_owned()));
+    }
+
+    let (head, tail) = buf.split_at(len);
+    *buf = tail;
+
+    Ok(head)
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let items = vec![
+            SnapshotItem::Store("bank".to_owned()),
+            SnapshotItem::Node(ExportedNode {
+                key: vec![1, 2, 3],
+                value: Some(vec![4; 300]),
+                version: 1_000,
+                height: 0,
+            }),
+            SnapshotItem::Node(ExportedNode {
+                key: vec![5],
+                value: None,
+                version: 7,
+                height: 3,
+            }),
+        ];
+
+        let mut buf = Vec::new();
+        for item in &items {
+            item.encode(&mut buf);
+        }
+
+        let mut bytes = buf.as_slice();
+        let mut decoded = Vec::new();
+        while !bytes.is_empty() {
+            decoded.push(SnapshotItem::decode(&mut bytes).unwrap_test());
+        }
+
+        assert_eq!(items, decoded);
+    }
+
+    #[test]
+    fn decode_truncated_fails() {
+        let mut buf = Vec::new();
+        SnapshotItem::Store("bank".to_owned()).encode(&mut buf);
+        buf.pop();
+
+        assert!(SnapshotItem::decode(&mut buf.as_slice()).is_err());
+    }
+}
@@ -9,7 +9,7 @@ pub mod range;
 ///
 /// That is the smallest x such that, prefix + y < x for all y. If
 /// no such x exists (i.e. prefix = vec![255; N]; for some N) it returns Bound::Unbounded
-fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
+pub fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
     loop {
         let last = prefix.last_mut();
 
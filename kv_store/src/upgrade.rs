@@ -0,0 +1,45 @@
+/// StoreRename defines a store whose data is moved to a store with another name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreRename {
+    pub old_key: String,
+    pub new_key: String,
+}
+
+/// StoreUpgrades defines the stores which are added, renamed or deleted by a software upgrade.
+/// Stores are identified by the names of their store keys. Note that the data of a store is
+/// saved under its name as a prefix, so the name of a deleted or renamed store must not be
+/// a prefix of the name of another store.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoreUpgrades {
+    /// New stores which start empty at the upgrade height
+    pub added: Vec<String>,
+    /// Stores which keep their data under a new name
+    pub renamed: Vec<StoreRename>,
+    /// Stores whose data is dropped
+    pub deleted: Vec<String>,
+}
+
+impl StoreUpgrades {
+    /// Returns true if the store starts at the upgrade height, either added or renamed
+    pub fn is_new(&self, name: &str) -> bool {
+        self.added.iter().any(|added| added == name)
+            || self.renamed.iter().any(|rename| rename.new_key == name)
+    }
+
+    /// Returns the old name of the store if it is renamed by the upgrade
+    pub fn renamed_from(&self, name: &str) -> Option<&str> {
+        self.renamed
+            .iter()
+            .find(|rename| rename.new_key == name)
+            .map(|rename| rename.old_key.as_str())
+    }
+
+    /// Returns the names of the stores which are removed by the upgrade, either deleted or
+    /// renamed
+    pub fn removed(&self) -> impl Iterator<Item = &str> {
+        self.deleted
+            .iter()
+            .map(String::as_str)
+            .chain(self.renamed.iter().map(|rename| rename.old_key.as_str()))
+    }
+}
@@ -93,9 +93,9 @@ pub fn expand_macro(
 
     let signers_impl = quote! {
         fn get_signers(&self) -> ::std::vec::Vec<&#crate_prefix::types::address::AccAddress> {
-            ::std::vec![
-                #(&self.#signers),*
-            ]
+            ::std::iter::empty()
+                #(.chain(#crate_prefix::types::tx::MsgSigners::signers(&self.#signers)))*
+                .collect()
         }
     };
 
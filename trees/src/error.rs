@@ -7,6 +7,14 @@ pub enum Error {
     VersionNotFound(u32),
     #[error("cannot overwrite existing version")]
     Overwrite,
+    #[error("cannot delete latest or loaded version: {0}")]
+    DeleteActiveVersion(u32),
+    #[error("cannot import into a tree with saved versions")]
+    ImportIntoNonEmpty,
+    #[error("cannot set the initial version of a tree with saved versions")]
+    InitialVersionOfNonEmpty,
+    #[error("invalid exported tree: {0}")]
+    InvalidExport(String),
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
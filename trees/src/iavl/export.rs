@@ -0,0 +1,366 @@
+use std::cmp;
+
+use database::{Database, WriteBatch};
+
+use crate::{
+    merkle::{Sha256Hash, EMPTY_HASH},
+    Error,
+};
+
+use super::{node_db::NodeDB, InnerNode, LeafNode, Node, QueryTree, Tree};
+
+/// Max number of writes buffered while importing a tree
+const IMPORT_BATCH_SIZE: usize = 10_000;
+
+/// Node of a tree export. Nodes are exported in post-order, which allows to rebuild
+/// the exact same tree (and so the same root hash) from the export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedNode {
+    pub key: Vec<u8>,
+    /// Only leaf nodes have a value
+    pub value: Option<Vec<u8>>,
+    pub version: u32,
+    pub height: u8,
+}
+
+/// Iterator over the nodes of a saved version in post-order
+#[derive(Debug)]
+pub struct Exporter<'a, DB> {
+    // second element is true if the node's children were already pushed on the stack
+    stack: Vec<(Box<Node>, bool)>,
+    node_db: &'a NodeDB<DB>,
+}
+
+impl<'a, DB: Database> Iterator for Exporter<'a, DB> {
+    type Item = ExportedNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, expanded) = self.stack.pop()?;
+
+            match *node {
+                Node::Leaf(leaf) => {
+                    return Some(ExportedNode {
+                        key: leaf.key,
+                        value: Some(leaf.value),
+                        version: leaf.version,
+                        height: 0,
+                    })
+                }
+                Node::Inner(inner) if expanded => {
+                    return Some(ExportedNode {
+                        key: inner.key,
+                        value: None,
+                        version: inner.version,
+                        height: inner.height,
+                    })
+                }
+                Node::Inner(mut inner) => {
+                    let left_node = inner.left_node.take().unwrap_or_else(|| {
+                        self.node_db
+                            .get_node(&inner.left_hash)
+                            .expect("node db should contain all nodes")
+                    });
+                    let right_node = inner.right_node.take().unwrap_or_else(|| {
+                        self.node_db
+                            .get_node(&inner.right_hash)
+                            .expect("node db should contain all nodes")
+                    });
+
+                    self.stack.push((Box::new(Node::Inner(inner)), true));
+                    self.stack.push((right_node, false));
+                    self.stack.push((left_node, false));
+                }
+            }
+        }
+    }
+}
+
+impl<T: Database> Tree<T> {
+    /// Export all nodes of a saved version
+    pub fn export(&self, version: u32) -> Result<Exporter<'_, T>, Error> {
+        if !self.versions.contains(&version) {
+            return Err(Error::VersionNotFound(version));
+        }
+
+        let root = self.node_db.get_root_node(version)?;
+
+        Ok(Exporter {
+            stack: root.into_iter().map(|root| (root, false)).collect(),
+            node_db: &self.node_db,
+        })
+    }
+
+    /// Rebuild the tree from an export and save it as `version`.
+    /// Only a tree without saved versions can be imported into. Returns the root hash.
+    pub fn import(
+        &mut self,
+        version: u32,
+        nodes: impl IntoIterator<Item = ExportedNode>,
+    ) -> Result<Sha256Hash, Error> {
+        let mut importer = Importer::default();
+        for node in nodes {
+            importer.add(self, node)?;
+        }
+
+        importer.commit(self, version)
+    }
+}
+
+/// Rebuilds a tree from an export which is received in several parts. Only the nodes
+/// which are still waiting for their parent are kept in memory, the rest are written
+/// as soon as they're rebuilt.
+#[derive(Debug, Default)]
+pub struct Importer {
+    stack: Vec<(Node, Sha256Hash)>,
+    batch: WriteBatch,
+}
+
+impl Importer {
+    /// Add the next node of the export
+    pub fn add<T: Database>(
+        &mut self,
+        tree: &mut Tree<T>,
+        node: ExportedNode,
+    ) -> Result<(), Error> {
+        if !tree.versions.is_empty() {
+            return Err(Error::ImportIntoNonEmpty);
+        }
+
+        push_exported(&mut self.stack, node)?;
+        let (node, hash) = self.stack.last().expect("node was pushed above");
+        tree.node_db.save_node(node, hash, &mut self.batch);
+
+        if self.batch.len() >= IMPORT_BATCH_SIZE {
+            // Nodes aren't reachable until the root is saved, so writing them in
+            // several batches doesn't break atomicity of the import
+            tree.node_db.write(std::mem::take(&mut self.batch));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the root hash of the added nodes. Fails if they don't form a single tree.
+    pub fn root_hash(&self) -> Result<Sha256Hash, Error> {
+        stack_root_hash(&self.stack)
+    }
+
+    /// Save the imported tree as `version` and return its root hash
+    pub fn commit<T: Database>(
+        mut self,
+        tree: &mut Tree<T>,
+        version: u32,
+    ) -> Result<Sha256Hash, Error> {
+        if !tree.versions.is_empty() {
+            return Err(Error::ImportIntoNonEmpty);
+        }
+
+        let root_hash = self.root_hash()?;
+        tree.node_db
+            .save_version(version, &root_hash, &mut self.batch);
+        tree.node_db.write(self.batch);
+
+        tree.root = self.stack.pop().map(|(root, _)| Box::new(root));
+        tree.loaded_version = version;
+        tree.versions.insert(version);
+
+        Ok(root_hash)
+    }
+}
+
+impl<T: Database> QueryTree<T> {
+    /// Export all nodes of the version the tree was loaded at
+    pub fn export(&self) -> Exporter<'_, T> {
+        Exporter {
+            stack: self.root.iter().map(|root| (root.clone(), false)).collect(),
+            node_db: &self.node_db,
+        }
+    }
+}
+
+/// Returns the root hash of the tree which the exported nodes describe, without saving anything
+pub fn exported_root_hash<'a>(
+    nodes: impl IntoIterator<Item = &'a ExportedNode>,
+) -> Result<Sha256Hash, Error> {
+    let mut stack = Vec::new();
+    for node in nodes {
+        push_exported(&mut stack, node.clone())?;
+    }
+
+    stack_root_hash(&stack)
+}
+
+/// Rebuilds the next node of an export in post-order and pushes it on the `stack`, replacing
+/// the children of an inner node. Inner nodes only reference their children by hash.
+fn push_exported(
+    stack: &mut Vec<(Node, Sha256Hash)>,
+    ExportedNode {
+        key,
+        value,
+        version,
+        height,
+    }: ExportedNode,
+) -> Result<(), Error> {
+    let node = if height == 0 {
+        let value =
+            value.ok_or_else(|| Error::InvalidExport("leaf node without value".to_owned()))?;
+
+        Node::Leaf(LeafNode {
+            value,
+            key,
+            version,
+        })
+    } else {
+        let missing_child = || Error::InvalidExport("inner node without children".to_owned());
+        let (right_node, right_hash) = stack.pop().ok_or_else(missing_child)?;
+        let (left_node, left_hash) = stack.pop().ok_or_else(missing_child)?;
+
+        if height != 1 + cmp::max(left_node.get_height(), right_node.get_height()) {
+            return Err(Error::InvalidExport(format!(
+                "inner node height {height} doesn't match its children"
+            )));
+        }
+
+        Node::Inner(InnerNode {
+            left_node: None,
+            right_node: None,
+            height,
+            size: left_node.get_size() + right_node.get_size(),
+            left_hash,
+            right_hash,
+            key,
+            version,
+        })
+    };
+
+    let hash = node.hash();
+    stack.push((node, hash));
+
+    Ok(())
+}
+
+fn stack_root_hash(stack: &[(Node, Sha256Hash)]) -> Result<Sha256Hash, Error> {
+    match stack {
+        [] => Ok(EMPTY_HASH),
+        [(_, hash)] => Ok(*hash),
+        _ => Err(Error::InvalidExport("more than one root node".to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+
+    fn versioned_tree(versions: u8) -> Tree<MemDB> {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for version in 1..=versions {
+            tree.set(vec![0], vec![version]);
+            tree.set(vec![version], vec![version]);
+            tree.save_version().unwrap_test();
+        }
+
+        tree
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        let tree = versioned_tree(20);
+        let nodes: Vec<_> = tree.export(20).unwrap_test().collect();
+
+        // 21 leaves and 20 inner nodes
+        assert_eq!(nodes.len(), 41);
+        assert_eq!(exported_root_hash(&nodes).unwrap_test(), tree.root_hash());
+
+        let mut imported =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        let root_hash = imported.import(20, nodes).unwrap_test();
+
+        assert_eq!(root_hash, tree.root_hash());
+        assert_eq!(imported.loaded_version(), 20);
+        assert_eq!(
+            imported.range(..).collect::<Vec<_>>(),
+            tree.range(..).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn import_in_parts() {
+        let tree = versioned_tree(20);
+        let nodes: Vec<_> = tree.export(20).unwrap_test().collect();
+
+        let mut imported =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        let mut importer = Importer::default();
+        for part in nodes.chunks(7) {
+            for node in part {
+                importer.add(&mut imported, node.clone()).unwrap_test();
+            }
+
+            // nothing is visible until the import is committed
+            assert!(imported.range(..).next().is_none());
+        }
+
+        assert_eq!(importer.root_hash().unwrap_test(), tree.root_hash());
+        assert_eq!(
+            importer.commit(&mut imported, 20).unwrap_test(),
+            tree.root_hash()
+        );
+        assert_eq!(
+            imported.range(..).collect::<Vec<_>>(),
+            tree.range(..).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn export_old_version() {
+        let tree = versioned_tree(5);
+        let nodes: Vec<_> = tree.export(3).unwrap_test().collect();
+
+        let mut imported =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        imported.import(3, nodes).unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, 3).unwrap_test();
+        assert_eq!(
+            imported.range(..).collect::<Vec<_>>(),
+            query_tree.range(..).collect::<Vec<_>>()
+        );
+        assert!(tree.export(6).is_err());
+    }
+
+    #[test]
+    fn export_query_tree() {
+        let tree = versioned_tree(5);
+        let query_tree = QueryTree::new(&tree, 3).unwrap_test();
+
+        assert_eq!(
+            query_tree.export().collect::<Vec<_>>(),
+            tree.export(3).unwrap_test().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn import_empty_tree() {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+
+        assert_eq!(tree.import(7, vec![]).unwrap_test(), EMPTY_HASH);
+        assert_eq!(tree.loaded_version(), 7);
+        assert_eq!(tree.import(8, vec![]), Err(Error::ImportIntoNonEmpty));
+    }
+
+    #[test]
+    fn import_rejects_invalid_export() {
+        let tree = versioned_tree(3);
+        let mut nodes: Vec<_> = tree.export(3).unwrap_test().collect();
+        nodes.remove(0);
+
+        assert!(matches!(
+            exported_root_hash(&nodes),
+            Err(Error::InvalidExport(_))
+        ));
+    }
+}
@@ -1,8 +1,13 @@
+mod export;
 mod node_db;
+mod proof;
+mod pruning;
 mod query_tree;
 mod tree;
 #[allow(dead_code)]
 pub mod tree_v3;
 
+pub use export::*;
+pub use pruning::*;
 pub use query_tree::*;
 pub use tree::*;
@@ -4,11 +4,14 @@ use std::{
 };
 
 use caches::{Cache, DefaultHashBuilder, LRUCache};
-use database::Database;
+use database::{Database, WriteBatch};
 use extensions::corruption::UnwrapCorrupt;
 use integer_encoding::VarInt;
 
-use crate::{merkle::EMPTY_HASH, Error};
+use crate::{
+    merkle::{Sha256Hash, EMPTY_HASH, HASH_LENGTH},
+    Error,
+};
 
 use super::{CacheSize, Node};
 
@@ -20,8 +23,8 @@ pub struct NodeDB<T> {
 
 const ROOTS_PREFIX: [u8; 1] = [1];
 const NODES_PREFIX: [u8; 1] = [2];
+const ORPHANS_PREFIX: [u8; 1] = [3];
 
-// TODO: batch writes
 // TODO: fast nodes
 impl<T> NodeDB<T>
 where
@@ -70,6 +73,28 @@ where
         [NODES_PREFIX.to_vec(), hash.to_vec()].concat()
     }
 
+    /// Orphan keys are ordered by the last version the node was part of, so all
+    /// orphans of a version can be found with a prefix scan
+    fn get_orphan_key(to_version: u32, from_version: u32, hash: &Sha256Hash) -> Vec<u8> {
+        [
+            ORPHANS_PREFIX.to_vec(),
+            to_version.to_be_bytes().to_vec(),
+            from_version.to_be_bytes().to_vec(),
+            hash.to_vec(),
+        ]
+        .concat()
+    }
+
+    /// Returns the version the orphan was created at and its hash.
+    /// Only the key suffix is parsed since iterators may or may not strip the prefix.
+    fn parse_orphan_key(key: &[u8]) -> (u32, Sha256Hash) {
+        let suffix = &key[key.len() - HASH_LENGTH - 4..];
+        let from_version = u32::from_be_bytes(suffix[..4].try_into().unwrap_or_corrupt());
+        let hash = suffix[4..].try_into().unwrap_or_corrupt();
+
+        (from_version, hash)
+    }
+
     pub(crate) fn get_node(&self, hash: &[u8; 32]) -> Option<Box<Node>> {
         let cache = &mut self.cache.lock().expect("Lock will not be poisoned");
         let cache_node = cache.get(hash);
@@ -85,32 +110,32 @@ where
         Some(Box::new(node))
     }
 
-    fn save_node(&mut self, node: &Node, hash: &[u8; 32]) {
-        self.db.put(Self::get_node_key(hash), node.serialize());
+    pub(crate) fn save_node(&mut self, node: &Node, hash: &[u8; 32], batch: &mut WriteBatch) {
+        batch.put(Self::get_node_key(hash), node.serialize());
         self.cache
             .lock()
             .expect("Lock will not be poisoned")
             .put(*hash, node.shallow_clone());
     }
 
-    fn recursive_tree_save(&mut self, node: &Node, hash: &[u8; 32]) {
+    fn recursive_tree_save(&mut self, node: &Node, hash: &[u8; 32], batch: &mut WriteBatch) {
         if let Node::Inner(inner) = node {
             if let Some(left_node) = &inner.left_node {
-                self.recursive_tree_save(left_node, &inner.left_hash);
+                self.recursive_tree_save(left_node, &inner.left_hash, batch);
             }
             if let Some(right_node) = &inner.right_node {
-                self.recursive_tree_save(right_node, &inner.right_hash);
+                self.recursive_tree_save(right_node, &inner.right_hash, batch);
             }
         }
 
-        self.save_node(node, hash)
+        self.save_node(node, hash, batch)
     }
 
-    /// Saves the given node and all of its descendants.
+    /// Adds the given node and all of its descendants to the batch.
     /// Clears left_node/right_node on the root.
-    pub(crate) fn save_tree(&mut self, root: &mut Node) -> [u8; 32] {
+    pub(crate) fn save_tree(&mut self, root: &mut Node, batch: &mut WriteBatch) -> [u8; 32] {
         let root_hash = root.hash();
-        self.recursive_tree_save(root, &root_hash);
+        self.recursive_tree_save(root, &root_hash, batch);
 
         if let Node::Inner(inner) = root {
             inner.left_node = None;
@@ -120,9 +145,67 @@ where
         root_hash
     }
 
-    pub(crate) fn save_version(&mut self, version: u32, hash: &[u8; 32]) {
+    pub(crate) fn save_version(&mut self, version: u32, hash: &[u8; 32], batch: &mut WriteBatch) {
         let key = Self::get_root_key(version);
-        self.db.put(key, hash.to_vec());
+        batch.put(key, hash.to_vec());
+    }
+
+    /// Records nodes which were part of `to_version` but are not part of the version after it.
+    /// Each orphan is given as the version it was created at and its hash.
+    pub(crate) fn save_orphans(
+        &mut self,
+        to_version: u32,
+        orphans: &[(u32, Sha256Hash)],
+        batch: &mut WriteBatch,
+    ) {
+        for (from_version, hash) in orphans {
+            batch.put(
+                Self::get_orphan_key(to_version, *from_version, hash),
+                Vec::new(),
+            );
+        }
+    }
+
+    /// Atomically applies all writes collected in the batch
+    pub(crate) fn write(&mut self, batch: WriteBatch) {
+        self.db.write(batch)
+    }
+
+    /// Deletes the root of `version` and all nodes which are no longer part of any saved version.
+    /// `predecessor` is the greatest saved version lower than `version`, orphans which are still
+    /// part of it are handed over to it rather than being deleted.
+    pub(crate) fn delete_version(&mut self, version: u32, predecessor: Option<u32>) {
+        let prefix = [ORPHANS_PREFIX.to_vec(), version.to_be_bytes().to_vec()].concat();
+        let orphans: Vec<_> = self
+            .db
+            .prefix_iterator(prefix)
+            .map(|(key, _)| Self::parse_orphan_key(&key))
+            .collect();
+
+        let mut batch = WriteBatch::new();
+        let mut deleted_nodes = Vec::new();
+        for (from_version, hash) in orphans {
+            batch.delete(Self::get_orphan_key(version, from_version, &hash));
+
+            match predecessor {
+                Some(predecessor) if predecessor >= from_version => batch.put(
+                    Self::get_orphan_key(predecessor, from_version, &hash),
+                    Vec::new(),
+                ),
+                _ => {
+                    batch.delete(Self::get_node_key(&hash));
+                    deleted_nodes.push(hash);
+                }
+            }
+        }
+
+        batch.delete(Self::get_root_key(version));
+        self.db.write(batch);
+
+        let mut cache = self.cache.lock().expect("Lock will not be poisoned");
+        for hash in deleted_nodes {
+            cache.remove(&hash);
+        }
     }
 }
 
@@ -153,6 +236,15 @@ mod tests {
         )
     }
 
+    #[test]
+    fn orphan_key_round_trip() {
+        let hash = [7; 32];
+        let key = NodeDB::<MemDB>::get_orphan_key(5, 3, &hash);
+
+        assert_eq!(key[..5], [3, 0, 0, 0, 5]);
+        assert_eq!(NodeDB::<MemDB>::parse_orphan_key(&key), (3, hash));
+    }
+
     #[test]
     fn get_versions_works() {
         let db = MemDB::new();
@@ -0,0 +1,277 @@
+use std::borrow::Cow;
+
+use database::Database;
+use integer_encoding::VarInt;
+
+use crate::{
+    merkle::HASH_LENGTH,
+    proof::{CommitmentProof, ExistenceProof, InnerOp, LeafOp, NonExistenceProof},
+    Error,
+};
+
+use super::{node_db::NodeDB, InnerNode, Node, QueryTree, Tree};
+
+impl<DB: Database> QueryTree<DB> {
+    /// Returns an existence proof if `key` is in the tree, otherwise a non-existence proof
+    pub fn get_proof(&self, key: &[u8]) -> CommitmentProof {
+        get_proof(self.root.as_deref(), &self.node_db, key)
+    }
+}
+
+impl<DB: Database> Tree<DB> {
+    /// Returns an existence proof if `key` is in the tree at `version`, otherwise a
+    /// non-existence proof. A `version` of zero means the loaded version.
+    pub fn get_proof(&self, key: &[u8], version: u32) -> Result<CommitmentProof, Error> {
+        Ok(QueryTree::new(self, version)?.get_proof(key))
+    }
+}
+
+fn get_proof<DB: Database>(
+    root: Option<&Node>,
+    node_db: &NodeDB<DB>,
+    key: &[u8],
+) -> CommitmentProof {
+    let Some(root) = root else {
+        return CommitmentProof::Nonexist(NonExistenceProof {
+            key: key.to_vec(),
+            left: None,
+            right: None,
+        });
+    };
+
+    if let Some(proof) = existence_proof(root, node_db, key) {
+        return CommitmentProof::Exist(proof);
+    }
+
+    let (left, right) = neighbour_keys(root, node_db, key);
+
+    CommitmentProof::Nonexist(NonExistenceProof {
+        key: key.to_vec(),
+        left: left.and_then(|left| existence_proof(root, node_db, &left)),
+        right: right.and_then(|right| existence_proof(root, node_db, &right)),
+    })
+}
+
+fn child<'a, DB: Database>(node: &'a InnerNode, node_db: &NodeDB<DB>, left: bool) -> Cow<'a, Node> {
+    let (child, hash) = match left {
+        true => (&node.left_node, &node.left_hash),
+        false => (&node.right_node, &node.right_hash),
+    };
+
+    match child {
+        Some(child) => Cow::Borrowed(child),
+        None => Cow::Owned(
+            *node_db
+                .get_node(hash)
+                .expect("node db should contain all nodes"),
+        ),
+    }
+}
+
+/// Returns the proof of `key` or `None` if `key` isn't in the tree
+fn existence_proof<DB: Database>(
+    root: &Node,
+    node_db: &NodeDB<DB>,
+    key: &[u8],
+) -> Option<ExistenceProof> {
+    let mut path = Vec::new();
+    let mut node = Cow::Borrowed(root);
+
+    loop {
+        let next = match node.as_ref() {
+            Node::Leaf(leaf) => {
+                if leaf.key != key {
+                    return None;
+                }
+
+                // NOTE: i64 is used here for parameters for compatibility wih cosmos
+                let mut prefix = 0_i64.encode_var_vec();
+                prefix.extend(1_i64.encode_var_vec());
+                prefix.extend(i64::from(leaf.version).encode_var_vec());
+
+                path.reverse();
+
+                return Some(ExistenceProof {
+                    key: leaf.key.clone(),
+                    value: leaf.value.clone(),
+                    leaf: LeafOp { prefix },
+                    path,
+                });
+            }
+            Node::Inner(inner) => {
+                let mut prefix = i64::from(inner.height).encode_var_vec();
+                prefix.extend(i64::from(inner.size).encode_var_vec());
+                prefix.extend(i64::from(inner.version).encode_var_vec());
+
+                let hash_length = HASH_LENGTH.encode_var_vec();
+                let go_left = key < inner.key.as_slice();
+
+                path.push(match go_left {
+                    true => {
+                        prefix.extend(&hash_length);
+                        InnerOp {
+                            prefix,
+                            suffix: [hash_length.as_slice(), &inner.right_hash].concat(),
+                        }
+                    }
+                    false => {
+                        prefix.extend(&hash_length);
+                        prefix.extend(inner.left_hash);
+                        prefix.extend(&hash_length);
+                        InnerOp {
+                            prefix,
+                            suffix: Vec::new(),
+                        }
+                    }
+                });
+
+                child(inner, node_db, go_left).into_owned()
+            }
+        };
+
+        node = Cow::Owned(next);
+    }
+}
+
+/// Returns the greatest key less than `key` and the smallest key greater than `key`
+fn neighbour_keys<DB: Database>(
+    root: &Node,
+    node_db: &NodeDB<DB>,
+    key: &[u8],
+) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+    // left child of the deepest node where the search went right
+    let mut last_right_turn: Option<Node> = None;
+    // key of the deepest node where the search went left
+    let mut last_left_turn: Option<Vec<u8>> = None;
+    let mut node = Cow::Borrowed(root);
+
+    let leaf_key = loop {
+        let next = match node.as_ref() {
+            Node::Leaf(leaf) => break leaf.key.clone(),
+            Node::Inner(inner) => {
+                let go_left = key < inner.key.as_slice();
+
+                if go_left {
+                    // the key of an inner node is the smallest key of its right subtree
+                    last_left_turn = Some(inner.key.clone());
+                } else {
+                    last_right_turn = Some(child(inner, node_db, true).into_owned());
+                }
+
+                child(inner, node_db, go_left).into_owned()
+            }
+        };
+
+        node = Cow::Owned(next);
+    };
+
+    let left = match leaf_key.as_slice() < key {
+        true => Some(leaf_key.clone()),
+        false => last_right_turn.map(|node| rightmost_leaf_key(&node, node_db)),
+    };
+
+    let right = match leaf_key.as_slice() > key {
+        true => Some(leaf_key),
+        false => last_left_turn,
+    };
+
+    (left, right)
+}
+
+fn rightmost_leaf_key<DB: Database>(node: &Node, node_db: &NodeDB<DB>) -> Vec<u8> {
+    let mut node = Cow::Borrowed(node);
+
+    loop {
+        let next = match node.as_ref() {
+            Node::Leaf(leaf) => return leaf.key.clone(),
+            Node::Inner(inner) => child(inner, node_db, false).into_owned(),
+        };
+
+        node = Cow::Owned(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    fn tree_with_keys(keys: impl IntoIterator<Item = u8>) -> Tree<MemDB> {
+        let mut tree =
+            Tree::new(MemDB::new(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for key in keys {
+            tree.set(vec![key], vec![key, key]);
+        }
+        tree.save_version().unwrap_test();
+
+        tree
+    }
+
+    #[test]
+    fn existence_proof_verifies() {
+        let tree = tree_with_keys((0..50).map(|i| i * 2));
+        let root = tree.root_hash();
+
+        for key in (0..50).map(|i| i * 2) {
+            match tree.get_proof(&[key], 1).unwrap_test() {
+                CommitmentProof::Exist(proof) => {
+                    assert!(proof.verify(&root, &[key], &[key, key]))
+                }
+                CommitmentProof::Nonexist(_) => panic!("key {key} should exist"),
+            }
+        }
+    }
+
+    #[test]
+    fn non_existence_proof_verifies() {
+        let keys = (1..50).map(|i| i * 2).collect::<Vec<u8>>();
+        let tree = tree_with_keys(keys.clone());
+        let root = tree.root_hash();
+
+        for key in (0..=50).map(|i| i * 2 + 1).chain([0, 200]) {
+            match tree.get_proof(&[key], 1).unwrap_test() {
+                CommitmentProof::Nonexist(proof) => {
+                    assert!(proof.verify(&root, &[key]));
+
+                    let expected_left = keys.iter().filter(|k| **k < key).max();
+                    let expected_right = keys.iter().filter(|k| **k > key).min();
+                    assert_eq!(proof.left.map(|left| left.key[0]).as_ref(), expected_left);
+                    assert_eq!(
+                        proof.right.map(|right| right.key[0]).as_ref(),
+                        expected_right
+                    );
+                }
+                CommitmentProof::Exist(_) => panic!("key {key} shouldn't exist"),
+            }
+        }
+    }
+
+    #[test]
+    fn proof_of_old_version() {
+        let mut tree = tree_with_keys([1, 2, 3]);
+        let root = tree.root_hash();
+        tree.set(vec![4], vec![4]);
+        tree.save_version().unwrap_test();
+
+        match tree.get_proof(&[4], 1).unwrap_test() {
+            CommitmentProof::Nonexist(proof) => assert!(proof.verify(&root, &[4])),
+            CommitmentProof::Exist(_) => panic!("key shouldn't exist at version 1"),
+        }
+    }
+
+    #[test]
+    fn empty_tree_proof() {
+        let tree = tree_with_keys([]);
+
+        assert_eq!(
+            tree.get_proof(&[1], 1).unwrap_test(),
+            CommitmentProof::Nonexist(NonExistenceProof {
+                key: vec![1],
+                left: None,
+                right: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::BTreeSet;
+
+/// Number of recent versions kept by the default strategy, ~3 weeks of 5 second blocks.
+pub const DEFAULT_KEEP_RECENT: u32 = 362_880;
+/// Number of blocks between two pruning runs for the default and "everything" strategies.
+pub const DEFAULT_PRUNING_INTERVAL: u32 = 10;
+
+/// Defines which of the saved versions are deleted from the database and when.
+///
+/// A version is retained if it is one of the `keep_recent` versions preceding the latest one,
+/// or if it is a multiple of `keep_every`. The latest version is never deleted. Pruning runs
+/// every `interval` versions, an `interval` of zero disables pruning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PruningOptions {
+    keep_recent: u32,
+    keep_every: u32,
+    interval: u32,
+}
+
+impl PruningOptions {
+    pub const fn new(keep_recent: u32, keep_every: u32, interval: u32) -> Self {
+        Self {
+            keep_recent,
+            keep_every,
+            interval,
+        }
+    }
+
+    /// Keep every version, i.e. an archive node
+    pub const fn nothing() -> Self {
+        Self::new(0, 1, 0)
+    }
+
+    /// Keep only the latest two versions
+    pub const fn everything() -> Self {
+        Self::new(2, 0, DEFAULT_PRUNING_INTERVAL)
+    }
+
+    pub fn keep_recent(&self) -> u32 {
+        self.keep_recent
+    }
+
+    pub fn keep_every(&self) -> u32 {
+        self.keep_every
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns true if no version will ever be deleted with these options
+    pub fn is_nothing(&self) -> bool {
+        self.interval == 0 || self.keep_every == 1
+    }
+
+    /// Returns true if pruning should run after `version` was saved
+    pub fn should_prune(&self, version: u32) -> bool {
+        !self.is_nothing() && version.is_multiple_of(self.interval)
+    }
+
+    /// Returns true if `version` must be retained when `latest` is the latest saved version
+    pub fn retain(&self, version: u32, latest: u32) -> bool {
+        version >= latest
+            || latest - version <= self.keep_recent
+            || (self.keep_every != 0 && version.is_multiple_of(self.keep_every))
+    }
+
+    /// Returns the versions which should be deleted when `latest` is the latest saved version
+    pub fn versions_to_prune(&self, latest: u32, versions: &BTreeSet<u32>) -> Vec<u32> {
+        if self.is_nothing() {
+            return Vec::new();
+        }
+
+        versions
+            .range(..latest)
+            .copied()
+            .filter(|version| !self.retain(*version, latest))
+            .collect()
+    }
+}
+
+impl Default for PruningOptions {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEEP_RECENT, 0, DEFAULT_PRUNING_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_to_prune_keep_recent() {
+        let options = PruningOptions::new(2, 0, 1);
+        let versions = (1..=6).collect();
+
+        assert_eq!(options.versions_to_prune(6, &versions), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn versions_to_prune_keep_every() {
+        let options = PruningOptions::new(1, 3, 1);
+        let versions = (1..=8).collect();
+
+        assert_eq!(options.versions_to_prune(8, &versions), vec![1, 2, 4, 5]);
+    }
+
+    #[test]
+    fn versions_to_prune_nothing() {
+        let versions = (1..=100).collect();
+
+        assert!(PruningOptions::nothing()
+            .versions_to_prune(100, &versions)
+            .is_empty());
+    }
+
+    #[test]
+    fn should_prune_respects_interval() {
+        let options = PruningOptions::everything();
+
+        assert!(!options.should_prune(9));
+        assert!(options.should_prune(10));
+        assert!(!PruningOptions::nothing().should_prune(10));
+    }
+}
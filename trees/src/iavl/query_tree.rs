@@ -2,7 +2,7 @@ use std::ops::RangeBounds;
 
 use database::Database;
 
-use crate::Error;
+use crate::{merkle::EMPTY_HASH, Error};
 
 use super::{node_db::NodeDB, Node, Range, Tree};
 
@@ -36,6 +36,13 @@ impl<DB: Database> QueryTree<DB> {
 }
 
 impl<DB: Database> QueryTree<DB> {
+    pub fn root_hash(&self) -> [u8; 32] {
+        match &self.root {
+            Some(root) => root.hash(),
+            None => EMPTY_HASH,
+        }
+    }
+
     pub fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
         match &self.root {
             Some(root) => self.get_(key, root),
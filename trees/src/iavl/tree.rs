@@ -1,11 +1,11 @@
 use std::{
     cmp::{self, Ordering},
-    collections::BTreeSet,
+    collections::{BTreeSet, HashSet},
     mem,
     ops::{Bound, RangeBounds},
 };
 
-use database::Database;
+use database::{Database, WriteBatch};
 use extensions::corruption::UnwrapCorrupt;
 use integer_encoding::VarInt;
 use nutype::nutype;
@@ -17,7 +17,7 @@ use crate::{
     Error,
 };
 
-use super::node_db::NodeDB;
+use super::{node_db::NodeDB, PruningOptions};
 
 #[derive(Debug, Clone, PartialEq, Hash, Default)]
 pub(crate) struct InnerNode {
@@ -25,12 +25,12 @@ pub(crate) struct InnerNode {
     // Sha256Hash to avoid needing to calculate hashes until save_version is called
     pub(crate) left_node: Option<Box<Node>>, // None means value is the same as what's in the DB
     pub(crate) right_node: Option<Box<Node>>,
-    height: u8,
-    size: u32, // number of leaf nodes in this node's subtrees
+    pub(crate) height: u8,
+    pub(crate) size: u32, // number of leaf nodes in this node's subtrees
     pub(crate) left_hash: Sha256Hash,
     pub(crate) right_hash: Sha256Hash,
     pub(crate) key: Vec<u8>,
-    version: u32,
+    pub(crate) version: u32,
 }
 
 impl InnerNode {
@@ -139,7 +139,7 @@ impl InnerNode {
 pub(crate) struct LeafNode {
     pub(crate) value: Vec<u8>,
     pub(crate) key: Vec<u8>,
-    version: u32,
+    pub(crate) version: u32,
 }
 
 impl LeafNode {
@@ -498,18 +498,25 @@ impl Node {
         }
     }
 
-    fn get_size(&self) -> u32 {
+    pub(crate) fn get_size(&self) -> u32 {
         match &self {
             Node::Leaf(_) => 1,
             Node::Inner(n) => n.size,
         }
     }
+
+    pub(crate) fn get_version(&self) -> u32 {
+        match &self {
+            Node::Leaf(leaf) => leaf.version,
+            Node::Inner(inner) => inner.version,
+        }
+    }
 }
 
 // TODO: rename loaded_version to head_version introduce a working_version (+ remove redundant loaded_version?). this will allow the first committed version to be version 0 rather than 1 (there is no version 0 currently!)
 #[derive(Debug)]
 pub struct Tree<T> {
-    root: Option<Box<Node>>,
+    pub(crate) root: Option<Box<Node>>,
     pub(crate) node_db: NodeDB<T>,
     pub(crate) loaded_version: u32,
     pub(crate) versions: BTreeSet<u32>,
@@ -594,15 +601,22 @@ where
             return Err(Error::Overwrite);
         }
 
+        // All writes of a version are committed in a single batch, so a crash can't leave a
+        // partially written version behind
+        let mut batch = WriteBatch::new();
+
+        let orphans = self.orphans(version);
+        self.node_db
+            .save_orphans(self.loaded_version, &orphans, &mut batch);
+
         let root = self.root.as_mut();
         let root_hash = if let Some(root) = root {
-            let root_hash = self.node_db.save_tree(root);
-            self.node_db.save_version(version, &root_hash);
-            root_hash
+            self.node_db.save_tree(root, &mut batch)
         } else {
-            self.node_db.save_version(version, &EMPTY_HASH);
             EMPTY_HASH
         };
+        self.node_db.save_version(version, &root_hash, &mut batch);
+        self.node_db.write(batch);
 
         self.versions.insert(version);
 
@@ -610,6 +624,117 @@ where
         Ok((root_hash, self.loaded_version))
     }
 
+    /// Returns the nodes of the loaded version which are not part of the working tree,
+    /// as pairs of the version the node was created at and its hash.
+    /// `version` is the version the working tree will be saved as.
+    fn orphans(&self, version: u32) -> Vec<(u32, Sha256Hash)> {
+        if !self.versions.contains(&self.loaded_version) {
+            return Vec::new();
+        }
+
+        // Collect the roots of all persisted subtrees which are shared with the working tree.
+        // Nodes created in the working tree are the only ones which carry the new version.
+        let mut retained = HashSet::new();
+        let mut pending: Vec<&Node> = self.root.iter().map(|root| &**root).collect();
+        while let Some(node) = pending.pop() {
+            if node.get_version() < version {
+                retained.insert(node.hash());
+                continue;
+            }
+
+            if let Node::Inner(inner) = node {
+                match &inner.left_node {
+                    Some(left_node) => pending.push(left_node),
+                    None => {
+                        retained.insert(inner.left_hash);
+                    }
+                }
+                match &inner.right_node {
+                    Some(right_node) => pending.push(right_node),
+                    None => {
+                        retained.insert(inner.right_hash);
+                    }
+                }
+            }
+        }
+
+        let old_root_hash = self
+            .node_db
+            .get_root_hash(self.loaded_version)
+            .ok()
+            .unwrap_or_corrupt();
+        if old_root_hash == EMPTY_HASH {
+            return Vec::new();
+        }
+
+        let mut orphans = Vec::new();
+        let mut pending = vec![old_root_hash];
+        while let Some(hash) = pending.pop() {
+            if retained.contains(&hash) {
+                continue;
+            }
+
+            let node = self
+                .node_db
+                .get_node(&hash)
+                .expect("node db should contain all nodes");
+            orphans.push((node.get_version(), hash));
+
+            if let Node::Inner(inner) = *node {
+                pending.push(inner.left_hash);
+                pending.push(inner.right_hash);
+            }
+        }
+
+        orphans
+    }
+
+    /// Deletes a saved version and all nodes which are not part of any other saved version.
+    /// Neither the latest saved version nor the loaded version can be deleted.
+    pub fn delete_version(&mut self, version: u32) -> Result<(), Error> {
+        if !self.versions.contains(&version) {
+            return Err(Error::VersionNotFound(version));
+        }
+
+        if version == self.loaded_version || Some(&version) == self.versions.last() {
+            return Err(Error::DeleteActiveVersion(version));
+        }
+
+        let predecessor = self.versions.range(..version).next_back().copied();
+        self.node_db.delete_version(version, predecessor);
+        self.versions.remove(&version);
+
+        Ok(())
+    }
+
+    /// Deletes all versions which shouldn't be retained according to the pruning options.
+    /// Returns the deleted versions.
+    pub fn prune(&mut self, options: &PruningOptions) -> Result<Vec<u32>, Error> {
+        let versions = options.versions_to_prune(self.loaded_version, &self.versions);
+
+        for version in &versions {
+            self.delete_version(*version)?;
+        }
+
+        Ok(versions)
+    }
+
+    /// Sets the version which the first saved version follows, so a tree added to an existing
+    /// multi store saves the same versions as the other trees.
+    /// Returns an error if the tree already has saved versions.
+    pub fn set_initial_version(&mut self, version: u32) -> Result<(), Error> {
+        if !self.versions.is_empty() {
+            return Err(Error::InitialVersionOfNonEmpty);
+        }
+
+        self.loaded_version = version;
+        Ok(())
+    }
+
+    pub fn versions(&self) -> &BTreeSet<u32> {
+        &self.versions
+    }
+
     pub fn root_hash(&self) -> [u8; 32] {
         match &self.root {
             Some(root) => root.hash(),
@@ -1078,6 +1203,7 @@ mod tests {
     use std::vec;
 
     use super::*;
+    use crate::iavl::QueryTree;
     use cmp::max;
     use database::MemDB;
     use extensions::testing::UnwrapTesting;
@@ -1354,6 +1480,21 @@ mod tests {
         assert_eq!(hash, expected)
     }
 
+    #[test]
+    fn set_initial_version_works() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set_initial_version(10).unwrap_test();
+        tree.set(vec![1], vec![2]);
+
+        let (_, version) = tree.save_version().unwrap_test();
+        assert_eq!(version, 11);
+        assert_eq!(
+            tree.set_initial_version(20),
+            Err(Error::InitialVersionOfNonEmpty)
+        );
+    }
+
     #[test]
     fn set_equal_leaf_works() {
         let db = MemDB::new();
@@ -1441,6 +1582,65 @@ mod tests {
         assert_eq!(expected, tree.root_hash());
     }
 
+    fn versioned_tree(db: MemDB, versions: u8) -> Tree<MemDB> {
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for version in 1..=versions {
+            tree.set(vec![0], vec![version]);
+            tree.set(vec![version], vec![version]);
+            tree.save_version().unwrap_test();
+        }
+
+        tree
+    }
+
+    #[test]
+    fn prune_deletes_orphaned_nodes() {
+        let db = MemDB::new();
+        let mut tree = versioned_tree(db.clone(), 10);
+
+        let pruned = tree.prune(&PruningOptions::new(0, 0, 1)).unwrap_test();
+
+        assert_eq!(pruned, (1..10).collect::<Vec<_>>());
+        assert_eq!(
+            tree.versions().iter().copied().collect::<Vec<_>>(),
+            vec![10]
+        );
+        // 11 leaves and 10 inner nodes of the latest version are all that's left
+        assert_eq!(db.prefix_iterator(vec![2]).count(), 21);
+        assert_eq!(db.prefix_iterator(vec![3]).count(), 0);
+        for key in 0..=10 {
+            let expected = if key == 0 { 10 } else { key };
+            assert_eq!(tree.get(&[key]), Some(vec![expected]));
+        }
+    }
+
+    #[test]
+    fn prune_keeps_retained_versions_readable() {
+        let db = MemDB::new();
+        let mut tree = versioned_tree(db, 10);
+
+        let pruned = tree.prune(&PruningOptions::new(1, 5, 1)).unwrap_test();
+        assert_eq!(pruned, vec![1, 2, 3, 4, 6, 7, 8]);
+
+        let query_tree = QueryTree::new(&tree, 5).unwrap_test();
+        assert_eq!(query_tree.get(&[0]), Some(vec![5]));
+        for key in 1..=10 {
+            let expected = if key <= 5 { Some(vec![key]) } else { None };
+            assert_eq!(query_tree.get(&[key]), expected);
+        }
+
+        assert!(QueryTree::new(&tree, 4).is_err());
+    }
+
+    #[test]
+    fn delete_version_rejects_latest() {
+        let mut tree = versioned_tree(MemDB::new(), 3);
+
+        assert_eq!(tree.delete_version(3), Err(Error::DeleteActiveVersion(3)));
+        assert_eq!(tree.delete_version(4), Err(Error::VersionNotFound(4)));
+        assert_eq!(tree.delete_version(2), Ok(()));
+    }
+
     #[test]
     fn get_works() {
         let db = MemDB::new();
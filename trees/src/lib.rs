@@ -3,5 +3,6 @@
 mod error;
 pub mod iavl;
 pub mod merkle;
+pub mod proof;
 
 pub use error::Error;
@@ -1,5 +1,7 @@
 use sha2::{Digest, Sha256};
 
+use crate::proof::InnerOp;
+
 const LEAF_PREFIX: [u8; 1] = [0];
 const INNER_PREFIX: [u8; 1] = [1];
 pub const EMPTY_HASH: [u8; 32] = [
@@ -24,6 +26,35 @@ pub fn root_hash(items: &[Vec<u8>]) -> [u8; 32] {
     }
 }
 
+/// Returns the inner operations which hash the item at `index` up to the root,
+/// ordered from the leaf to the root. Returns `None` if `index` is out of bounds.
+pub fn proof_path(items: &[Vec<u8>], index: usize) -> Option<Vec<InnerOp>> {
+    if index >= items.len() {
+        return None;
+    }
+
+    if items.len() == 1 {
+        return Some(Vec::new());
+    }
+
+    let k = get_split_point(items.len());
+    if index < k {
+        let mut path = proof_path(&items[..k], index)?;
+        path.push(InnerOp {
+            prefix: INNER_PREFIX.to_vec(),
+            suffix: root_hash(&items[k..]).to_vec(),
+        });
+        Some(path)
+    } else {
+        let mut path = proof_path(&items[k..], index - k)?;
+        path.push(InnerOp {
+            prefix: [&INNER_PREFIX[..], &root_hash(&items[..k])].concat(),
+            suffix: Vec::new(),
+        });
+        Some(path)
+    }
+}
+
 /// Returns sha256(0x00 || leaf)
 fn leaf_hash(leaf: &[u8]) -> [u8; 32] {
     Sha256::digest([&LEAF_PREFIX, leaf].concat()).into()
@@ -52,6 +83,8 @@ fn get_split_point(length: usize) -> usize {
 #[cfg(test)]
 mod tests {
 
+    use extensions::testing::UnwrapTesting;
+
     use super::*;
 
     #[test]
@@ -87,6 +120,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn proof_path_works() {
+        let items = [vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8], vec![9, 10]];
+        let root = root_hash(&items);
+
+        for (index, item) in items.iter().enumerate() {
+            let path = proof_path(&items, index).unwrap_test();
+            let hash = path
+                .iter()
+                .fold(leaf_hash(item), |hash, op| op.apply(&hash));
+
+            assert_eq!(hash, root);
+        }
+
+        assert!(proof_path(&items, 5).is_none());
+    }
+
     #[test]
     fn leaf_hash_works() {
         assert_eq!(
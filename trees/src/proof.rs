@@ -0,0 +1,106 @@
+//! ICS-23 compatible commitment proofs. All operations use sha256, values are
+//! prehashed with sha256 and lengths are encoded as protobuf varints, which is
+//! the common part of the ICS-23 IAVL and Tendermint proof specs.
+
+use integer_encoding::VarInt;
+use sha2::{Digest, Sha256};
+
+use crate::merkle::Sha256Hash;
+
+/// Hashes the leaf as `sha256(prefix || len(key) || key || len(sha256(value)) || sha256(value))`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeafOp {
+    pub prefix: Vec<u8>,
+}
+
+impl LeafOp {
+    pub fn apply(&self, key: &[u8], value: &[u8]) -> Sha256Hash {
+        let hashed_value = Sha256::digest(value);
+
+        let mut hasher = Sha256::new();
+        hasher.update(&self.prefix);
+        hasher.update(key.len().encode_var_vec());
+        hasher.update(key);
+        hasher.update(hashed_value.len().encode_var_vec());
+        hasher.update(hashed_value);
+
+        hasher.finalize().into()
+    }
+}
+
+/// Hashes the child as `sha256(prefix || child || suffix)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InnerOp {
+    pub prefix: Vec<u8>,
+    pub suffix: Vec<u8>,
+}
+
+impl InnerOp {
+    pub fn apply(&self, child: &[u8]) -> Sha256Hash {
+        let mut hasher = Sha256::new();
+        hasher.update(&self.prefix);
+        hasher.update(child);
+        hasher.update(&self.suffix);
+
+        hasher.finalize().into()
+    }
+}
+
+/// Proves that `key` is mapped to `value`. The path is ordered from the leaf to the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExistenceProof {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub leaf: LeafOp,
+    pub path: Vec<InnerOp>,
+}
+
+impl ExistenceProof {
+    pub fn calculate_root(&self) -> Sha256Hash {
+        self.path
+            .iter()
+            .fold(self.leaf.apply(&self.key, &self.value), |hash, op| {
+                op.apply(&hash)
+            })
+    }
+
+    pub fn verify(&self, root: &Sha256Hash, key: &[u8], value: &[u8]) -> bool {
+        self.key == key && self.value == value && &self.calculate_root() == root
+    }
+}
+
+/// Proves that `key` is absent by proving the existence of its neighbours.
+/// A missing neighbour means that `key` is outside of the range of the tree keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonExistenceProof {
+    pub key: Vec<u8>,
+    pub left: Option<ExistenceProof>,
+    pub right: Option<ExistenceProof>,
+}
+
+impl NonExistenceProof {
+    /// Checks that both neighbours exist in the tree and that `key` lies between them.
+    /// Adjacency of the neighbours isn't checked.
+    pub fn verify(&self, root: &Sha256Hash, key: &[u8]) -> bool {
+        if self.key != key || (self.left.is_none() && self.right.is_none()) {
+            return false;
+        }
+
+        let left_valid = self
+            .left
+            .as_ref()
+            .is_none_or(|left| left.key.as_slice() < key && &left.calculate_root() == root);
+        let right_valid = self
+            .right
+            .as_ref()
+            .is_none_or(|right| right.key.as_slice() > key && &right.calculate_root() == root);
+
+        left_valid && right_valid
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitmentProof {
+    Exist(ExistenceProof),
+    Nonexist(NonExistenceProof),
+}
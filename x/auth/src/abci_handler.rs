@@ -1,5 +1,6 @@
 use gears::application::handlers::node::{ABCIHandler, TxError};
 use gears::baseapp::errors::QueryError;
+use gears::baseapp::mode::RunTxMode;
 use gears::baseapp::QueryRequest;
 use gears::context::init::InitContext;
 use gears::context::query::QueryContext;
@@ -89,7 +90,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
         &self,
         _: &mut TxContext<'_, DB, Self::StoreKey>,
         _: &TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -112,6 +113,14 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
         Vec::new()
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        _for_zero_height: bool,
+    ) -> Self::Genesis {
+        self.keeper.export_genesis(ctx)
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -1,4 +1,4 @@
-use crate::{AuthParamsKeeper, AuthsParams};
+use crate::{AuthParamsKeeper, AuthsParams, GenesisState};
 
 use bytes::Bytes;
 use gears::context::init::InitContext;
@@ -162,6 +162,15 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> Keeper<SK, PSK, M> {
             .unwrap_gas();
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let (_, accounts) = self.accounts(ctx, None);
+
+        GenesisState {
+            accounts,
+            params: self.auth_params_keeper.get(ctx),
+        }
+    }
+
     pub fn accounts<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -0,0 +1,376 @@
+use crate::{
+    errors::AuthzTxError,
+    message::Message,
+    types::query::{
+        QueryGranteeGrantsRequest, QueryGranteeGrantsResponse, QueryGranterGrantsRequest,
+        QueryGranterGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+    },
+    GenesisState, Keeper, MsgExec,
+};
+use gears::{
+    application::handlers::node::TxError,
+    baseapp::{errors::QueryError, QueryResponse},
+    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
+    core::Protobuf,
+    store::{database::Database, StoreKey},
+    tendermint::types::request::query::RequestQuery,
+    types::tx::TxMessage,
+    x::{keepers::auth::AuthKeeper, module::Module},
+};
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub enum AuthzNodeQueryRequest {
+    Grants(QueryGrantsRequest),
+    GranterGrants(QueryGranterGrantsRequest),
+    GranteeGrants(QueryGranteeGrantsRequest),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum AuthzNodeQueryResponse {
+    Grants(QueryGrantsResponse),
+    GranterGrants(QueryGranterGrantsResponse),
+    GranteeGrants(QueryGranteeGrantsResponse),
+}
+
+#[derive(Debug, Clone)]
+pub struct ABCIHandler<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> {
+    keeper: Keeper<SK, AK, M>,
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> ABCIHandler<SK, AK, M> {
+    pub fn new(keeper: Keeper<SK, AK, M>) -> Self {
+        ABCIHandler { keeper }
+    }
+
+    pub fn genesis<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>, genesis: GenesisState) {
+        self.keeper.init_genesis(ctx, genesis)
+    }
+
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        self.keeper.export_genesis(ctx)
+    }
+
+    /// end_block prunes the grants which expired before the current block time
+    pub fn end_block<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) {
+        self.keeper.remove_expired_grants(ctx)
+    }
+
+    /// The messages of a `MsgExec` are decoded into the application messages `MSG` and
+    /// executed one by one by `handler`, which should route them the same way as the
+    /// messages of a transaction.
+    pub fn tx<'a, DB: Database + Sync + Send, MSG: TxMessage>(
+        &self,
+        ctx: &mut TxContext<'a, DB, SK>,
+        msg: &Message,
+        mut handler: impl FnMut(&mut TxContext<'a, DB, SK>, &MSG) -> Result<(), TxError>,
+    ) -> Result<(), AuthzTxError> {
+        match msg {
+            Message::Grant(msg) => self.keeper.grant(ctx, msg),
+            Message::Revoke(msg) => self.keeper.revoke(ctx, msg),
+            Message::Exec(MsgExec { grantee, msgs }) => {
+                for msg in msgs {
+                    let msg = MSG::try_from(msg.clone())?;
+
+                    self.keeper.dispatch_action(ctx, grantee, &msg)?;
+                    handler(ctx, &msg).map_err(AuthzTxError::Exec)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    pub fn query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: RequestQuery,
+    ) -> Result<prost::bytes::Bytes, QueryError> {
+        match query.path.as_str() {
+            "/cosmos.authz.v1beta1.Query/Grants" => {
+                let req = QueryGrantsRequest::decode_vec(&query.data)?;
+
+                Ok(self.keeper.query_grants(ctx, req).into_bytes().into())
+            }
+            "/cosmos.authz.v1beta1.Query/GranterGrants" => {
+                let req = QueryGranterGrantsRequest::decode_vec(&query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_granter_grants(ctx, req)
+                    .into_bytes()
+                    .into())
+            }
+            "/cosmos.authz.v1beta1.Query/GranteeGrants" => {
+                let req = QueryGranteeGrantsRequest::decode_vec(&query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_grantee_grants(ctx, req)
+                    .into_bytes()
+                    .into())
+            }
+            _ => Err(QueryError::PathNotFound),
+        }
+    }
+
+    pub fn typed_query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: AuthzNodeQueryRequest,
+    ) -> AuthzNodeQueryResponse {
+        match query {
+            AuthzNodeQueryRequest::Grants(req) => {
+                AuthzNodeQueryResponse::Grants(self.keeper.query_grants(ctx, req))
+            }
+            AuthzNodeQueryRequest::GranterGrants(req) => {
+                AuthzNodeQueryResponse::GranterGrants(self.keeper.query_granter_grants(ctx, req))
+            }
+            AuthzNodeQueryRequest::GranteeGrants(req) => {
+                AuthzNodeQueryResponse::GranteeGrants(self.keeper.query_grantee_grants(ctx, req))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        derive::StoreKeys,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::TransactionMultiBank, database::MemDB},
+        tendermint::types::{proto::header::Header, time::timestamp::Timestamp},
+        types::{
+            address::AccAddress,
+            base::coins::UnsignedCoins,
+            gas::{kind::TxKind, GasMeter},
+            msg::send::MsgSend,
+        },
+        utils::node::build_store,
+        x::keepers::mocks::auth::MockAuthKeeper,
+    };
+
+    use super::*;
+    use crate::{
+        errors::AuthorizationError, Authorization, Grant, GrantAuthorization, MsgGrant,
+        SendAuthorization,
+    };
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+    #[skey(params = Params)]
+    enum SpaceKey {
+        #[skey(to_string = "authz")]
+        Authz,
+        #[skey(to_string = "params")]
+        Params,
+    }
+
+    #[derive(Debug, Clone)]
+    struct AuthzModule;
+
+    impl Module for AuthzModule {
+        fn name(&self) -> String {
+            "authz".to_owned()
+        }
+    }
+
+    type TestHandler = ABCIHandler<SpaceKey, MockAuthKeeper, AuthzModule>;
+
+    /// Time of the block in which the grants are given
+    const GRANT_TIME: i64 = 1000;
+
+    fn handler() -> TestHandler {
+        ABCIHandler::new(Keeper::new(
+            SpaceKey::Authz,
+            MockAuthKeeper::former().has_account(true).form(),
+        ))
+    }
+
+    fn tx_store() -> TransactionMultiBank<MemDB, SpaceKey> {
+        build_store().to_tx_kind()
+    }
+
+    fn granter() -> AccAddress {
+        AccAddress::try_from(vec![1; 20]).unwrap_test()
+    }
+
+    fn grantee() -> AccAddress {
+        AccAddress::try_from(vec![2; 20]).unwrap_test()
+    }
+
+    fn coins(amount: u64) -> UnsignedCoins {
+        UnsignedCoins::new(vec![format!("{amount}uatom").parse().unwrap_test()]).unwrap_test()
+    }
+
+    fn send(amount: u64) -> MsgSend {
+        MsgSend {
+            from_address: granter(),
+            to_address: AccAddress::try_from(vec![3; 20]).unwrap_test(),
+            amount: coins(amount),
+        }
+    }
+
+    /// Runs the message in a transaction of a block at `time` and returns the result
+    /// together with the number of executed messages. Changes of a failed transaction
+    /// are discarded.
+    fn run(
+        handler: &TestHandler,
+        tx_store: &mut TransactionMultiBank<MemDB, SpaceKey>,
+        time: i64,
+        msg: &Message,
+    ) -> (Result<(), AuthzTxError>, usize) {
+        let header = Header {
+            height: 1,
+            time: Timestamp::try_new(time, 0).unwrap_test(),
+            ..Default::default()
+        };
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            tx_store,
+            1,
+            header,
+            ConsensusParams::default(),
+            GasMeter::<TxKind>::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let mut executed = 0;
+        let result = handler.tx(&mut ctx, msg, |_, _: &MsgSend| {
+            executed += 1;
+            Ok(())
+        });
+
+        match result {
+            Ok(()) => tx_store.upgrade_cache(),
+            Err(_) => tx_store.tx_cache_clear(),
+        }
+
+        (result, executed)
+    }
+
+    /// Grants the grantee to send up to `spend_limit` coins of the granter
+    fn grant_send(
+        handler: &TestHandler,
+        tx_store: &mut TransactionMultiBank<MemDB, SpaceKey>,
+        spend_limit: u64,
+        expiration: Option<i64>,
+    ) {
+        let msg = Message::Grant(MsgGrant {
+            granter: granter(),
+            grantee: grantee(),
+            grant: Grant {
+                authorization: Authorization::Send(SendAuthorization {
+                    spend_limit: coins(spend_limit),
+                }),
+                expiration: expiration.map(|time| Timestamp::try_new(time, 0).unwrap_test()),
+            },
+        });
+
+        run(handler, tx_store, GRANT_TIME, &msg).0.unwrap_test();
+    }
+
+    fn exec(msg: MsgSend) -> Message {
+        Message::Exec(MsgExec {
+            grantee: grantee(),
+            msgs: vec![msg.into()],
+        })
+    }
+
+    fn send_grant(
+        handler: &TestHandler,
+        tx_store: &mut TransactionMultiBank<MemDB, SpaceKey>,
+    ) -> Option<GrantAuthorization> {
+        let header = Header::default();
+        let mut block_gas_meter = GasMeter::infinite();
+        let ctx = TxContext::new(
+            tx_store,
+            1,
+            header,
+            ConsensusParams::default(),
+            GasMeter::<TxKind>::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        handler
+            .keeper
+            .authorization(&ctx, &granter(), &grantee(), MsgSend::TYPE_URL)
+            .unwrap_test()
+    }
+
+    #[test]
+    fn exec_without_grant_fails() {
+        let handler = handler();
+        let mut tx_store = tx_store();
+
+        let (result, executed) = run(&handler, &mut tx_store, GRANT_TIME, &exec(send(10)));
+
+        assert!(matches!(result, Err(AuthzTxError::NotFound)));
+        assert_eq!(executed, 0);
+    }
+
+    #[test]
+    fn exec_with_expired_grant_fails() {
+        let handler = handler();
+        let mut tx_store = tx_store();
+        grant_send(&handler, &mut tx_store, 100, Some(GRANT_TIME + 10));
+
+        let (result, executed) = run(&handler, &mut tx_store, GRANT_TIME + 20, &exec(send(10)));
+
+        assert!(matches!(result, Err(AuthzTxError::Expired)));
+        assert_eq!(executed, 0);
+    }
+
+    #[test]
+    fn exec_over_spend_limit_fails() {
+        let handler = handler();
+        let mut tx_store = tx_store();
+        grant_send(&handler, &mut tx_store, 100, None);
+
+        let (result, executed) = run(&handler, &mut tx_store, GRANT_TIME, &exec(send(150)));
+
+        assert!(matches!(
+            result,
+            Err(AuthzTxError::Authorization(
+                AuthorizationError::InsufficientSpendLimit
+            ))
+        ));
+        assert_eq!(executed, 0);
+        // the grant is left untouched
+        assert_eq!(
+            send_grant(&handler, &mut tx_store).map(|grant| grant.authorization),
+            Some(Authorization::Send(SendAuthorization {
+                spend_limit: coins(100)
+            }))
+        );
+    }
+
+    #[test]
+    fn exec_updates_grant_and_deletes_exhausted_grant() {
+        let handler = handler();
+        let mut tx_store = tx_store();
+        grant_send(&handler, &mut tx_store, 100, Some(GRANT_TIME + 10));
+
+        let (result, executed) = run(&handler, &mut tx_store, GRANT_TIME, &exec(send(40)));
+        result.unwrap_test();
+        assert_eq!(executed, 1);
+        assert_eq!(
+            send_grant(&handler, &mut tx_store).map(|grant| grant.authorization),
+            Some(Authorization::Send(SendAuthorization {
+                spend_limit: coins(60)
+            }))
+        );
+
+        // accept reports the grant as exhausted once the whole limit is spent
+        let (result, executed) = run(&handler, &mut tx_store, GRANT_TIME, &exec(send(60)));
+        result.unwrap_test();
+        assert_eq!(executed, 1);
+        assert_eq!(send_grant(&handler, &mut tx_store), None);
+
+        let (result, executed) = run(&handler, &mut tx_store, GRANT_TIME, &exec(send(1)));
+        assert!(matches!(result, Err(AuthzTxError::NotFound)));
+        assert_eq!(executed, 0);
+    }
+}
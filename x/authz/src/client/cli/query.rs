@@ -0,0 +1,139 @@
+use crate::types::query::{
+    QueryGranteeGrantsRequest, QueryGranteeGrantsResponse, QueryGranterGrantsRequest,
+    QueryGranterGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+};
+use clap::{Args, Subcommand};
+use gears::{
+    application::handlers::client::QueryHandler,
+    cli::pagination::CliPaginationRequest,
+    core::Protobuf,
+    derive::Query,
+    types::{address::AccAddress, pagination::request::PaginationRequest},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Args, Debug)]
+pub struct AuthzQueryCli {
+    #[command(subcommand)]
+    pub command: AuthzCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AuthzCommands {
+    Grants(GrantsCommand),
+    GrantsByGranter(GrantsByGranterCommand),
+    GrantsByGrantee(GrantsByGranteeCommand),
+}
+
+/// Query grants given by a granter to a grantee. The grants are filtered by the message
+/// type url if it is set.
+#[derive(Args, Debug, Clone)]
+pub struct GrantsCommand {
+    /// granter address
+    pub granter: AccAddress,
+    /// grantee address
+    pub grantee: AccAddress,
+    /// message type url
+    pub msg_type_url: Option<String>,
+    #[command(flatten)]
+    pub pagination: CliPaginationRequest,
+}
+
+/// Query all grants given by a granter.
+#[derive(Args, Debug, Clone)]
+pub struct GrantsByGranterCommand {
+    /// granter address
+    pub granter: AccAddress,
+    #[command(flatten)]
+    pub pagination: CliPaginationRequest,
+}
+
+/// Query all grants of a grantee.
+#[derive(Args, Debug, Clone)]
+pub struct GrantsByGranteeCommand {
+    /// grantee address
+    pub grantee: AccAddress,
+    #[command(flatten)]
+    pub pagination: CliPaginationRequest,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthzQueryHandler;
+
+impl QueryHandler for AuthzQueryHandler {
+    type QueryRequest = AuthzQueryRequest;
+
+    type QueryResponse = AuthzQueryResponse;
+
+    type QueryCommands = AuthzQueryCli;
+
+    fn prepare_query_request(
+        &self,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryRequest> {
+        let res = match &command.command {
+            AuthzCommands::Grants(GrantsCommand {
+                granter,
+                grantee,
+                msg_type_url,
+                pagination,
+            }) => Self::QueryRequest::Grants(QueryGrantsRequest {
+                granter: granter.clone(),
+                grantee: grantee.clone(),
+                msg_type_url: msg_type_url.clone(),
+                pagination: PaginationRequest::try_from(pagination.clone())?,
+            }),
+            AuthzCommands::GrantsByGranter(GrantsByGranterCommand {
+                granter,
+                pagination,
+            }) => Self::QueryRequest::GranterGrants(QueryGranterGrantsRequest {
+                granter: granter.clone(),
+                pagination: PaginationRequest::try_from(pagination.clone())?,
+            }),
+            AuthzCommands::GrantsByGrantee(GrantsByGranteeCommand {
+                grantee,
+                pagination,
+            }) => Self::QueryRequest::GranteeGrants(QueryGranteeGrantsRequest {
+                grantee: grantee.clone(),
+                pagination: PaginationRequest::try_from(pagination.clone())?,
+            }),
+        };
+
+        Ok(res)
+    }
+
+    fn handle_raw_response(
+        &self,
+        query_bytes: Vec<u8>,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryResponse> {
+        let res = match &command.command {
+            AuthzCommands::Grants(_) => {
+                AuthzQueryResponse::Grants(QueryGrantsResponse::decode_vec(&query_bytes)?)
+            }
+            AuthzCommands::GrantsByGranter(_) => AuthzQueryResponse::GranterGrants(
+                QueryGranterGrantsResponse::decode_vec(&query_bytes)?,
+            ),
+            AuthzCommands::GrantsByGrantee(_) => AuthzQueryResponse::GranteeGrants(
+                QueryGranteeGrantsResponse::decode_vec(&query_bytes)?,
+            ),
+        };
+
+        Ok(res)
+    }
+}
+
+#[derive(Clone, PartialEq, Query)]
+pub enum AuthzQueryRequest {
+    Grants(QueryGrantsRequest),
+    GranterGrants(QueryGranterGrantsRequest),
+    GranteeGrants(QueryGranteeGrantsRequest),
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query)]
+#[serde(untagged)]
+pub enum AuthzQueryResponse {
+    Grants(QueryGrantsResponse),
+    GranterGrants(QueryGranterGrantsResponse),
+    GranteeGrants(QueryGranteeGrantsResponse),
+}
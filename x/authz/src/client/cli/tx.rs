@@ -0,0 +1,155 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Args, Subcommand, ValueEnum};
+use gears::{
+    core::any::google::Any,
+    tendermint::types::time::timestamp::Timestamp,
+    types::{
+        address::{AccAddress, ValAddress},
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        tx::raw::TxRaw,
+    },
+};
+use prost::Message as _;
+
+use crate::{
+    Authorization, AuthorizationType, GenericAuthorization, Grant, Message, MsgExec, MsgGrant,
+    MsgRevoke, SendAuthorization, StakeAuthorization,
+};
+
+#[derive(Args, Debug, Clone)]
+pub struct AuthzTxCli {
+    #[command(subcommand)]
+    pub command: AuthzCommands,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorizationKind {
+    Send,
+    Generic,
+    Delegate,
+    Unbond,
+    Redelegate,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum AuthzCommands {
+    /// Grant an authorization to the grantee to execute messages on behalf of the granter.
+    /// A send authorization requires '--spend-limit', a generic authorization '--msg-type'
+    /// and the stake authorizations one of '--allowed-validators' and '--deny-validators'.
+    Grant {
+        /// grantee address
+        grantee: AccAddress,
+        /// type of the authorization
+        #[arg(value_enum)]
+        authorization: AuthorizationKind,
+        /// maximum amount of coins the grantee can send
+        #[arg(long)]
+        spend_limit: Option<UnsignedCoins>,
+        /// type url of the message the grantee can execute
+        #[arg(long)]
+        msg_type: Option<String>,
+        /// maximum amount of tokens the grantee can stake, no limit if unset
+        #[arg(long)]
+        max_tokens: Option<UnsignedCoin>,
+        /// comma separated list of validators the grantee is allowed to stake to
+        #[arg(long, value_delimiter = ',')]
+        allowed_validators: Vec<ValAddress>,
+        /// comma separated list of validators the grantee isn't allowed to stake to
+        #[arg(long, value_delimiter = ',')]
+        deny_validators: Vec<ValAddress>,
+        /// expiration of the grant as a UNIX epoch timestamp
+        #[arg(long)]
+        expiration: Option<i64>,
+    },
+    /// Revoke the authorization of the grantee to execute the messages of the type.
+    Revoke {
+        /// grantee address
+        grantee: AccAddress,
+        /// type url of the message
+        msg_type: String,
+    },
+    /// Execute the messages of a transaction file generated with '--generate-only' on
+    /// behalf of their signers.
+    Exec {
+        /// path to the transaction file
+        tx_file: PathBuf,
+    },
+}
+
+/// Body of a transaction with only the messages decoded
+#[derive(Clone, PartialEq, prost::Message)]
+struct TxBodyMessages {
+    #[prost(message, repeated, tag = "1")]
+    messages: Vec<Any>,
+}
+
+pub fn run_authz_tx_command(args: AuthzTxCli, from_address: AccAddress) -> Result<Message> {
+    match args.command {
+        AuthzCommands::Grant {
+            grantee,
+            authorization,
+            spend_limit,
+            msg_type,
+            max_tokens,
+            allowed_validators,
+            deny_validators,
+            expiration,
+        } => {
+            let stake_authorization = |authorization_type| {
+                StakeAuthorization::new(
+                    allowed_validators,
+                    deny_validators,
+                    authorization_type,
+                    max_tokens,
+                )
+                .map(Authorization::Stake)
+            };
+
+            let authorization = match authorization {
+                AuthorizationKind::Send => Authorization::Send(SendAuthorization {
+                    spend_limit: spend_limit
+                        .ok_or_else(|| anyhow::anyhow!("spend limit is required"))?,
+                }),
+                AuthorizationKind::Generic => Authorization::Generic(GenericAuthorization {
+                    msg: msg_type.ok_or_else(|| anyhow::anyhow!("message type is required"))?,
+                }),
+                AuthorizationKind::Delegate => stake_authorization(AuthorizationType::Delegate)?,
+                AuthorizationKind::Unbond => stake_authorization(AuthorizationType::Undelegate)?,
+                AuthorizationKind::Redelegate => {
+                    stake_authorization(AuthorizationType::Redelegate)?
+                }
+            };
+
+            Ok(Message::Grant(MsgGrant {
+                granter: from_address,
+                grantee,
+                grant: Grant {
+                    authorization,
+                    expiration: expiration
+                        .map(|seconds| Timestamp::try_new(seconds, 0))
+                        .transpose()?,
+                },
+            }))
+        }
+        AuthzCommands::Revoke { grantee, msg_type } => Ok(Message::Revoke(MsgRevoke {
+            granter: from_address,
+            grantee,
+            msg_type_url: msg_type,
+        })),
+        AuthzCommands::Exec { tx_file } => {
+            let tx: TxRaw = serde_json::from_slice(&std::fs::read(tx_file)?)?;
+            let TxBodyMessages { messages } = TxBodyMessages::decode(tx.body_bytes.as_slice())?;
+
+            if messages.is_empty() {
+                return Err(anyhow::anyhow!("transaction file doesn't contain messages"));
+            }
+
+            Ok(Message::Exec(MsgExec {
+                grantee: from_address,
+                msgs: messages,
+            }))
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    types::query::{QueryGranteeGrantsRequest, QueryGranterGrantsRequest, QueryGrantsRequest},
+    AuthzNodeQueryRequest, AuthzNodeQueryResponse,
+};
+use axum::{
+    extract::{Path, Query, State},
+    routing::get,
+    Json, Router,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, Pagination, RestState},
+    types::{address::AccAddress, pagination::request::PaginationRequest},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct GrantsQuery {
+    granter: AccAddress,
+    grantee: AccAddress,
+    msg_type_url: Option<String>,
+    // TODO: serde(flatten) doesn't work
+    offset: Option<u32>,
+    limit: Option<u8>,
+}
+
+/// Gets the grants given by a granter to a grantee
+pub async fn grants<
+    QReq: QueryRequest + From<AuthzNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthzNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Query(GrantsQuery {
+        granter,
+        grantee,
+        msg_type_url,
+        offset,
+        limit,
+    }): Query<GrantsQuery>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthzNodeQueryRequest::Grants(QueryGrantsRequest {
+        granter,
+        grantee,
+        msg_type_url: msg_type_url.filter(|url| !url.is_empty()),
+        pagination: PaginationRequest::from(Pagination::new(offset, limit)),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+/// Gets all the grants given by a granter
+pub async fn granter_grants<
+    QReq: QueryRequest + From<AuthzNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthzNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(granter): Path<AccAddress>,
+    pagination: Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthzNodeQueryRequest::GranterGrants(QueryGranterGrantsRequest {
+        granter,
+        pagination: PaginationRequest::from(pagination.0),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+/// Gets all the grants given to a grantee
+pub async fn grantee_grants<
+    QReq: QueryRequest + From<AuthzNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthzNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(grantee): Path<AccAddress>,
+    pagination: Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthzNodeQueryRequest::GranteeGrants(QueryGranteeGrantsRequest {
+        grantee,
+        pagination: PaginationRequest::from(pagination.0),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<AuthzNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthzNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/v1beta1/grants", get(grants))
+        .route("/v1beta1/grants/granter/:granter", get(granter_grants))
+        .route("/v1beta1/grants/grantee/:grantee", get(grantee_grants))
+}
@@ -0,0 +1,58 @@
+use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
+    core::errors::CoreError,
+    types::{address::ValAddress, base::errors::CoinsError, store::gas::errors::GasStoreErrors},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuthorizationError {
+    #[error("invalid coins: {0}")]
+    Coins(#[from] CoinsError),
+    #[error("message type url shouldn't be empty")]
+    EmptyMsgTypeUrl,
+    #[error("invalid authorization type: {0}")]
+    InvalidAuthorizationType(i32),
+    #[error("allowed and deny lists cannot be both empty")]
+    EmptyValidators,
+    #[error("cannot set both allowed and deny lists")]
+    BothValidators,
+    #[error("invalid message: {0}")]
+    InvalidMessage(String),
+    #[error("requested amount is more than spend limit")]
+    InsufficientSpendLimit,
+    #[error("cannot delegate/undelegate to {0} validator")]
+    ValidatorNotAllowed(ValAddress),
+    #[error("authorization type not recognized: {0}")]
+    UnknownType(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthzTxError {
+    #[error("authorization not found")]
+    NotFound,
+    #[error("authorization expired")]
+    Expired,
+    #[error("expiration is before current block time")]
+    ExpirationBeforeBlockTime,
+    #[error("authorization can be given to msg with only one signer")]
+    InvalidSigners,
+    #[error(transparent)]
+    Authorization(#[from] AuthorizationError),
+    #[error("failed to decode message: {0}")]
+    Decode(#[from] CoreError),
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+    #[error(transparent)]
+    Exec(TxError),
+}
+
+impl AuthzTxError {
+    /// Errors of the executed messages are returned as is to keep the codespace of the
+    /// module which handled the message.
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        match self {
+            AuthzTxError::Exec(e) => e,
+            e => TxError::new::<MI>(e.to_string(), nz::u16!(1)),
+        }
+    }
+}
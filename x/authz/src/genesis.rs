@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::GrantAuthorization;
+
+/// GenesisState defines the authz module's genesis state.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct GenesisState {
+    pub authorization: Vec<GrantAuthorization>,
+}
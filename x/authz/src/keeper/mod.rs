@@ -0,0 +1,306 @@
+use gears::{
+    context::{
+        init::InitContext, query::QueryContext, InfallibleContext, InfallibleContextMut,
+        QueryableContext, TransactionalContext,
+    },
+    core::Protobuf,
+    extensions::{corruption::UnwrapCorrupt, gas::GasResultExt},
+    store::{database::Database, store::prefix::prefix_end_bound, StoreKey},
+    tendermint::types::{
+        proto::event::{Event, EventAttribute},
+        time::timestamp::Timestamp,
+    },
+    types::{address::AccAddress, store::gas::errors::GasStoreErrors, tx::TxMessage},
+    x::{keepers::auth::AuthKeeper, module::Module},
+};
+use std::{marker::PhantomData, ops::Bound};
+
+use crate::{errors::AuthzTxError, GenesisState, GrantAuthorization, MsgGrant, MsgRevoke};
+
+mod query;
+
+const GRANT_KEY_PREFIX: [u8; 1] = [0x01];
+const GRANT_QUEUE_KEY_PREFIX: [u8; 1] = [0x02];
+
+/// Returns the key of a grant given by the granter to the grantee for a message type.
+/// The key is of the form granter.len() (1 byte) || granter || grantee.len() (1 byte) || grantee || msg_type_url
+fn grant_key(granter: &AccAddress, grantee: &AccAddress, msg_type_url: &str) -> Vec<u8> {
+    [
+        granter.prefix_len_bytes(),
+        grantee.prefix_len_bytes(),
+        msg_type_url.as_bytes().to_vec(),
+    ]
+    .concat()
+}
+
+/// Returns the key of a grant in the expiration queue.
+/// The key is of the form expiration || grant_key
+fn grant_queue_key(
+    expiration: &Timestamp,
+    granter: &AccAddress,
+    grantee: &AccAddress,
+    msg_type_url: &str,
+) -> Vec<u8> {
+    [
+        expiration.format_bytes_rounded(),
+        grant_key(granter, grantee, msg_type_url),
+    ]
+    .concat()
+}
+
+/// Keeper of the authz store
+#[derive(Debug, Clone)]
+pub struct Keeper<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> {
+    store_key: SK,
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> Keeper<SK, AK, M> {
+    pub fn new(store_key: SK, auth_keeper: AK) -> Self {
+        Self {
+            store_key,
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+
+    pub fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, SK>,
+        genesis: GenesisState,
+    ) {
+        for grant in genesis.authorization {
+            // grants which expired before the chain start are dropped
+            if grant.is_expired(&ctx.get_time()) {
+                continue;
+            }
+
+            self.set_grant(ctx, &grant).unwrap_gas();
+        }
+    }
+
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let authorization = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(GRANT_KEY_PREFIX)
+            .into_range(..)
+            .map(|(_, v)| GrantAuthorization::decode_vec(&v).unwrap_or_corrupt())
+            .collect();
+
+        GenesisState { authorization }
+    }
+
+    /// grant creates a new grant or overrides an existing grant for the same message type
+    pub fn grant<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        MsgGrant {
+            granter,
+            grantee,
+            grant,
+        }: &MsgGrant,
+    ) -> Result<(), AuthzTxError> {
+        if let Some(expiration) = &grant.expiration {
+            if expiration < &ctx.get_time() {
+                return Err(AuthzTxError::ExpirationBeforeBlockTime);
+            }
+        }
+
+        let msg_type_url = grant.authorization.msg_type_url();
+
+        // the expiration queue entry of the overridden grant is removed as well
+        if let Some(existing) = self.authorization(ctx, granter, grantee, msg_type_url)? {
+            self.delete_grant(ctx, &existing)?;
+        }
+
+        // create the account if it is not in account state
+        if !self.auth_keeper.has_account(ctx, grantee)? {
+            self.auth_keeper.create_new_base_account(ctx, grantee)?;
+        }
+
+        let grant = GrantAuthorization {
+            granter: granter.clone(),
+            grantee: grantee.clone(),
+            authorization: grant.authorization.clone(),
+            expiration: grant.expiration,
+        };
+        self.set_grant(ctx, &grant)?;
+
+        ctx.push_event(grant_event(
+            "cosmos.authz.v1beta1.EventGrant",
+            msg_type_url,
+            granter,
+            grantee,
+        ));
+
+        Ok(())
+    }
+
+    /// revoke removes an existing grant
+    pub fn revoke<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        MsgRevoke {
+            granter,
+            grantee,
+            msg_type_url,
+        }: &MsgRevoke,
+    ) -> Result<(), AuthzTxError> {
+        let grant = self
+            .authorization(ctx, granter, grantee, msg_type_url)?
+            .ok_or(AuthzTxError::NotFound)?;
+
+        self.delete_grant(ctx, &grant)?;
+
+        ctx.push_event(grant_event(
+            "cosmos.authz.v1beta1.EventRevoke",
+            msg_type_url,
+            granter,
+            grantee,
+        ));
+
+        Ok(())
+    }
+
+    /// dispatch_action checks that the grantee is allowed to execute the message on behalf of
+    /// its signer and updates the grant. The message is executed by the caller.
+    pub fn dispatch_action<DB: Database, CTX: TransactionalContext<DB, SK>, MSG: TxMessage>(
+        &self,
+        ctx: &mut CTX,
+        grantee: &AccAddress,
+        msg: &MSG,
+    ) -> Result<(), AuthzTxError> {
+        let granter = match msg.get_signers().as_slice() {
+            [signer] => (*signer).clone(),
+            _ => return Err(AuthzTxError::InvalidSigners),
+        };
+
+        // the grantee may execute its own messages without a grant
+        if &granter == grantee {
+            return Ok(());
+        }
+
+        let mut grant = self
+            .authorization(ctx, &granter, grantee, msg.type_url())?
+            .ok_or(AuthzTxError::NotFound)?;
+
+        if grant.is_expired(&ctx.get_time()) {
+            return Err(AuthzTxError::Expired);
+        }
+
+        let remove = grant.authorization.accept(&msg.clone().into())?;
+
+        if remove {
+            self.delete_grant(ctx, &grant)?;
+        } else {
+            self.set_grant(ctx, &grant)?;
+        }
+
+        Ok(())
+    }
+
+    /// authorization returns the grant given by the granter to the grantee for the message
+    /// type if it exists
+    pub fn authorization<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        granter: &AccAddress,
+        grantee: &AccAddress,
+        msg_type_url: &str,
+    ) -> Result<Option<GrantAuthorization>, GasStoreErrors> {
+        Ok(ctx
+            .kv_store(&self.store_key)
+            .prefix_store(GRANT_KEY_PREFIX)
+            .get(&grant_key(granter, grantee, msg_type_url))?
+            .map(|bytes| GrantAuthorization::decode_vec(&bytes).unwrap_or_corrupt()))
+    }
+
+    /// remove_expired_grants removes all grants which expire at or before the current block time
+    pub fn remove_expired_grants<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+    ) {
+        let end = ctx.get_time().format_bytes_rounded();
+
+        // queue keys continue after the expiration, so the keys of grants which expire at
+        // the block time are only covered by a range up to the next expiration
+        let keys = InfallibleContext::infallible_store(ctx, &self.store_key)
+            .prefix_store(GRANT_QUEUE_KEY_PREFIX)
+            .into_range((Bound::Unbounded, prefix_end_bound(end.clone())))
+            .map(|(k, _)| k.into_owned())
+            .collect::<Vec<_>>();
+
+        let mut store = InfallibleContextMut::infallible_store_mut(ctx, &self.store_key);
+        for key in keys {
+            store.delete(&[GRANT_QUEUE_KEY_PREFIX.as_slice(), &key].concat());
+            store.delete(&[GRANT_KEY_PREFIX.as_slice(), &key[end.len()..]].concat());
+        }
+    }
+
+    fn set_grant<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        grant: &GrantAuthorization,
+    ) -> Result<(), GasStoreErrors> {
+        let msg_type_url = grant.authorization.msg_type_url();
+        let key = grant_key(&grant.granter, &grant.grantee, msg_type_url);
+        let mut store = ctx.kv_store_mut(&self.store_key);
+
+        if let Some(expiration) = &grant.expiration {
+            store.set(
+                [
+                    GRANT_QUEUE_KEY_PREFIX.as_slice(),
+                    &grant_queue_key(expiration, &grant.granter, &grant.grantee, msg_type_url),
+                ]
+                .concat(),
+                [],
+            )?;
+        }
+
+        store.set(
+            [GRANT_KEY_PREFIX.as_slice(), &key].concat(),
+            grant.encode_vec(),
+        )
+    }
+
+    fn delete_grant<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        grant: &GrantAuthorization,
+    ) -> Result<(), GasStoreErrors> {
+        let msg_type_url = grant.authorization.msg_type_url();
+        let key = grant_key(&grant.granter, &grant.grantee, msg_type_url);
+        let mut store = ctx.kv_store_mut(&self.store_key);
+
+        if let Some(expiration) = &grant.expiration {
+            store.delete(
+                &[
+                    GRANT_QUEUE_KEY_PREFIX.as_slice(),
+                    &grant_queue_key(expiration, &grant.granter, &grant.grantee, msg_type_url),
+                ]
+                .concat(),
+            )?;
+        }
+
+        store.delete(&[GRANT_KEY_PREFIX.as_slice(), &key].concat())?;
+
+        Ok(())
+    }
+}
+
+fn grant_event(
+    kind: &'static str,
+    msg_type_url: &str,
+    granter: &AccAddress,
+    grantee: &AccAddress,
+) -> Event {
+    Event::new(
+        kind,
+        [
+            EventAttribute::new("msg_type_url".into(), msg_type_url.to_owned().into(), true),
+            EventAttribute::new("granter".into(), String::from(granter.clone()).into(), true),
+            EventAttribute::new("grantee".into(), String::from(grantee.clone()).into(), true),
+        ],
+    )
+}
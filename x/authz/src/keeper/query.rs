@@ -0,0 +1,127 @@
+use super::*;
+use crate::{
+    types::query::{
+        QueryGranteeGrantsRequest, QueryGranteeGrantsResponse, QueryGranterGrantsRequest,
+        QueryGranterGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+    },
+    Grant,
+};
+use gears::{
+    extensions::pagination::{IteratorPaginate, Pagination},
+    types::pagination::response::PaginationResponse,
+};
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> Keeper<SK, AK, M> {
+    /// query_grants returns the grants given by the granter to the grantee. Only the grant
+    /// for the message type is returned if it is set.
+    pub fn query_grants<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryGrantsRequest {
+            granter,
+            grantee,
+            msg_type_url,
+            pagination,
+        }: QueryGrantsRequest,
+    ) -> QueryGrantsResponse {
+        if let Some(msg_type_url) = msg_type_url {
+            let grants = self
+                .authorization(ctx, &granter, &grantee, &msg_type_url)
+                .unwrap_gas()
+                .map(Grant::from)
+                .into_iter()
+                .collect();
+
+            return QueryGrantsResponse {
+                grants,
+                pagination: None,
+            };
+        }
+
+        let (p_result, iter) = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(
+                [
+                    GRANT_KEY_PREFIX.as_slice(),
+                    &granter.prefix_len_bytes(),
+                    &grantee.prefix_len_bytes(),
+                ]
+                .concat(),
+            )
+            .into_range(..)
+            .maybe_paginate(Some(Pagination::from(pagination)));
+
+        let grants = iter
+            .map(|(_, v)| Grant::from(GrantAuthorization::decode_vec(&v).unwrap_or_corrupt()))
+            .collect();
+
+        QueryGrantsResponse {
+            grants,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
+    pub fn query_granter_grants<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryGranterGrantsRequest {
+            granter,
+            pagination,
+        }: QueryGranterGrantsRequest,
+    ) -> QueryGranterGrantsResponse {
+        let (p_result, iter) = ctx
+            .kv_store(&self.store_key)
+            .prefix_store([GRANT_KEY_PREFIX.as_slice(), &granter.prefix_len_bytes()].concat())
+            .into_range(..)
+            .maybe_paginate(Some(Pagination::from(pagination)));
+
+        let grants = iter
+            .map(|(_, v)| GrantAuthorization::decode_vec(&v).unwrap_or_corrupt())
+            .collect();
+
+        QueryGranterGrantsResponse {
+            grants,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
+    /// query_grantee_grants returns all the grants given to an address. The grants are
+    /// indexed by the granter, so this iterates over all the grants in the store.
+    pub fn query_grantee_grants<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryGranteeGrantsRequest {
+            grantee,
+            pagination,
+        }: QueryGranteeGrantsRequest,
+    ) -> QueryGranteeGrantsResponse {
+        let grantee = grantee.prefix_len_bytes();
+
+        let (p_result, iter) = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(GRANT_KEY_PREFIX)
+            .into_range(..)
+            .filter(|(k, _)| is_grantee_key(k, &grantee))
+            .maybe_paginate(Some(Pagination::from(pagination)));
+
+        let grants = iter
+            .map(|(_, v)| GrantAuthorization::decode_vec(&v).unwrap_or_corrupt())
+            .collect();
+
+        QueryGranteeGrantsResponse {
+            grants,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+}
+
+/// Checks that the grantee part of a grant key matches the length prefixed grantee. The key
+/// is of the form granter.len() (1 byte) || granter || grantee.len() (1 byte) || grantee || msg_type_url
+fn is_grantee_key(key: &[u8], grantee: &[u8]) -> bool {
+    match key.first() {
+        Some(granter_len) => key
+            .get(1 + *granter_len as usize..)
+            .is_some_and(|rest| rest.starts_with(grantee)),
+        None => false,
+    }
+}
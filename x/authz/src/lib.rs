@@ -0,0 +1,14 @@
+mod abci_handler;
+mod client;
+pub mod errors;
+mod genesis;
+mod keeper;
+mod message;
+mod types;
+
+pub use abci_handler::*;
+pub use client::*;
+pub use genesis::*;
+pub use keeper::*;
+pub use message::*;
+pub use types::*;
@@ -0,0 +1,16 @@
+use crate::types::{MsgExec, MsgGrant, MsgRevoke};
+use gears::derive::AppMessage;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, AppMessage)]
+pub enum Message {
+    #[serde(rename = "/cosmos.authz.v1beta1.MsgGrant")]
+    #[msg(url(path = MsgGrant::TYPE_URL))]
+    Grant(MsgGrant),
+    #[serde(rename = "/cosmos.authz.v1beta1.MsgRevoke")]
+    #[msg(url(path = MsgRevoke::TYPE_URL))]
+    Revoke(MsgRevoke),
+    #[serde(rename = "/cosmos.authz.v1beta1.MsgExec")]
+    #[msg(url(path = MsgExec::TYPE_URL))]
+    Exec(MsgExec),
+}
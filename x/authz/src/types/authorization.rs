@@ -0,0 +1,581 @@
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    types::{
+        address::ValAddress,
+        base::{
+            coin::{inner::Coin as RawCoin, UnsignedCoin},
+            coins::UnsignedCoins,
+            errors::CoinError,
+        },
+        msg::send::MsgSend,
+    },
+};
+use prost::{Message, Oneof};
+use serde::{Deserialize, Serialize};
+use staking::{DelegateMsg, RedelegateMsg, UndelegateMsg};
+
+use crate::errors::AuthorizationError;
+
+pub const GENERIC_AUTHORIZATION_TYPE_URL: &str = "/cosmos.authz.v1beta1.GenericAuthorization";
+pub const SEND_AUTHORIZATION_TYPE_URL: &str = "/cosmos.bank.v1beta1.SendAuthorization";
+pub const STAKE_AUTHORIZATION_TYPE_URL: &str = "/cosmos.staking.v1beta1.StakeAuthorization";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct GenericAuthorizationRaw {
+    #[prost(string, tag = "1")]
+    pub msg: String,
+}
+
+impl From<GenericAuthorization> for GenericAuthorizationRaw {
+    fn from(GenericAuthorization { msg }: GenericAuthorization) -> Self {
+        Self { msg }
+    }
+}
+
+/// GenericAuthorization gives the grantee unrestricted permissions to execute
+/// the provided method on behalf of the granter's account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GenericAuthorization {
+    /// msg is the type url of the message the grant allows to execute.
+    pub msg: String,
+}
+
+impl TryFrom<GenericAuthorizationRaw> for GenericAuthorization {
+    type Error = CoreError;
+
+    fn try_from(
+        GenericAuthorizationRaw { msg }: GenericAuthorizationRaw,
+    ) -> Result<Self, Self::Error> {
+        if msg.is_empty() {
+            return Err(CoreError::DecodeGeneral(
+                AuthorizationError::EmptyMsgTypeUrl.to_string(),
+            ));
+        }
+
+        Ok(Self { msg })
+    }
+}
+
+impl Protobuf<GenericAuthorizationRaw> for GenericAuthorization {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct SendAuthorizationRaw {
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<RawCoin>,
+}
+
+impl From<SendAuthorization> for SendAuthorizationRaw {
+    fn from(SendAuthorization { spend_limit }: SendAuthorization) -> Self {
+        Self {
+            spend_limit: spend_limit
+                .into_inner()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// SendAuthorization allows the grantee to spend up to spend_limit coins from
+/// the granter's account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SendAuthorization {
+    /// spend_limit specifies the maximum amount of coins that can be sent and is
+    /// updated as coins are sent.
+    pub spend_limit: UnsignedCoins,
+}
+
+impl SendAuthorization {
+    /// accept deducts the amount of the `MsgSend` from the spend limit. It returns true if
+    /// the authorization is exhausted and should be removed.
+    pub fn accept(&mut self, msg: &Any) -> Result<bool, AuthorizationError> {
+        let msg = MsgSend::try_from(msg.clone())
+            .map_err(|e| AuthorizationError::InvalidMessage(e.to_string()))?;
+
+        let left = sub_coins(self.spend_limit.inner(), msg.amount.inner())
+            .ok_or(AuthorizationError::InsufficientSpendLimit)?;
+
+        if left.is_empty() {
+            return Ok(true);
+        }
+
+        self.spend_limit = UnsignedCoins::new(left)?;
+
+        Ok(false)
+    }
+}
+
+impl TryFrom<SendAuthorizationRaw> for SendAuthorization {
+    type Error = CoreError;
+
+    fn try_from(
+        SendAuthorizationRaw { spend_limit }: SendAuthorizationRaw,
+    ) -> Result<Self, Self::Error> {
+        let spend_limit = spend_limit
+            .into_iter()
+            .map(UnsignedCoin::try_from)
+            .collect::<Result<Vec<_>, CoinError>>()
+            .map_err(|e| CoreError::Coin(e.to_string()))?;
+
+        Ok(Self {
+            spend_limit: UnsignedCoins::new(spend_limit)
+                .map_err(|e| CoreError::Coins(e.to_string()))?,
+        })
+    }
+}
+
+impl Protobuf<SendAuthorizationRaw> for SendAuthorization {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct ValidatorsRaw {
+    #[prost(string, repeated, tag = "1")]
+    pub address: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Oneof)]
+pub enum StakeValidatorsRaw {
+    #[prost(message, tag = "2")]
+    AllowList(ValidatorsRaw),
+    #[prost(message, tag = "3")]
+    DenyList(ValidatorsRaw),
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct StakeAuthorizationRaw {
+    #[prost(message, optional, tag = "1")]
+    pub max_tokens: Option<RawCoin>,
+    #[prost(oneof = "StakeValidatorsRaw", tags = "2, 3")]
+    pub validators: Option<StakeValidatorsRaw>,
+    #[prost(int32, tag = "4")]
+    pub authorization_type: i32,
+}
+
+impl From<StakeAuthorization> for StakeAuthorizationRaw {
+    fn from(
+        StakeAuthorization {
+            max_tokens,
+            validators,
+            authorization_type,
+        }: StakeAuthorization,
+    ) -> Self {
+        let into_raw = |validators: Vec<ValAddress>| ValidatorsRaw {
+            address: validators.into_iter().map(|v| v.to_string()).collect(),
+        };
+
+        Self {
+            max_tokens: max_tokens.map(Into::into),
+            validators: validators.map(|validators| match validators {
+                StakeValidators::AllowList(list) => StakeValidatorsRaw::AllowList(into_raw(list)),
+                StakeValidators::DenyList(list) => StakeValidatorsRaw::DenyList(into_raw(list)),
+            }),
+            authorization_type: authorization_type as i32,
+        }
+    }
+}
+
+/// AuthorizationType defines the type of staking module authorization type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(i32)]
+pub enum AuthorizationType {
+    /// defines an authorization type for Msg/Delegate
+    #[serde(rename = "AUTHORIZATION_TYPE_DELEGATE")]
+    Delegate = 1,
+    /// defines an authorization type for Msg/Undelegate
+    #[serde(rename = "AUTHORIZATION_TYPE_UNDELEGATE")]
+    Undelegate = 2,
+    /// defines an authorization type for Msg/BeginRedelegate
+    #[serde(rename = "AUTHORIZATION_TYPE_REDELEGATE")]
+    Redelegate = 3,
+}
+
+impl TryFrom<i32> for AuthorizationType {
+    type Error = AuthorizationError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(AuthorizationType::Delegate),
+            2 => Ok(AuthorizationType::Undelegate),
+            3 => Ok(AuthorizationType::Redelegate),
+            _ => Err(AuthorizationError::InvalidAuthorizationType(value)),
+        }
+    }
+}
+
+/// StakeValidators is the list of validators the grantee is allowed or denied to
+/// delegate to.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeValidators {
+    AllowList(Vec<ValAddress>),
+    DenyList(Vec<ValAddress>),
+}
+
+/// StakeAuthorization defines authorization for delegate/undelegate/redelegate.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StakeAuthorization {
+    /// max_tokens specifies the maximum amount of tokens can be delegate to a validator. If it is
+    /// empty, there is no spend limit and any amount of coins can be delegated.
+    pub max_tokens: Option<UnsignedCoin>,
+    /// validators is the list of validators the grantee is allowed or denied to stake to.
+    pub validators: Option<StakeValidators>,
+    /// authorization_type defines one of AuthorizationType.
+    pub authorization_type: AuthorizationType,
+}
+
+impl StakeAuthorization {
+    /// Creates a stake authorization. Exactly one of the allowed and denied validator lists
+    /// should be non empty.
+    pub fn new(
+        allowed: Vec<ValAddress>,
+        denied: Vec<ValAddress>,
+        authorization_type: AuthorizationType,
+        max_tokens: Option<UnsignedCoin>,
+    ) -> Result<Self, AuthorizationError> {
+        let validators = match (allowed.is_empty(), denied.is_empty()) {
+            (true, true) => Err(AuthorizationError::EmptyValidators)?,
+            (false, false) => Err(AuthorizationError::BothValidators)?,
+            (false, true) => StakeValidators::AllowList(allowed),
+            (true, false) => StakeValidators::DenyList(denied),
+        };
+
+        Ok(Self {
+            max_tokens,
+            validators: Some(validators),
+            authorization_type,
+        })
+    }
+
+    pub fn msg_type_url(&self) -> &'static str {
+        match self.authorization_type {
+            AuthorizationType::Delegate => DelegateMsg::TYPE_URL,
+            AuthorizationType::Undelegate => UndelegateMsg::TYPE_URL,
+            AuthorizationType::Redelegate => RedelegateMsg::TYPE_URL,
+        }
+    }
+
+    /// accept checks the validator of the staking message against the allowed and denied
+    /// validators and deducts the amount from max tokens. It returns true if the authorization
+    /// is exhausted and should be removed.
+    pub fn accept(&mut self, msg: &Any) -> Result<bool, AuthorizationError> {
+        let invalid_msg = |e: CoreError| AuthorizationError::InvalidMessage(e.to_string());
+
+        let (validator, amount) = match self.authorization_type {
+            AuthorizationType::Delegate => {
+                let msg = DelegateMsg::try_from(msg.clone()).map_err(invalid_msg)?;
+                (msg.validator_address, msg.amount)
+            }
+            AuthorizationType::Undelegate => {
+                let msg = UndelegateMsg::try_from(msg.clone()).map_err(invalid_msg)?;
+                (msg.validator_address, msg.amount)
+            }
+            AuthorizationType::Redelegate => {
+                let msg = RedelegateMsg::try_from(msg.clone()).map_err(invalid_msg)?;
+                (msg.dst_validator_address, msg.amount)
+            }
+        };
+
+        let allowed = match &self.validators {
+            Some(StakeValidators::AllowList(list)) => list.is_empty() || list.contains(&validator),
+            Some(StakeValidators::DenyList(list)) => !list.contains(&validator),
+            None => true,
+        };
+
+        if !allowed {
+            return Err(AuthorizationError::ValidatorNotAllowed(validator));
+        }
+
+        match &mut self.max_tokens {
+            Some(max_tokens) => {
+                if max_tokens.denom != amount.denom || max_tokens.amount < amount.amount {
+                    return Err(AuthorizationError::InsufficientSpendLimit);
+                }
+
+                max_tokens.amount -= amount.amount;
+
+                Ok(max_tokens.amount.is_zero())
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+impl TryFrom<StakeAuthorizationRaw> for StakeAuthorization {
+    type Error = CoreError;
+
+    fn try_from(
+        StakeAuthorizationRaw {
+            max_tokens,
+            validators,
+            authorization_type,
+        }: StakeAuthorizationRaw,
+    ) -> Result<Self, Self::Error> {
+        let from_raw = |ValidatorsRaw { address }: ValidatorsRaw| {
+            address
+                .iter()
+                .map(|addr| ValAddress::from_bech32(addr))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))
+        };
+
+        Ok(Self {
+            max_tokens: max_tokens
+                .map(UnsignedCoin::try_from)
+                .transpose()
+                .map_err(|e| CoreError::Coin(e.to_string()))?,
+            validators: validators
+                .map(|validators| match validators {
+                    StakeValidatorsRaw::AllowList(list) => {
+                        from_raw(list).map(StakeValidators::AllowList)
+                    }
+                    StakeValidatorsRaw::DenyList(list) => {
+                        from_raw(list).map(StakeValidators::DenyList)
+                    }
+                })
+                .transpose()?,
+            authorization_type: AuthorizationType::try_from(authorization_type)
+                .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?,
+        })
+    }
+}
+
+impl Protobuf<StakeAuthorizationRaw> for StakeAuthorization {}
+
+/// Authorization is one of the authorizations a granter may give to a grantee to execute
+/// messages on its behalf.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@type")]
+pub enum Authorization {
+    #[serde(rename = "/cosmos.authz.v1beta1.GenericAuthorization")]
+    Generic(GenericAuthorization),
+    #[serde(rename = "/cosmos.bank.v1beta1.SendAuthorization")]
+    Send(SendAuthorization),
+    #[serde(rename = "/cosmos.staking.v1beta1.StakeAuthorization")]
+    Stake(StakeAuthorization),
+}
+
+impl Authorization {
+    /// msg_type_url returns the type url of the message the authorization allows to execute
+    pub fn msg_type_url(&self) -> &str {
+        match self {
+            Authorization::Generic(authorization) => &authorization.msg,
+            Authorization::Send(_) => MsgSend::TYPE_URL,
+            Authorization::Stake(authorization) => authorization.msg_type_url(),
+        }
+    }
+
+    /// accept checks if the message is allowed by the authorization and updates its state.
+    /// It returns true if the authorization is exhausted and should be removed.
+    pub fn accept(&mut self, msg: &Any) -> Result<bool, AuthorizationError> {
+        match self {
+            Authorization::Generic(_) => Ok(false),
+            Authorization::Send(authorization) => authorization.accept(msg),
+            Authorization::Stake(authorization) => authorization.accept(msg),
+        }
+    }
+}
+
+impl TryFrom<Any> for Authorization {
+    type Error = CoreError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            GENERIC_AUTHORIZATION_TYPE_URL => Ok(Authorization::Generic(
+                GenericAuthorization::decode_vec(&any.value)?,
+            )),
+            SEND_AUTHORIZATION_TYPE_URL => Ok(Authorization::Send(SendAuthorization::decode_vec(
+                &any.value,
+            )?)),
+            STAKE_AUTHORIZATION_TYPE_URL => Ok(Authorization::Stake(
+                StakeAuthorization::decode_vec(&any.value)?,
+            )),
+            _ => Err(CoreError::DecodeAny(
+                AuthorizationError::UnknownType(any.type_url).to_string(),
+            )),
+        }
+    }
+}
+
+impl From<Authorization> for Any {
+    fn from(authorization: Authorization) -> Self {
+        match authorization {
+            Authorization::Generic(authorization) => Any {
+                type_url: GENERIC_AUTHORIZATION_TYPE_URL.to_string(),
+                value: authorization.encode_vec(),
+            },
+            Authorization::Send(authorization) => Any {
+                type_url: SEND_AUTHORIZATION_TYPE_URL.to_string(),
+                value: authorization.encode_vec(),
+            },
+            Authorization::Stake(authorization) => Any {
+                type_url: STAKE_AUTHORIZATION_TYPE_URL.to_string(),
+                value: authorization.encode_vec(),
+            },
+        }
+    }
+}
+
+impl Protobuf<Any> for Authorization {}
+
+/// Subtracts `amount` from `coins` and drops the exhausted denominations. Returns `None` if
+/// `coins` doesn't cover `amount`.
+fn sub_coins(coins: &[UnsignedCoin], amount: &[UnsignedCoin]) -> Option<Vec<UnsignedCoin>> {
+    let covered = amount.iter().all(|coin| {
+        coins
+            .iter()
+            .find(|this| this.denom == coin.denom)
+            .is_some_and(|this| this.amount >= coin.amount)
+    });
+
+    if !covered {
+        return None;
+    }
+
+    Some(
+        coins
+            .iter()
+            .map(|coin| {
+                let spent = amount
+                    .iter()
+                    .find(|this| this.denom == coin.denom)
+                    .map(|this| this.amount)
+                    .unwrap_or_default();
+
+                UnsignedCoin {
+                    denom: coin.denom.clone(),
+                    amount: coin.amount - spent,
+                }
+            })
+            .filter(|coin| !coin.amount.is_zero())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gears::{extensions::testing::UnwrapTesting, types::address::AccAddress};
+
+    fn coins(coins: &str) -> UnsignedCoins {
+        coins.parse().unwrap_test()
+    }
+
+    fn validator(addr: &str) -> ValAddress {
+        ValAddress::from_bech32(addr).unwrap_test()
+    }
+
+    fn delegate(validator_address: ValAddress, amount: &str) -> Any {
+        DelegateMsg {
+            delegator_address: AccAddress::from_bech32(
+                "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+            )
+            .unwrap_test(),
+            validator_address,
+            amount: amount.parse().unwrap_test(),
+        }
+        .into()
+    }
+
+    const VALIDATOR: &str = "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4";
+    const OTHER_VALIDATOR: &str = "cosmosvaloper15jlqmacda2pzerhw48gvvxskweg8sz2scfexfk";
+
+    #[test]
+    fn send_authorization_spends_limit() {
+        let mut authorization = SendAuthorization {
+            spend_limit: coins("100uatom"),
+        };
+
+        let send = |amount: &str| -> Any {
+            MsgSend {
+                from_address: AccAddress::from_bech32(
+                    "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+                )
+                .unwrap_test(),
+                to_address: AccAddress::from_bech32(
+                    "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs",
+                )
+                .unwrap_test(),
+                amount: coins(amount),
+            }
+            .into()
+        };
+
+        assert!(!authorization.accept(&send("40uatom")).unwrap_test());
+        assert_eq!(authorization.spend_limit, coins("60uatom"));
+
+        assert_eq!(
+            authorization.accept(&send("61uatom")),
+            Err(AuthorizationError::InsufficientSpendLimit)
+        );
+
+        assert!(authorization.accept(&send("60uatom")).unwrap_test());
+    }
+
+    #[test]
+    fn stake_authorization_checks_validators() {
+        let mut authorization = StakeAuthorization::new(
+            vec![],
+            vec![validator(OTHER_VALIDATOR)],
+            AuthorizationType::Delegate,
+            Some("100uatom".parse().unwrap_test()),
+        )
+        .unwrap_test();
+
+        assert_eq!(
+            authorization.accept(&delegate(validator(OTHER_VALIDATOR), "10uatom")),
+            Err(AuthorizationError::ValidatorNotAllowed(validator(
+                OTHER_VALIDATOR
+            )))
+        );
+
+        assert!(!authorization
+            .accept(&delegate(validator(VALIDATOR), "40uatom"))
+            .unwrap_test());
+        assert_eq!(
+            authorization.max_tokens,
+            Some("60uatom".parse().unwrap_test())
+        );
+
+        assert!(authorization
+            .accept(&delegate(validator(VALIDATOR), "60uatom"))
+            .unwrap_test());
+    }
+
+    #[test]
+    fn stake_authorization_rejects_other_messages() {
+        let mut authorization = StakeAuthorization::new(
+            vec![validator(VALIDATOR)],
+            vec![],
+            AuthorizationType::Undelegate,
+            None,
+        )
+        .unwrap_test();
+
+        assert!(matches!(
+            authorization.accept(&delegate(validator(VALIDATOR), "10uatom")),
+            Err(AuthorizationError::InvalidMessage(_))
+        ));
+    }
+
+    #[test]
+    fn authorization_any_roundtrip() {
+        let authorization = Authorization::Stake(
+            StakeAuthorization::new(
+                vec![validator(VALIDATOR), validator(OTHER_VALIDATOR)],
+                vec![],
+                AuthorizationType::Redelegate,
+                Some("10uatom".parse().unwrap_test()),
+            )
+            .unwrap_test(),
+        );
+
+        let any: Any = authorization.clone().into();
+        assert_eq!(any.type_url, STAKE_AUTHORIZATION_TYPE_URL);
+        assert_eq!(Authorization::try_from(any).unwrap_test(), authorization);
+
+        let json = serde_json::to_value(&authorization).unwrap_test();
+        assert_eq!(
+            serde_json::from_value::<Authorization>(json).unwrap_test(),
+            authorization
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    tendermint::types::time::timestamp::Timestamp,
+    types::address::AccAddress,
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use super::Authorization;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct GrantRaw {
+    #[prost(message, optional, tag = "1")]
+    pub authorization: Option<Any>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<Timestamp>,
+}
+
+impl From<Grant> for GrantRaw {
+    fn from(
+        Grant {
+            authorization,
+            expiration,
+        }: Grant,
+    ) -> Self {
+        Self {
+            authorization: Some(authorization.into()),
+            expiration,
+        }
+    }
+}
+
+/// Grant gives permissions to execute the provide method with expiration time.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Grant {
+    /// authorization can be any of generic, send and stake authorization.
+    pub authorization: Authorization,
+    /// expiration specifies an optional time when this grant expires
+    pub expiration: Option<Timestamp>,
+}
+
+impl TryFrom<GrantRaw> for Grant {
+    type Error = CoreError;
+
+    fn try_from(
+        GrantRaw {
+            authorization,
+            expiration,
+        }: GrantRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            authorization: authorization
+                .ok_or(CoreError::MissingField("authorization".into()))?
+                .try_into()?,
+            expiration,
+        })
+    }
+}
+
+impl Protobuf<GrantRaw> for Grant {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct GrantAuthorizationRaw {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub authorization: Option<Any>,
+    #[prost(message, optional, tag = "4")]
+    pub expiration: Option<Timestamp>,
+}
+
+impl From<GrantAuthorization> for GrantAuthorizationRaw {
+    fn from(
+        GrantAuthorization {
+            granter,
+            grantee,
+            authorization,
+            expiration,
+        }: GrantAuthorization,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            authorization: Some(authorization.into()),
+            expiration,
+        }
+    }
+}
+
+/// GrantAuthorization extends a grant with both the addresses of the grantee and granter.
+/// It is stored in the KVStore to record a grant with full context.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GrantAuthorization {
+    /// granter is the address of the user granting the authorization.
+    pub granter: AccAddress,
+    /// grantee is the address of the user being granted the authorization.
+    pub grantee: AccAddress,
+    /// authorization can be any of generic, send and stake authorization.
+    pub authorization: Authorization,
+    /// expiration specifies an optional time when this grant expires
+    pub expiration: Option<Timestamp>,
+}
+
+impl GrantAuthorization {
+    pub fn is_expired(&self, time: &Timestamp) -> bool {
+        self.expiration
+            .as_ref()
+            .is_some_and(|expiration| expiration < time)
+    }
+}
+
+impl From<GrantAuthorization> for Grant {
+    fn from(
+        GrantAuthorization {
+            authorization,
+            expiration,
+            ..
+        }: GrantAuthorization,
+    ) -> Self {
+        Self {
+            authorization,
+            expiration,
+        }
+    }
+}
+
+impl TryFrom<GrantAuthorizationRaw> for GrantAuthorization {
+    type Error = CoreError;
+
+    fn try_from(
+        GrantAuthorizationRaw {
+            granter,
+            grantee,
+            authorization,
+            expiration,
+        }: GrantAuthorizationRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: AccAddress::from_bech32(&granter)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            grantee: AccAddress::from_bech32(&grantee)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            authorization: authorization
+                .ok_or(CoreError::MissingField("authorization".into()))?
+                .try_into()?,
+            expiration,
+        })
+    }
+}
+
+impl Protobuf<GrantAuthorizationRaw> for GrantAuthorization {}
@@ -0,0 +1,8 @@
+mod authorization;
+mod grant;
+pub mod query;
+mod tx;
+
+pub use authorization::*;
+pub use grant::*;
+pub use tx::*;
@@ -0,0 +1,153 @@
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    derive::{Protobuf, Query, Raw},
+    types::{
+        address::AccAddress,
+        pagination::{request::PaginationRequest, response::PaginationResponse},
+    },
+};
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::{Grant, GrantAuthorization, GrantAuthorizationRaw, GrantRaw};
+
+// =====
+// Requests
+// =====
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryGrantsRequestRaw {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(string, tag = "3")]
+    pub msg_type_url: String,
+    #[prost(message, optional, tag = "4")]
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<QueryGrantsRequest> for QueryGrantsRequestRaw {
+    fn from(
+        QueryGrantsRequest {
+            granter,
+            grantee,
+            msg_type_url,
+            pagination,
+        }: QueryGrantsRequest,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            msg_type_url: msg_type_url.unwrap_or_default(),
+            pagination: Some(pagination.into()),
+        }
+    }
+}
+
+/// QueryGrantsRequest is the request type for the Query/Grants RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Query)]
+#[query(url = "/cosmos.authz.v1beta1.Query/Grants")]
+pub struct QueryGrantsRequest {
+    pub granter: AccAddress,
+    pub grantee: AccAddress,
+    /// msg_type_url, when set, will query only grants matching given msg type.
+    pub msg_type_url: Option<String>,
+    /// pagination defines an optional pagination for the request.
+    pub pagination: PaginationRequest,
+}
+
+impl TryFrom<QueryGrantsRequestRaw> for QueryGrantsRequest {
+    type Error = CoreError;
+
+    fn try_from(
+        QueryGrantsRequestRaw {
+            granter,
+            grantee,
+            msg_type_url,
+            pagination,
+        }: QueryGrantsRequestRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: AccAddress::from_bech32(&granter)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            grantee: AccAddress::from_bech32(&grantee)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            msg_type_url: match msg_type_url.is_empty() {
+                true => None,
+                false => Some(msg_type_url),
+            },
+            pagination: pagination.unwrap_or_default().into(),
+        })
+    }
+}
+
+impl Protobuf<QueryGrantsRequestRaw> for QueryGrantsRequest {}
+
+/// QueryGranterGrantsRequest is the request type for the Query/GranterGrants RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf, Query)]
+#[query(url = "/cosmos.authz.v1beta1.Query/GranterGrants")]
+pub struct QueryGranterGrantsRequest {
+    #[raw(raw = String, kind(string))]
+    pub granter: AccAddress,
+    /// pagination defines an optional pagination for the request.
+    #[proto(optional)]
+    #[raw(kind(message), optional, raw = PageRequest)]
+    pub pagination: PaginationRequest,
+}
+
+/// QueryGranteeGrantsRequest is the request type for the Query/GranteeGrants RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf, Query)]
+#[query(url = "/cosmos.authz.v1beta1.Query/GranteeGrants")]
+pub struct QueryGranteeGrantsRequest {
+    #[raw(raw = String, kind(string))]
+    pub grantee: AccAddress,
+    /// pagination defines an optional pagination for the request.
+    #[proto(optional)]
+    #[raw(kind(message), optional, raw = PageRequest)]
+    pub pagination: PaginationRequest,
+}
+
+// =====
+// Responses
+// =====
+
+/// QueryGrantsResponse is the response type for the Query/Grants RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Query, Raw, Protobuf)]
+pub struct QueryGrantsResponse {
+    /// grants is a list of grants granted for the grantee by the granter.
+    #[raw(kind(message), raw = GrantRaw, repeated)]
+    #[proto(repeated)]
+    pub grants: Vec<Grant>,
+    /// pagination defines an pagination for the response.
+    #[raw(kind(message), raw = PageResponse, optional)]
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
+
+/// QueryGranterGrantsResponse is the response type for the Query/GranterGrants RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Query, Raw, Protobuf)]
+pub struct QueryGranterGrantsResponse {
+    /// grants is a list of grants granted by the granter.
+    #[raw(kind(message), raw = GrantAuthorizationRaw, repeated)]
+    #[proto(repeated)]
+    pub grants: Vec<GrantAuthorization>,
+    /// pagination defines an pagination for the response.
+    #[raw(kind(message), raw = PageResponse, optional)]
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
+
+/// QueryGranteeGrantsResponse is the response type for the Query/GranteeGrants RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Query, Raw, Protobuf)]
+pub struct QueryGranteeGrantsResponse {
+    /// grants is a list of grants granted to the grantee.
+    #[raw(kind(message), raw = GrantAuthorizationRaw, repeated)]
+    #[proto(repeated)]
+    pub grants: Vec<GrantAuthorization>,
+    /// pagination defines an pagination for the response.
+    #[raw(kind(message), raw = PageResponse, optional)]
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
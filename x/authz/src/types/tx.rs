@@ -0,0 +1,213 @@
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    derive::AppMessage,
+    types::address::AccAddress,
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use super::{Grant, GrantRaw};
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgGrantRaw {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub grant: Option<GrantRaw>,
+}
+
+impl From<MsgGrant> for MsgGrantRaw {
+    fn from(
+        MsgGrant {
+            granter,
+            grantee,
+            grant,
+        }: MsgGrant,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            grant: Some(grant.into()),
+        }
+    }
+}
+
+/// MsgGrant is a request type for Grant method. It declares authorization to the grantee
+/// on behalf of the granter with the provided expiration time.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(
+    url = "/cosmos.authz.v1beta1.MsgGrant",
+    amino_url = "cosmos-sdk/MsgGrant"
+)]
+pub struct MsgGrant {
+    /// granter is the address of the user granting the authorization.
+    #[msg(signer)]
+    pub granter: AccAddress,
+    /// grantee is the address of the user being granted the authorization.
+    pub grantee: AccAddress,
+    pub grant: Grant,
+}
+
+impl TryFrom<MsgGrantRaw> for MsgGrant {
+    type Error = CoreError;
+
+    fn try_from(
+        MsgGrantRaw {
+            granter,
+            grantee,
+            grant,
+        }: MsgGrantRaw,
+    ) -> Result<Self, Self::Error> {
+        let granter = AccAddress::from_bech32(&granter)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+        let grantee = AccAddress::from_bech32(&grantee)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+
+        if granter == grantee {
+            return Err(CoreError::DecodeGeneral(
+                "granter and grantee cannot be same".into(),
+            ));
+        }
+
+        Ok(Self {
+            granter,
+            grantee,
+            grant: grant
+                .ok_or(CoreError::MissingField("grant".into()))?
+                .try_into()?,
+        })
+    }
+}
+
+impl Protobuf<MsgGrantRaw> for MsgGrant {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgRevokeRaw {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(string, tag = "3")]
+    pub msg_type_url: String,
+}
+
+impl From<MsgRevoke> for MsgRevokeRaw {
+    fn from(
+        MsgRevoke {
+            granter,
+            grantee,
+            msg_type_url,
+        }: MsgRevoke,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            msg_type_url,
+        }
+    }
+}
+
+/// MsgRevoke revokes any authorization with the provided message type url on the granter's
+/// account with the provided grantee.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(
+    url = "/cosmos.authz.v1beta1.MsgRevoke",
+    amino_url = "cosmos-sdk/MsgRevoke"
+)]
+pub struct MsgRevoke {
+    /// granter is the address of the user granting the authorization.
+    #[msg(signer)]
+    pub granter: AccAddress,
+    /// grantee is the address of the user being granted the authorization.
+    pub grantee: AccAddress,
+    /// msg_type_url is the type url of the message the revoked authorization allows to execute.
+    pub msg_type_url: String,
+}
+
+impl TryFrom<MsgRevokeRaw> for MsgRevoke {
+    type Error = CoreError;
+
+    fn try_from(
+        MsgRevokeRaw {
+            granter,
+            grantee,
+            msg_type_url,
+        }: MsgRevokeRaw,
+    ) -> Result<Self, Self::Error> {
+        let granter = AccAddress::from_bech32(&granter)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+        let grantee = AccAddress::from_bech32(&grantee)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+
+        if granter == grantee {
+            return Err(CoreError::DecodeGeneral(
+                "granter and grantee cannot be same".into(),
+            ));
+        }
+
+        if msg_type_url.is_empty() {
+            return Err(CoreError::MissingField("msg_type_url".into()));
+        }
+
+        Ok(Self {
+            granter,
+            grantee,
+            msg_type_url,
+        })
+    }
+}
+
+impl Protobuf<MsgRevokeRaw> for MsgRevoke {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgExecRaw {
+    #[prost(string, tag = "1")]
+    pub grantee: String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: Vec<Any>,
+}
+
+impl From<MsgExec> for MsgExecRaw {
+    fn from(MsgExec { grantee, msgs }: MsgExec) -> Self {
+        Self {
+            grantee: grantee.into(),
+            msgs,
+        }
+    }
+}
+
+/// MsgExec attempts to execute the provided messages using authorizations granted to the
+/// grantee. Each message should have only one signer corresponding to the granter of the
+/// authorization.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(
+    url = "/cosmos.authz.v1beta1.MsgExec",
+    amino_url = "cosmos-sdk/MsgExec"
+)]
+pub struct MsgExec {
+    #[msg(signer)]
+    pub grantee: AccAddress,
+    /// msgs are the messages executed on behalf of the granters. They are decoded into the
+    /// messages of the application when executed.
+    pub msgs: Vec<Any>,
+}
+
+impl TryFrom<MsgExecRaw> for MsgExec {
+    type Error = CoreError;
+
+    fn try_from(MsgExecRaw { grantee, msgs }: MsgExecRaw) -> Result<Self, Self::Error> {
+        if msgs.is_empty() {
+            return Err(CoreError::DecodeGeneral("messages cannot be empty".into()));
+        }
+
+        Ok(Self {
+            grantee: AccAddress::from_bech32(&grantee)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            msgs,
+        })
+    }
+}
+
+impl Protobuf<MsgExecRaw> for MsgExec {}
@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 
 use gears::application::handlers::node::{ABCIHandler, ModuleInfo, TxError};
 use gears::baseapp::errors::QueryError;
+use gears::baseapp::mode::RunTxMode;
 use gears::baseapp::QueryRequest;
 use gears::context::{init::InitContext, query::QueryContext, tx::TxContext};
 use gears::core::Protobuf;
@@ -131,7 +132,7 @@ impl<
         &self,
         _: &mut TxContext<'_, DB, Self::StoreKey>,
         _: &gears::types::tx::raw::TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -144,7 +145,12 @@ impl<
         let result = match msg {
             Message::Send(msg_send) => self
                 .keeper
-                .send_coins_from_account_to_account(ctx, msg_send),
+                .is_send_enabled_coins(ctx, msg_send.amount.inner())
+                .and_then(|_| {
+                    self.keeper
+                        .send_coins_from_account_to_account(ctx, msg_send)
+                }),
+            Message::MultiSend(msg) => self.keeper.multi_send(ctx, msg),
         };
 
         result.map_err(|e| Into::<BankTxError>::into(e).into::<MI>())
@@ -165,6 +171,14 @@ impl<
         Vec::new()
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        _for_zero_height: bool,
+    ) -> Self::Genesis {
+        self.keeper.export_genesis(ctx)
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -236,14 +250,17 @@ impl<
     ) -> QuerySpendableBalancesResponse {
         let (spendable, pagination_result) = self
             .keeper
-            .spendable_coins(ctx, &address, pagination.map(Pagination::from))
-            .map(|(spendable, _, pag)| {
-                (spendable.map(Vec::from), pag.map(PaginationResponse::from))
-            })
+            .spendable_coins(
+                ctx,
+                &address,
+                &ctx.get_time(),
+                pagination.map(Pagination::from),
+            )
+            .map(|(spendable, _, pag)| (spendable, pag.map(PaginationResponse::from)))
             .unwrap_or_default();
 
         QuerySpendableBalancesResponse {
-            balances: spendable.unwrap_or_default(),
+            balances: spendable,
             pagination: pagination_result,
         }
     }
@@ -3,7 +3,11 @@ use clap::{Args, Subcommand};
 use gears::types::{
     address::AccAddress,
     base::{coin::UnsignedCoin, coins::UnsignedCoins},
-    msg::send::MsgSend,
+    msg::{
+        multi_send::{Input, MsgMultiSend, Output},
+        send::MsgSend,
+    },
+    uint::Uint256,
 };
 
 use crate::Message as BankMessage;
@@ -23,6 +27,17 @@ pub enum BankCommands {
         /// amount
         amount: UnsignedCoin,
     },
+    /// Send funds from one account to two or more accounts
+    MultiSend {
+        /// amount sent to every address
+        amount: UnsignedCoins,
+        /// to addresses
+        #[arg(required = true, num_args = 2..)]
+        to_addresses: Vec<AccAddress>,
+        /// Send the equally split amount to each address instead of the full amount
+        #[arg(long, default_value_t = false)]
+        split: bool,
+    },
 }
 
 pub fn run_bank_tx_command(args: BankTxCli, from_address: AccAddress) -> Result<BankMessage> {
@@ -32,5 +47,55 @@ pub fn run_bank_tx_command(args: BankTxCli, from_address: AccAddress) -> Result<
             to_address: to_address.clone(),
             amount: UnsignedCoins::new(vec![amount.clone()])?,
         })),
+        BankCommands::MultiSend {
+            amount,
+            to_addresses,
+            split,
+        } => {
+            let count = Uint256::from(to_addresses.len() as u64);
+
+            let output_coins = match split {
+                true => UnsignedCoins::new(
+                    amount
+                        .inner()
+                        .iter()
+                        .map(|coin| UnsignedCoin {
+                            denom: coin.denom.clone(),
+                            amount: coin.amount / count,
+                        })
+                        .collect::<Vec<_>>(),
+                )?,
+                false => amount.clone(),
+            };
+
+            let input_coins = UnsignedCoins::new(
+                output_coins
+                    .inner()
+                    .iter()
+                    .map(|coin| {
+                        Ok(UnsignedCoin {
+                            denom: coin.denom.clone(),
+                            amount: coin.amount.checked_mul(count)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            )?;
+
+            let outputs = to_addresses
+                .iter()
+                .map(|address| Output {
+                    address: address.clone(),
+                    coins: output_coins.clone(),
+                })
+                .collect();
+
+            Ok(BankMessage::MultiSend(MsgMultiSend::new(
+                vec![Input {
+                    address: from_address,
+                    coins: input_coins,
+                }],
+                outputs,
+            )?))
+        }
     }
 }
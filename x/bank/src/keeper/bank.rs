@@ -1,5 +1,3 @@
-use std::{collections::HashSet, sync::OnceLock};
-
 use super::*;
 
 impl<
@@ -110,7 +108,7 @@ impl<
         // Simple check that we don't try to send coins to account that actually a module.
         // Cosmos uses set with accounts. For details check:
         // https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/bank/keeper/keeper.go#L316-L318
-        if blocked_addr::<M>().contains(address) {
+        if self.blocked_addr(address) {
             Err(BankKeeperError::Blocked(address.to_owned()))?
         }
 
@@ -121,12 +119,8 @@ impl<
                 to_address: address.clone(),
                 amount,
             },
-        )
-    }
-}
+        )?;
 
-fn blocked_addr<M: Module + strum::IntoEnumIterator>() -> &'static HashSet<AccAddress> {
-    static ADDR: OnceLock<HashSet<AccAddress>> = OnceLock::new();
-
-    ADDR.get_or_init(|| M::iter().map(|this| this.address()).collect::<HashSet<_>>())
+        Ok(())
+    }
 }
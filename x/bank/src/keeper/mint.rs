@@ -0,0 +1,66 @@
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        M: Module + strum::IntoEnumIterator,
+    > MintingBankKeeper<SK, M> for Keeper<SK, PSK, AK, M>
+{
+    fn supply<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        denom: &Denom,
+    ) -> Result<UnsignedCoin, GasStoreErrors> {
+        let supply = Keeper::supply(self, ctx, denom)?.unwrap_or(UnsignedCoin {
+            denom: denom.to_owned(),
+            amount: Uint256::zero(),
+        });
+
+        Ok(supply)
+    }
+
+    fn mint_coins<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        module: &M,
+        amount: UnsignedCoins,
+    ) -> Result<(), BankKeeperError> {
+        if !module.permissions().iter().any(|p| p == "minter") {
+            return Err(BankKeeperError::Permission(format!(
+                "module account {} does not have permissions to mint tokens",
+                module.name()
+            )));
+        }
+
+        self.auth_keeper
+            .check_create_new_module_account(ctx, module)?;
+
+        let module_acc_addr = module.address();
+        self.add_coins(ctx, &module_acc_addr, amount.inner().clone())?;
+
+        for coin in amount.inner() {
+            let mut supply = MintingBankKeeper::supply(self, ctx, &coin.denom)?;
+            supply.amount += coin.amount;
+            self.set_supply(ctx, supply)?;
+        }
+
+        ctx.push_event(Event::new(
+            "mint",
+            vec![
+                EventAttribute::new(
+                    "minter".as_bytes().to_owned().into(),
+                    String::from(module_acc_addr).into(),
+                    false,
+                ),
+                EventAttribute::new(
+                    "amount".as_bytes().to_owned().into(),
+                    SimpleCoins::new(amount.into_inner()).to_string_bytes(),
+                    false,
+                ),
+            ],
+        ));
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Balance, BankParams, BankParamsKeeper};
+use crate::{Balance, BankParams, BankParamsKeeper, GenesisState};
 use bytes::Bytes;
 use gears::application::keepers::params::ParamsKeeper;
 use gears::context::{init::InitContext, query::QueryContext};
@@ -12,10 +12,12 @@ use gears::store::database::prefix::PrefixDB;
 use gears::store::database::Database;
 use gears::store::StoreKey;
 use gears::tendermint::types::proto::event::{Event, EventAttribute};
+use gears::tendermint::types::time::timestamp::Timestamp;
 use gears::types::address::AccAddress;
 use gears::types::base::coin::UnsignedCoin;
 use gears::types::base::coins::{SimpleCoins, UnsignedCoins};
 use gears::types::denom::Denom;
+use gears::types::msg::multi_send::{Input, MsgMultiSend, Output};
 use gears::types::msg::send::MsgSend;
 use gears::types::store::gas::errors::GasStoreErrors;
 use gears::types::store::prefix::mutable::PrefixStoreMut;
@@ -25,16 +27,25 @@ use gears::x::errors::{AccountNotFound, BankCoinsError, BankKeeperError, Insuffi
 use gears::x::keepers::auth::AuthKeeper;
 use gears::x::keepers::bank::{BalancesKeeper, BankKeeper};
 use gears::x::keepers::gov::GovernanceBankKeeper;
+use gears::x::keepers::mint::MintingBankKeeper;
 use gears::x::keepers::staking::StakingBankKeeper;
+use gears::x::keepers::vesting::VestingBankKeeper;
 use gears::x::module::Module;
+use std::collections::HashSet;
 use std::marker::PhantomData;
 use std::ops::SubAssign;
+use std::sync::Arc;
 use std::{collections::HashMap, str::FromStr};
 
 pub mod balances;
 pub mod bank;
 pub mod gov;
+pub mod mint;
+mod restriction;
 pub mod staking;
+pub mod vesting;
+
+pub use restriction::*;
 
 const SUPPLY_KEY: [u8; 1] = [0];
 const ADDRESS_BALANCES_STORE_PREFIX: [u8; 1] = [2];
@@ -67,6 +78,8 @@ pub struct Keeper<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M
     store_key: SK,
     bank_params_keeper: BankParamsKeeper<PSK>,
     auth_keeper: AK,
+    blocked_addrs: HashSet<AccAddress>,
+    send_restriction: Option<Arc<dyn SendRestriction>>,
     module_key: PhantomData<M>,
 }
 
@@ -77,7 +90,11 @@ impl<
         M: Module,
     > Keeper<SK, PSK, AK, M>
 {
-    pub fn new(store_key: SK, params_subspace_key: PSK, auth_keeper: AK) -> Self {
+    /// Creates a new keeper. By default all module accounts are blocked from receiving funds.
+    pub fn new(store_key: SK, params_subspace_key: PSK, auth_keeper: AK) -> Self
+    where
+        M: strum::IntoEnumIterator,
+    {
         let bank_params_keeper = BankParamsKeeper {
             params_subspace_key,
         };
@@ -85,10 +102,44 @@ impl<
             store_key,
             bank_params_keeper,
             auth_keeper,
+            blocked_addrs: M::iter().map(|module| module.address()).collect(),
+            send_restriction: None,
             module_key: PhantomData,
         }
     }
 
+    /// Allows the accounts of the given modules to receive funds
+    pub fn with_allowed_modules(mut self, modules: impl IntoIterator<Item = M>) -> Self {
+        for module in modules {
+            self.blocked_addrs.remove(&module.address());
+        }
+
+        self
+    }
+
+    /// Blocks additional addresses from receiving funds
+    pub fn with_blocked_addrs(mut self, addrs: impl IntoIterator<Item = AccAddress>) -> Self {
+        self.blocked_addrs.extend(addrs);
+
+        self
+    }
+
+    /// Sets a hook which is called on every transfer and is able to veto or redirect it
+    pub fn with_send_restriction(mut self, restriction: impl SendRestriction + 'static) -> Self {
+        self.send_restriction = Some(Arc::new(restriction));
+
+        self
+    }
+
+    /// blocked_addr checks if a given address is restricted from receiving funds
+    pub fn blocked_addr(&self, addr: &AccAddress) -> bool {
+        self.blocked_addrs.contains(addr)
+    }
+
+    pub fn blocked_addrs(&self) -> &HashSet<AccAddress> {
+        &self.blocked_addrs
+    }
+
     pub fn init_genesis<DB: Database>(
         &self,
         ctx: &mut InitContext<'_, DB, SK>,
@@ -131,6 +182,45 @@ impl<
         }
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let bank_store = ctx.kv_store(&self.store_key);
+
+        let mut balances: Vec<(AccAddress, Vec<UnsignedCoin>)> = Vec::new();
+        for (key, coin) in bank_store
+            .prefix_store(ADDRESS_BALANCES_STORE_PREFIX)
+            .into_range(..)
+        {
+            // the key is a length prefixed address followed by the denom
+            let address = key
+                .split_first()
+                .and_then(|(len, rest)| rest.get(..*len as usize))
+                .and_then(|address| AccAddress::try_from(address).ok())
+                .unwrap_or_corrupt();
+            let coin = UnsignedCoin::decode::<Bytes>(coin.into_owned().into())
+                .ok()
+                .unwrap_or_corrupt();
+
+            match balances.last_mut() {
+                Some((last, coins)) if *last == address => coins.push(coin),
+                _ => balances.push((address, vec![coin])),
+            }
+        }
+
+        let balances = balances
+            .into_iter()
+            .map(|(address, coins)| Balance {
+                address,
+                coins: UnsignedCoins::new(coins).ok().unwrap_or_corrupt(),
+            })
+            .collect();
+
+        GenesisState {
+            balances,
+            params: self.params(ctx),
+            denom_metadata: self.denoms_metadata(ctx, None).1,
+        }
+    }
+
     pub fn params<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> BankParams {
         self.bank_params_keeper.get(ctx)
     }
@@ -243,23 +333,127 @@ impl<
         (p_result, store)
     }
 
+    /// is_send_enabled_coins checks the coins provided and returns an error if any of
+    /// the coins are not configured for sending
+    pub fn is_send_enabled_coins<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        coins: &[UnsignedCoin],
+    ) -> Result<(), BankKeeperError> {
+        let params = self.bank_params_keeper.try_get(ctx)?;
+
+        match coins
+            .iter()
+            .find(|coin| !params.send_enabled_denom(&coin.denom))
+        {
+            Some(coin) => Err(BankKeeperError::SendDisabled(coin.denom.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// multi_send checks that the coins of the input can be sent and transfers them to the
+    /// outputs
+    pub fn multi_send<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        msg: &MsgMultiSend,
+    ) -> Result<(), BankKeeperError> {
+        for input in &msg.inputs {
+            self.is_send_enabled_coins(ctx, input.coins.inner())?;
+        }
+
+        self.input_output_coins(ctx, &msg.inputs, &msg.outputs)
+    }
+
+    /// input_output_coins performs multi-send functionality. It accepts a series of
+    /// inputs that correspond to a series of outputs. The sum of the inputs is checked
+    /// to be equal to the sum of the outputs on the creation of the message. Blocked
+    /// addresses can't be outputs.
+    pub fn input_output_coins<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        inputs: &[Input],
+        outputs: &[Output],
+    ) -> Result<(), BankKeeperError> {
+        if let Some(output) = outputs
+            .iter()
+            .find(|output| self.blocked_addr(&output.address))
+        {
+            Err(BankKeeperError::Blocked(output.address.clone()))?
+        }
+
+        for input in inputs {
+            self.sub_unlocked_coins(ctx, &input.address, &input.coins)?;
+
+            ctx.push_event(Event::new(
+                "message",
+                [EventAttribute::new(
+                    "sender".into(),
+                    String::from(input.address.clone()).into(),
+                    true,
+                )],
+            ));
+        }
+
+        for output in outputs {
+            let to_address = match (&self.send_restriction, inputs) {
+                // restriction could be applied only to a single sender
+                (Some(restriction), [input]) => {
+                    restriction.restrict(&input.address, output.address.clone(), &output.coins)?
+                }
+                _ => output.address.clone(),
+            };
+
+            self.add_coins(ctx, &to_address, output.coins.inner().clone())?;
+
+            ctx.push_event(Event::new(
+                "transfer",
+                [
+                    EventAttribute::new(
+                        "recipient".into(),
+                        String::from(to_address.clone()).into(),
+                        true,
+                    ),
+                    EventAttribute::new(
+                        "amount".into(),
+                        SimpleCoins::new(output.coins.clone()).to_string_bytes(),
+                        true,
+                    ),
+                ],
+            ));
+
+            // Create account if recipient does not exist
+            if !self.auth_keeper.has_account(ctx, &to_address)? {
+                self.auth_keeper.create_new_base_account(ctx, &to_address)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Moves coins from one account to another. Blocked addresses can't receive them.
     pub fn send_coins_from_account_to_account<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         msg: &MsgSend,
     ) -> Result<(), BankKeeperError> {
-        self.send_coins(ctx, msg.clone())?;
+        if self.blocked_addr(&msg.to_address) {
+            Err(BankKeeperError::Blocked(msg.to_address.clone()))?
+        }
+
+        let to_address = self.send_coins(ctx, msg.clone())?;
 
         // Create account if recipient does not exist
 
-        if !self.auth_keeper.has_account(ctx, &msg.to_address)? {
-            self.auth_keeper
-                .create_new_base_account(ctx, &msg.to_address)?;
+        if !self.auth_keeper.has_account(ctx, &to_address)? {
+            self.auth_keeper.create_new_base_account(ctx, &to_address)?;
         };
 
         Ok(())
     }
 
+    /// Moves coins between accounts and returns the address which actually received them
+    /// as the send restriction may redirect the transfer.
     fn send_coins<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
@@ -268,9 +462,16 @@ impl<
             to_address,
             amount,
         }: MsgSend,
-    ) -> Result<(), BankKeeperError> {
+    ) -> Result<AccAddress, BankKeeperError> {
+        let to_address = match &self.send_restriction {
+            Some(restriction) => restriction.restrict(&from_address, to_address, &amount)?,
+            None => to_address,
+        };
+
         let mut events = vec![];
 
+        let locked_coins = self.locked_coins(ctx, &from_address, &ctx.get_time())?;
+
         for send_coin in amount.inner() {
             let mut from_account_store = self.address_balances_store(ctx, &from_address);
             let from_balance = from_account_store
@@ -285,10 +486,14 @@ impl<
                     .ok()
                     .unwrap_or_corrupt();
 
-            if from_balance.amount < send_coin.amount {
+            let spendable = from_balance
+                .amount
+                .saturating_sub(amount_of(&locked_coins, &send_coin.denom));
+
+            if spendable < send_coin.amount {
                 Err(InsufficientFundsError::RequiredActual {
                     required: send_coin.amount,
-                    actual: from_balance.amount,
+                    actual: spendable,
                 })?;
             }
 
@@ -346,7 +551,7 @@ impl<
 
         ctx.append_events(events);
 
-        Ok(())
+        Ok(to_address)
     }
 
     pub fn set_supply<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -537,17 +742,12 @@ impl<
         addr: &AccAddress,
         amount: &UnsignedCoins,
     ) -> Result<(), BankKeeperError> {
-        let locked_coins = self.locked_coins(ctx, addr)?;
-
-        let amount_of = |coins: &Vec<UnsignedCoin>, denom: &Denom| -> Uint256 {
-            let coins = coins.iter().find(|c| c.denom == *denom);
-            coins.map(|c| c.amount).unwrap_or(Uint256::zero())
-        };
+        let locked_coins = self.locked_coins(ctx, addr, &ctx.get_time())?;
 
         for coin in amount.inner() {
             if let Some(mut balance) = self.balance(ctx, addr, &coin.denom)? {
                 let locked_amount = amount_of(&locked_coins, &coin.denom);
-                let spendable = balance.amount - locked_amount;
+                let spendable = balance.amount.saturating_sub(locked_amount);
 
                 if spendable.checked_sub(coin.amount).is_err() {
                     Err(BankCoinsError::Amount {
@@ -589,17 +789,15 @@ impl<
         &self,
         ctx: &CTX,
         addr: &AccAddress,
+        time: &Timestamp,
     ) -> Result<Vec<UnsignedCoin>, BankKeeperError> {
-        if let Some(_acc) = self.auth_keeper.get_account(ctx, addr)? {
-            //     vacc, ok := acc.(vestexported.VestingAccount)
-            //     if ok {
-            //         return vacc.LockedCoins(ctx.BlockTime())
-            //     }
-            // TODO: logic with vesting accounts
-            Ok(vec![])
-        } else {
-            Ok(vec![])
-        }
+        let locked = self
+            .auth_keeper
+            .get_account(ctx, addr)?
+            .and_then(|acc| acc.vesting_account().map(|vacc| vacc.locked_coins(time)))
+            .unwrap_or_default();
+
+        Ok(locked)
     }
 
     /// track_delegation tracks the delegation of the given account if it is a vesting account
@@ -607,16 +805,15 @@ impl<
         &self,
         ctx: &mut CTX,
         addr: &AccAddress,
-        _balance: &UnsignedCoins,
-        _amount: &UnsignedCoins,
+        balance: &UnsignedCoins,
+        amount: &UnsignedCoins,
     ) -> Result<(), BankKeeperError> {
-        if let Some(_acc) = self.auth_keeper.get_account(ctx, addr)? {
-            // TODO: logic with vesting accounts
-            //     vacc, ok := acc.(vestexported.VestingAccount)
-            //     if ok {
-            //         vacc.TrackDelegation(ctx.BlockHeader().Time, balance, amt)
-            //         k.ak.SetAccount(ctx, acc)
-            //     }
+        if let Some(mut acc) = self.auth_keeper.get_account(ctx, addr)? {
+            if let Some(vacc) = acc.vesting_account_mut() {
+                vacc.track_delegation(&ctx.get_time(), balance.inner(), amount.inner())?;
+                self.auth_keeper.set_account(ctx, acc)?;
+            }
+
             Ok(())
         } else {
             Err(AccountNotFound::from(addr.to_owned()))?
@@ -628,47 +825,316 @@ impl<
         &self,
         ctx: &mut CTX,
         addr: &AccAddress,
-        _amount: &UnsignedCoins,
+        amount: &UnsignedCoins,
     ) -> Result<(), BankKeeperError> {
-        if let Some(_acc) = self.auth_keeper.get_account(ctx, addr)? {
-            // TODO: logic with vesting accounts
-            //     vacc, ok := acc.(vestexported.VestingAccount)
-            //     if ok {
-            //         vacc.TrackUndelegation(amt)
-            //         k.ak.SetAccount(ctx, acc)
-            //     }
+        if let Some(mut acc) = self.auth_keeper.get_account(ctx, addr)? {
+            if let Some(vacc) = acc.vesting_account_mut() {
+                vacc.track_undelegation(amount.inner())?;
+                self.auth_keeper.set_account(ctx, acc)?;
+            }
+
             Ok(())
         } else {
             Err(AccountNotFound::from(addr.to_owned()))?
         }
     }
 
-    /// returns the coins the given address can spend alongside the total amount of coins it holds.
-    /// It exists for gas efficiency, in order to avoid to have to get balance multiple times.
+    /// returns the coins the given address can spend at the given time alongside the total
+    /// amount of coins it holds. It exists for gas efficiency, in order to avoid to have
+    /// to get balance multiple times.
     pub fn spendable_coins<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
         ctx: &CTX,
         addr: &AccAddress,
+        time: &Timestamp,
         pagination: Option<Pagination>,
     ) -> Result<
         (
-            Option<UnsignedCoins>,
-            UnsignedCoins,
+            Vec<UnsignedCoin>,
+            Vec<UnsignedCoin>,
             Option<PaginationResult>,
         ),
         BankKeeperError,
     > {
         let (pagination, total) = self.balance_all(ctx, addr.clone(), pagination)?;
-        let locked = self.locked_coins(ctx, addr)?;
+        let locked = self.locked_coins(ctx, addr, time)?;
 
-        let total = UnsignedCoins::new(total)?;
-        let locked = UnsignedCoins::new(locked)?;
+        let spendable = total
+            .iter()
+            .map(|coin| UnsignedCoin {
+                denom: coin.denom.clone(),
+                amount: coin.amount.saturating_sub(amount_of(&locked, &coin.denom)),
+            })
+            .filter(|coin| !coin.amount.is_zero())
+            .collect();
 
-        match total.checked_sub(&locked) {
-            Ok(spendable) => Ok((Some(spendable), total, pagination)),
-            Err(_) => Ok((None, total, pagination)),
-        }
+        Ok((spendable, total, pagination))
     }
 }
 
+fn amount_of(coins: &[UnsignedCoin], denom: &Denom) -> Uint256 {
+    let coins = coins.iter().find(|c| c.denom == *denom);
+    coins.map(|c| c.amount).unwrap_or(Uint256::zero())
+}
+
 //TODO: copy tests across
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        baseapp::ConsensusParams,
+        derive::{ParamsKeys, StoreKeys},
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        utils::node::build_init_ctx,
+        x::keepers::mocks::auth::MockAuthKeeper,
+    };
+
+    use super::*;
+
+    const SENDER: &str = "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux";
+    const RECIPIENT: &str = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut";
+    const OTHER: &str = "cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs";
+
+    type TestKeeper = Keeper<SpaceKey, SubspaceKey, MockAuthKeeper, Modules>;
+
+    fn keeper() -> TestKeeper {
+        Keeper::new(
+            SpaceKey::Bank,
+            SubspaceKey::Bank,
+            MockAuthKeeper::former().form(),
+        )
+    }
+
+    fn address(address: &str) -> AccAddress {
+        AccAddress::from_bech32(address).unwrap_test()
+    }
+
+    fn coins(coins: &str) -> UnsignedCoins {
+        UnsignedCoins::new(vec![UnsignedCoin::from_str(coins).unwrap_test()]).unwrap_test()
+    }
+
+    fn uatom_balance<DB: Database, CTX: QueryableContext<DB, SpaceKey>>(
+        keeper: &TestKeeper,
+        ctx: &CTX,
+        addr: &str,
+    ) -> Option<UnsignedCoin> {
+        keeper
+            .balance(ctx, &address(addr), &Denom::from_str("uatom").unwrap_test())
+            .unwrap_test()
+    }
+
+    /// Runs the test with a context in which the sender holds 100uatom
+    fn with_funded_ctx(
+        keeper: &TestKeeper,
+        test: impl FnOnce(&mut InitContext<'_, MemDB, SpaceKey>),
+    ) {
+        let mut multi_store = ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper.init_genesis(
+            &mut ctx,
+            vec![Balance {
+                address: address(SENDER),
+                coins: coins("100uatom"),
+            }],
+            BankParams::default(),
+            vec![],
+        );
+
+        test(&mut ctx)
+    }
+
+    fn msg_send(to_address: AccAddress) -> MsgSend {
+        MsgSend {
+            from_address: address(SENDER),
+            to_address,
+            amount: coins("10uatom"),
+        }
+    }
+
+    fn msg_multi_send(outputs: &[&str]) -> MsgMultiSend {
+        MsgMultiSend {
+            inputs: vec![Input {
+                address: address(SENDER),
+                coins: coins(&format!("{}uatom", 10 * outputs.len())),
+            }],
+            outputs: outputs
+                .iter()
+                .map(|output| Output {
+                    address: address(output),
+                    coins: coins("10uatom"),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn module_accounts_are_blocked() {
+        let keeper = keeper();
+
+        with_funded_ctx(&keeper, |ctx| {
+            let module = Modules::FeeCollector.address();
+
+            assert!(matches!(
+                keeper.send_coins_from_account_to_account(ctx, &msg_send(module.clone())),
+                Err(BankKeeperError::Blocked(address)) if address == module
+            ));
+            assert!(matches!(
+                keeper.multi_send(ctx, &msg_multi_send(&[RECIPIENT, &module.to_string()])),
+                Err(BankKeeperError::Blocked(address)) if address == module
+            ));
+            assert_eq!(
+                uatom_balance(&keeper, ctx, SENDER),
+                Some(UnsignedCoin::from_str("100uatom").unwrap_test())
+            );
+        });
+    }
+
+    #[test]
+    fn allowed_modules_receive_funds() {
+        let keeper = keeper()
+            .with_allowed_modules([Modules::FeeCollector])
+            .with_blocked_addrs([address(OTHER)]);
+
+        with_funded_ctx(&keeper, |ctx| {
+            let module = Modules::FeeCollector.address();
+
+            keeper
+                .send_coins_from_account_to_account(ctx, &msg_send(module.clone()))
+                .unwrap_test();
+            assert_eq!(
+                keeper
+                    .balance(ctx, &module, &Denom::from_str("uatom").unwrap_test())
+                    .unwrap_test(),
+                Some(UnsignedCoin::from_str("10uatom").unwrap_test())
+            );
+
+            assert!(matches!(
+                keeper.send_coins_from_account_to_account(ctx, &msg_send(address(OTHER))),
+                Err(BankKeeperError::Blocked(_))
+            ));
+        });
+    }
+
+    #[derive(Debug)]
+    struct Veto;
+
+    impl SendRestriction for Veto {
+        fn restrict(
+            &self,
+            _from_address: &AccAddress,
+            to_address: AccAddress,
+            _amount: &UnsignedCoins,
+        ) -> Result<AccAddress, BankKeeperError> {
+            match to_address == address(OTHER) {
+                true => Err(BankKeeperError::Permission("vetoed".into())),
+                false => Ok(to_address),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct Redirect;
+
+    impl SendRestriction for Redirect {
+        fn restrict(
+            &self,
+            _from_address: &AccAddress,
+            _to_address: AccAddress,
+            _amount: &UnsignedCoins,
+        ) -> Result<AccAddress, BankKeeperError> {
+            Ok(address(OTHER))
+        }
+    }
+
+    #[test]
+    fn send_restriction_vetoes_transfer() {
+        let keeper = keeper().with_send_restriction(Veto);
+
+        with_funded_ctx(&keeper, |ctx| {
+            assert!(matches!(
+                keeper.send_coins_from_account_to_account(ctx, &msg_send(address(OTHER))),
+                Err(BankKeeperError::Permission(_))
+            ));
+            assert!(matches!(
+                keeper.multi_send(ctx, &msg_multi_send(&[OTHER])),
+                Err(BankKeeperError::Permission(_))
+            ));
+
+            keeper
+                .send_coins_from_account_to_account(ctx, &msg_send(address(RECIPIENT)))
+                .unwrap_test();
+            assert_eq!(
+                uatom_balance(&keeper, ctx, RECIPIENT),
+                Some(UnsignedCoin::from_str("10uatom").unwrap_test())
+            );
+        });
+    }
+
+    #[test]
+    fn send_restriction_redirects_transfer() {
+        let keeper = keeper().with_send_restriction(Redirect);
+
+        with_funded_ctx(&keeper, |ctx| {
+            keeper
+                .send_coins_from_account_to_account(ctx, &msg_send(address(RECIPIENT)))
+                .unwrap_test();
+            keeper
+                .multi_send(ctx, &msg_multi_send(&[RECIPIENT]))
+                .unwrap_test();
+
+            assert_eq!(uatom_balance(&keeper, ctx, RECIPIENT), None);
+            assert_eq!(
+                uatom_balance(&keeper, ctx, OTHER),
+                Some(UnsignedCoin::from_str("20uatom").unwrap_test())
+            );
+            assert_eq!(
+                uatom_balance(&keeper, ctx, SENDER),
+                Some(UnsignedCoin::from_str("80uatom").unwrap_test())
+            );
+        });
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+    #[skey(params = Params)]
+    enum SpaceKey {
+        #[skey(to_string = "bank")]
+        Bank,
+        #[skey(to_string = "params")]
+        Params,
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys)]
+    enum SubspaceKey {
+        #[pkey(to_string = "bank/")]
+        Bank,
+    }
+
+    #[derive(strum::EnumIter, Debug, Clone, PartialEq, Eq)]
+    enum Modules {
+        FeeCollector,
+    }
+
+    impl Module for Modules {
+        fn name(&self) -> String {
+            match self {
+                Modules::FeeCollector => "fee_collector".into(),
+            }
+        }
+
+        fn address(&self) -> AccAddress {
+            match self {
+                Modules::FeeCollector => {
+                    AccAddress::from_bech32("cosmos17xpfvakm2amg962yls6f84z3kell8c5lserqta")
+                        .expect("hard coded address is valid")
+                }
+            }
+        }
+
+        fn permissions(&self) -> Vec<String> {
+            match self {
+                Modules::FeeCollector => vec![],
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use gears::types::address::AccAddress;
+use gears::types::base::coins::UnsignedCoins;
+use gears::x::errors::BankKeeperError;
+
+/// SendRestriction is a hook which is called before any coins are moved from one
+/// account to another. Implementations may veto the transfer by returning an error
+/// or redirect it by returning a different recipient address.
+pub trait SendRestriction: std::fmt::Debug + Send + Sync {
+    fn restrict(
+        &self,
+        from_address: &AccAddress,
+        to_address: AccAddress,
+        amount: &UnsignedCoins,
+    ) -> Result<AccAddress, BankKeeperError>;
+}
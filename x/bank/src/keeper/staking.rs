@@ -23,7 +23,9 @@ impl<
             amount,
         };
 
-        self.send_coins(ctx, msg)
+        self.send_coins(ctx, msg)?;
+
+        Ok(())
     }
 
     fn undelegate_coins_from_module_to_account<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -0,0 +1,29 @@
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        M: Module + strum::IntoEnumIterator,
+    > VestingBankKeeper<SK, M> for Keeper<SK, PSK, AK, M>
+{
+    fn is_send_enabled_coins<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        coins: &[UnsignedCoin],
+    ) -> Result<(), BankKeeperError> {
+        Keeper::is_send_enabled_coins(self, ctx, coins)
+    }
+
+    fn blocked_addr(&self, addr: &AccAddress) -> bool {
+        Keeper::blocked_addr(self, addr)
+    }
+
+    fn send_coins_from_account_to_account<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        msg: &MsgSend,
+    ) -> Result<(), BankKeeperError> {
+        Keeper::send_coins_from_account_to_account(self, ctx, msg)
+    }
+}
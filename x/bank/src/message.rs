@@ -4,7 +4,10 @@ use gears::{
         handler::MetadataGetter,
         renderer::value_renderer::{RenderError, ValueRenderer},
     },
-    types::{msg::send::MsgSend, rendering::screen::Screen},
+    types::{
+        msg::{multi_send::MsgMultiSend, send::MsgSend},
+        rendering::screen::Screen,
+    },
 };
 use serde::Serialize;
 
@@ -14,12 +17,16 @@ pub enum Message {
     #[serde(rename = "/cosmos.bank.v1beta1.MsgSend")]
     #[msg(url(path = MsgSend::TYPE_URL))]
     Send(MsgSend),
+    #[serde(rename = "/cosmos.bank.v1beta1.MsgMultiSend")]
+    #[msg(url(path = MsgMultiSend::TYPE_URL))]
+    MultiSend(MsgMultiSend),
 }
 
 impl ValueRenderer for Message {
     fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
         match self {
             Message::Send(msg) => msg.format(get_metadata),
+            Message::MultiSend(msg) => msg.format(get_metadata),
         }
     }
 }
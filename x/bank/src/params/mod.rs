@@ -31,6 +31,18 @@ pub struct BankParams {
     pub default_send_enabled: bool,
 }
 
+impl BankParams {
+    /// Returns true if transfers of the denom are enabled. Denoms without a specific
+    /// entry use the default value.
+    pub fn send_enabled_denom(&self, denom: &Denom) -> bool {
+        self.send_enabled
+            .iter()
+            .find(|this| &this.denom == denom)
+            .map(|this| this.enabled)
+            .unwrap_or(self.default_send_enabled)
+    }
+}
+
 pub const DEFAULT_PARAMS: BankParams = BankParams {
     send_enabled: vec![],
     default_send_enabled: true,
@@ -1,10 +1,12 @@
 use crate::{
     errors::DistributionTxError, GenesisState, Keeper, Message, QueryCommunityPoolRequest,
-    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegatorParams,
-    QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+    QueryDelegatorParams, QueryDelegatorTotalRewardsResponse, QueryDelegatorValidatorsRequest,
+    QueryDelegatorValidatorsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
-    QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, QueryWithdrawAllRewardsRequest,
+    QueryValidatorSlashesRequest, QueryValidatorSlashesResponse,
 };
 use gears::{
     baseapp::errors::QueryError,
@@ -33,6 +35,9 @@ pub enum DistributionNodeQueryRequest {
     ValidatorCommission(QueryValidatorCommissionRequest),
     ValidatorSlashes(QueryValidatorSlashesRequest),
     DelegatorTotalRewards(QueryDelegatorParams),
+    DelegationRewards(QueryDelegationRewardsRequest),
+    DelegatorValidators(QueryDelegatorValidatorsRequest),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressRequest),
     CommunityPool(QueryCommunityPoolRequest),
     Params(QueryParamsRequest),
 }
@@ -42,6 +47,9 @@ pub enum DistributionNodeQueryResponse {
     ValidatorCommission(QueryValidatorCommissionResponse),
     ValidatorSlashes(QueryValidatorSlashesResponse),
     DelegatorTotalRewards(QueryDelegatorTotalRewardsResponse),
+    DelegationRewards(QueryDelegationRewardsResponse),
+    DelegatorValidators(QueryDelegatorValidatorsResponse),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressResponse),
     CommunityPool(QueryCommunityPoolResponse),
     Params(QueryParamsResponse),
 }
@@ -77,6 +85,14 @@ impl<
         }
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        self.keeper.export_genesis(ctx)
+    }
+
+    pub fn prepare_for_zero_height<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>) {
+        self.keeper.prepare_for_zero_height(ctx)
+    }
+
     pub fn tx<DB: Database + Sync + Send>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
@@ -88,6 +104,9 @@ impl<
                 .withdraw_delegator_reward_and_commission(ctx, msg)?),
             Message::SetWithdrawAddr(msg) => Ok(self.keeper.set_withdraw_address(ctx, msg)?),
             Message::FundCommunityPool(msg) => Ok(self.keeper.fund_community_pool_cmd(ctx, msg)?),
+            Message::WithdrawValidatorCommission(msg) => {
+                Ok(self.keeper.withdraw_validator_commission_cmd(ctx, msg)?)
+            }
         }
     }
 
@@ -143,7 +162,7 @@ impl<
                     .into())
             }
             "/cosmos.distribution.v1beta1.Query/DelegatorValidators" => {
-                let req = QueryWithdrawAllRewardsRequest::decode(query.data)?;
+                let req = QueryDelegatorValidatorsRequest::decode(query.data)?;
 
                 Ok(self
                     .keeper
@@ -151,6 +170,15 @@ impl<
                     .encode_vec()
                     .into())
             }
+            "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress" => {
+                let req = QueryDelegatorWithdrawAddressRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_delegator_withdraw_address(ctx, req)
+                    .encode_vec()
+                    .into())
+            }
             "/cosmos.distribution.v1beta1.Query/CommunityPool" => {
                 let req = QueryCommunityPoolRequest::decode(query.data)?;
 
@@ -195,6 +223,23 @@ impl<
                     self.keeper.query_delegator_total_rewards(ctx, req),
                 )
             }
+            DistributionNodeQueryRequest::DelegationRewards(req) => {
+                DistributionNodeQueryResponse::DelegationRewards(
+                    self.keeper
+                        .query_delegation_rewards(ctx, req)
+                        .unwrap_or(QueryDelegationRewardsResponse { rewards: None }),
+                )
+            }
+            DistributionNodeQueryRequest::DelegatorValidators(req) => {
+                DistributionNodeQueryResponse::DelegatorValidators(
+                    self.keeper.query_delegator_validators(ctx, req),
+                )
+            }
+            DistributionNodeQueryRequest::DelegatorWithdrawAddress(req) => {
+                DistributionNodeQueryResponse::DelegatorWithdrawAddress(
+                    self.keeper.query_delegator_withdraw_address(ctx, req),
+                )
+            }
             DistributionNodeQueryRequest::CommunityPool(req) => {
                 DistributionNodeQueryResponse::CommunityPool(
                     self.keeper.query_community_pool(ctx, req),
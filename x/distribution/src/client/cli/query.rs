@@ -1,6 +1,8 @@
 use crate::{
     QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
-    QueryDelegationRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryDelegationRewardsResponse, QueryDelegatorValidatorsRequest,
+    QueryDelegatorValidatorsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
     QueryValidatorSlashesRequest, QueryValidatorSlashesResponse,
@@ -8,7 +10,7 @@ use crate::{
 use clap::{Args, Subcommand};
 use gears::{
     application::handlers::client::QueryHandler,
-    baseapp::Query,
+    baseapp::{Query, QueryResponse},
     cli::pagination::CliPaginationRequest,
     core::Protobuf,
     extensions::try_map::FallibleMapExt,
@@ -32,6 +34,8 @@ pub enum DistributionCommands {
     ValidatorCommission(ValidatorCommissionCommand),
     ValidatorSlashes(ValidatorSlashesCommand),
     Rewards(DelegationRewardsCommand),
+    DelegatorValidators(DelegatorValidatorsCommand),
+    DelegatorWithdrawAddress(DelegatorWithdrawAddressCommand),
     /// Query the amount of coins in the community pool
     CommunityPool,
     /// Query distribution params
@@ -74,6 +78,20 @@ pub struct DelegationRewardsCommand {
     pub validator_address: ValAddress,
 }
 
+/// Query the validators of a delegator
+#[derive(Args, Debug, Clone)]
+pub struct DelegatorValidatorsCommand {
+    /// delegator address
+    pub delegator_address: AccAddress,
+}
+
+/// Query the address which receives the rewards of a delegator
+#[derive(Args, Debug, Clone)]
+pub struct DelegatorWithdrawAddressCommand {
+    /// delegator address
+    pub delegator_address: AccAddress,
+}
+
 #[derive(Debug, Clone)]
 pub struct DistributionQueryHandler;
 
@@ -119,6 +137,18 @@ impl QueryHandler for DistributionQueryHandler {
                 delegator_address: delegator_address.clone(),
                 validator_address: validator_address.clone(),
             }),
+            DistributionCommands::DelegatorValidators(DelegatorValidatorsCommand {
+                delegator_address,
+            }) => Self::QueryRequest::DelegatorValidators(QueryDelegatorValidatorsRequest {
+                delegator_address: delegator_address.clone(),
+            }),
+            DistributionCommands::DelegatorWithdrawAddress(DelegatorWithdrawAddressCommand {
+                delegator_address,
+            }) => {
+                Self::QueryRequest::DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressRequest {
+                    delegator_address: delegator_address.clone(),
+                })
+            }
             DistributionCommands::CommunityPool => {
                 Self::QueryRequest::CommunityPool(QueryCommunityPoolRequest {})
             }
@@ -152,6 +182,16 @@ impl QueryHandler for DistributionQueryHandler {
             DistributionCommands::Rewards(_) => DistributionQueryResponse::DelegationRewards(
                 QueryDelegationRewardsResponse::decode_vec(&query_bytes)?,
             ),
+            DistributionCommands::DelegatorValidators(_) => {
+                DistributionQueryResponse::DelegatorValidators(
+                    QueryDelegatorValidatorsResponse::decode_vec(&query_bytes)?,
+                )
+            }
+            DistributionCommands::DelegatorWithdrawAddress(_) => {
+                DistributionQueryResponse::DelegatorWithdrawAddress(
+                    QueryDelegatorWithdrawAddressResponse::decode_vec(&query_bytes)?,
+                )
+            }
             DistributionCommands::CommunityPool => DistributionQueryResponse::CommunityPool(
                 QueryCommunityPoolResponse::decode_vec(&query_bytes)?,
             ),
@@ -170,6 +210,8 @@ pub enum DistributionQueryRequest {
     ValidatorCommission(QueryValidatorCommissionRequest),
     ValidatorSlashes(QueryValidatorSlashesRequest),
     DelegationRewards(QueryDelegationRewardsRequest),
+    DelegatorValidators(QueryDelegatorValidatorsRequest),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressRequest),
     CommunityPool(QueryCommunityPoolRequest),
     Params(QueryParamsRequest),
 }
@@ -189,6 +231,12 @@ impl Query for DistributionQueryRequest {
             DistributionQueryRequest::DelegationRewards(_) => {
                 "/cosmos.distribution.v1beta1.Query/DelegationRewards"
             }
+            DistributionQueryRequest::DelegatorValidators(_) => {
+                "/cosmos.distribution.v1beta1.Query/DelegatorValidators"
+            }
+            DistributionQueryRequest::DelegatorWithdrawAddress(_) => {
+                "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress"
+            }
             DistributionQueryRequest::CommunityPool(_) => {
                 "/cosmos.distribution.v1beta1.Query/CommunityPool"
             }
@@ -202,6 +250,8 @@ impl Query for DistributionQueryRequest {
             DistributionQueryRequest::ValidatorCommission(var) => var.encode_vec(),
             DistributionQueryRequest::ValidatorSlashes(var) => var.encode_vec(),
             DistributionQueryRequest::DelegationRewards(var) => var.encode_vec(),
+            DistributionQueryRequest::DelegatorValidators(var) => var.encode_vec(),
+            DistributionQueryRequest::DelegatorWithdrawAddress(var) => var.encode_vec(),
             DistributionQueryRequest::CommunityPool(var) => var.encode_vec(),
             DistributionQueryRequest::Params(var) => var.encode_vec(),
         }
@@ -215,6 +265,23 @@ pub enum DistributionQueryResponse {
     ValidatorCommission(QueryValidatorCommissionResponse),
     ValidatorSlashes(QueryValidatorSlashesResponse),
     DelegationRewards(QueryDelegationRewardsResponse),
+    DelegatorValidators(QueryDelegatorValidatorsResponse),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressResponse),
     CommunityPool(QueryCommunityPoolResponse),
     Params(QueryParamsResponse),
 }
+
+impl QueryResponse for DistributionQueryResponse {
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            DistributionQueryResponse::ValidatorOutstandingRewards(var) => var.encode_vec(),
+            DistributionQueryResponse::ValidatorCommission(var) => var.encode_vec(),
+            DistributionQueryResponse::ValidatorSlashes(var) => var.encode_vec(),
+            DistributionQueryResponse::DelegationRewards(var) => var.encode_vec(),
+            DistributionQueryResponse::DelegatorValidators(var) => var.encode_vec(),
+            DistributionQueryResponse::DelegatorWithdrawAddress(var) => var.encode_vec(),
+            DistributionQueryResponse::CommunityPool(var) => var.encode_vec(),
+            DistributionQueryResponse::Params(var) => var.encode_vec(),
+        }
+    }
+}
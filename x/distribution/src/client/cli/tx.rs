@@ -1,7 +1,7 @@
 use crate::{
     Message, MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward,
-    QueryWithdrawAllRewardsRequest, QueryWithdrawAllRewardsResponse,
-    QueryWithdrawAllRewardsResponseRaw,
+    MsgWithdrawValidatorCommission, QueryDelegatorValidatorsRequest,
+    QueryDelegatorValidatorsResponse, RawQueryDelegatorValidatorsResponse,
 };
 use anyhow::{Ok, Result};
 use clap::{Args, Subcommand};
@@ -47,25 +47,32 @@ pub fn run_staking_tx_command(
         DistributionCommands::WithdrawRewards {
             validator_address,
             commission,
-        } => Ok(Message::WithdrawRewards(MsgWithdrawDelegatorReward {
-            validator_address: validator_address.clone(),
-            delegator_address: from_address.clone(),
-            withdraw_commission: *commission,
-        })
-        .into()),
+        } => {
+            let mut msgs = vec![Message::WithdrawRewards(MsgWithdrawDelegatorReward {
+                validator_address: validator_address.clone(),
+                delegator_address: from_address.clone(),
+                withdraw_commission: false,
+            })];
+            if *commission {
+                msgs.push(Message::WithdrawValidatorCommission(
+                    MsgWithdrawValidatorCommission::new(validator_address.clone()),
+                ));
+            }
+
+            Ok(msgs.try_into()?)
+        }
         DistributionCommands::WithdrawAllRewards => {
-            let query = QueryWithdrawAllRewardsRequest {
+            let query = QueryDelegatorValidatorsRequest {
                 delegator_address: from_address.clone(),
             };
             let res = ctx
-                .query::<QueryWithdrawAllRewardsResponse, QueryWithdrawAllRewardsResponseRaw>(
+                .query::<QueryDelegatorValidatorsResponse, RawQueryDelegatorValidatorsResponse>(
                     "/cosmos.distribution.v1beta1.Query/DelegatorValidators".to_string(),
                     query.encode_vec(),
                 )?;
 
             let mut msgs = vec![];
-            for addr in res.validators {
-                let validator_address = ValAddress::from_bech32(&addr)?;
+            for validator_address in res.validators {
                 msgs.push(Message::WithdrawRewards(MsgWithdrawDelegatorReward {
                     validator_address,
                     delegator_address: from_address.clone(),
@@ -3,11 +3,16 @@
 // use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
 // use ibc_proto::cosmos::distribution::v1beta1::{
 //     query_server::{Query, QueryServer},
+//     QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+//     QueryDelegatorValidatorsRequest, QueryDelegatorValidatorsResponse,
+//     QueryDelegatorWithdrawAddressRequest, QueryDelegatorWithdrawAddressResponse,
 //     QueryParamsRequest, QueryParamsResponse,
 // };
 // use std::marker::PhantomData;
 // use tonic::{Request, Response, Status};
 //
+// const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+//
 // #[derive(Debug, Default)]
 // pub struct DistributionService<QH, QReq, QRes> {
 //     app: QH,
@@ -24,6 +29,51 @@
 //     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
 //     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse, Error = Status>,
 // {
+//     async fn delegation_rewards(
+//         &self,
+//         request: Request<QueryDelegationRewardsRequest>,
+//     ) -> Result<Response<QueryDelegationRewardsResponse>, Status> {
+//         let req = DistributionNodeQueryRequest::DelegationRewards(request.into_inner().try_into()?);
+//         let response: DistributionNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+//
+//         if let DistributionNodeQueryResponse::DelegationRewards(response) = response {
+//             Ok(Response::new(response.into()))
+//         } else {
+//             Err(Status::internal(ERROR_STATE_MSG))
+//         }
+//     }
+//
+//     async fn delegator_validators(
+//         &self,
+//         request: Request<QueryDelegatorValidatorsRequest>,
+//     ) -> Result<Response<QueryDelegatorValidatorsResponse>, Status> {
+//         let req =
+//             DistributionNodeQueryRequest::DelegatorValidators(request.into_inner().try_into()?);
+//         let response: DistributionNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+//
+//         if let DistributionNodeQueryResponse::DelegatorValidators(response) = response {
+//             Ok(Response::new(response.into()))
+//         } else {
+//             Err(Status::internal(ERROR_STATE_MSG))
+//         }
+//     }
+//
+//     async fn delegator_withdraw_address(
+//         &self,
+//         request: Request<QueryDelegatorWithdrawAddressRequest>,
+//     ) -> Result<Response<QueryDelegatorWithdrawAddressResponse>, Status> {
+//         let req = DistributionNodeQueryRequest::DelegatorWithdrawAddress(
+//             request.into_inner().try_into()?,
+//         );
+//         let response: DistributionNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+//
+//         if let DistributionNodeQueryResponse::DelegatorWithdrawAddress(response) = response {
+//             Ok(Response::new(response.into()))
+//         } else {
+//             Err(Status::internal(ERROR_STATE_MSG))
+//         }
+//     }
+//
 //     // fill
 // }
 //
@@ -1,6 +1,7 @@
 use crate::{
     DistributionNodeQueryRequest, DistributionNodeQueryResponse, DistributionParams,
-    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegatorParams,
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegatorParams, QueryDelegatorValidatorsRequest, QueryDelegatorWithdrawAddressRequest,
     QueryParamsRequest, QueryParamsResponse, QueryValidatorCommissionRequest,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorSlashesRequest,
 };
@@ -33,6 +34,52 @@ pub async fn delegation_delegator_rewards<
     Ok(Json(res))
 }
 
+pub async fn delegation_rewards<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((delegator_address, validator_address)): Path<(AccAddress, ValAddress)>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegationRewards(QueryDelegationRewardsRequest {
+        delegator_address,
+        validator_address,
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_validators<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_address): Path<AccAddress>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegatorValidators(QueryDelegatorValidatorsRequest {
+        delegator_address,
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_withdraw_address<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_address): Path<AccAddress>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegatorWithdrawAddress(
+        QueryDelegatorWithdrawAddressRequest { delegator_address },
+    );
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub async fn community_pool<
     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
@@ -144,6 +191,18 @@ pub fn get_router<
             "/v1beta1/delegators/:delegator_address/rewards",
             get(delegation_delegator_rewards),
         )
+        .route(
+            "/v1beta1/delegators/:delegator_address/rewards/:validator_address",
+            get(delegation_rewards),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_address/validators",
+            get(delegator_validators),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_address/withdraw_address",
+            get(delegator_withdraw_address),
+        )
         .route(
             "/v1beta1/validators/:validator_address/commission",
             get(validator_commission),
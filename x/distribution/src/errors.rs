@@ -1,11 +1,12 @@
 use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
     error::NumericError,
     types::{
         address::{AccAddress, ValAddress},
         base::errors::CoinsError,
         store::gas::errors::GasStoreErrors,
     },
-    x::errors::{AccountNotFound, BankKeeperError},
+    x::errors::{AccountNotFound, BankKeeperError, StakingHooksError},
 };
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -14,6 +15,12 @@ pub enum DistributionTxError {
     DelegatorValidator(#[from] DistributionError),
 }
 
+impl DistributionTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        TxError::new::<MI>(self.to_string(), nz::u16!(1))
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum TokenAllocationError {
     #[error(transparent)]
@@ -52,6 +59,10 @@ pub enum DistributionError {
     DelegationNotFound(AccAddress, ValAddress),
     #[error("cannot set negative reference count")]
     NegativeHistoricalInfoCount,
+    #[error("community pool does not have sufficient coins to distribute")]
+    InsufficientCommunityPool,
+    #[error("{0} is not allowed to receive external funds")]
+    BlockedAddress(AccAddress),
     #[error(transparent)]
     AccountNotFound(#[from] AccountNotFound),
     #[error("{0}")]
@@ -64,6 +75,18 @@ pub enum DistributionError {
     Gas(#[from] GasStoreErrors),
 }
 
+impl From<DistributionError> for StakingHooksError {
+    fn from(value: DistributionError) -> Self {
+        match value {
+            DistributionError::Gas(e)
+            | DistributionError::BankSend(BankKeeperError::GasError(e)) => {
+                StakingHooksError::GasError(e)
+            }
+            e => StakingHooksError::Other(e.to_string()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ValidatorHistoricalRewardsReferenceCountError {
     #[error("cannot create counter with value higher than upper bound.\ngot: {0}, expected: {1}")]
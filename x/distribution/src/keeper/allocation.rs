@@ -47,6 +47,10 @@ impl<
             .balance_all(ctx, self.fee_collector_module.address(), None)
             .unwrap_gas()
             .1;
+        // there is nothing to allocate, the coins can't hold zero amounts
+        if fees_collected_int.is_empty() {
+            return Ok(());
+        }
         let fees_collected = DecimalCoins::try_from(fees_collected_int.clone())?;
 
         // transfer collected fees to the distribution module account
@@ -64,7 +68,7 @@ impl<
             .unwrap_gas()
             .ok_or(TokenAllocationError::FeePoolNone)?;
         if total_previous_power == 0 {
-            fee_pool.community_pool = fee_pool.community_pool.checked_add(&fees_collected)?;
+            fee_pool.add_to_community_pool(&fees_collected)?;
             self.set_fee_pool(ctx, &fee_pool).unwrap_gas();
             return Ok(());
         }
@@ -181,7 +185,7 @@ impl<
         }
 
         // allocate community funding
-        fee_pool.community_pool = fee_pool.community_pool.checked_add(&remaining)?;
+        fee_pool.add_to_community_pool(&remaining)?;
         self.set_fee_pool(ctx, &fee_pool).unwrap_gas();
 
         Ok(())
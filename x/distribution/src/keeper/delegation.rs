@@ -24,9 +24,9 @@ impl<
     > Keeper<SK, PSK, AK, BK, DSK, M>
 {
     /// initialize starting info for a new delegation
-    pub fn initialize_delegation<DB: Database>(
+    pub fn initialize_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_address: &ValAddress,
         delegator_address: &AccAddress,
     ) -> Result<(), DistributionError> {
@@ -71,9 +71,9 @@ impl<
         )?)
     }
 
-    pub fn delegation_withdraw_rewards<DB: Database>(
+    pub fn delegation_withdraw_rewards<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator: impl StakingValidator,
         delegation: impl StakingDelegation,
     ) -> Result<Option<UnsignedCoins>, DistributionError> {
@@ -129,9 +129,7 @@ impl<
         if final_rewards.is_some() {
             let withdraw_address = self
                 .delegator_withdraw_addr(ctx, delegation.delegator())?
-                .ok_or(DistributionError::AccountNotFound(
-                    delegation.delegator().clone().into(),
-                ))?;
+                .unwrap_or(delegation.delegator().clone());
             self.bank_keeper.send_coins_from_module_to_account(
                 ctx,
                 &withdraw_address,
@@ -153,7 +151,7 @@ impl<
         )?;
         let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
         if let Some(rem) = remainder {
-            fee_pool.community_pool = fee_pool.community_pool.checked_add(&rem)?;
+            fee_pool.add_to_community_pool(&rem)?;
             self.set_fee_pool(ctx, &fee_pool)?;
         }
 
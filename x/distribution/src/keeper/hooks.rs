@@ -0,0 +1,282 @@
+use super::*;
+use crate::ValidatorSlashEvent;
+use gears::{
+    context::{QueryableContext, TransactionalContext},
+    types::decimal256::Decimal256,
+    x::{
+        errors::{AccountNotFound, StakingHooksError},
+        keepers::staking::KeeperHooks,
+    },
+};
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, DSK, M>
+{
+    /// moves the validator rewards to the community pool and the withdraw address of the
+    /// validator operator, then clears all distribution records of the removed validator
+    pub fn after_validator_removed_hook<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+    ) -> Result<(), DistributionError> {
+        // fetch outstanding
+        let mut outstanding = self
+            .validator_outstanding_rewards(ctx, validator_address)?
+            .map(|outstanding| outstanding.rewards);
+
+        let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
+
+        // force-withdraw commission
+        if let Some(accumulated) = self.validator_accumulated_commission(ctx, validator_address)? {
+            let commission = accumulated.commission;
+            // subtract from outstanding
+            outstanding = match outstanding {
+                Some(outstanding) if outstanding != commission => {
+                    Some(outstanding.checked_sub(&commission)?)
+                }
+                _ => None,
+            };
+
+            // split into integral & remainder
+            let (coins, remainder) = commission.truncate_decimal();
+
+            // remainder to community pool
+            if let Some(remainder) = remainder {
+                fee_pool.add_to_community_pool(&remainder)?;
+            }
+
+            // add to validator account
+            if let Some(coins) = coins {
+                let acc_address = AccAddress::from(validator_address.clone());
+                let withdraw_address = self
+                    .delegator_withdraw_addr(ctx, &acc_address)?
+                    .unwrap_or(acc_address);
+                self.bank_keeper.send_coins_from_module_to_account(
+                    ctx,
+                    &withdraw_address,
+                    &self.distribution_module,
+                    coins,
+                )?;
+            }
+        }
+
+        // add outstanding to community pool
+        if let Some(outstanding) = outstanding {
+            fee_pool.add_to_community_pool(&outstanding)?;
+        }
+        self.set_fee_pool(ctx, &fee_pool)?;
+
+        // delete outstanding
+        self.delete_validator_outstanding_rewards(ctx, validator_address)?;
+        // remove commission record
+        self.delete_validator_accumulated_commission(ctx, validator_address)?;
+        // clear slashes
+        self.delete_validator_slash_events(ctx, validator_address)?;
+        // clear historical rewards
+        self.delete_validator_historical_rewards_all(ctx, validator_address)?;
+        // clear current rewards
+        self.delete_validator_current_rewards(ctx, validator_address)?;
+
+        Ok(())
+    }
+
+    /// increments the period of the validator before a new delegation is created
+    pub fn before_delegation_created_hook<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+    ) -> Result<(), DistributionError> {
+        if !self.has_validator_current_rewards(ctx, validator_address)? {
+            return Ok(());
+        }
+        let validator = self
+            .staking_keeper
+            .validator(ctx, validator_address)?
+            .ok_or(AccountNotFound::from(validator_address.clone()))?;
+        self.increment_validator_period(ctx, validator_address, validator.tokens())?;
+        Ok(())
+    }
+
+    /// withdraws delegation rewards (which also increments the period)
+    pub fn before_delegation_shares_modified_hook<
+        DB: Database,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        delegator_address: &AccAddress,
+        validator_address: &ValAddress,
+    ) -> Result<(), DistributionError> {
+        if !self.has_delegator_starting_info(ctx, validator_address, delegator_address)? {
+            return Ok(());
+        }
+        self.withdraw_delegation_rewards(ctx, delegator_address, validator_address)?;
+        Ok(())
+    }
+
+    /// creates new delegation period record
+    pub fn after_delegation_modified_hook<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        delegator_address: &AccAddress,
+        validator_address: &ValAddress,
+    ) -> Result<(), DistributionError> {
+        if !self.has_validator_current_rewards(ctx, validator_address)? {
+            return Ok(());
+        }
+        self.initialize_delegation(ctx, validator_address, delegator_address)
+    }
+
+    /// records the slash event of the validator
+    pub fn before_validator_slashed_hook<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator_address: &ValAddress,
+        fraction: Decimal256,
+    ) -> Result<(), DistributionError> {
+        if !self.has_validator_current_rewards(ctx, validator_address)? {
+            return Ok(());
+        }
+        let validator = self
+            .staking_keeper
+            .validator(ctx, validator_address)?
+            .ok_or(AccountNotFound::from(validator_address.clone()))?;
+
+        // increment current period
+        let new_period =
+            self.increment_validator_period(ctx, validator_address, validator.tokens())?;
+
+        // increment reference count on period we need to track
+        self.increment_reference_count(ctx, validator_address, new_period)?;
+
+        let height = ctx.height() as u64;
+        self.set_validator_slash_event(
+            ctx,
+            validator_address,
+            height,
+            new_period,
+            &ValidatorSlashEvent {
+                validator_period: new_period,
+                fraction,
+            },
+        )?;
+        Ok(())
+    }
+
+    // Rewards records are created with the first allocated tokens because the coins can't
+    // hold zero amounts. Until then there is no period to increment.
+    fn has_validator_current_rewards<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        validator_address: &ValAddress,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(self
+            .validator_current_rewards(ctx, validator_address)?
+            .is_some())
+    }
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > KeeperHooks<SK, AK, M> for Keeper<SK, PSK, AK, BK, DSK, M>
+{
+    fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        // the validator rewards records are initialized with the first allocated tokens
+        Ok(())
+    }
+
+    fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.after_validator_removed_hook(ctx, &val_addr)?)
+    }
+
+    fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        _del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.before_delegation_created_hook(ctx, &val_addr)?)
+    }
+
+    fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.before_delegation_shares_modified_hook(ctx, &del_addr, &val_addr)?)
+    }
+
+    fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        del_addr: AccAddress,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.after_delegation_modified_hook(ctx, &del_addr, &val_addr)?)
+    }
+
+    fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+        fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.before_validator_slashed_hook(ctx, &val_addr, fraction)?)
+    }
+}
@@ -1,9 +1,16 @@
 use crate::{
-    errors::DistributionError, params::DistributionParamsKeeper, GenesisState,
-    ValidatorAccumulatedCommission, ValidatorOutstandingRewards,
+    errors::DistributionError, keys::split_length_prefixed, params::DistributionParamsKeeper,
+    DelegatorStartingInfo, DelegatorStartingInfoRecord, DelegatorWithdrawInfo, GenesisState,
+    ValidatorAccumulatedCommission, ValidatorAccumulatedCommissionRecord, ValidatorCurrentRewards,
+    ValidatorCurrentRewardsRecord, ValidatorHistoricalRewards, ValidatorHistoricalRewardsRecord,
+    ValidatorOutstandingRewards, ValidatorOutstandingRewardsRecord, ValidatorSlashEvent,
+    ValidatorSlashEventRecord, DELEGATOR_STARTING_INFO_PREFIX, DELEGATOR_WITHDRAW_ADDR_PREFIX,
+    VALIDATOR_ACCUMULATED_COMMISSION_PREFIX, VALIDATOR_CURRENT_REWARDS_PREFIX,
+    VALIDATOR_HISTORICAL_REWARDS_PREFIX, VALIDATOR_OUTSTANDING_REWARDS_PREFIX,
+    VALIDATOR_SLASH_EVENT_PREFIX,
 };
 use anyhow::anyhow;
-use gears::extensions::gas::GasResultExt;
+use gears::extensions::{corruption::UnwrapCorrupt, gas::GasResultExt};
 pub use gears::{
     context::init::InitContext,
     params::ParamsSubspaceKey,
@@ -18,11 +25,15 @@ pub use gears::{
     },
 };
 use gears::{
-    context::{tx::TxContext, TransactionalContext},
+    context::{query::QueryContext, tx::TxContext, TransactionalContext},
+    core::Protobuf,
     tendermint::types::proto::event::{Event, EventAttribute},
     types::{
         address::{AccAddress, ConsAddress, ValAddress},
-        base::coins::{DecimalCoins, UnsignedCoins},
+        base::{
+            coins::{DecimalCoins, UnsignedCoins},
+            errors::CoinsError,
+        },
         store::gas::errors::GasStoreErrors,
     },
     x::keepers::staking::DistributionStakingKeeper,
@@ -31,6 +42,7 @@ use std::collections::HashMap;
 
 mod allocation;
 mod delegation;
+mod hooks;
 mod query;
 mod store;
 mod tx;
@@ -103,15 +115,13 @@ impl<
                 .unwrap_gas()
         });
 
-        let previous_proposer = if !genesis.previous_proposer.is_empty() {
-            ConsAddress::from_bech32(&genesis.previous_proposer)?
-        } else {
-            todo!("the sdk doesn't have this branch. It may use a default value");
-        };
-
-        self.set_previous_proposer_cons_addr(ctx, &previous_proposer);
+        // the proposer is empty for a new chain and it is set in the first begin block
+        if !genesis.previous_proposer.is_empty() {
+            let previous_proposer = ConsAddress::from_bech32(&genesis.previous_proposer)?;
+            self.set_previous_proposer_cons_addr(ctx, &previous_proposer);
+        }
 
-        let mut module_holdings = vec![];
+        let mut module_holdings = genesis.fee_pool.community_pool.clone();
         for rew in genesis.outstanding_rewards {
             self.set_validator_outstanding_rewards(
                 ctx,
@@ -119,13 +129,11 @@ impl<
                 &rew.outstanding_rewards,
             )
             .unwrap_gas();
-            module_holdings.push(rew.outstanding_rewards.rewards);
+            module_holdings = Some(match module_holdings {
+                Some(holdings) => holdings.checked_add(&rew.outstanding_rewards.rewards)?,
+                None => rew.outstanding_rewards.rewards,
+            });
         }
-        let start = module_holdings[0].clone();
-        let module_holdings = module_holdings
-            .into_iter()
-            .take(1)
-            .try_fold(start, |acc, holdings| acc.checked_add(&holdings))?;
 
         genesis
             .validator_accumulated_commissions
@@ -172,10 +180,11 @@ impl<
                 vse.period,
                 &vse.validator_slash_event,
             )
+            .unwrap_gas()
         });
 
-        let module_holdings = module_holdings.checked_add(&genesis.fee_pool.community_pool)?;
-        let (module_holdings_int, _) = module_holdings.truncate_decimal();
+        let module_holdings_int =
+            module_holdings.and_then(|holdings| holdings.truncate_decimal().0);
 
         // check if the module account exists
 
@@ -186,7 +195,13 @@ impl<
             .unwrap_gas()
             .1;
 
-        if module_holdings_int != Some(UnsignedCoins::new(balances)?) {
+        let balances = if balances.is_empty() {
+            None
+        } else {
+            Some(UnsignedCoins::new(balances)?)
+        };
+
+        if module_holdings_int != balances {
             return Err(anyhow!(
                 "distribution module balance does not match the module holdings".to_string(),
             ));
@@ -195,6 +210,180 @@ impl<
         Ok(())
     }
 
+    /// export_genesis returns the distribution state at the height of the context. For zero
+    /// height exports the state must be prepared with `prepare_for_zero_height` first.
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let store = ctx.kv_store(&self.store_key);
+
+        let delegator_withdraw_infos = store
+            .prefix_store(DELEGATOR_WITHDRAW_ADDR_PREFIX)
+            .into_range(..)
+            .map(|(key, value)| {
+                let (delegator_address, _) = split_length_prefixed(&key).unwrap_or_corrupt();
+                DelegatorWithdrawInfo {
+                    delegator_address: AccAddress::try_from(delegator_address).unwrap_or_corrupt(),
+                    withdraw_address: AccAddress::try_from(value.into_owned()).unwrap_or_corrupt(),
+                }
+            })
+            .collect();
+
+        let outstanding_rewards = store
+            .prefix_store(VALIDATOR_OUTSTANDING_REWARDS_PREFIX)
+            .into_range(..)
+            .map(|(key, value)| {
+                let (validator_address, _) = split_length_prefixed(&key).unwrap_or_corrupt();
+                ValidatorOutstandingRewardsRecord {
+                    validator_address: ValAddress::try_from(validator_address).unwrap_or_corrupt(),
+                    outstanding_rewards: ValidatorOutstandingRewards::decode_vec(&value)
+                        .unwrap_or_corrupt(),
+                }
+            })
+            .collect();
+
+        let validator_accumulated_commissions = store
+            .prefix_store(VALIDATOR_ACCUMULATED_COMMISSION_PREFIX)
+            .into_range(..)
+            .map(|(key, value)| {
+                let (validator_address, _) = split_length_prefixed(&key).unwrap_or_corrupt();
+                ValidatorAccumulatedCommissionRecord {
+                    validator_address: ValAddress::try_from(validator_address).unwrap_or_corrupt(),
+                    accumulated: ValidatorAccumulatedCommission::decode_vec(&value)
+                        .unwrap_or_corrupt(),
+                }
+            })
+            .collect();
+
+        let validator_historical_rewards = store
+            .prefix_store(VALIDATOR_HISTORICAL_REWARDS_PREFIX)
+            .into_range(..)
+            .map(|(key, value)| {
+                let (validator_address, period) = split_length_prefixed(&key).unwrap_or_corrupt();
+                ValidatorHistoricalRewardsRecord {
+                    validator_address: ValAddress::try_from(validator_address).unwrap_or_corrupt(),
+                    period: u64::from_le_bytes(period.try_into().unwrap_or_corrupt()),
+                    rewards: ValidatorHistoricalRewards::decode_vec(&value).unwrap_or_corrupt(),
+                }
+            })
+            .collect();
+
+        let validator_current_rewards = store
+            .prefix_store(VALIDATOR_CURRENT_REWARDS_PREFIX)
+            .into_range(..)
+            .map(|(key, value)| {
+                let (validator_address, _) = split_length_prefixed(&key).unwrap_or_corrupt();
+                ValidatorCurrentRewardsRecord {
+                    validator_address: ValAddress::try_from(validator_address).unwrap_or_corrupt(),
+                    rewards: ValidatorCurrentRewards::decode_vec(&value).unwrap_or_corrupt(),
+                }
+            })
+            .collect();
+
+        let delegator_starting_infos = store
+            .prefix_store(DELEGATOR_STARTING_INFO_PREFIX)
+            .into_range(..)
+            .map(|(key, value)| {
+                let (validator_address, rest) = split_length_prefixed(&key).unwrap_or_corrupt();
+                let (delegator_address, _) = split_length_prefixed(rest).unwrap_or_corrupt();
+                DelegatorStartingInfoRecord {
+                    delegator_address: AccAddress::try_from(delegator_address).unwrap_or_corrupt(),
+                    validator_address: ValAddress::try_from(validator_address).unwrap_or_corrupt(),
+                    starting_info: DelegatorStartingInfo::decode_vec(&value).unwrap_or_corrupt(),
+                }
+            })
+            .collect();
+
+        let validator_slash_events = store
+            .prefix_store(VALIDATOR_SLASH_EVENT_PREFIX)
+            .into_range(..)
+            .map(|(key, value)| {
+                let (validator_address, rest) = split_length_prefixed(&key).unwrap_or_corrupt();
+                let (height, period) = rest.split_at_checked(8).unwrap_or_corrupt();
+                ValidatorSlashEventRecord {
+                    validator_address: ValAddress::try_from(validator_address).unwrap_or_corrupt(),
+                    height: u64::from_be_bytes(height.try_into().unwrap_or_corrupt()),
+                    period: u64::from_be_bytes(period.try_into().unwrap_or_corrupt()),
+                    validator_slash_event: ValidatorSlashEvent::decode_vec(&value)
+                        .unwrap_or_corrupt(),
+                }
+            })
+            .collect();
+
+        GenesisState {
+            params: self.params_keeper.get(ctx),
+            fee_pool: self.fee_pool(ctx).unwrap_gas().unwrap_or_corrupt(),
+            delegator_withdraw_infos,
+            previous_proposer: self
+                .previous_proposer_cons_addr(ctx)
+                .map(|address| address.to_string())
+                .unwrap_or_default(),
+            outstanding_rewards,
+            validator_accumulated_commissions,
+            validator_historical_rewards,
+            validator_current_rewards,
+            delegator_starting_infos,
+            validator_slash_events,
+        }
+    }
+
+    /// prepare_for_zero_height withdraws the commission of all validators and the rewards of
+    /// all delegations, clears the slash events and resets the heights of the delegator
+    /// starting infos, so the state can be exported for a chain starting at height zero
+    pub fn prepare_for_zero_height<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>) {
+        let validators = self
+            .staking_keeper
+            .validators_iter(ctx)
+            .unwrap_gas()
+            .map(|validator| validator.unwrap_gas().operator().clone())
+            .collect::<Vec<_>>();
+
+        for validator in &validators {
+            // a validator may have no commission to withdraw
+            let _ = self.withdraw_validator_commission(ctx, validator);
+        }
+
+        let delegations = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(DELEGATOR_STARTING_INFO_PREFIX)
+            .into_range(..)
+            .map(|res| {
+                let (key, _) = res.unwrap_gas();
+                let (validator_address, rest) = split_length_prefixed(&key).unwrap_or_corrupt();
+                let (delegator_address, _) = split_length_prefixed(rest).unwrap_or_corrupt();
+                (
+                    ValAddress::try_from(validator_address).unwrap_or_corrupt(),
+                    AccAddress::try_from(delegator_address).unwrap_or_corrupt(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        for (validator_address, delegator_address) in &delegations {
+            self.withdraw_delegation_rewards(ctx, delegator_address, validator_address)
+                .expect("rewards of an existing delegation can be withdrawn");
+        }
+
+        for validator in &validators {
+            self.delete_validator_slash_events(ctx, validator)
+                .unwrap_gas();
+        }
+
+        for (validator_address, delegator_address) in &delegations {
+            let starting_info = self
+                .delegator_starting_info(ctx, validator_address, delegator_address)
+                .unwrap_gas()
+                .unwrap_or_corrupt();
+            self.set_delegator_starting_info(
+                ctx,
+                validator_address,
+                delegator_address,
+                &DelegatorStartingInfo {
+                    height: 0,
+                    ..starting_info
+                },
+            )
+            .unwrap_gas();
+        }
+    }
+
     /// check_set_distribution_account creates module account for current module
     pub fn check_set_distribution_account<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
@@ -206,9 +395,9 @@ impl<
     }
 
     /// withdraw rewards from a delegation
-    pub fn withdraw_delegation_rewards<DB: Database>(
+    pub fn withdraw_delegation_rewards<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<DB, SK>,
+        ctx: &mut CTX,
         delegator_address: &AccAddress,
         validator_address: &ValAddress,
     ) -> Result<Option<UnsignedCoins>, DistributionError> {
@@ -240,9 +429,9 @@ impl<
     }
 
     /// withdraw validator commission
-    pub fn withdraw_validator_commission<DB: Database>(
+    pub fn withdraw_validator_commission<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<DB, SK>,
+        ctx: &mut CTX,
         validator_address: &ValAddress,
     ) -> Result<Option<UnsignedCoins>, DistributionError> {
         // fetch validator accumulated commission
@@ -259,13 +448,17 @@ impl<
 
         let (commission, reminder) = accumulated_commission.commission.truncate_decimal();
 
-        if let Some(rem) = reminder {
+        match reminder {
             // leave remainder to withdraw later
-            self.set_validator_accumulated_commission(
+            Some(rem) => self.set_validator_accumulated_commission(
                 ctx,
                 validator_address,
                 &ValidatorAccumulatedCommission { commission: rem },
-            )?
+            )?,
+            // the whole commission is withdrawn
+            None => {
+                self.delete_validator_accumulated_commission(ctx, validator_address)?;
+            }
         }
 
         // update outstanding
@@ -332,10 +525,157 @@ impl<
             amount.clone(),
         )?;
         let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
-        fee_pool.community_pool = fee_pool
+        fee_pool.add_to_community_pool(&DecimalCoins::try_from(amount.into_inner())?)?;
+        self.set_fee_pool(ctx, &fee_pool)?;
+        Ok(())
+    }
+
+    /// distribute_from_fee_pool distributes funds from the community pool to a receiver
+    /// address. An error is returned if the community pool holds less than the amount or
+    /// if the receiver isn't allowed to receive external funds.
+    pub fn distribute_from_fee_pool<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: UnsignedCoins,
+        receiver: &AccAddress,
+    ) -> Result<(), DistributionError> {
+        if self
+            .blocked_addrs
+            .get(&receiver.to_string())
+            .copied()
+            .unwrap_or_default()
+        {
+            return Err(DistributionError::BlockedAddress(receiver.clone()));
+        }
+
+        let mut fee_pool = self.fee_pool(ctx)?.ok_or(DistributionError::FeePoolNone)?;
+        let community_pool = fee_pool
             .community_pool
-            .checked_add(&DecimalCoins::try_from(amount.into_inner())?)?;
+            .ok_or(DistributionError::InsufficientCommunityPool)?;
+        let spent = DecimalCoins::try_from(amount.clone().into_inner())?;
+        if !community_pool.is_all_gte(spent.inner()) {
+            return Err(DistributionError::InsufficientCommunityPool);
+        }
+
+        fee_pool.community_pool = match community_pool.checked_sub(&spent) {
+            Ok(community_pool) => Some(community_pool),
+            // the whole pool is spent
+            Err(CoinsError::EmptyList) => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        self.bank_keeper.send_coins_from_module_to_account(
+            ctx,
+            receiver,
+            &self.distribution_module,
+            amount,
+        )?;
         self.set_fee_pool(ctx, &fee_pool)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr};
+
+    use gears::{
+        extensions::testing::UnwrapTesting,
+        types::base::coin::{DecimalCoin, UnsignedCoin},
+    };
+
+    use super::*;
+    use crate::testing::{init_ctx, keeper, multi_store};
+
+    fn decimal_coins(coins: &str) -> DecimalCoins {
+        DecimalCoins::new(vec![DecimalCoin::from_str(coins).unwrap_test()]).unwrap_test()
+    }
+
+    fn unsigned_coins(coins: &str) -> UnsignedCoins {
+        UnsignedCoins::new(vec![UnsignedCoin::from_str(coins).unwrap_test()]).unwrap_test()
+    }
+
+    #[test]
+    fn withdraw_validator_commission_twice() {
+        let keeper = keeper(vec![], HashMap::new());
+        let mut multi_store = multi_store();
+        let mut ctx = init_ctx(&mut multi_store);
+
+        let whole = ValAddress::from_bech32("cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4")
+            .unwrap_test();
+        let fraction =
+            ValAddress::from_bech32("cosmosvaloper15jlqmacda2pzerhw48gvvxskweg8sz2scfexfk")
+                .unwrap_test();
+        for (validator, commission, outstanding) in [
+            (&whole, "10uatom", "25uatom"),
+            (&fraction, "10.5uatom", "25.5uatom"),
+        ] {
+            keeper
+                .set_validator_accumulated_commission(
+                    &mut ctx,
+                    validator,
+                    &ValidatorAccumulatedCommission {
+                        commission: decimal_coins(commission),
+                    },
+                )
+                .unwrap_test();
+            keeper
+                .set_validator_outstanding_rewards(
+                    &mut ctx,
+                    validator,
+                    &ValidatorOutstandingRewards {
+                        rewards: decimal_coins(outstanding),
+                    },
+                )
+                .unwrap_test();
+        }
+
+        // the whole commission is withdrawn and the record is removed
+        let withdrawn = keeper
+            .withdraw_validator_commission(&mut ctx, &whole)
+            .unwrap_test();
+        assert_eq!(withdrawn, Some(unsigned_coins("10uatom")));
+        assert!(keeper
+            .validator_accumulated_commission(&ctx, &whole)
+            .unwrap_test()
+            .is_none());
+        assert!(matches!(
+            keeper.withdraw_validator_commission(&mut ctx, &whole),
+            Err(DistributionError::ValidatorAccumulatedCommissionNotFound(_))
+        ));
+        assert_eq!(
+            keeper
+                .validator_outstanding_rewards(&ctx, &whole)
+                .unwrap_test(),
+            Some(ValidatorOutstandingRewards {
+                rewards: decimal_coins("15uatom")
+            })
+        );
+
+        // the remainder is kept, so nothing is withdrawn the second time
+        let withdrawn = keeper
+            .withdraw_validator_commission(&mut ctx, &fraction)
+            .unwrap_test();
+        assert_eq!(withdrawn, Some(unsigned_coins("10uatom")));
+        let withdrawn = keeper
+            .withdraw_validator_commission(&mut ctx, &fraction)
+            .unwrap_test();
+        assert_eq!(withdrawn, None);
+        assert_eq!(
+            keeper
+                .validator_accumulated_commission(&ctx, &fraction)
+                .unwrap_test(),
+            Some(ValidatorAccumulatedCommission {
+                commission: decimal_coins("0.5uatom")
+            })
+        );
+        assert_eq!(
+            keeper
+                .validator_outstanding_rewards(&ctx, &fraction)
+                .unwrap_test(),
+            Some(ValidatorOutstandingRewards {
+                rewards: decimal_coins("15.5uatom")
+            })
+        );
+    }
+}
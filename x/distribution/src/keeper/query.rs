@@ -2,11 +2,12 @@ use super::*;
 use crate::{
     DelegationDelegatorReward, QueryCommunityPoolRequest, QueryCommunityPoolResponse,
     QueryDelegationRewardsRequest, QueryDelegationRewardsResponse, QueryDelegatorParams,
-    QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryDelegatorTotalRewardsResponse, QueryDelegatorValidatorsRequest,
+    QueryDelegatorValidatorsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
-    QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, QueryWithdrawAllRewardsRequest,
-    QueryWithdrawAllRewardsResponse, SlashEventIterator,
+    QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, SlashEventIterator,
 };
 use gears::{
     baseapp::errors::QueryError,
@@ -212,17 +213,30 @@ impl<
     pub fn query_delegator_validators<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
-        QueryWithdrawAllRewardsRequest { delegator_address }: QueryWithdrawAllRewardsRequest,
-    ) -> QueryWithdrawAllRewardsResponse {
+        QueryDelegatorValidatorsRequest { delegator_address }: QueryDelegatorValidatorsRequest,
+    ) -> QueryDelegatorValidatorsResponse {
         let validators = self
             .staking_keeper
             .delegations_iter(ctx, &delegator_address)
             .map(|res| {
                 let del = res.unwrap_gas();
-                del.validator().to_string()
+                del.validator().clone()
             })
             .collect::<Vec<_>>();
-        QueryWithdrawAllRewardsResponse { validators }
+        QueryDelegatorValidatorsResponse { validators }
+    }
+
+    pub fn query_delegator_withdraw_address<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryDelegatorWithdrawAddressRequest { delegator_address }: QueryDelegatorWithdrawAddressRequest,
+    ) -> QueryDelegatorWithdrawAddressResponse {
+        // the rewards go to the delegator unless another address is set
+        let withdraw_address = self
+            .delegator_withdraw_addr(ctx, &delegator_address)
+            .unwrap_gas()
+            .unwrap_or(delegator_address);
+        QueryDelegatorWithdrawAddressResponse { withdraw_address }
     }
 
     pub fn query_community_pool<DB: Database>(
@@ -234,7 +248,7 @@ impl<
             pool: self
                 .fee_pool(ctx)
                 .unwrap_gas()
-                .map(|fee_pool| fee_pool.community_pool),
+                .and_then(|fee_pool| fee_pool.community_pool),
         }
     }
 
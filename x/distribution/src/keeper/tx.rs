@@ -1,7 +1,10 @@
 use gears::tendermint::types::proto::event::{Event, EventAttribute};
 
 use super::*;
-use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
+use crate::{
+    MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward,
+    MsgWithdrawValidatorCommission,
+};
 
 impl<
         SK: StoreKey,
@@ -57,6 +60,32 @@ impl<
         Ok(())
     }
 
+    pub fn withdraw_validator_commission_cmd<DB: Database>(
+        &self,
+        ctx: &mut TxContext<DB, SK>,
+        msg: &MsgWithdrawValidatorCommission,
+    ) -> Result<(), DistributionError> {
+        self.withdraw_validator_commission(ctx, &msg.validator_address)?;
+
+        ctx.push_event(Event {
+            r#type: "message".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "module".into(),
+                    value: self.distribution_module.name().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "sender".into(),
+                    value: msg.validator_address.to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        Ok(())
+    }
+
     pub fn set_withdraw_address<DB: Database>(
         &self,
         ctx: &mut TxContext<DB, SK>,
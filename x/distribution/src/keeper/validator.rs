@@ -14,9 +14,9 @@ impl<
     > Keeper<SK, PSK, AK, BK, DSK, M>
 {
     /// increment the reference count for a historical rewards value
-    pub fn increment_reference_count<DB: Database>(
+    pub fn increment_reference_count<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_address: &ValAddress,
         period: u64,
     ) -> Result<(), DistributionError> {
@@ -31,9 +31,9 @@ impl<
     }
 
     /// increment validator period, returning the period just ended
-    pub fn increment_validator_period<DB: Database>(
+    pub fn increment_validator_period<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_operator_addr: &ValAddress,
         validator_tokens: Uint256,
     ) -> Result<u64, DistributionError> {
@@ -55,7 +55,7 @@ impl<
                 .ok_or(DistributionError::ValidatorOutstandingRewardsNotFound(
                     validator_operator_addr.clone(),
                 ))?;
-            fee_pool.community_pool = fee_pool.community_pool.checked_add(&rewards.rewards)?;
+            fee_pool.add_to_community_pool(&rewards.rewards)?;
             outstanding.rewards = outstanding.rewards.checked_sub(&rewards.rewards)?;
             self.set_fee_pool(ctx, &fee_pool)?;
             self.set_validator_outstanding_rewards(ctx, validator_operator_addr, &outstanding)?;
@@ -71,8 +71,11 @@ impl<
         };
 
         // fetch historical rewards for last period
+        let previous_period = rewards.period.checked_sub(1).ok_or(
+            DistributionError::ValidatorHistoricalRewardsNotFound(validator_operator_addr.clone()),
+        )?;
         let historical = if let Some(rewards) =
-            self.validator_historical_rewards(ctx, validator_operator_addr, rewards.period - 1)?
+            self.validator_historical_rewards(ctx, validator_operator_addr, previous_period)?
         {
             rewards.cumulative_reward_ratio
         } else {
@@ -82,7 +85,7 @@ impl<
         };
 
         // decrement reference count
-        self.decrement_reference_count(ctx, validator_operator_addr, rewards.period - 1)?;
+        self.decrement_reference_count(ctx, validator_operator_addr, previous_period)?;
 
         // set new historical rewards with reference count of 1
         let cumulative_reward_ratio = if let Some(current) = current {
@@ -116,9 +119,9 @@ impl<
     }
 
     /// decrement the reference count for a historical rewards value, and delete if zero references remain
-    pub fn decrement_reference_count<DB: Database>(
+    pub fn decrement_reference_count<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut TxContext<'_, DB, SK>,
+        ctx: &mut CTX,
         validator_operator_addr: &ValAddress,
         period: u64,
     ) -> Result<(), DistributionError> {
@@ -57,6 +57,15 @@ pub fn validator_historical_rewards_key(addr: ValAddress, power: u64) -> Vec<u8>
     .concat()
 }
 
+/// validator_historical_rewards_prefix creates the prefix key for a validator's historical rewards
+pub fn validator_historical_rewards_prefix(addr: ValAddress) -> Vec<u8> {
+    [
+        VALIDATOR_HISTORICAL_REWARDS_PREFIX.to_vec(),
+        length_prefixed(addr.len(), addr),
+    ]
+    .concat()
+}
+
 /// validator_current_rewards_key creates the key for a validator's historical rewards
 pub fn validator_current_rewards_key(addr: ValAddress) -> Vec<u8> {
     [
@@ -66,6 +75,15 @@ pub fn validator_current_rewards_key(addr: ValAddress) -> Vec<u8> {
     .concat()
 }
 
+/// validator_slash_events_prefix creates the prefix key for all slash events of a validator
+pub fn validator_slash_events_prefix(addr: ValAddress) -> Vec<u8> {
+    [
+        VALIDATOR_SLASH_EVENT_PREFIX.to_vec(),
+        length_prefixed(addr.len(), addr),
+    ]
+    .concat()
+}
+
 /// validator_slash_event_key_prefix creates the prefix key for a validator's slash fraction (ValidatorSlashEventPrefix + height)
 pub fn validator_slash_event_key_prefix(addr: ValAddress, height: u64) -> Vec<u8> {
     [
@@ -114,6 +132,12 @@ pub fn validator_slash_event_key(addr: ValAddress, height: u64, period: u64) ->
     .concat()
 }
 
+/// split_length_prefixed splits a length prefixed address from the beginning of the key
+pub(crate) fn split_length_prefixed(key: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (len, rest) = key.split_first()?;
+    (rest.len() >= *len as usize).then(|| rest.split_at(*len as usize))
+}
+
 // private function for addresses
 fn length_prefixed(len: u8, addr: impl AsRef<[u8]>) -> Vec<u8> {
     [vec![len], addr.as_ref().to_vec()].concat()
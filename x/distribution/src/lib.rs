@@ -7,6 +7,9 @@ mod keeper;
 mod keys;
 mod message;
 mod params;
+pub mod submission;
+#[cfg(test)]
+mod testing;
 mod types;
 
 pub use abci_handler::*;
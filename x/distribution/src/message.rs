@@ -1,7 +1,10 @@
 use gears::derive::AppMessage;
 use serde::Serialize;
 
-use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
+use crate::{
+    MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward,
+    MsgWithdrawValidatorCommission,
+};
 
 #[derive(Debug, Clone, Serialize, AppMessage)]
 pub enum Message {
@@ -14,4 +17,7 @@ pub enum Message {
     #[serde(rename = "/cosmos.distribution.v1beta1.FundCommunityPool")]
     #[msg(url(path = MsgFundCommunityPool::TYPE_URL))]
     FundCommunityPool(MsgFundCommunityPool),
+    #[serde(rename = "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission")]
+    #[msg(url(path = MsgWithdrawValidatorCommission::TYPE_URL))]
+    WithdrawValidatorCommission(MsgWithdrawValidatorCommission),
 }
@@ -0,0 +1,257 @@
+use gears::{
+    context::InfallibleContextMut,
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    tendermint::types::proto::event::{Event, EventAttribute},
+    x::{
+        keepers::{
+            auth::AuthKeeper,
+            staking::{DistributionStakingKeeper, StakingBankKeeper as BankKeeper},
+        },
+        module::Module,
+    },
+};
+use gov::{
+    submission::{community_pool::CommunityPoolSpendProposal, handler::SubmissionHandlingError},
+    types::proposal::Proposal,
+    ProposalHandler,
+};
+use tracing::info;
+
+use crate::Keeper;
+
+/// Handler of the community pool spend proposals which passed the governance
+#[derive(Debug, Clone)]
+pub struct CommunityPoolSpendProposalHandler<
+    SK: StoreKey,
+    PSK: ParamsSubspaceKey,
+    AK: AuthKeeper<SK, M>,
+    BK: BankKeeper<SK, M>,
+    DSK: DistributionStakingKeeper<SK, M>,
+    M: Module,
+> {
+    keeper: Keeper<SK, PSK, AK, BK, DSK, M>,
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > CommunityPoolSpendProposalHandler<SK, PSK, AK, BK, DSK, M>
+{
+    pub fn new(keeper: Keeper<SK, PSK, AK, BK, DSK, M>) -> Self {
+        Self { keeper }
+    }
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > ProposalHandler<SK, PSK, Proposal>
+    for CommunityPoolSpendProposalHandler<SK, PSK, AK, BK, DSK, M>
+{
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: Database>(
+        &self,
+        proposal: &Proposal,
+        ctx: &mut CTX,
+    ) -> Result<(), SubmissionHandlingError> {
+        if proposal.content.type_url != CommunityPoolSpendProposal::TYPE_URL {
+            return Err(SubmissionHandlingError::InvalidProposal);
+        }
+
+        let CommunityPoolSpendProposal {
+            recipient, amount, ..
+        } = proposal.content.clone().try_into()?;
+
+        self.keeper
+            .distribute_from_fee_pool(ctx, amount.clone(), &recipient)
+            .map_err(|e| SubmissionHandlingError::Handling(e.to_string()))?;
+
+        ctx.push_event(Event {
+            r#type: "community_pool_spend".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "recipient".into(),
+                    value: recipient.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "amount".into(),
+                    // TODO: stringify coins structs
+                    value: serde_json::to_string(&amount)
+                        .expect("serde can't fail")
+                        .into(),
+                    index: false,
+                },
+            ],
+        });
+
+        info!("transferred from the community pool to recipient {recipient}");
+
+        Ok(())
+    }
+
+    fn check(proposal: &Proposal) -> bool {
+        proposal.content.type_url == CommunityPoolSpendProposal::TYPE_URL
+            && CommunityPoolSpendProposal::try_from(proposal.content.clone()).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr};
+
+    use gears::{
+        extensions::testing::UnwrapTesting,
+        tendermint::types::time::timestamp::Timestamp,
+        types::{
+            address::AccAddress,
+            base::{
+                coin::{DecimalCoin, UnsignedCoin},
+                coins::{DecimalCoins, UnsignedCoins},
+            },
+        },
+    };
+    use gov::types::proposal::ProposalStatus;
+
+    use super::*;
+    use crate::{
+        errors::DistributionError,
+        testing::{init_ctx, keeper, multi_store, TestKeeper},
+        FeePool,
+    };
+
+    const RECIPIENT: &str = "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux";
+
+    fn decimal_coins(coins: &str) -> DecimalCoins {
+        DecimalCoins::new(vec![DecimalCoin::from_str(coins).unwrap_test()]).unwrap_test()
+    }
+
+    fn unsigned_coins(coins: &str) -> UnsignedCoins {
+        UnsignedCoins::new(vec![UnsignedCoin::from_str(coins).unwrap_test()]).unwrap_test()
+    }
+
+    fn proposal(amount: &str) -> Proposal {
+        Proposal {
+            proposal_id: 1,
+            content: CommunityPoolSpendProposal {
+                title: "Spend".to_owned(),
+                description: "Spend the community pool".to_owned(),
+                recipient: AccAddress::from_bech32(RECIPIENT).unwrap_test(),
+                amount: unsigned_coins(amount),
+            }
+            .into(),
+            status: ProposalStatus::Passed,
+            final_tally_result: None,
+            submit_time: Timestamp::UNIX_EPOCH,
+            deposit_end_time: Timestamp::UNIX_EPOCH,
+            total_deposit: unsigned_coins("10uatom"),
+            voting_start_time: None,
+            voting_end_time: None,
+        }
+    }
+
+    /// Funds the community pool with `pool`, handles the proposal spending `amount` and
+    /// returns the result together with the community pool and the events after it
+    fn spend(
+        keeper: TestKeeper,
+        pool: &str,
+        amount: &str,
+    ) -> (
+        Result<(), SubmissionHandlingError>,
+        Option<DecimalCoins>,
+        Vec<Event>,
+    ) {
+        let mut multi_store = multi_store();
+        let mut ctx = init_ctx(&mut multi_store);
+        keeper
+            .set_fee_pool(
+                &mut ctx,
+                &FeePool {
+                    community_pool: Some(decimal_coins(pool)),
+                },
+            )
+            .unwrap_test();
+
+        let result = CommunityPoolSpendProposalHandler::new(keeper.clone())
+            .handle(&proposal(amount), &mut ctx);
+        let fee_pool = keeper.fee_pool(&ctx).unwrap_test().unwrap_test();
+
+        (result, fee_pool.community_pool, ctx.events)
+    }
+
+    #[test]
+    fn spend_from_community_pool() {
+        let (result, community_pool, events) =
+            spend(keeper(vec![], HashMap::new()), "100uatom", "40uatom");
+
+        result.unwrap_test();
+        assert_eq!(community_pool, Some(decimal_coins("60uatom")));
+        assert_eq!(
+            events,
+            vec![Event {
+                r#type: "community_pool_spend".to_owned(),
+                attributes: vec![
+                    EventAttribute {
+                        key: "recipient".into(),
+                        value: RECIPIENT.into(),
+                        index: false,
+                    },
+                    EventAttribute {
+                        key: "amount".into(),
+                        value: serde_json::to_string(&unsigned_coins("40uatom"))
+                            .unwrap_test()
+                            .into(),
+                        index: false,
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn spend_whole_community_pool() {
+        let (result, community_pool, _) =
+            spend(keeper(vec![], HashMap::new()), "100uatom", "100uatom");
+
+        result.unwrap_test();
+        assert_eq!(community_pool, None);
+    }
+
+    #[test]
+    fn spend_more_than_community_pool() {
+        let (result, community_pool, events) =
+            spend(keeper(vec![], HashMap::new()), "10uatom", "40uatom");
+
+        assert!(matches!(
+            result,
+            Err(SubmissionHandlingError::Handling(e))
+                if e == DistributionError::InsufficientCommunityPool.to_string()
+        ));
+        assert_eq!(community_pool, Some(decimal_coins("10uatom")));
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn spend_to_blocked_recipient() {
+        let keeper = keeper(vec![], HashMap::from([(RECIPIENT.to_owned(), true)]));
+
+        let (result, community_pool, events) = spend(keeper, "100uatom", "40uatom");
+
+        let recipient = AccAddress::from_bech32(RECIPIENT).unwrap_test();
+        assert!(matches!(
+            result,
+            Err(SubmissionHandlingError::Handling(e))
+                if e == DistributionError::BlockedAddress(recipient).to_string()
+        ));
+        assert_eq!(community_pool, Some(decimal_coins("100uatom")));
+        assert!(events.is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use std::{collections::HashMap, sync::Arc};
+
+use gears::{
+    baseapp::ConsensusParams,
+    context::init::InitContext,
+    derive::{ParamsKeys, StoreKeys},
+    extensions::testing::UnwrapTesting,
+    store::{bank::multi::ApplicationMultiBank, database::MemDB},
+    types::address::AccAddress,
+    utils::node::build_init_ctx,
+    x::{
+        keepers::mocks::{
+            auth::MockAuthKeeper,
+            bank::MockBankKeeper,
+            staking::{MockStakingKeeper, MockValidator},
+        },
+        module::Module,
+    },
+};
+
+use crate::Keeper;
+
+pub type TestKeeper =
+    Keeper<SpaceKey, SubspaceKey, MockAuthKeeper, MockBankKeeper, MockStakingKeeper, Modules>;
+
+pub fn keeper(validators: Vec<MockValidator>, blocked_addrs: HashMap<String, bool>) -> TestKeeper {
+    Keeper::new(
+        SpaceKey::Distribution,
+        SubspaceKey::Distribution,
+        MockAuthKeeper::former().form(),
+        MockBankKeeper::former()
+            .balance("0uatom".parse().unwrap_test())
+            .form(),
+        MockStakingKeeper {
+            validators,
+            delegations: vec![],
+            total_bonded_tokens: "0uatom".parse().unwrap_test(),
+            max_validators: 100,
+        },
+        Modules::FeeCollector,
+        Modules::Distribution,
+        blocked_addrs,
+    )
+}
+
+pub fn multi_store() -> ApplicationMultiBank<MemDB, SpaceKey> {
+    ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test()
+}
+
+pub fn init_ctx(
+    multi_store: &mut ApplicationMultiBank<MemDB, SpaceKey>,
+) -> InitContext<'_, MemDB, SpaceKey> {
+    build_init_ctx(multi_store, ConsensusParams::default())
+}
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+#[skey(params = Params)]
+pub enum SpaceKey {
+    #[skey(to_string = "distribution")]
+    Distribution,
+    #[skey(to_string = "params")]
+    Params,
+}
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys)]
+pub enum SubspaceKey {
+    #[pkey(to_string = "distribution/")]
+    Distribution,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Modules {
+    FeeCollector,
+    Distribution,
+}
+
+impl Module for Modules {
+    fn name(&self) -> String {
+        match self {
+            Modules::FeeCollector => "fee_collector".into(),
+            Modules::Distribution => "distribution".into(),
+        }
+    }
+
+    fn address(&self) -> AccAddress {
+        match self {
+            Modules::FeeCollector => {
+                AccAddress::from_bech32("cosmos17xpfvakm2amg962yls6f84z3kell8c5lserqta")
+                    .expect("hard coded address is valid")
+            }
+            Modules::Distribution => {
+                AccAddress::from_bech32("cosmos1jv65s3grqf6v6jl3dp4t6c9t9rk99cd88lyufl")
+                    .expect("hard coded address is valid")
+            }
+        }
+    }
+
+    fn permissions(&self) -> Vec<String> {
+        vec![]
+    }
+}
@@ -19,28 +19,56 @@ impl From<FeePool> for FeePoolRaw {
     fn from(FeePool { community_pool }: FeePool) -> Self {
         Self {
             community_pool: community_pool
-                .into_inner()
-                .into_iter()
-                .map(Into::into)
-                .collect(),
+                .map(|pool| pool.into_inner().into_iter().map(Into::into).collect())
+                .unwrap_or_default(),
         }
     }
 }
 
 /// FeePool is the global fee pool for distribution.
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(try_from = "Vec<DecimalCoin>")]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "FeePoolJson", into = "FeePoolJson")]
 pub struct FeePool {
-    pub community_pool: DecimalCoins,
+    /// community pool coins, `None` stands for an empty pool
+    pub community_pool: Option<DecimalCoins>,
 }
 
-impl TryFrom<Vec<DecimalCoin>> for FeePool {
+impl FeePool {
+    /// add_to_community_pool adds the coins to the community pool
+    pub fn add_to_community_pool(&mut self, coins: &DecimalCoins) -> Result<(), CoinsError> {
+        self.community_pool = Some(match &self.community_pool {
+            Some(pool) => pool.checked_add(coins)?,
+            None => coins.clone(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+struct FeePoolJson {
+    community_pool: Vec<DecimalCoin>,
+}
+
+impl From<FeePool> for FeePoolJson {
+    fn from(FeePool { community_pool }: FeePool) -> Self {
+        Self {
+            community_pool: community_pool.map(Vec::from).unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<FeePoolJson> for FeePool {
     type Error = CoinsError;
 
-    fn try_from(value: Vec<DecimalCoin>) -> Result<Self, Self::Error> {
-        Ok(Self {
-            community_pool: DecimalCoins::new(value)?,
-        })
+    fn try_from(FeePoolJson { community_pool }: FeePoolJson) -> Result<Self, Self::Error> {
+        let community_pool = if community_pool.is_empty() {
+            None
+        } else {
+            Some(DecimalCoins::new(community_pool)?)
+        };
+
+        Ok(Self { community_pool })
     }
 }
 
@@ -52,8 +80,11 @@ impl TryFrom<FeePoolRaw> for FeePool {
         for coin in community_pool {
             coins.push(coin.try_into()?);
         }
-        let community_pool =
-            DecimalCoins::new(coins).map_err(|e| CoreError::Coin(e.to_string()))?;
+        let community_pool = if coins.is_empty() {
+            None
+        } else {
+            Some(DecimalCoins::new(coins).map_err(|e| CoreError::Coin(e.to_string()))?)
+        };
         Ok(Self { community_pool })
     }
 }
@@ -177,44 +177,24 @@ pub struct QueryDelegationRewardsRequest {
     pub validator_address: ValAddress,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize, Message)]
-pub struct QueryWithdrawAllRewardsRequestRaw {
-    #[prost(bytes, tag = "1")]
-    pub delegator_address: Vec<u8>,
-}
-
-impl From<QueryWithdrawAllRewardsRequest> for QueryWithdrawAllRewardsRequestRaw {
-    fn from(
-        QueryWithdrawAllRewardsRequest { delegator_address }: QueryWithdrawAllRewardsRequest,
-    ) -> Self {
-        Self {
-            delegator_address: delegator_address.into(),
-        }
-    }
-}
-
 /// QueryDelegatorValidatorsRequest is the request type for the
 /// Query/DelegatorValidators RPC method.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct QueryWithdrawAllRewardsRequest {
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
+pub struct QueryDelegatorValidatorsRequest {
     /// delegator_address defines the delegator address to query for.
+    #[raw(kind(string), raw = String)]
     pub delegator_address: AccAddress,
 }
 
-impl TryFrom<QueryWithdrawAllRewardsRequestRaw> for QueryWithdrawAllRewardsRequest {
-    type Error = AddressError;
-
-    fn try_from(
-        QueryWithdrawAllRewardsRequestRaw { delegator_address }: QueryWithdrawAllRewardsRequestRaw,
-    ) -> Result<Self, Self::Error> {
-        Ok(QueryWithdrawAllRewardsRequest {
-            delegator_address: AccAddress::try_from(delegator_address)?,
-        })
-    }
+/// QueryDelegatorWithdrawAddressRequest is the request type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
+pub struct QueryDelegatorWithdrawAddressRequest {
+    /// delegator_address defines the delegator address to query for.
+    #[raw(kind(string), raw = String)]
+    pub delegator_address: AccAddress,
 }
 
-impl Protobuf<QueryWithdrawAllRewardsRequestRaw> for QueryWithdrawAllRewardsRequest {}
-
 /// QueryDelegatorParams is params for query 'custom/distr/delegator_total_rewards'
 /// and 'custom/distr/delegator_validators'
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
@@ -415,44 +395,25 @@ impl TryFrom<QueryDelegationRewardsResponseRaw> for QueryDelegationRewardsRespon
 
 impl Protobuf<QueryDelegationRewardsResponseRaw> for QueryDelegationRewardsResponse {}
 
-#[derive(Clone, Serialize, Message)]
-pub struct QueryWithdrawAllRewardsResponseRaw {
-    #[prost(bytes, tag = "1")]
-    pub validators: Vec<u8>,
-}
-
-impl From<QueryWithdrawAllRewardsResponse> for QueryWithdrawAllRewardsResponseRaw {
-    fn from(
-        QueryWithdrawAllRewardsResponse { validators }: QueryWithdrawAllRewardsResponse,
-    ) -> Self {
-        Self {
-            validators: serde_json::to_vec(&validators)
-                .expect("serialization of domain type can't fail"),
-        }
-    }
-}
-
-/// QueryWithdrawAllRewardsResponse is the response type for the Query/DelegationRewards RPC method
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-pub struct QueryWithdrawAllRewardsResponse {
-    pub validators: Vec<String>,
+/// QueryDelegatorValidatorsResponse is the response type for the
+/// Query/DelegatorValidators RPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Raw, Protobuf)]
+pub struct QueryDelegatorValidatorsResponse {
+    /// validators defines the validators a delegator is delegating for.
+    #[proto(repeated)]
+    #[raw(kind(string), raw = String, repeated)]
+    pub validators: Vec<ValAddress>,
 }
 
-impl TryFrom<QueryWithdrawAllRewardsResponseRaw> for QueryWithdrawAllRewardsResponse {
-    type Error = CoreError;
-
-    fn try_from(
-        QueryWithdrawAllRewardsResponseRaw { validators }: QueryWithdrawAllRewardsResponseRaw,
-    ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            validators: serde_json::from_slice(&validators)
-                .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?,
-        })
-    }
+/// QueryDelegatorWithdrawAddressResponse is the response type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Raw, Protobuf)]
+pub struct QueryDelegatorWithdrawAddressResponse {
+    /// withdraw_address defines the address which receives the rewards of the delegator.
+    #[raw(kind(string), raw = String)]
+    pub withdraw_address: AccAddress,
 }
 
-impl Protobuf<QueryWithdrawAllRewardsResponseRaw> for QueryWithdrawAllRewardsResponse {}
-
 /// QueryDelegatorTotalRewardsResponse defines the properties of
 /// QueryDelegatorTotalRewards query's response.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Raw, Protobuf)]
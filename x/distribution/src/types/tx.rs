@@ -159,3 +159,53 @@ impl TryFrom<MsgFundCommunityPoolRaw> for MsgFundCommunityPool {
 }
 
 impl Protobuf<MsgFundCommunityPoolRaw> for MsgFundCommunityPool {}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Message)]
+pub struct MsgWithdrawValidatorCommissionRaw {
+    #[prost(bytes, tag = "1")]
+    pub validator_address: Vec<u8>,
+}
+
+impl From<MsgWithdrawValidatorCommission> for MsgWithdrawValidatorCommissionRaw {
+    fn from(
+        MsgWithdrawValidatorCommission {
+            validator_address, ..
+        }: MsgWithdrawValidatorCommission,
+    ) -> Self {
+        Self {
+            validator_address: validator_address.into(),
+        }
+    }
+}
+
+/// MsgWithdrawValidatorCommission withdraws the full commission to the validator
+/// address.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(url = "/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission")]
+pub struct MsgWithdrawValidatorCommission {
+    pub validator_address: ValAddress,
+    // for method `get_signers`. The sdk converts validator_address
+    #[msg(signer)]
+    from_address: AccAddress,
+}
+
+impl MsgWithdrawValidatorCommission {
+    pub fn new(validator_address: ValAddress) -> Self {
+        Self {
+            validator_address: validator_address.clone(),
+            from_address: validator_address.into(),
+        }
+    }
+}
+
+impl TryFrom<MsgWithdrawValidatorCommissionRaw> for MsgWithdrawValidatorCommission {
+    type Error = AddressError;
+
+    fn try_from(
+        MsgWithdrawValidatorCommissionRaw { validator_address }: MsgWithdrawValidatorCommissionRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self::new(ValAddress::try_from(validator_address)?))
+    }
+}
+
+impl Protobuf<MsgWithdrawValidatorCommissionRaw> for MsgWithdrawValidatorCommission {}
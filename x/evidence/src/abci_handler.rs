@@ -21,13 +21,14 @@ use gears::{
         module::Module,
     },
 };
+use serde::Serialize;
 
 #[derive(Debug, Clone)]
 pub enum EvidenceNodeQueryRequest {
     Evidence(QueryEvidenceRequest),
     AllEvidence(QueryAllEvidenceRequest),
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum EvidenceNodeQueryResponse {
     Evidence(QueryEvidenceResponse),
     AllEvidence(QueryAllEvidenceResponse),
@@ -70,6 +71,10 @@ where
         }
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState<E> {
+        self.keeper.export_genesis(ctx)
+    }
+
     /// begin_block iterates through and handles any newly discovered evidence of
     /// misbehavior submitted by Tendermint. Currently, only equivocation is handled.
     pub fn begin_block<DB: Database>(
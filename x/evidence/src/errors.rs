@@ -1,4 +1,5 @@
 use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
     tendermint::informal::hash::Hash,
     types::{address::ConsAddress, store::gas::errors::GasStoreErrors},
 };
@@ -23,6 +24,12 @@ pub enum TxEvidenceError {
     AlreadyExists(#[from] EvidenceAlreadyExistsError),
 }
 
+impl TxEvidenceError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        TxError::new::<MI>(self.to_string(), nz::u16!(1))
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error("failure in conversion of any type into concrete evidence")]
 pub struct DecodeError;
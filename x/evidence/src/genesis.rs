@@ -33,6 +33,15 @@ where
     }
 }
 
+impl<E: Evidence> From<Vec<E>> for Evidences<E>
+where
+    <E as std::convert::TryFrom<Any>>::Error: std::fmt::Debug,
+{
+    fn from(evidences: Vec<E>) -> Self {
+        Self(evidences)
+    }
+}
+
 impl<E: Evidence> TryFrom<Vec<Any>> for Evidences<E>
 where
     <E as std::convert::TryFrom<Any>>::Error: std::fmt::Debug,
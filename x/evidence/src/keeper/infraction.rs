@@ -154,13 +154,15 @@ where
         // to/by Tendermint. This value is validator.Tokens as sent to Tendermint via
         // ABCI, and now received as evidence. The fraction is passed in to separately
         // to slash unbonding and rebonding delegations.
+        let slash_fraction = self
+            .slashing_keeper
+            .slash_fraction_double_sign(ctx)
+            .unwrap_gas();
         self.slashing_keeper
             .slash(
                 ctx,
                 &cons_address,
-                self.slashing_keeper
-                    .slash_fraction_double_sign(ctx)
-                    .unwrap_gas(),
+                slash_fraction,
                 evidence.power.into(),
                 distribution_height,
             )
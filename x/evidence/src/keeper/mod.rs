@@ -1,7 +1,7 @@
 use crate::{errors::EvidenceAlreadyExistsError, types::Evidence, GenesisState};
 use gears::extensions::gas::GasResultExt;
 use gears::{
-    context::{init::InitContext, QueryableContext, TransactionalContext},
+    context::{init::InitContext, query::QueryContext, QueryableContext, TransactionalContext},
     core::any::google::Any,
     extensions::corruption::UnwrapCorrupt,
     store::{database::Database, StoreKey},
@@ -88,6 +88,20 @@ where
         Ok(())
     }
 
+    /// export_genesis returns all Evidence stored in the module's KVStore.
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState<E> {
+        let store = ctx.kv_store(&self.store_key);
+        let evidence = store
+            .prefix_store(KEY_PREFIX_EVIDENCE)
+            .into_range(..)
+            .map(|(_, bytes)| E::decode(bytes.as_slice()).unwrap_or_corrupt())
+            .collect::<Vec<_>>();
+
+        GenesisState {
+            evidence: evidence.into(),
+        }
+    }
+
     /// evidence gets Evidence by hash in the module's KVStore.
     pub fn evidence<CTX: QueryableContext<DB, SK>, DB: Database, Ev: Evidence + Default>(
         &self,
@@ -0,0 +1,116 @@
+use crate::{
+    errors::FeeGrantTxError,
+    message::Message,
+    types::query::{
+        QueryAllowanceRequest, QueryAllowanceResponse, QueryAllowancesByGranterRequest,
+        QueryAllowancesByGranterResponse, QueryAllowancesRequest, QueryAllowancesResponse,
+    },
+    GenesisState, Keeper,
+};
+use gears::{
+    baseapp::{errors::QueryError, QueryResponse},
+    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
+    core::Protobuf,
+    store::{database::Database, StoreKey},
+    tendermint::types::request::query::RequestQuery,
+    x::{keepers::auth::AuthKeeper, module::Module},
+};
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub enum FeeGrantNodeQueryRequest {
+    Allowance(QueryAllowanceRequest),
+    Allowances(QueryAllowancesRequest),
+    AllowancesByGranter(QueryAllowancesByGranterRequest),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum FeeGrantNodeQueryResponse {
+    Allowance(QueryAllowanceResponse),
+    Allowances(QueryAllowancesResponse),
+    AllowancesByGranter(QueryAllowancesByGranterResponse),
+}
+
+#[derive(Debug, Clone)]
+pub struct ABCIHandler<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> {
+    keeper: Keeper<SK, AK, M>,
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> ABCIHandler<SK, AK, M> {
+    pub fn new(keeper: Keeper<SK, AK, M>) -> Self {
+        ABCIHandler { keeper }
+    }
+
+    pub fn genesis<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>, genesis: GenesisState) {
+        self.keeper.init_genesis(ctx, genesis)
+    }
+
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        self.keeper.export_genesis(ctx)
+    }
+
+    /// end_block prunes the allowances which expired before the current block time
+    pub fn end_block<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) {
+        self.keeper.remove_expired_allowances(ctx)
+    }
+
+    pub fn tx<DB: Database + Sync + Send>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: &Message,
+    ) -> Result<(), FeeGrantTxError> {
+        match msg {
+            Message::GrantAllowance(msg) => self.keeper.grant_allowance(ctx, msg),
+            Message::RevokeAllowance(msg) => self.keeper.revoke_allowance(ctx, msg),
+        }
+    }
+
+    pub fn query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: RequestQuery,
+    ) -> Result<prost::bytes::Bytes, QueryError> {
+        match query.path.as_str() {
+            "/cosmos.feegrant.v1beta1.Query/Allowance" => {
+                let req = QueryAllowanceRequest::decode_vec(&query.data)?;
+
+                Ok(self.keeper.query_allowance(ctx, req).into_bytes().into())
+            }
+            "/cosmos.feegrant.v1beta1.Query/Allowances" => {
+                let req = QueryAllowancesRequest::decode_vec(&query.data)?;
+
+                Ok(self.keeper.query_allowances(ctx, req).into_bytes().into())
+            }
+            "/cosmos.feegrant.v1beta1.Query/AllowancesByGranter" => {
+                let req = QueryAllowancesByGranterRequest::decode_vec(&query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_allowances_by_granter(ctx, req)
+                    .into_bytes()
+                    .into())
+            }
+            _ => Err(QueryError::PathNotFound),
+        }
+    }
+
+    pub fn typed_query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: FeeGrantNodeQueryRequest,
+    ) -> FeeGrantNodeQueryResponse {
+        match query {
+            FeeGrantNodeQueryRequest::Allowance(req) => {
+                FeeGrantNodeQueryResponse::Allowance(self.keeper.query_allowance(ctx, req))
+            }
+            FeeGrantNodeQueryRequest::Allowances(req) => {
+                FeeGrantNodeQueryResponse::Allowances(self.keeper.query_allowances(ctx, req))
+            }
+            FeeGrantNodeQueryRequest::AllowancesByGranter(req) => {
+                FeeGrantNodeQueryResponse::AllowancesByGranter(
+                    self.keeper.query_allowances_by_granter(ctx, req),
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::types::query::{
+    QueryAllowanceRequest, QueryAllowanceResponse, QueryAllowancesByGranterRequest,
+    QueryAllowancesByGranterResponse, QueryAllowancesRequest, QueryAllowancesResponse,
+};
+use clap::{Args, Subcommand};
+use gears::{
+    application::handlers::client::QueryHandler,
+    cli::pagination::CliPaginationRequest,
+    core::Protobuf,
+    derive::Query,
+    types::{address::AccAddress, pagination::request::PaginationRequest},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Args, Debug)]
+pub struct FeeGrantQueryCli {
+    #[command(subcommand)]
+    pub command: FeeGrantCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum FeeGrantCommands {
+    Grant(GrantCommand),
+    Grants(GrantsCommand),
+    GrantsByGranter(GrantsByGranterCommand),
+}
+
+/// Query details of a single grant given by a granter to a grantee.
+#[derive(Args, Debug, Clone)]
+pub struct GrantCommand {
+    /// granter address
+    pub granter: AccAddress,
+    /// grantee address
+    pub grantee: AccAddress,
+}
+
+/// Query all grants of a grantee.
+#[derive(Args, Debug, Clone)]
+pub struct GrantsCommand {
+    /// grantee address
+    pub grantee: AccAddress,
+    #[command(flatten)]
+    pub pagination: CliPaginationRequest,
+}
+
+/// Query all grants given by a granter.
+#[derive(Args, Debug, Clone)]
+pub struct GrantsByGranterCommand {
+    /// granter address
+    pub granter: AccAddress,
+    #[command(flatten)]
+    pub pagination: CliPaginationRequest,
+}
+
+#[derive(Debug, Clone)]
+pub struct FeeGrantQueryHandler;
+
+impl QueryHandler for FeeGrantQueryHandler {
+    type QueryRequest = FeeGrantQueryRequest;
+
+    type QueryResponse = FeeGrantQueryResponse;
+
+    type QueryCommands = FeeGrantQueryCli;
+
+    fn prepare_query_request(
+        &self,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryRequest> {
+        let res = match &command.command {
+            FeeGrantCommands::Grant(GrantCommand { granter, grantee }) => {
+                Self::QueryRequest::Allowance(QueryAllowanceRequest {
+                    granter: granter.clone(),
+                    grantee: grantee.clone(),
+                })
+            }
+            FeeGrantCommands::Grants(GrantsCommand {
+                grantee,
+                pagination,
+            }) => Self::QueryRequest::Allowances(QueryAllowancesRequest {
+                grantee: grantee.clone(),
+                pagination: PaginationRequest::try_from(pagination.clone())?,
+            }),
+            FeeGrantCommands::GrantsByGranter(GrantsByGranterCommand {
+                granter,
+                pagination,
+            }) => Self::QueryRequest::AllowancesByGranter(QueryAllowancesByGranterRequest {
+                granter: granter.clone(),
+                pagination: PaginationRequest::try_from(pagination.clone())?,
+            }),
+        };
+
+        Ok(res)
+    }
+
+    fn handle_raw_response(
+        &self,
+        query_bytes: Vec<u8>,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryResponse> {
+        let res = match &command.command {
+            FeeGrantCommands::Grant(_) => {
+                FeeGrantQueryResponse::Allowance(QueryAllowanceResponse::decode_vec(&query_bytes)?)
+            }
+            FeeGrantCommands::Grants(_) => FeeGrantQueryResponse::Allowances(
+                QueryAllowancesResponse::decode_vec(&query_bytes)?,
+            ),
+            FeeGrantCommands::GrantsByGranter(_) => FeeGrantQueryResponse::AllowancesByGranter(
+                QueryAllowancesByGranterResponse::decode_vec(&query_bytes)?,
+            ),
+        };
+
+        Ok(res)
+    }
+}
+
+#[derive(Clone, PartialEq, Query)]
+pub enum FeeGrantQueryRequest {
+    Allowance(QueryAllowanceRequest),
+    Allowances(QueryAllowancesRequest),
+    AllowancesByGranter(QueryAllowancesByGranterRequest),
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query)]
+#[serde(untagged)]
+pub enum FeeGrantQueryResponse {
+    Allowance(QueryAllowanceResponse),
+    Allowances(QueryAllowancesResponse),
+    AllowancesByGranter(QueryAllowancesByGranterResponse),
+}
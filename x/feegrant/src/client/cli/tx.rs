@@ -0,0 +1,104 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use gears::{
+    tendermint::types::time::{duration::Duration, timestamp::Timestamp},
+    types::{address::AccAddress, base::coins::UnsignedCoins},
+};
+
+use crate::{
+    AllowedMsgAllowance, BasicAllowance, FeeAllowance, Message, MsgGrantAllowance,
+    MsgRevokeAllowance, PeriodicAllowance,
+};
+
+#[derive(Args, Debug, Clone)]
+pub struct FeeGrantTxCli {
+    #[command(subcommand)]
+    pub command: FeeGrantCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum FeeGrantCommands {
+    /// Grant an allowance to pay the fees of the grantee. A basic allowance is created by
+    /// default, a periodic allowance if both '--period' and '--period-limit' are set. The
+    /// allowance is restricted to the messages listed in '--allowed-messages' if set.
+    Grant {
+        /// grantee address
+        grantee: AccAddress,
+        /// maximum amount of coins the grantee can spend, no limit if unset
+        #[arg(long)]
+        spend_limit: Option<UnsignedCoins>,
+        /// expiration of the allowance as a UNIX epoch timestamp
+        #[arg(long)]
+        expiration: Option<i64>,
+        /// period length in seconds of a periodic allowance
+        #[arg(long, requires = "period_limit")]
+        period: Option<i64>,
+        /// maximum amount of coins the grantee can spend within a period
+        #[arg(long, requires = "period")]
+        period_limit: Option<UnsignedCoins>,
+        /// comma separated list of message type urls the grantee is allowed to pay fees for
+        #[arg(long, value_delimiter = ',')]
+        allowed_messages: Vec<String>,
+    },
+    /// Revoke a fee allowance given to the grantee.
+    Revoke {
+        /// grantee address
+        grantee: AccAddress,
+    },
+}
+
+pub fn run_feegrant_tx_command(args: FeeGrantTxCli, from_address: AccAddress) -> Result<Message> {
+    match args.command {
+        FeeGrantCommands::Grant {
+            grantee,
+            spend_limit,
+            expiration,
+            period,
+            period_limit,
+            allowed_messages,
+        } => {
+            let basic = BasicAllowance {
+                spend_limit,
+                expiration: expiration
+                    .map(|seconds| Timestamp::try_new(seconds, 0))
+                    .transpose()?,
+            };
+
+            let allowance = match (period, period_limit) {
+                (Some(period), Some(period_spend_limit)) => {
+                    let period = Duration::try_new(period, 0)?;
+
+                    let allowance = PeriodicAllowance {
+                        basic,
+                        period,
+                        period_spend_limit: period_spend_limit.clone(),
+                        period_can_spend: Some(period_spend_limit),
+                        period_reset: Timestamp::UNIX_EPOCH,
+                    };
+                    allowance.validate()?;
+
+                    FeeAllowance::Periodic(allowance)
+                }
+                _ => FeeAllowance::Basic(basic),
+            };
+
+            let allowance = match allowed_messages.is_empty() {
+                true => allowance,
+                false => FeeAllowance::AllowedMsg(AllowedMsgAllowance {
+                    allowance: Box::new(allowance),
+                    allowed_messages,
+                }),
+            };
+
+            Ok(Message::GrantAllowance(MsgGrantAllowance {
+                granter: from_address,
+                grantee,
+                allowance,
+            }))
+        }
+        FeeGrantCommands::Revoke { grantee } => Ok(Message::RevokeAllowance(MsgRevokeAllowance {
+            granter: from_address,
+            grantee,
+        })),
+    }
+}
@@ -0,0 +1,39 @@
+use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
+    types::{base::errors::CoinsError, store::gas::errors::GasStoreErrors},
+    x::errors::FeeGrantKeeperError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AllowanceError {
+    #[error("invalid coins: {0}")]
+    Coins(#[from] CoinsError),
+    #[error("period duration cannot be negative")]
+    NegativePeriod,
+    #[error("period spend limit has different currency than basic spend limit")]
+    PeriodLimitCurrency,
+    #[error("allowed messages shouldn't be empty")]
+    EmptyAllowedMessages,
+    #[error("allowance is missing")]
+    MissingAllowance,
+    #[error("fee allowance type not recognized: {0}")]
+    UnknownType(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FeeGrantTxError {
+    #[error("fee allowance already exists")]
+    AlreadyExists,
+    #[error("expiration is before current block time")]
+    ExpirationBeforeBlockTime,
+    #[error(transparent)]
+    Keeper(#[from] FeeGrantKeeperError),
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+}
+
+impl FeeGrantTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        TxError::new::<MI>(self.to_string(), nz::u16!(1))
+    }
+}
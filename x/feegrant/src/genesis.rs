@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Grant;
+
+/// GenesisState contains a set of fee allowances, persisted from the store
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct GenesisState {
+    pub allowances: Vec<Grant>,
+}
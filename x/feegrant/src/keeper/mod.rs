@@ -0,0 +1,364 @@
+use gears::{
+    context::{
+        init::InitContext, query::QueryContext, InfallibleContext, InfallibleContextMut,
+        QueryableContext, TransactionalContext,
+    },
+    core::Protobuf,
+    extensions::{corruption::UnwrapCorrupt, gas::GasResultExt},
+    store::{database::Database, store::prefix::prefix_end_bound, StoreKey},
+    tendermint::types::{
+        proto::event::{Event, EventAttribute},
+        time::timestamp::Timestamp,
+    },
+    types::{address::AccAddress, base::coin::UnsignedCoin, store::gas::errors::GasStoreErrors},
+    x::{
+        errors::FeeGrantKeeperError,
+        keepers::{auth::AuthKeeper, feegrant::FeeGrantKeeper},
+        module::Module,
+    },
+};
+use std::{marker::PhantomData, ops::Bound};
+
+use crate::{errors::FeeGrantTxError, GenesisState, Grant, MsgGrantAllowance, MsgRevokeAllowance};
+
+mod query;
+
+const FEE_ALLOWANCE_KEY_PREFIX: [u8; 1] = [0x00];
+const FEE_ALLOWANCE_QUEUE_KEY_PREFIX: [u8; 1] = [0x01];
+
+/// Returns the key of a grant given by the granter to the grantee.
+/// The key is of the form grantee.len() (1 byte) || grantee || granter.len() (1 byte) || granter
+fn fee_allowance_key(granter: &AccAddress, grantee: &AccAddress) -> Vec<u8> {
+    [grantee.prefix_len_bytes(), granter.prefix_len_bytes()].concat()
+}
+
+/// Returns the key of a grant in the expiration queue.
+/// The key is of the form expiration || fee_allowance_key
+fn fee_allowance_queue_key(
+    expiration: &Timestamp,
+    granter: &AccAddress,
+    grantee: &AccAddress,
+) -> Vec<u8> {
+    [
+        expiration.format_bytes_rounded(),
+        fee_allowance_key(granter, grantee),
+    ]
+    .concat()
+}
+
+/// Keeper of the feegrant store
+#[derive(Debug, Clone)]
+pub struct Keeper<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> {
+    store_key: SK,
+    auth_keeper: AK,
+    _module: PhantomData<M>,
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> Keeper<SK, AK, M> {
+    pub fn new(store_key: SK, auth_keeper: AK) -> Self {
+        Self {
+            store_key,
+            auth_keeper,
+            _module: PhantomData,
+        }
+    }
+
+    pub fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, SK>,
+        genesis: GenesisState,
+    ) {
+        for grant in genesis.allowances {
+            self.set_allowance(ctx, &grant).unwrap_gas();
+        }
+    }
+
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let allowances = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(FEE_ALLOWANCE_KEY_PREFIX)
+            .into_range(..)
+            .map(|(_, v)| Grant::decode_vec(&v).unwrap_or_corrupt())
+            .collect();
+
+        GenesisState { allowances }
+    }
+
+    /// grant_allowance creates a new grant
+    pub fn grant_allowance<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        MsgGrantAllowance {
+            granter,
+            grantee,
+            allowance,
+        }: &MsgGrantAllowance,
+    ) -> Result<(), FeeGrantTxError> {
+        if self.allowance(ctx, granter, grantee)?.is_some() {
+            return Err(FeeGrantTxError::AlreadyExists);
+        }
+
+        if let Some(expiration) = allowance.expires_at() {
+            if expiration < ctx.get_time() {
+                return Err(FeeGrantTxError::ExpirationBeforeBlockTime);
+            }
+        }
+
+        // create the account if it is not in account state
+        if !self.auth_keeper.has_account(ctx, grantee)? {
+            self.auth_keeper.create_new_base_account(ctx, grantee)?;
+        }
+
+        let grant = Grant {
+            granter: granter.clone(),
+            grantee: grantee.clone(),
+            allowance: allowance.clone(),
+        };
+        self.set_allowance(ctx, &grant)?;
+
+        ctx.push_event(Event::new(
+            "set_feegrant",
+            [
+                EventAttribute::new("granter".into(), String::from(granter.clone()).into(), true),
+                EventAttribute::new("grantee".into(), String::from(grantee.clone()).into(), true),
+            ],
+        ));
+
+        Ok(())
+    }
+
+    /// revoke_allowance removes an existing grant
+    pub fn revoke_allowance<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        MsgRevokeAllowance { granter, grantee }: &MsgRevokeAllowance,
+    ) -> Result<(), FeeGrantTxError> {
+        let grant = self
+            .allowance(ctx, granter, grantee)?
+            .ok_or(FeeGrantKeeperError::NotFound)?;
+
+        self.delete_allowance(ctx, &grant)?;
+
+        ctx.push_event(Event::new(
+            "revoke_feegrant",
+            [
+                EventAttribute::new("granter".into(), String::from(granter.clone()).into(), true),
+                EventAttribute::new("grantee".into(), String::from(grantee.clone()).into(), true),
+            ],
+        ));
+
+        Ok(())
+    }
+
+    /// allowance returns the grant given by the granter to the grantee if it exists
+    pub fn allowance<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        granter: &AccAddress,
+        grantee: &AccAddress,
+    ) -> Result<Option<Grant>, GasStoreErrors> {
+        Ok(ctx
+            .kv_store(&self.store_key)
+            .prefix_store(FEE_ALLOWANCE_KEY_PREFIX)
+            .get(&fee_allowance_key(granter, grantee))?
+            .map(|bytes| Grant::decode_vec(&bytes).unwrap_or_corrupt()))
+    }
+
+    /// remove_expired_allowances removes all grants which expire at or before the current block time
+    pub fn remove_expired_allowances<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+    ) {
+        let end = ctx.get_time().format_bytes_rounded();
+
+        // queue keys continue after the expiration, so the keys of grants which expire at
+        // the block time are only covered by a range up to the next expiration
+        let keys = InfallibleContext::infallible_store(ctx, &self.store_key)
+            .prefix_store(FEE_ALLOWANCE_QUEUE_KEY_PREFIX)
+            .into_range((Bound::Unbounded, prefix_end_bound(end.clone())))
+            .map(|(k, _)| k.into_owned())
+            .collect::<Vec<_>>();
+
+        let mut store = InfallibleContextMut::infallible_store_mut(ctx, &self.store_key);
+        for key in keys {
+            store.delete(&[FEE_ALLOWANCE_QUEUE_KEY_PREFIX.as_slice(), &key].concat());
+            store.delete(&[FEE_ALLOWANCE_KEY_PREFIX.as_slice(), &key[end.len()..]].concat());
+        }
+    }
+
+    fn set_allowance<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        grant: &Grant,
+    ) -> Result<(), GasStoreErrors> {
+        let key = fee_allowance_key(&grant.granter, &grant.grantee);
+        let mut store = ctx.kv_store_mut(&self.store_key);
+
+        if let Some(expiration) = grant.allowance.expires_at() {
+            store.set(
+                [
+                    FEE_ALLOWANCE_QUEUE_KEY_PREFIX.as_slice(),
+                    &fee_allowance_queue_key(&expiration, &grant.granter, &grant.grantee),
+                ]
+                .concat(),
+                [],
+            )?;
+        }
+
+        store.set(
+            [FEE_ALLOWANCE_KEY_PREFIX.as_slice(), &key].concat(),
+            grant.encode_vec(),
+        )
+    }
+
+    fn delete_allowance<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        grant: &Grant,
+    ) -> Result<(), GasStoreErrors> {
+        let key = fee_allowance_key(&grant.granter, &grant.grantee);
+        let mut store = ctx.kv_store_mut(&self.store_key);
+
+        if let Some(expiration) = grant.allowance.expires_at() {
+            store.delete(
+                &[
+                    FEE_ALLOWANCE_QUEUE_KEY_PREFIX.as_slice(),
+                    &fee_allowance_queue_key(&expiration, &grant.granter, &grant.grantee),
+                ]
+                .concat(),
+            )?;
+        }
+
+        store.delete(&[FEE_ALLOWANCE_KEY_PREFIX.as_slice(), &key].concat())?;
+
+        Ok(())
+    }
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> FeeGrantKeeper<SK> for Keeper<SK, AK, M> {
+    fn use_granted_fees<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        granter: &AccAddress,
+        grantee: &AccAddress,
+        fee: &[UnsignedCoin],
+        msgs: &[&str],
+    ) -> Result<(), FeeGrantKeeperError> {
+        let mut grant = self
+            .allowance(ctx, granter, grantee)?
+            .ok_or(FeeGrantKeeperError::NotFound)?;
+
+        let remove = grant.allowance.accept(&ctx.get_time(), fee, msgs)?;
+
+        ctx.push_event(Event::new(
+            "use_feegrant",
+            [
+                EventAttribute::new("granter".into(), String::from(granter.clone()).into(), true),
+                EventAttribute::new("grantee".into(), String::from(grantee.clone()).into(), true),
+            ],
+        ));
+
+        if remove {
+            self.delete_allowance(ctx, &grant)?;
+        } else {
+            self.set_allowance(ctx, &grant)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        baseapp::ConsensusParams, context::block::BlockContext, derive::StoreKeys,
+        extensions::testing::UnwrapTesting, tendermint::types::proto::header::Header,
+        utils::node::build_store, x::keepers::mocks::auth::MockAuthKeeper,
+    };
+
+    use super::*;
+    use crate::{BasicAllowance, FeeAllowance};
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+    #[skey(params = Params)]
+    enum SpaceKey {
+        #[skey(to_string = "feegrant")]
+        FeeGrant,
+        #[skey(to_string = "params")]
+        Params,
+    }
+
+    #[derive(Debug, Clone)]
+    struct FeeGrantModule;
+
+    impl Module for FeeGrantModule {
+        fn name(&self) -> String {
+            "feegrant".to_owned()
+        }
+    }
+
+    const TIME: i64 = 1000;
+
+    fn grant(grantee: u8, expiration: i64) -> Grant {
+        Grant {
+            granter: AccAddress::try_from(vec![1; 20]).unwrap_test(),
+            grantee: AccAddress::try_from(vec![grantee; 20]).unwrap_test(),
+            allowance: FeeAllowance::Basic(BasicAllowance {
+                spend_limit: None,
+                expiration: Some(Timestamp::try_new(expiration, 0).unwrap_test()),
+            }),
+        }
+    }
+
+    #[test]
+    fn remove_expired_allowances_removes_allowances_expiring_at_block_time() {
+        let keeper: Keeper<SpaceKey, MockAuthKeeper, FeeGrantModule> =
+            Keeper::new(SpaceKey::FeeGrant, MockAuthKeeper::former().form());
+        let mut multi_store = build_store();
+        let header = Header {
+            height: 1,
+            time: Timestamp::try_new(TIME, 0).unwrap_test(),
+            ..Default::default()
+        };
+        let mut ctx = BlockContext::new(&mut multi_store, 1, header, ConsensusParams::default());
+
+        let expired = grant(2, TIME - 1);
+        let expiring = grant(3, TIME);
+        let active = grant(4, TIME + 1);
+        for grant in [&expired, &expiring, &active] {
+            keeper.set_allowance(&mut ctx, grant).unwrap_test();
+        }
+
+        keeper.remove_expired_allowances(&mut ctx);
+
+        for removed in [&expired, &expiring] {
+            assert_eq!(
+                keeper
+                    .allowance(&ctx, &removed.granter, &removed.grantee)
+                    .unwrap_test(),
+                None
+            );
+        }
+        assert_eq!(
+            keeper
+                .allowance(&ctx, &active.granter, &active.grantee)
+                .unwrap_test(),
+            Some(active.clone())
+        );
+
+        // only the queue entry of the active allowance is left
+        let queue = InfallibleContext::infallible_store(&ctx, &SpaceKey::FeeGrant)
+            .prefix_store(FEE_ALLOWANCE_QUEUE_KEY_PREFIX)
+            .into_range(..)
+            .map(|(k, _)| k.into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            queue,
+            vec![fee_allowance_queue_key(
+                &Timestamp::try_new(TIME + 1, 0).unwrap_test(),
+                &active.granter,
+                &active.grantee
+            )]
+        );
+    }
+}
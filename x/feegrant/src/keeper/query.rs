@@ -0,0 +1,89 @@
+use super::*;
+use crate::types::query::{
+    QueryAllowanceRequest, QueryAllowanceResponse, QueryAllowancesByGranterRequest,
+    QueryAllowancesByGranterResponse, QueryAllowancesRequest, QueryAllowancesResponse,
+};
+use gears::{
+    extensions::pagination::{IteratorPaginate, Pagination},
+    types::pagination::response::PaginationResponse,
+};
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, M: Module> Keeper<SK, AK, M> {
+    pub fn query_allowance<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryAllowanceRequest { granter, grantee }: QueryAllowanceRequest,
+    ) -> QueryAllowanceResponse {
+        QueryAllowanceResponse {
+            allowance: self.allowance(ctx, &granter, &grantee).unwrap_gas(),
+        }
+    }
+
+    pub fn query_allowances<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryAllowancesRequest {
+            grantee,
+            pagination,
+        }: QueryAllowancesRequest,
+    ) -> QueryAllowancesResponse {
+        let (p_result, iter) = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(
+                [
+                    FEE_ALLOWANCE_KEY_PREFIX.as_slice(),
+                    &grantee.prefix_len_bytes(),
+                ]
+                .concat(),
+            )
+            .into_range(..)
+            .maybe_paginate(Some(Pagination::from(pagination)));
+
+        let allowances = iter
+            .map(|(_, v)| Grant::decode_vec(&v).unwrap_or_corrupt())
+            .collect();
+
+        QueryAllowancesResponse {
+            allowances,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
+    /// query_allowances_by_granter returns all the grants given by an address. The grants are
+    /// indexed by the grantee, so this iterates over all the grants in the store.
+    pub fn query_allowances_by_granter<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryAllowancesByGranterRequest {
+            granter,
+            pagination,
+        }: QueryAllowancesByGranterRequest,
+    ) -> QueryAllowancesByGranterResponse {
+        let granter_suffix = granter.prefix_len_bytes();
+
+        let (p_result, iter) = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(FEE_ALLOWANCE_KEY_PREFIX)
+            .into_range(..)
+            .filter(|(k, _)| is_granter_key(k, &granter_suffix))
+            .maybe_paginate(Some(Pagination::from(pagination)));
+
+        let allowances = iter
+            .map(|(_, v)| Grant::decode_vec(&v).unwrap_or_corrupt())
+            .collect();
+
+        QueryAllowancesByGranterResponse {
+            allowances,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+}
+
+/// Checks that the granter part of a fee allowance key matches the length prefixed granter. The
+/// key is of the form grantee.len() (1 byte) || grantee || granter.len() (1 byte) || granter
+fn is_granter_key(key: &[u8], granter: &[u8]) -> bool {
+    match key.first() {
+        Some(grantee_len) => key.get(1 + *grantee_len as usize..) == Some(granter),
+        None => false,
+    }
+}
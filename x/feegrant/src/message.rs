@@ -0,0 +1,13 @@
+use crate::types::{MsgGrantAllowance, MsgRevokeAllowance};
+use gears::derive::AppMessage;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, AppMessage)]
+pub enum Message {
+    #[serde(rename = "/cosmos.feegrant.v1beta1.MsgGrantAllowance")]
+    #[msg(url(path = MsgGrantAllowance::TYPE_URL))]
+    GrantAllowance(MsgGrantAllowance),
+    #[serde(rename = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance")]
+    #[msg(url(path = MsgRevokeAllowance::TYPE_URL))]
+    RevokeAllowance(MsgRevokeAllowance),
+}
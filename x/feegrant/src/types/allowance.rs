@@ -0,0 +1,660 @@
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    tendermint::types::time::{duration::Duration, timestamp::Timestamp},
+    types::base::{
+        coin::{inner::Coin as RawCoin, UnsignedCoin},
+        coins::UnsignedCoins,
+        errors::CoinError,
+    },
+    x::errors::FeeGrantKeeperError,
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::errors::AllowanceError;
+
+pub const BASIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+pub const PERIODIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.PeriodicAllowance";
+pub const ALLOWED_MSG_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.AllowedMsgAllowance";
+
+#[derive(Clone, PartialEq, Message)]
+pub struct BasicAllowanceRaw {
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<RawCoin>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<Timestamp>,
+}
+
+impl From<BasicAllowance> for BasicAllowanceRaw {
+    fn from(
+        BasicAllowance {
+            spend_limit,
+            expiration,
+        }: BasicAllowance,
+    ) -> Self {
+        Self {
+            spend_limit: coins_into_raw(spend_limit),
+            expiration,
+        }
+    }
+}
+
+/// BasicAllowance implements Allowance with a one-time grant of coins
+/// that optionally expires. The grantee can use up to SpendLimit to cover fees.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BasicAllowance {
+    /// spend_limit specifies the maximum amount of coins that can be spent
+    /// by this allowance and will be updated as coins are spent. If it is
+    /// empty, there is no spend limit and any amount of coins can be spent.
+    #[serde(with = "serde_coins_opt", default)]
+    pub spend_limit: Option<UnsignedCoins>,
+    /// expiration specifies an optional time when this allowance expires
+    pub expiration: Option<Timestamp>,
+}
+
+impl BasicAllowance {
+    /// accept deducts the fee from the spend limit. It returns true if the allowance is
+    /// exhausted and should be removed.
+    pub fn accept(
+        &mut self,
+        time: &Timestamp,
+        fee: &[UnsignedCoin],
+    ) -> Result<bool, FeeGrantKeeperError> {
+        if self.is_expired(time) {
+            return Err(FeeGrantKeeperError::Expired);
+        }
+
+        match &self.spend_limit {
+            Some(spend_limit) => {
+                let left = sub_coins(spend_limit.inner(), fee)
+                    .ok_or(FeeGrantKeeperError::LimitExceeded("basic allowance".into()))?;
+
+                let exhausted = left.is_empty();
+                self.spend_limit = UnsignedCoins::new(left).ok();
+
+                Ok(exhausted)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn is_expired(&self, time: &Timestamp) -> bool {
+        self.expiration
+            .as_ref()
+            .is_some_and(|expiration| expiration < time)
+    }
+}
+
+impl TryFrom<BasicAllowanceRaw> for BasicAllowance {
+    type Error = CoreError;
+
+    fn try_from(
+        BasicAllowanceRaw {
+            spend_limit,
+            expiration,
+        }: BasicAllowanceRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            spend_limit: coins_from_raw(spend_limit)?,
+            expiration,
+        })
+    }
+}
+
+impl Protobuf<BasicAllowanceRaw> for BasicAllowance {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PeriodicAllowanceRaw {
+    #[prost(message, optional, tag = "1")]
+    pub basic: Option<BasicAllowanceRaw>,
+    #[prost(message, optional, tag = "2")]
+    pub period: Option<Duration>,
+    #[prost(message, repeated, tag = "3")]
+    pub period_spend_limit: Vec<RawCoin>,
+    #[prost(message, repeated, tag = "4")]
+    pub period_can_spend: Vec<RawCoin>,
+    #[prost(message, optional, tag = "5")]
+    pub period_reset: Option<Timestamp>,
+}
+
+impl From<PeriodicAllowance> for PeriodicAllowanceRaw {
+    fn from(
+        PeriodicAllowance {
+            basic,
+            period,
+            period_spend_limit,
+            period_can_spend,
+            period_reset,
+        }: PeriodicAllowance,
+    ) -> Self {
+        Self {
+            basic: Some(basic.into()),
+            period: Some(period),
+            period_spend_limit: coins_into_raw(Some(period_spend_limit)),
+            period_can_spend: coins_into_raw(period_can_spend),
+            period_reset: Some(period_reset),
+        }
+    }
+}
+
+/// PeriodicAllowance extends Allowance to allow for both a maximum cap,
+/// as well as a limit per time period.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PeriodicAllowance {
+    /// basic specifies a struct of `BasicAllowance`
+    pub basic: BasicAllowance,
+    /// period specifies the time duration in which period_spend_limit coins can
+    /// be spent before that allowance is reset
+    pub period: Duration,
+    /// period_spend_limit specifies the maximum number of coins that can be spent
+    /// in the period
+    pub period_spend_limit: UnsignedCoins,
+    /// period_can_spend is the number of coins left to be spent before the period_reset time.
+    /// It is empty if nothing is left in the current period.
+    #[serde(with = "serde_coins_opt", default)]
+    pub period_can_spend: Option<UnsignedCoins>,
+    /// period_reset is the time at which this period resets and a new one begins,
+    /// it is calculated from the start time of the first transaction after the
+    /// last period ended
+    pub period_reset: Timestamp,
+}
+
+impl PeriodicAllowance {
+    pub fn validate(&self) -> Result<(), AllowanceError> {
+        if i128::from(self.period.duration_nanoseconds()) < 0 {
+            return Err(AllowanceError::NegativePeriod);
+        }
+
+        if let Some(spend_limit) = &self.basic.spend_limit {
+            let denoms_subset = self
+                .period_spend_limit
+                .inner()
+                .iter()
+                .all(|coin| !spend_limit.amount_of(&coin.denom).is_zero());
+
+            if !denoms_subset {
+                return Err(AllowanceError::PeriodLimitCurrency);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// accept deducts the fee from both the period and the absolute spend limits. It returns
+    /// true if the allowance is exhausted and should be removed.
+    pub fn accept(
+        &mut self,
+        time: &Timestamp,
+        fee: &[UnsignedCoin],
+    ) -> Result<bool, FeeGrantKeeperError> {
+        if self.basic.is_expired(time) {
+            return Err(FeeGrantKeeperError::Expired);
+        }
+
+        self.try_reset_period(time);
+
+        let can_spend = self
+            .period_can_spend
+            .as_ref()
+            .map(|coins| coins.inner().as_slice())
+            .unwrap_or_default();
+        let period_left = sub_coins(can_spend, fee)
+            .ok_or(FeeGrantKeeperError::LimitExceeded("period limit".into()))?;
+        self.period_can_spend = UnsignedCoins::new(period_left).ok();
+
+        match &self.basic.spend_limit {
+            Some(spend_limit) => {
+                let left = sub_coins(spend_limit.inner(), fee)
+                    .ok_or(FeeGrantKeeperError::LimitExceeded("absolute limit".into()))?;
+
+                let exhausted = left.is_empty();
+                self.basic.spend_limit = UnsignedCoins::new(left).ok();
+
+                Ok(exhausted)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// try_reset_period resets the period if needed. It will top up period_can_spend to the
+    /// lesser of basic.spend_limit and period_spend_limit and moves period_reset one period
+    /// forward or to time + period if that is already in the past.
+    fn try_reset_period(&mut self, time: &Timestamp) {
+        if time < &self.period_reset {
+            return;
+        }
+
+        self.period_can_spend = match &self.basic.spend_limit {
+            Some(spend_limit)
+                if sub_coins(spend_limit.inner(), self.period_spend_limit.inner()).is_none() =>
+            {
+                Some(spend_limit.clone())
+            }
+            _ => Some(self.period_spend_limit.clone()),
+        };
+
+        self.period_reset = match self.period_reset.checked_add(self.period) {
+            Some(period_reset) if &period_reset >= time => period_reset,
+            _ => time.checked_add(self.period).unwrap_or(*time),
+        };
+    }
+}
+
+impl TryFrom<PeriodicAllowanceRaw> for PeriodicAllowance {
+    type Error = CoreError;
+
+    fn try_from(
+        PeriodicAllowanceRaw {
+            basic,
+            period,
+            period_spend_limit,
+            period_can_spend,
+            period_reset,
+        }: PeriodicAllowanceRaw,
+    ) -> Result<Self, Self::Error> {
+        let allowance = Self {
+            basic: basic
+                .ok_or(CoreError::MissingField("basic".into()))?
+                .try_into()?,
+            period: period.ok_or(CoreError::MissingField("period".into()))?,
+            period_spend_limit: coins_from_raw(period_spend_limit)?.ok_or(CoreError::Coins(
+                "period spend limit shouldn't be empty".into(),
+            ))?,
+            period_can_spend: coins_from_raw(period_can_spend)?,
+            period_reset: period_reset.ok_or(CoreError::MissingField("period_reset".into()))?,
+        };
+
+        allowance
+            .validate()
+            .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?;
+
+        Ok(allowance)
+    }
+}
+
+impl Protobuf<PeriodicAllowanceRaw> for PeriodicAllowance {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct AllowedMsgAllowanceRaw {
+    #[prost(message, optional, tag = "1")]
+    pub allowance: Option<Any>,
+    #[prost(string, repeated, tag = "2")]
+    pub allowed_messages: Vec<String>,
+}
+
+impl From<AllowedMsgAllowance> for AllowedMsgAllowanceRaw {
+    fn from(
+        AllowedMsgAllowance {
+            allowance,
+            allowed_messages,
+        }: AllowedMsgAllowance,
+    ) -> Self {
+        Self {
+            allowance: Some((*allowance).into()),
+            allowed_messages,
+        }
+    }
+}
+
+/// AllowedMsgAllowance creates allowance only for specified message types.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AllowedMsgAllowance {
+    /// allowance can be any of basic and periodic fee allowance.
+    pub allowance: Box<FeeAllowance>,
+    /// allowed_messages are the messages for which the grantee has the access.
+    pub allowed_messages: Vec<String>,
+}
+
+impl AllowedMsgAllowance {
+    pub fn accept(
+        &mut self,
+        time: &Timestamp,
+        fee: &[UnsignedCoin],
+        msgs: &[&str],
+    ) -> Result<bool, FeeGrantKeeperError> {
+        if let Some(msg) = msgs
+            .iter()
+            .find(|msg| !self.allowed_messages.iter().any(|allowed| allowed == *msg))
+        {
+            return Err(FeeGrantKeeperError::MessageNotAllowed(msg.to_string()));
+        }
+
+        self.allowance.accept(time, fee, msgs)
+    }
+}
+
+impl TryFrom<AllowedMsgAllowanceRaw> for AllowedMsgAllowance {
+    type Error = CoreError;
+
+    fn try_from(
+        AllowedMsgAllowanceRaw {
+            allowance,
+            allowed_messages,
+        }: AllowedMsgAllowanceRaw,
+    ) -> Result<Self, Self::Error> {
+        if allowed_messages.is_empty() {
+            return Err(CoreError::DecodeGeneral(
+                AllowanceError::EmptyAllowedMessages.to_string(),
+            ));
+        }
+
+        Ok(Self {
+            allowance: Box::new(
+                allowance
+                    .ok_or(CoreError::MissingField("allowance".into()))?
+                    .try_into()?,
+            ),
+            allowed_messages,
+        })
+    }
+}
+
+impl Protobuf<AllowedMsgAllowanceRaw> for AllowedMsgAllowance {}
+
+/// FeeAllowance is one of the allowances a granter may give to a grantee to pay the fees
+/// of its transactions.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "@type")]
+pub enum FeeAllowance {
+    #[serde(rename = "/cosmos.feegrant.v1beta1.BasicAllowance")]
+    Basic(BasicAllowance),
+    #[serde(rename = "/cosmos.feegrant.v1beta1.PeriodicAllowance")]
+    Periodic(PeriodicAllowance),
+    #[serde(rename = "/cosmos.feegrant.v1beta1.AllowedMsgAllowance")]
+    AllowedMsg(AllowedMsgAllowance),
+}
+
+impl FeeAllowance {
+    /// accept checks if the fee and messages are allowed by the allowance and updates its
+    /// state. It returns true if the allowance is exhausted and should be removed.
+    pub fn accept(
+        &mut self,
+        time: &Timestamp,
+        fee: &[UnsignedCoin],
+        msgs: &[&str],
+    ) -> Result<bool, FeeGrantKeeperError> {
+        match self {
+            FeeAllowance::Basic(allowance) => allowance.accept(time, fee),
+            FeeAllowance::Periodic(allowance) => allowance.accept(time, fee),
+            FeeAllowance::AllowedMsg(allowance) => allowance.accept(time, fee, msgs),
+        }
+    }
+
+    /// expires_at returns the time at which the allowance expires if it has one
+    pub fn expires_at(&self) -> Option<Timestamp> {
+        match self {
+            FeeAllowance::Basic(allowance) => allowance.expiration,
+            FeeAllowance::Periodic(allowance) => allowance.basic.expiration,
+            FeeAllowance::AllowedMsg(allowance) => allowance.allowance.expires_at(),
+        }
+    }
+}
+
+impl TryFrom<Any> for FeeAllowance {
+    type Error = CoreError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            BASIC_ALLOWANCE_TYPE_URL => {
+                Ok(FeeAllowance::Basic(BasicAllowance::decode_vec(&any.value)?))
+            }
+            PERIODIC_ALLOWANCE_TYPE_URL => Ok(FeeAllowance::Periodic(
+                PeriodicAllowance::decode_vec(&any.value)?,
+            )),
+            ALLOWED_MSG_ALLOWANCE_TYPE_URL => Ok(FeeAllowance::AllowedMsg(
+                AllowedMsgAllowance::decode_vec(&any.value)?,
+            )),
+            _ => Err(CoreError::DecodeAny(
+                AllowanceError::UnknownType(any.type_url).to_string(),
+            )),
+        }
+    }
+}
+
+impl From<FeeAllowance> for Any {
+    fn from(allowance: FeeAllowance) -> Self {
+        match allowance {
+            FeeAllowance::Basic(allowance) => Any {
+                type_url: BASIC_ALLOWANCE_TYPE_URL.to_string(),
+                value: allowance.encode_vec(),
+            },
+            FeeAllowance::Periodic(allowance) => Any {
+                type_url: PERIODIC_ALLOWANCE_TYPE_URL.to_string(),
+                value: allowance.encode_vec(),
+            },
+            FeeAllowance::AllowedMsg(allowance) => Any {
+                type_url: ALLOWED_MSG_ALLOWANCE_TYPE_URL.to_string(),
+                value: allowance.encode_vec(),
+            },
+        }
+    }
+}
+
+impl Protobuf<Any> for FeeAllowance {}
+
+/// Subtracts `amount` from `coins` and drops the exhausted denominations. Returns `None` if
+/// `coins` doesn't cover `amount`.
+fn sub_coins(coins: &[UnsignedCoin], amount: &[UnsignedCoin]) -> Option<Vec<UnsignedCoin>> {
+    let covered = amount.iter().all(|coin| {
+        coins
+            .iter()
+            .find(|this| this.denom == coin.denom)
+            .is_some_and(|this| this.amount >= coin.amount)
+            || coin.amount.is_zero()
+    });
+
+    if !covered {
+        return None;
+    }
+
+    Some(
+        coins
+            .iter()
+            .map(|coin| {
+                let spent = amount
+                    .iter()
+                    .find(|this| this.denom == coin.denom)
+                    .map(|this| this.amount)
+                    .unwrap_or_default();
+
+                UnsignedCoin {
+                    denom: coin.denom.clone(),
+                    amount: coin.amount - spent,
+                }
+            })
+            .filter(|coin| !coin.amount.is_zero())
+            .collect(),
+    )
+}
+
+fn coins_from_raw(coins: Vec<RawCoin>) -> Result<Option<UnsignedCoins>, CoreError> {
+    if coins.is_empty() {
+        return Ok(None);
+    }
+
+    let coins = coins
+        .into_iter()
+        .map(UnsignedCoin::try_from)
+        .collect::<Result<Vec<_>, CoinError>>()
+        .map_err(|e| CoreError::Coin(e.to_string()))?;
+
+    UnsignedCoins::new(coins)
+        .map(Some)
+        .map_err(|e| CoreError::Coins(e.to_string()))
+}
+
+fn coins_into_raw(coins: Option<UnsignedCoins>) -> Vec<RawCoin> {
+    coins
+        .map(|coins| coins.into_inner().into_iter().map(Into::into).collect())
+        .unwrap_or_default()
+}
+
+/// Serializes empty coins as an empty list as the cosmos SDK does
+mod serde_coins_opt {
+    use gears::types::base::{coin::UnsignedCoin, coins::UnsignedCoins};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        coins: &Option<UnsignedCoins>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match coins {
+            Some(coins) => coins.serialize(serializer),
+            None => Vec::<UnsignedCoin>::new().serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<UnsignedCoins>, D::Error> {
+        let coins = Option::<Vec<UnsignedCoin>>::deserialize(deserializer)?.unwrap_or_default();
+
+        match coins.is_empty() {
+            true => Ok(None),
+            false => UnsignedCoins::new(coins)
+                .map(Some)
+                .map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gears::extensions::testing::UnwrapTesting;
+
+    fn coins(coins: &str) -> UnsignedCoins {
+        coins.parse().unwrap_test()
+    }
+
+    fn time(seconds: i64) -> Timestamp {
+        Timestamp::try_new(seconds, 0).unwrap_test()
+    }
+
+    #[test]
+    fn basic_allowance_spends_limit() {
+        let mut allowance = BasicAllowance {
+            spend_limit: Some(coins("100uatom")),
+            expiration: Some(time(100)),
+        };
+
+        let exhausted = allowance
+            .accept(&time(10), coins("40uatom").inner())
+            .unwrap_test();
+        assert!(!exhausted);
+        assert_eq!(allowance.spend_limit, Some(coins("60uatom")));
+
+        let res = allowance.accept(&time(10), coins("61uatom").inner());
+        assert!(matches!(res, Err(FeeGrantKeeperError::LimitExceeded(_))));
+
+        let exhausted = allowance
+            .accept(&time(10), coins("60uatom").inner())
+            .unwrap_test();
+        assert!(exhausted);
+    }
+
+    #[test]
+    fn basic_allowance_expires() {
+        let mut allowance = BasicAllowance {
+            spend_limit: None,
+            expiration: Some(time(100)),
+        };
+
+        assert!(!allowance
+            .accept(&time(100), coins("1000uatom").inner())
+            .unwrap_test());
+
+        let res = allowance.accept(&time(101), coins("1uatom").inner());
+        assert!(matches!(res, Err(FeeGrantKeeperError::Expired)));
+    }
+
+    #[test]
+    fn periodic_allowance_resets_period() {
+        let mut allowance = PeriodicAllowance {
+            basic: BasicAllowance {
+                spend_limit: Some(coins("100uatom")),
+                expiration: None,
+            },
+            period: Duration::try_new(10, 0).unwrap_test(),
+            period_spend_limit: coins("30uatom"),
+            period_can_spend: None,
+            period_reset: time(0),
+        };
+
+        // the first use starts a new period
+        allowance
+            .accept(&time(5), coins("20uatom").inner())
+            .unwrap_test();
+        assert_eq!(allowance.period_can_spend, Some(coins("10uatom")));
+        assert_eq!(allowance.period_reset, time(10));
+
+        let res = allowance.accept(&time(6), coins("11uatom").inner());
+        assert!(matches!(res, Err(FeeGrantKeeperError::LimitExceeded(_))));
+
+        allowance
+            .accept(&time(15), coins("30uatom").inner())
+            .unwrap_test();
+        assert_eq!(allowance.period_can_spend, None);
+        assert_eq!(allowance.period_reset, time(20));
+        assert_eq!(allowance.basic.spend_limit, Some(coins("50uatom")));
+    }
+
+    #[test]
+    fn allowed_msg_allowance_rejects_other_messages() {
+        let mut allowance = AllowedMsgAllowance {
+            allowance: Box::new(FeeAllowance::Basic(BasicAllowance {
+                spend_limit: None,
+                expiration: None,
+            })),
+            allowed_messages: vec!["/cosmos.bank.v1beta1.MsgSend".into()],
+        };
+
+        assert!(allowance
+            .accept(
+                &time(0),
+                coins("1uatom").inner(),
+                &["/cosmos.bank.v1beta1.MsgSend"],
+            )
+            .is_ok());
+
+        let res = allowance.accept(
+            &time(0),
+            coins("1uatom").inner(),
+            &[
+                "/cosmos.bank.v1beta1.MsgSend",
+                "/cosmos.staking.v1beta1.MsgDelegate",
+            ],
+        );
+        assert!(matches!(
+            res,
+            Err(FeeGrantKeeperError::MessageNotAllowed(_))
+        ));
+    }
+
+    #[test]
+    fn fee_allowance_any_roundtrip() {
+        let allowance = FeeAllowance::AllowedMsg(AllowedMsgAllowance {
+            allowance: Box::new(FeeAllowance::Periodic(PeriodicAllowance {
+                basic: BasicAllowance {
+                    spend_limit: Some(coins("100uatom")),
+                    expiration: Some(time(1_000)),
+                },
+                period: Duration::try_new(3_600, 0).unwrap_test(),
+                period_spend_limit: coins("10uatom"),
+                period_can_spend: Some(coins("5uatom")),
+                period_reset: time(500),
+            })),
+            allowed_messages: vec!["/cosmos.bank.v1beta1.MsgSend".into()],
+        });
+
+        let any: Any = allowance.clone().into();
+        assert_eq!(any.type_url, ALLOWED_MSG_ALLOWANCE_TYPE_URL);
+        assert_eq!(FeeAllowance::try_from(any).unwrap_test(), allowance);
+
+        let json = serde_json::to_value(&allowance).unwrap_test();
+        assert_eq!(
+            serde_json::from_value::<FeeAllowance>(json).unwrap_test(),
+            allowance
+        );
+    }
+}
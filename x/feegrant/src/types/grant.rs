@@ -0,0 +1,69 @@
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    types::address::AccAddress,
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use super::FeeAllowance;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct GrantRaw {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub allowance: Option<Any>,
+}
+
+impl From<Grant> for GrantRaw {
+    fn from(
+        Grant {
+            granter,
+            grantee,
+            allowance,
+        }: Grant,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            allowance: Some(allowance.into()),
+        }
+    }
+}
+
+/// Grant is stored in the KVStore to record a grant with full context
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Grant {
+    /// granter is the address of the user granting an allowance of their funds.
+    pub granter: AccAddress,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    pub grantee: AccAddress,
+    /// allowance can be any of basic, periodic, allowed fee allowance.
+    pub allowance: FeeAllowance,
+}
+
+impl TryFrom<GrantRaw> for Grant {
+    type Error = CoreError;
+
+    fn try_from(
+        GrantRaw {
+            granter,
+            grantee,
+            allowance,
+        }: GrantRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: AccAddress::from_bech32(&granter)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            grantee: AccAddress::from_bech32(&grantee)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            allowance: allowance
+                .ok_or(CoreError::MissingField("allowance".into()))?
+                .try_into()?,
+        })
+    }
+}
+
+impl Protobuf<GrantRaw> for Grant {}
@@ -0,0 +1,8 @@
+mod allowance;
+mod grant;
+pub mod query;
+mod tx;
+
+pub use allowance::*;
+pub use grant::*;
+pub use tx::*;
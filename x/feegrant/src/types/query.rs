@@ -0,0 +1,90 @@
+use gears::{
+    derive::{Protobuf, Query, Raw},
+    types::{
+        address::AccAddress,
+        pagination::{request::PaginationRequest, response::PaginationResponse},
+    },
+};
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use serde::{Deserialize, Serialize};
+
+use crate::{Grant, GrantRaw};
+
+// =====
+// Requests
+// =====
+
+/// QueryAllowanceRequest is the request type for the Query/Allowance RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf, Query)]
+#[query(url = "/cosmos.feegrant.v1beta1.Query/Allowance")]
+pub struct QueryAllowanceRequest {
+    /// granter is the address of the user granting an allowance of their funds.
+    #[raw(raw = String, kind(string))]
+    pub granter: AccAddress,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    #[raw(raw = String, kind(string))]
+    pub grantee: AccAddress,
+}
+
+/// QueryAllowancesRequest is the request type for the Query/Allowances RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf, Query)]
+#[query(url = "/cosmos.feegrant.v1beta1.Query/Allowances")]
+pub struct QueryAllowancesRequest {
+    #[raw(raw = String, kind(string))]
+    pub grantee: AccAddress,
+    /// pagination defines an optional pagination for the request.
+    #[proto(optional)]
+    #[raw(kind(message), optional, raw = PageRequest)]
+    pub pagination: PaginationRequest,
+}
+
+/// QueryAllowancesByGranterRequest is the request type for the Query/AllowancesByGranter RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf, Query)]
+#[query(url = "/cosmos.feegrant.v1beta1.Query/AllowancesByGranter")]
+pub struct QueryAllowancesByGranterRequest {
+    #[raw(raw = String, kind(string))]
+    pub granter: AccAddress,
+    /// pagination defines an optional pagination for the request.
+    #[proto(optional)]
+    #[raw(kind(message), optional, raw = PageRequest)]
+    pub pagination: PaginationRequest,
+}
+
+// =====
+// Responses
+// =====
+
+/// QueryAllowanceResponse is the response type for the Query/Allowance RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Query, Raw, Protobuf)]
+pub struct QueryAllowanceResponse {
+    /// allowance is a allowance granted for grantee by granter.
+    #[proto(optional)]
+    #[raw(kind(message), raw = GrantRaw, optional)]
+    pub allowance: Option<Grant>,
+}
+
+/// QueryAllowancesResponse is the response type for the Query/Allowances RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Query, Raw, Protobuf)]
+pub struct QueryAllowancesResponse {
+    /// allowances are allowance's granted for grantee by granter.
+    #[raw(kind(message), raw = GrantRaw, repeated)]
+    #[proto(repeated)]
+    pub allowances: Vec<Grant>,
+    /// pagination defines an pagination for the response.
+    #[raw(kind(message), raw = PageResponse, optional)]
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
+
+/// QueryAllowancesByGranterResponse is the response type for the Query/AllowancesByGranter RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Query, Raw, Protobuf)]
+pub struct QueryAllowancesByGranterResponse {
+    /// allowances that have been issued by the granter.
+    #[raw(kind(message), raw = GrantRaw, repeated)]
+    #[proto(repeated)]
+    pub allowances: Vec<Grant>,
+    /// pagination defines an pagination for the response.
+    #[raw(kind(message), raw = PageResponse, optional)]
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
@@ -0,0 +1,139 @@
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    derive::AppMessage,
+    types::address::AccAddress,
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use super::FeeAllowance;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgGrantAllowanceRaw {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub allowance: Option<Any>,
+}
+
+impl From<MsgGrantAllowance> for MsgGrantAllowanceRaw {
+    fn from(
+        MsgGrantAllowance {
+            granter,
+            grantee,
+            allowance,
+        }: MsgGrantAllowance,
+    ) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            allowance: Some(allowance.into()),
+        }
+    }
+}
+
+/// MsgGrantAllowance adds permission for Grantee to spend up to Allowance
+/// of fees from the account of Granter.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(
+    url = "/cosmos.feegrant.v1beta1.MsgGrantAllowance",
+    amino_url = "cosmos-sdk/MsgGrantAllowance"
+)]
+pub struct MsgGrantAllowance {
+    /// granter is the address of the user granting an allowance of their funds.
+    #[msg(signer)]
+    pub granter: AccAddress,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    pub grantee: AccAddress,
+    /// allowance can be any of basic, periodic, allowed fee allowance.
+    pub allowance: FeeAllowance,
+}
+
+impl TryFrom<MsgGrantAllowanceRaw> for MsgGrantAllowance {
+    type Error = CoreError;
+
+    fn try_from(
+        MsgGrantAllowanceRaw {
+            granter,
+            grantee,
+            allowance,
+        }: MsgGrantAllowanceRaw,
+    ) -> Result<Self, Self::Error> {
+        let granter = AccAddress::from_bech32(&granter)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+        let grantee = AccAddress::from_bech32(&grantee)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+
+        if granter == grantee {
+            return Err(CoreError::DecodeGeneral(
+                "cannot self-grant fee authorization".into(),
+            ));
+        }
+
+        Ok(Self {
+            granter,
+            grantee,
+            allowance: allowance
+                .ok_or(CoreError::MissingField("allowance".into()))?
+                .try_into()?,
+        })
+    }
+}
+
+impl Protobuf<MsgGrantAllowanceRaw> for MsgGrantAllowance {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgRevokeAllowanceRaw {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+}
+
+impl From<MsgRevokeAllowance> for MsgRevokeAllowanceRaw {
+    fn from(MsgRevokeAllowance { granter, grantee }: MsgRevokeAllowance) -> Self {
+        Self {
+            granter: granter.into(),
+            grantee: grantee.into(),
+        }
+    }
+}
+
+/// MsgRevokeAllowance removes any existing Allowance from Granter to Grantee.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(
+    url = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance",
+    amino_url = "cosmos-sdk/MsgRevokeAllowance"
+)]
+pub struct MsgRevokeAllowance {
+    /// granter is the address of the user granting an allowance of their funds.
+    #[msg(signer)]
+    pub granter: AccAddress,
+    /// grantee is the address of the user being granted an allowance of another user's funds.
+    pub grantee: AccAddress,
+}
+
+impl TryFrom<MsgRevokeAllowanceRaw> for MsgRevokeAllowance {
+    type Error = CoreError;
+
+    fn try_from(
+        MsgRevokeAllowanceRaw { granter, grantee }: MsgRevokeAllowanceRaw,
+    ) -> Result<Self, Self::Error> {
+        let granter = AccAddress::from_bech32(&granter)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+        let grantee = AccAddress::from_bech32(&grantee)
+            .map_err(|e| CoreError::DecodeAddress(e.to_string()))?;
+
+        if granter == grantee {
+            return Err(CoreError::DecodeGeneral(
+                "addresses must be different".into(),
+            ));
+        }
+
+        Ok(Self { granter, grantee })
+    }
+}
+
+impl Protobuf<MsgRevokeAllowanceRaw> for MsgRevokeAllowance {}
@@ -2,9 +2,9 @@ use std::{cell::RefCell, sync::Arc};
 
 use gears::{
     application::handlers::node::ABCIHandler,
-    baseapp::{options::NodeOptions, NullQueryRequest, NullQueryResponse},
+    baseapp::{mode::RunTxMode, options::NodeOptions, NullQueryRequest, NullQueryResponse},
     params::ParamsSubspaceKey,
-    store::StoreKey,
+    store::{PruningOptions, StoreKey},
     types::{
         base::min_gas::MinGasPrices,
         gas::GasMeter,
@@ -15,6 +15,7 @@ use gears::{
         keepers::{
             auth::AuthKeeper,
             bank::BankKeeper,
+            feegrant::FeeGrantKeeper,
             staking::{KeeperHooks, StakingBankKeeper},
         },
         module::Module,
@@ -30,11 +31,12 @@ pub struct GenutilAbciHandler<
     AK: AuthKeeper<SK, M>,
     BK: StakingBankKeeper<SK, M> + BankKeeper<SK, M>,
     KH: KeeperHooks<SK, AK, M>,
+    FK: FeeGrantKeeper<SK>,
     M: Module,
     GC: SignGasConsumer,
 > {
     staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-    ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
+    ante_handler: BaseAnteHandler<BK, AK, FK, SK, GC, M>,
 }
 
 impl<
@@ -43,13 +45,14 @@ impl<
         AK: AuthKeeper<SK, M>,
         BK: StakingBankKeeper<SK, M>,
         KH: KeeperHooks<SK, AK, M>,
+        FK: FeeGrantKeeper<SK>,
         M: Module,
         GC: SignGasConsumer,
-    > GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+    > GenutilAbciHandler<SK, PSK, AK, BK, KH, FK, M, GC>
 {
     pub fn new(
         staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-        ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
+        ante_handler: BaseAnteHandler<BK, AK, FK, SK, GC, M>,
     ) -> Self {
         Self {
             staking,
@@ -64,9 +67,10 @@ impl<
         AK: AuthKeeper<SK, M> + Send + Sync + 'static,
         BK: StakingBankKeeper<SK, M>,
         KH: KeeperHooks<SK, AK, M>,
+        FK: FeeGrantKeeper<SK>,
         M: Module,
         GC: SignGasConsumer,
-    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, FK, M, GC>
 {
     type Message = NullTxMsg;
 
@@ -90,7 +94,7 @@ impl<
         &self,
         _ctx: &mut gears::context::tx::TxContext<'_, DB, Self::StoreKey>,
         _tx: &gears::types::tx::raw::TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), gears::application::handlers::node::TxError> {
         Ok(())
     }
@@ -117,8 +121,8 @@ impl<
                 let ante_check_res = self.ante_handler.run(
                     ctx,
                     &tx,
-                    false,
-                    NodeOptions::new(MinGasPrices::default()),
+                    RunTxMode::Deliver,
+                    NodeOptions::new(MinGasPrices::default(), PruningOptions::default()),
                     Arc::new(RefCell::new(GasMeter::infinite())),
                 );
 
@@ -148,6 +152,15 @@ impl<
         }
     }
 
+    fn export_genesis<DB: gears::store::database::Database>(
+        &self,
+        _ctx: &gears::context::query::QueryContext<DB, Self::StoreKey>,
+        _for_zero_height: bool,
+    ) -> Self::Genesis {
+        // gen txs are already applied to the state of other modules
+        GenutilGenesis::default()
+    }
+
     fn query<DB: gears::store::database::Database + Send + Sync>(
         &self,
         _ctx: &gears::context::query::QueryContext<DB, Self::StoreKey>,
@@ -5,7 +5,7 @@ use gears::extensions::gas::GasResultExt;
 use gears::tendermint::request::RequestEndBlock;
 use gears::{
     application::handlers::node::{ABCIHandler, ModuleInfo, TxError},
-    baseapp::errors::QueryError,
+    baseapp::{errors::QueryError, mode::RunTxMode},
     context::{
         block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
         TransactionalContext,
@@ -51,7 +51,7 @@ pub struct GovAbciHandler<
     M: Module,
     BK: GovernanceBankKeeper<SK, M>,
     STK: GovStakingKeeper<SK, M>,
-    PH: ProposalHandler<PSK, Proposal>,
+    PH: ProposalHandler<SK, PSK, Proposal>,
     MI,
 > {
     keeper: GovKeeper<SK, PSK, M, BK, STK, PH>,
@@ -64,7 +64,7 @@ impl<
         M: Module,
         BK: GovernanceBankKeeper<SK, M>,
         STK: GovStakingKeeper<SK, M>,
-        PH: ProposalHandler<PSK, Proposal>,
+        PH: ProposalHandler<SK, PSK, Proposal>,
         MI: ModuleInfo,
     > GovAbciHandler<SK, PSK, M, BK, STK, PH, MI>
 {
@@ -82,7 +82,7 @@ impl<
         M: Module,
         BK: GovernanceBankKeeper<SK, M>,
         STK: GovStakingKeeper<SK, M>,
-        PH: ProposalHandler<PSK, Proposal> + Clone + Send + Sync + 'static,
+        PH: ProposalHandler<SK, PSK, Proposal> + Clone + Send + Sync + 'static,
         MI: ModuleInfo + Clone + Send + Sync + 'static,
     > ABCIHandler for GovAbciHandler<SK, PSK, M, BK, STK, PH, MI>
 {
@@ -108,7 +108,7 @@ impl<
         &self,
         _ctx: &mut TxContext<'_, DB, Self::StoreKey>,
         _tx: &TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -233,6 +233,14 @@ impl<
         vec![]
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        _for_zero_height: bool,
+    ) -> Self::Genesis {
+        self.keeper.export_genesis(ctx)
+    }
+
     fn query<DB: Database>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -56,6 +56,7 @@ pub struct ProposalCliCommand<T: Subcommand> {
 pub enum ProposalCliSubcommand {
     Text(TextProposalCliCommand),
     ParamChange(ParamChangeProposalCliCommand),
+    CommunityPoolSpend(CommunityPoolSpendProposalCliCommand),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -68,3 +69,10 @@ pub struct TextProposalCliCommand {
 pub struct ParamChangeProposalCliCommand {
     pub file: PathBuf,
 }
+
+/// Submit a community pool spend proposal from a JSON file with the title, description,
+/// recipient and amount of the proposal
+#[derive(Args, Debug, Clone)]
+pub struct CommunityPoolSpendProposalCliCommand {
+    pub file: PathBuf,
+}
@@ -7,15 +7,18 @@ use gears::{
 
 use crate::{
     client::cli::tx::{
-        DepositCliCommand, GovTxCli, GovTxCommands, ParamChangeProposalCliCommand,
-        ProposalCliCommand, ProposalCliSubcommand, TextProposalCliCommand, VoteCliCommand,
-        WeightedVoteCliCommand,
+        CommunityPoolSpendProposalCliCommand, DepositCliCommand, GovTxCli, GovTxCommands,
+        ParamChangeProposalCliCommand, ProposalCliCommand, ProposalCliSubcommand,
+        TextProposalCliCommand, VoteCliCommand, WeightedVoteCliCommand,
     },
     msg::{
         deposit::Deposit, proposal::MsgSubmitProposal, vote::Vote, weighted_vote::MsgVoteWeighted,
         GovMsg,
     },
-    submission::{param::RawParameterChangeProposal, text::TextProposal},
+    submission::{
+        community_pool::CommunityPoolSpendProposal, param::RawParameterChangeProposal,
+        text::TextProposal,
+    },
 };
 
 use super::GovClientHandler;
@@ -73,6 +76,20 @@ impl TxHandler for GovClientHandler {
 
                     let proposal = serde_json::from_str::<RawParameterChangeProposal>(&buf)?;
 
+                    GovMsg::Proposal(MsgSubmitProposal {
+                        content: proposal.into(),
+                        initial_deposit,
+                        proposer: pubkey.get_address(),
+                    })
+                }
+                ProposalCliSubcommand::CommunityPoolSpend(
+                    CommunityPoolSpendProposalCliCommand { file },
+                ) => {
+                    let mut buf = String::new();
+                    File::open(file)?.read_to_string(&mut buf)?;
+
+                    let proposal = serde_json::from_str::<CommunityPoolSpendProposal>(&buf)?;
+
                     GovMsg::Proposal(MsgSubmitProposal {
                         content: proposal.into(),
                         initial_deposit,
@@ -68,7 +68,7 @@ pub struct GovKeeper<
     M: Module,
     BK: GovernanceBankKeeper<SK, M>,
     STK: GovStakingKeeper<SK, M>,
-    PH: ProposalHandler<PSK, Proposal>,
+    PH: ProposalHandler<SK, PSK, Proposal>,
 > {
     store_key: SK,
     gov_params_keeper: GovParamsKeeper<PSK>,
@@ -85,7 +85,7 @@ impl<
         M: Module,
         BK: GovernanceBankKeeper<SK, M>,
         STK: GovStakingKeeper<SK, M>,
-        PH: ProposalHandler<PSK, Proposal>,
+        PH: ProposalHandler<SK, PSK, Proposal>,
     > GovKeeper<SK, PSK, M, BK, STK, PH>
 {
     pub fn new(
@@ -201,6 +201,45 @@ impl<
         }
     }
 
+    pub fn export_genesis<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> GovGenesisState {
+        let proposals = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(KEY_PROPOSAL_PREFIX)
+            .into_range(..)
+            .map(|this| {
+                this.map(|(_, value)| {
+                    serde_json::from_slice::<Proposal>(&value).expect(SERDE_JSON_CONVERSION)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_gas();
+
+        let deposits = DepositIterator::new(ctx.kv_store(&self.store_key))
+            .map(|this| this.map(|(_, value)| value))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_gas();
+
+        let mut votes = Vec::new();
+        for proposal in &proposals {
+            for vote in
+                WeightedVoteIterator::new(ctx.kv_store(&self.store_key), proposal.proposal_id)
+            {
+                votes.push(vote.unwrap_gas().1);
+            }
+        }
+
+        GovGenesisState {
+            starting_proposal_id: proposal_id_get(ctx, &self.store_key).unwrap_gas(),
+            deposits,
+            votes,
+            proposals,
+            params: self.gov_params_keeper.try_get(ctx).unwrap_gas(),
+        }
+    }
+
     pub fn query<CTX: QueryableContext<DB, SK>, DB: Database>(
         &self,
         ctx: &CTX,
@@ -922,7 +961,7 @@ fn deposit_del<
     BK: GovernanceBankKeeper<SK, M>,
     STK: GovStakingKeeper<SK, M>,
     CTX: TransactionalContext<DB, SK>,
-    PH: ProposalHandler<PSK, Proposal>,
+    PH: ProposalHandler<SK, PSK, Proposal>,
 >(
     ctx: &mut CTX,
     keeper: &GovKeeper<SK, PSK, M, BK, STK, PH>,
@@ -955,7 +994,7 @@ fn deposit_refund<
     BK: GovernanceBankKeeper<SK, M>,
     STK: GovStakingKeeper<SK, M>,
     CTX: TransactionalContext<DB, SK>,
-    PH: ProposalHandler<PSK, Proposal>,
+    PH: ProposalHandler<SK, PSK, Proposal>,
 >(
     ctx: &mut CTX,
     keeper: &GovKeeper<SK, PSK, M, BK, STK, PH>,
@@ -16,8 +16,8 @@ pub mod query;
 pub mod submission;
 pub mod types;
 
-pub trait ProposalHandler<PSK: ParamsSubspaceKey, P> {
-    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: Database, SK: StoreKey>(
+pub trait ProposalHandler<SK: StoreKey, PSK: ParamsSubspaceKey, P> {
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: Database>(
         &self,
         proposal: &P,
         ctx: &mut CTX,
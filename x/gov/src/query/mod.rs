@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 pub mod request;
 pub mod response;
 
-#[derive(Debug, Clone, Query)]
+#[derive(Debug, Clone, PartialEq, Query)]
 #[query(request)]
 pub enum GovQuery {
     Deposit(QueryDepositRequest),
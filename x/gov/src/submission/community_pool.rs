@@ -0,0 +1,177 @@
+use bytes::Bytes;
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    types::{
+        address::AccAddress,
+        base::{coins::UnsignedCoins, errors::CoinError},
+    },
+};
+use ibc_proto::{cosmos::base::v1beta1::Coin, google::protobuf::Any};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of the title of a proposal
+pub const MAX_TITLE_LENGTH: usize = 140;
+/// Maximum length of the description of a proposal
+pub const MAX_DESCRIPTION_LENGTH: usize = 10000;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct RawCommunityPoolSpendProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(string, tag = "3")]
+    pub recipient: String,
+    #[prost(message, repeated, tag = "4")]
+    pub amount: Vec<Coin>,
+}
+
+impl From<CommunityPoolSpendProposal> for RawCommunityPoolSpendProposal {
+    fn from(
+        CommunityPoolSpendProposal {
+            title,
+            description,
+            recipient,
+            amount,
+        }: CommunityPoolSpendProposal,
+    ) -> Self {
+        Self {
+            title,
+            description,
+            recipient: recipient.to_string(),
+            amount: amount.into_inner().into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// CommunityPoolSpendProposal details a proposal for use of community funds,
+/// together with how many coins are proposed to be spent, and to which
+/// recipient account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "CommunityPoolSpendProposalJson")]
+pub struct CommunityPoolSpendProposal {
+    pub title: String,
+    pub description: String,
+    pub recipient: AccAddress,
+    pub amount: UnsignedCoins,
+}
+
+impl CommunityPoolSpendProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.distribution.v1beta1.CommunityPoolSpendProposal";
+    pub const AMINO_URL: &'static str = "cosmos-sdk/CommunityPoolSpendProposal";
+}
+
+/// validate_abstract validates the title and the description which are common for all
+/// proposal contents
+fn validate_abstract(title: &str, description: &str) -> Result<(), CoreError> {
+    if title.trim().is_empty() {
+        return Err(CoreError::DecodeGeneral(
+            "proposal title cannot be blank".to_owned(),
+        ));
+    }
+    if title.len() > MAX_TITLE_LENGTH {
+        return Err(CoreError::DecodeGeneral(format!(
+            "proposal title is longer than max length of {MAX_TITLE_LENGTH}"
+        )));
+    }
+
+    if description.is_empty() {
+        return Err(CoreError::DecodeGeneral(
+            "proposal description cannot be blank".to_owned(),
+        ));
+    }
+    if description.len() > MAX_DESCRIPTION_LENGTH {
+        return Err(CoreError::DecodeGeneral(format!(
+            "proposal description is longer than max length of {MAX_DESCRIPTION_LENGTH}"
+        )));
+    }
+
+    Ok(())
+}
+
+impl TryFrom<RawCommunityPoolSpendProposal> for CommunityPoolSpendProposal {
+    type Error = CoreError;
+
+    fn try_from(
+        RawCommunityPoolSpendProposal {
+            title,
+            description,
+            recipient,
+            amount,
+        }: RawCommunityPoolSpendProposal,
+    ) -> Result<Self, Self::Error> {
+        let mut coins = Vec::with_capacity(amount.len());
+        for coin in amount {
+            coins.push(
+                coin.try_into()
+                    .map_err(|e: CoinError| CoreError::Coin(e.to_string()))?,
+            );
+        }
+
+        CommunityPoolSpendProposalJson {
+            title,
+            description,
+            recipient: AccAddress::from_bech32(&recipient)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            amount: UnsignedCoins::new(coins).map_err(|e| CoreError::Coins(e.to_string()))?,
+        }
+        .try_into()
+    }
+}
+
+impl Protobuf<RawCommunityPoolSpendProposal> for CommunityPoolSpendProposal {}
+
+impl TryFrom<Any> for CommunityPoolSpendProposal {
+    type Error = CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        if value.type_url != Self::TYPE_URL {
+            Err(CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            ))?
+        }
+        Self::decode::<Bytes>(value.value.into())
+            .map_err(|e| CoreError::DecodeProtobuf(e.to_string()))
+    }
+}
+
+impl From<CommunityPoolSpendProposal> for Any {
+    fn from(msg: CommunityPoolSpendProposal) -> Self {
+        Any {
+            type_url: CommunityPoolSpendProposal::TYPE_URL.to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
+
+/// Unvalidated JSON representation of the proposal, which is also its amino JSON value
+#[derive(Deserialize)]
+struct CommunityPoolSpendProposalJson {
+    title: String,
+    description: String,
+    recipient: AccAddress,
+    amount: UnsignedCoins,
+}
+
+impl TryFrom<CommunityPoolSpendProposalJson> for CommunityPoolSpendProposal {
+    type Error = CoreError;
+
+    fn try_from(
+        CommunityPoolSpendProposalJson {
+            title,
+            description,
+            recipient,
+            amount,
+        }: CommunityPoolSpendProposalJson,
+    ) -> Result<Self, Self::Error> {
+        validate_abstract(&title, &description)?;
+
+        Ok(Self {
+            title,
+            description,
+            recipient,
+            amount,
+        })
+    }
+}
@@ -34,6 +34,8 @@ pub enum SubmissionHandlingError {
     InvalidProposal,
     #[error("Can't handle this proposal: {0}")]
     Gas(#[from] GasStoreErrors),
+    #[error("Can't handle this proposal: {0}")]
+    Handling(String),
 }
 
 #[derive(Debug)]
@@ -1,3 +1,4 @@
+pub mod community_pool;
 pub mod handler;
 pub mod param;
 pub mod text;
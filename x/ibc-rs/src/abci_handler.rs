@@ -162,4 +162,8 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
     pub fn genesis<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>, genesis: GenesisState) {
         self.keeper.init_genesis(ctx, genesis)
     }
+
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        self.keeper.export_genesis(ctx)
+    }
 }
@@ -13,10 +13,11 @@ use ibc::primitives::ToVec;
 use ibc::{core::host::types::path::ClientStatePath, primitives::proto::Protobuf};
 
 use crate::ics02_client::types::{client_state::ClientState, query::IdentifiedClientState};
-use crate::types::context::CLIENT_STATE_KEY;
+use crate::types::context::{CLIENT_STATE_KEY, KEY_CONSENSUS_STATE_PREFIX};
 
 use super::{params::ClientParamsKeeper, types::query::QueryClientStatesResponse, GenesisState};
-use gears::context::{InfallibleContextMut, TransactionalContext};
+use gears::context::{InfallibleContext, InfallibleContextMut, TransactionalContext};
+use gears::extensions::corruption::UnwrapCorrupt;
 use ibc::core::{
     client::types::proto::v1::QueryClientStatesRequest, host::types::identifiers::ClientId,
 };
@@ -83,6 +84,49 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         self.set_next_client_sequence(ctx, genesis.next_client_sequence);
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let clients = self
+            .client_states(ctx, QueryClientStatesRequest { pagination: None })
+            .client_states
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        // consensus states aren't imported by `init_genesis` yet, so a state with them can't be
+        // exported without losing them
+        let has_consensus_states = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(KEY_CLIENT_STORE_PREFIX.to_string().into_bytes())
+            .into_range(..)
+            .any(|(key, _)| {
+                String::from_utf8_lossy(&key)
+                    .split('/')
+                    .any(|part| part == KEY_CONSENSUS_STATE_PREFIX)
+            });
+        assert!(
+            !has_consensus_states,
+            "export of client consensus states isn't supported"
+        );
+
+        GenesisState {
+            clients,
+            params: self.client_params_keeper.get(ctx),
+            next_client_sequence: self.next_client_sequence(ctx),
+            ..Default::default()
+        }
+    }
+
+    pub fn next_client_sequence<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> u64 {
+        let ibc_store = ctx.infallible_store(&self.store_key);
+        ibc_store
+            .get(KEY_NEXT_CLIENT_SEQUENCE)
+            .map(|bytes| u64::from_be_bytes(bytes.try_into().unwrap_or_corrupt()))
+            .unwrap_or_default()
+    }
+
     pub fn set_next_client_sequence<DB: Database>(
         &self,
         ctx: &mut InitContext<'_, DB, SK>,
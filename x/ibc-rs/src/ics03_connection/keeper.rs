@@ -1,6 +1,7 @@
-use gears::context::TransactionalContext;
+use gears::context::{InfallibleContext, QueryableContext, TransactionalContext};
+use gears::extensions::corruption::UnwrapCorrupt;
 use gears::{
-    context::init::InitContext,
+    context::{init::InitContext, query::QueryContext},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
 };
@@ -8,6 +9,7 @@ use gears::{
 use super::{params::ConnectionParamsKeeper, GenesisState};
 
 const KEY_NEXT_CONNECTION_SEQUENCE: &[u8; 22] = b"nextConnectionSequence";
+const KEY_CONNECTION_PREFIX: &[u8; 11] = b"connections";
 
 #[derive(Debug, Clone)]
 pub struct Keeper<SK, PSK> {
@@ -45,6 +47,35 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
             .set(ctx, genesis.params.clone());
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        // connections aren't imported by `init_genesis` yet, so a state with them can't be
+        // exported without losing them
+        let has_connections = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(KEY_CONNECTION_PREFIX.to_vec())
+            .into_range(..)
+            .next()
+            .is_some();
+        assert!(!has_connections, "export of connections isn't supported");
+
+        GenesisState {
+            next_connection_sequence: self.next_connection_sequence(ctx),
+            params: self.connection_params_keeper.get(ctx),
+            ..Default::default()
+        }
+    }
+
+    pub fn next_connection_sequence<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> u64 {
+        let ibc_store = ctx.infallible_store(&self.store_key);
+        ibc_store
+            .get(KEY_NEXT_CONNECTION_SEQUENCE)
+            .map(|bytes| u64::from_be_bytes(bytes.try_into().unwrap_or_corrupt()))
+            .unwrap_or_default()
+    }
+
     pub fn set_next_connection_sequence<DB: Database>(
         &self,
         ctx: &mut InitContext<'_, DB, SK>,
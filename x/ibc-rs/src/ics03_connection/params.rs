@@ -75,7 +75,7 @@ pub struct ConnectionParamsKeeper<PSK> {
 }
 
 impl<PSK: ParamsSubspaceKey> ConnectionParamsKeeper<PSK> {
-    pub fn _get<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
+    pub fn get<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
         &self,
         ctx: &CTX,
     ) -> ConnectionParams {
@@ -1,10 +1,13 @@
 use gears::context::init::InitContext;
-use gears::context::TransactionalContext;
+use gears::context::query::QueryContext;
+use gears::context::{InfallibleContext, QueryableContext, TransactionalContext};
+use gears::extensions::corruption::UnwrapCorrupt;
 use gears::store::{database::Database, StoreKey};
 
 use super::GenesisState;
 
 const KEY_NEXT_CHANNEL_SEQUENCE: &[u8; 19] = b"nextChannelSequence";
+const KEY_CHANNEL_END_PREFIX: &[u8; 11] = b"channelEnds";
 
 #[derive(Debug, Clone)]
 pub struct Keeper<SK> {
@@ -48,6 +51,34 @@ impl<SK: StoreKey> Keeper<SK> {
         self.set_next_channel_sequence(ctx, genesis.next_channel_sequence);
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        // channels aren't imported by `init_genesis` yet, so a state with them can't be
+        // exported without losing them
+        let has_channels = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(KEY_CHANNEL_END_PREFIX.to_vec())
+            .into_range(..)
+            .next()
+            .is_some();
+        assert!(!has_channels, "export of channels isn't supported");
+
+        GenesisState {
+            next_channel_sequence: self.next_channel_sequence(ctx),
+            ..Default::default()
+        }
+    }
+
+    pub fn next_channel_sequence<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> u64 {
+        let ibc_store = ctx.infallible_store(&self.store_key);
+        ibc_store
+            .get(KEY_NEXT_CHANNEL_SEQUENCE)
+            .map(|bytes| u64::from_be_bytes(bytes.try_into().unwrap_or_corrupt()))
+            .unwrap_or_default()
+    }
+
     pub fn set_next_channel_sequence<DB: Database>(
         &self,
         ctx: &mut InitContext<'_, DB, SK>,
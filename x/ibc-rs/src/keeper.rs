@@ -47,6 +47,14 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
             .init_genesis(ctx, genesis.channel_genesis);
     }
 
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        GenesisState {
+            client_genesis: self.client_keeper.export_genesis(ctx),
+            connection_genesis: self.connection_keeper.export_genesis(ctx),
+            channel_genesis: self.channel_keeper.export_genesis(ctx),
+        }
+    }
+
     pub fn client_create<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
@@ -0,0 +1,117 @@
+use crate::{
+    GenesisState, Keeper, QueryAnnualProvisionsRequest, QueryAnnualProvisionsResponse,
+    QueryInflationRequest, QueryInflationResponse, QueryParamsRequest, QueryParamsResponse,
+};
+use gears::{
+    baseapp::{errors::QueryError, QueryResponse},
+    context::{block::BlockContext, init::InitContext, query::QueryContext},
+    core::Protobuf,
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    tendermint::types::request::query::RequestQuery,
+    x::{
+        keepers::{mint::MintingBankKeeper, staking::MintingStakingKeeper},
+        module::Module,
+    },
+};
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub struct ABCIHandler<
+    SK: StoreKey,
+    PSK: ParamsSubspaceKey,
+    BK: MintingBankKeeper<SK, M>,
+    STK: MintingStakingKeeper<SK, M>,
+    M: Module,
+> {
+    keeper: Keeper<SK, PSK, BK, STK, M>,
+}
+
+#[derive(Clone)]
+pub enum MintNodeQueryRequest {
+    Params(QueryParamsRequest),
+    Inflation(QueryInflationRequest),
+    AnnualProvisions(QueryAnnualProvisionsRequest),
+}
+
+#[derive(Clone, Serialize)]
+pub enum MintNodeQueryResponse {
+    Params(QueryParamsResponse),
+    Inflation(QueryInflationResponse),
+    AnnualProvisions(QueryAnnualProvisionsResponse),
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        BK: MintingBankKeeper<SK, M>,
+        STK: MintingStakingKeeper<SK, M>,
+        M: Module,
+    > ABCIHandler<SK, PSK, BK, STK, M>
+{
+    pub fn new(keeper: Keeper<SK, PSK, BK, STK, M>) -> Self {
+        ABCIHandler { keeper }
+    }
+
+    pub fn genesis<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>, genesis: GenesisState) {
+        self.keeper.init_genesis(ctx, genesis)
+    }
+
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        self.keeper.export_genesis(ctx)
+    }
+
+    pub fn query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: RequestQuery,
+    ) -> Result<prost::bytes::Bytes, QueryError> {
+        match query.path.as_str() {
+            "/cosmos.mint.v1beta1.Query/Params" => {
+                let req = QueryParamsRequest::decode(query.data)?;
+
+                Ok(self.keeper.query_params(ctx, req).into_bytes().into())
+            }
+            "/cosmos.mint.v1beta1.Query/Inflation" => {
+                let req = QueryInflationRequest::decode(query.data)?;
+
+                Ok(self.keeper.query_inflation(ctx, req).into_bytes().into())
+            }
+            "/cosmos.mint.v1beta1.Query/AnnualProvisions" => {
+                let req = QueryAnnualProvisionsRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_annual_provisions(ctx, req)
+                    .into_bytes()
+                    .into())
+            }
+            _ => Err(QueryError::PathNotFound),
+        }
+    }
+
+    pub fn typed_query<DB: Database + Send + Sync>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: MintNodeQueryRequest,
+    ) -> MintNodeQueryResponse {
+        match query {
+            MintNodeQueryRequest::Params(req) => {
+                MintNodeQueryResponse::Params(self.keeper.query_params(ctx, req))
+            }
+            MintNodeQueryRequest::Inflation(req) => {
+                MintNodeQueryResponse::Inflation(self.keeper.query_inflation(ctx, req))
+            }
+            MintNodeQueryRequest::AnnualProvisions(req) => MintNodeQueryResponse::AnnualProvisions(
+                self.keeper.query_annual_provisions(ctx, req),
+            ),
+        }
+    }
+
+    /// begin_block mints new tokens for the previous block
+    pub fn begin_block<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) {
+        if let Err(e) = self.keeper.mint_block_provision(ctx) {
+            panic!("Error thrown in begin_block method: \n{e}");
+        }
+    }
+}
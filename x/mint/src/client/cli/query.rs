@@ -0,0 +1,84 @@
+use crate::{
+    QueryAnnualProvisionsRequest, QueryAnnualProvisionsResponse, QueryInflationRequest,
+    QueryInflationResponse, QueryParamsRequest, QueryParamsResponse,
+};
+use clap::{Args, Subcommand};
+use gears::{application::handlers::client::QueryHandler, core::Protobuf, derive::Query};
+use serde::{Deserialize, Serialize};
+
+#[derive(Args, Debug)]
+pub struct MintQueryCli {
+    #[command(subcommand)]
+    pub command: MintCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MintCommands {
+    /// Query the current minting parameters
+    Params,
+    /// Query the current minting inflation value
+    Inflation,
+    /// Query the current minting annual provisions value
+    AnnualProvisions,
+}
+
+#[derive(Debug, Clone)]
+pub struct MintQueryHandler;
+
+impl QueryHandler for MintQueryHandler {
+    type QueryRequest = MintQueryRequest;
+
+    type QueryResponse = MintQueryResponse;
+
+    type QueryCommands = MintQueryCli;
+
+    fn prepare_query_request(
+        &self,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryRequest> {
+        let res = match &command.command {
+            MintCommands::Params => Self::QueryRequest::Params(QueryParamsRequest {}),
+            MintCommands::Inflation => Self::QueryRequest::Inflation(QueryInflationRequest {}),
+            MintCommands::AnnualProvisions => {
+                Self::QueryRequest::AnnualProvisions(QueryAnnualProvisionsRequest {})
+            }
+        };
+
+        Ok(res)
+    }
+
+    fn handle_raw_response(
+        &self,
+        query_bytes: Vec<u8>,
+        command: &Self::QueryCommands,
+    ) -> anyhow::Result<Self::QueryResponse> {
+        let res = match &command.command {
+            MintCommands::Params => {
+                MintQueryResponse::Params(QueryParamsResponse::decode_vec(&query_bytes)?)
+            }
+            MintCommands::Inflation => {
+                MintQueryResponse::Inflation(QueryInflationResponse::decode_vec(&query_bytes)?)
+            }
+            MintCommands::AnnualProvisions => MintQueryResponse::AnnualProvisions(
+                QueryAnnualProvisionsResponse::decode_vec(&query_bytes)?,
+            ),
+        };
+
+        Ok(res)
+    }
+}
+
+#[derive(Clone, PartialEq, Query)]
+pub enum MintQueryRequest {
+    Params(QueryParamsRequest),
+    Inflation(QueryInflationRequest),
+    AnnualProvisions(QueryAnnualProvisionsRequest),
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query)]
+#[serde(untagged)]
+pub enum MintQueryResponse {
+    Params(QueryParamsResponse),
+    Inflation(QueryInflationResponse),
+    AnnualProvisions(QueryAnnualProvisionsResponse),
+}
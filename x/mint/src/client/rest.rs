@@ -0,0 +1,57 @@
+use axum::{extract::State, routing::get, Json, Router};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    rest::{error::HTTPError, RestState},
+};
+
+use crate::{
+    MintNodeQueryRequest, MintNodeQueryResponse, QueryAnnualProvisionsRequest,
+    QueryInflationRequest, QueryParamsRequest,
+};
+
+pub async fn params<
+    QReq: QueryRequest + From<MintNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<MintNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = MintNodeQueryRequest::Params(QueryParamsRequest {});
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn inflation<
+    QReq: QueryRequest + From<MintNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<MintNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = MintNodeQueryRequest::Inflation(QueryInflationRequest {});
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn annual_provisions<
+    QReq: QueryRequest + From<MintNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<MintNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = MintNodeQueryRequest::AnnualProvisions(QueryAnnualProvisionsRequest {});
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub fn get_router<
+    QReq: QueryRequest + From<MintNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<MintNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>() -> Router<RestState<QReq, QRes, App>> {
+    Router::new()
+        .route("/v1beta1/params", get(params))
+        .route("/v1beta1/inflation", get(inflation))
+        .route("/v1beta1/annual_provisions", get(annual_provisions))
+}
@@ -0,0 +1,13 @@
+use gears::{
+    error::NumericError, types::store::gas::errors::GasStoreErrors, x::errors::BankKeeperError,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MintError {
+    #[error(transparent)]
+    Numeric(#[from] NumericError),
+    #[error(transparent)]
+    Bank(#[from] BankKeeperError),
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+}
@@ -0,0 +1,11 @@
+use crate::{MintParams, Minter};
+use serde::{Deserialize, Serialize};
+
+/// GenesisState defines the mint module's genesis state.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct GenesisState {
+    /// minter is a space for holding current inflation information.
+    pub minter: Minter,
+    /// params defines all the parameters of the module.
+    pub params: MintParams,
+}
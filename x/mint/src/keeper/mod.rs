@@ -0,0 +1,204 @@
+use crate::{
+    errors::MintError, GenesisState, MintParamsKeeper, Minter, QueryAnnualProvisionsRequest,
+    QueryAnnualProvisionsResponse, QueryInflationRequest, QueryInflationResponse,
+    QueryParamsRequest, QueryParamsResponse,
+};
+use gears::{
+    application::keepers::params::ParamsKeeper,
+    context::{
+        block::BlockContext, init::InitContext, query::QueryContext, QueryableContext,
+        TransactionalContext,
+    },
+    core::Protobuf,
+    extensions::{corruption::UnwrapCorrupt, gas::GasResultExt},
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    tendermint::types::proto::event::{Event, EventAttribute},
+    types::{base::coins::UnsignedCoins, store::gas::errors::GasStoreErrors},
+    x::{
+        keepers::{mint::MintingBankKeeper, staking::MintingStakingKeeper},
+        module::Module,
+    },
+};
+
+pub(crate) const MINTER_KEY: [u8; 1] = [0x0];
+
+/// Keeper of the mint store
+#[derive(Debug, Clone)]
+pub struct Keeper<
+    SK: StoreKey,
+    PSK: ParamsSubspaceKey,
+    BK: MintingBankKeeper<SK, M>,
+    STK: MintingStakingKeeper<SK, M>,
+    M: Module,
+> {
+    store_key: SK,
+    mint_params_keeper: MintParamsKeeper<PSK>,
+    bank_keeper: BK,
+    staking_keeper: STK,
+    mint_module: M,
+    fee_collector_module: M,
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        BK: MintingBankKeeper<SK, M>,
+        STK: MintingStakingKeeper<SK, M>,
+        M: Module,
+    > Keeper<SK, PSK, BK, STK, M>
+{
+    pub fn new(
+        store_key: SK,
+        params_subspace_key: PSK,
+        bank_keeper: BK,
+        staking_keeper: STK,
+        mint_module: M,
+        fee_collector_module: M,
+    ) -> Self {
+        Self {
+            store_key,
+            mint_params_keeper: MintParamsKeeper {
+                params_subspace_key,
+            },
+            bank_keeper,
+            staking_keeper,
+            mint_module,
+            fee_collector_module,
+        }
+    }
+
+    /// init_genesis sets the minter and the parameters of the module
+    pub fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, SK>,
+        genesis: GenesisState,
+    ) {
+        self.set_minter(ctx, &genesis.minter).unwrap_gas();
+        self.mint_params_keeper.set(ctx, genesis.params);
+    }
+
+    /// export_genesis returns the mint state at the height of the context
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        GenesisState {
+            minter: self.minter(ctx).unwrap_gas(),
+            params: self.mint_params_keeper.get(ctx),
+        }
+    }
+
+    pub fn minter<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Minter, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        // the minter is set in genesis, so it always exists in a valid state
+        let bytes = store.get(&MINTER_KEY)?.unwrap_or_corrupt();
+        Ok(Minter::decode_vec(&bytes).unwrap_or_corrupt())
+    }
+
+    pub fn set_minter<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        minter: &Minter,
+    ) -> Result<(), GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.set(MINTER_KEY, minter.encode_vec())
+    }
+
+    /// mint_block_provision recalculates the inflation and annual provisions, then mints
+    /// the provision of the block and sends it to the fee collector, so the
+    /// distribution module allocates it with the collected fees
+    pub fn mint_block_provision<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, SK>,
+    ) -> Result<(), MintError> {
+        let mut minter = self.minter(ctx)?;
+        let params = self.mint_params_keeper.try_get(ctx)?;
+
+        // recalculate inflation rate
+        let total_staking_supply = self.staking_keeper.staking_token_supply(ctx)?;
+        let bonded_ratio = self.staking_keeper.bonded_ratio(ctx)?;
+        minter.inflation = minter.next_inflation_rate(&params, bonded_ratio)?;
+        minter.annual_provisions = minter.next_annual_provisions(total_staking_supply.amount)?;
+        self.set_minter(ctx, &minter)?;
+
+        // mint coins, update supply
+        let minted_coin = minter.block_provision(&params)?;
+        let amount = minted_coin.amount;
+
+        // coins can't hold zero amounts, so there is nothing to mint
+        if !amount.is_zero() {
+            let minted_coins = UnsignedCoins::new(vec![minted_coin])
+                .expect("the coin has a valid denom and a positive amount");
+
+            self.bank_keeper
+                .mint_coins(ctx, &self.mint_module, minted_coins.clone())?;
+
+            // send the minted coins to the fee collector account
+            self.bank_keeper.send_coins_from_module_to_module(
+                ctx,
+                &self.mint_module,
+                &self.fee_collector_module,
+                minted_coins,
+            )?;
+        }
+
+        ctx.push_event(Event {
+            r#type: "mint".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "bonded_ratio".into(),
+                    value: bonded_ratio.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "inflation".into(),
+                    value: minter.inflation.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "annual_provisions".into(),
+                    value: minter.annual_provisions.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "amount".into(),
+                    value: amount.to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        Ok(())
+    }
+
+    pub fn query_params<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        _query: QueryParamsRequest,
+    ) -> QueryParamsResponse {
+        QueryParamsResponse {
+            params: self.mint_params_keeper.get(ctx),
+        }
+    }
+
+    pub fn query_inflation<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        _query: QueryInflationRequest,
+    ) -> QueryInflationResponse {
+        QueryInflationResponse {
+            inflation: self.minter(ctx).unwrap_gas().inflation,
+        }
+    }
+
+    pub fn query_annual_provisions<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        _query: QueryAnnualProvisionsRequest,
+    ) -> QueryAnnualProvisionsResponse {
+        QueryAnnualProvisionsResponse {
+            annual_provisions: self.minter(ctx).unwrap_gas().annual_provisions,
+        }
+    }
+}
@@ -0,0 +1 @@
+pub const DEFAULT_MINT_DENOM: &str = env!("XMOD_MINT_PARAMS_MINT_DENOM");
@@ -0,0 +1,404 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use gears::{
+    application::keepers::params::ParamsKeeper,
+    core::{serializers::serialize_number_to_string, Protobuf},
+    extensions::corruption::UnwrapCorrupt,
+    params::{ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey},
+    types::{
+        decimal256::{CosmosDecimalProtoString, Decimal256},
+        denom::Denom,
+    },
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_number_from_string;
+
+mod environment;
+
+const KEY_MINT_DENOM: &str = "MintDenom";
+const KEY_INFLATION_RATE_CHANGE: &str = "InflationRateChange";
+const KEY_INFLATION_MAX: &str = "InflationMax";
+const KEY_INFLATION_MIN: &str = "InflationMin";
+const KEY_GOAL_BONDED: &str = "GoalBonded";
+const KEY_BLOCKS_PER_YEAR: &str = "BlocksPerYear";
+
+#[derive(Clone, Serialize, Message)]
+pub struct MintParamsRaw {
+    #[prost(string, tag = "1")]
+    pub mint_denom: String,
+    #[prost(string, tag = "2")]
+    pub inflation_rate_change: String,
+    #[prost(string, tag = "3")]
+    pub inflation_max: String,
+    #[prost(string, tag = "4")]
+    pub inflation_min: String,
+    #[prost(string, tag = "5")]
+    pub goal_bonded: String,
+    #[prost(uint64, tag = "6")]
+    pub blocks_per_year: u64,
+}
+
+impl From<MintParams> for MintParamsRaw {
+    fn from(
+        MintParams {
+            mint_denom,
+            inflation_rate_change,
+            inflation_max,
+            inflation_min,
+            goal_bonded,
+            blocks_per_year,
+        }: MintParams,
+    ) -> Self {
+        Self {
+            mint_denom: mint_denom.to_string(),
+            inflation_rate_change: inflation_rate_change.to_cosmos_proto_string(),
+            inflation_max: inflation_max.to_cosmos_proto_string(),
+            inflation_min: inflation_min.to_cosmos_proto_string(),
+            goal_bonded: goal_bonded.to_cosmos_proto_string(),
+            blocks_per_year,
+        }
+    }
+}
+
+impl TryFrom<MintParamsRaw> for MintParams {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        MintParamsRaw {
+            mint_denom,
+            inflation_rate_change,
+            inflation_max,
+            inflation_min,
+            goal_bonded,
+            blocks_per_year,
+        }: MintParamsRaw,
+    ) -> Result<Self, Self::Error> {
+        MintParams::new(
+            Denom::try_from(mint_denom)?,
+            Decimal256::from_cosmos_proto_string(&inflation_rate_change)?,
+            Decimal256::from_cosmos_proto_string(&inflation_max)?,
+            Decimal256::from_cosmos_proto_string(&inflation_min)?,
+            Decimal256::from_cosmos_proto_string(&goal_bonded)?,
+            blocks_per_year,
+        )
+    }
+}
+
+/// [`RawMintParams`] exists to allow us to validate params when deserializing them
+#[derive(Deserialize)]
+struct RawMintParams {
+    mint_denom: Denom,
+    inflation_rate_change: Decimal256,
+    inflation_max: Decimal256,
+    inflation_min: Decimal256,
+    goal_bonded: Decimal256,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    blocks_per_year: u64,
+}
+
+impl TryFrom<RawMintParams> for MintParams {
+    type Error = anyhow::Error;
+
+    fn try_from(params: RawMintParams) -> Result<Self, Self::Error> {
+        MintParams::new(
+            params.mint_denom,
+            params.inflation_rate_change,
+            params.inflation_max,
+            params.inflation_min,
+            params.goal_bonded,
+            params.blocks_per_year,
+        )
+    }
+}
+
+/// MintParams defines the parameters for the mint module. The params are guaranteed to be valid:
+/// - inflation_rate_change, inflation_max, inflation_min and goal_bonded are not greater than one
+/// - inflation_min is not greater than inflation_max
+/// - goal_bonded and blocks_per_year are positive
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "RawMintParams")]
+pub struct MintParams {
+    /// type of coin to mint
+    mint_denom: Denom,
+    /// maximum annual change in inflation rate
+    inflation_rate_change: Decimal256,
+    /// maximum inflation rate
+    inflation_max: Decimal256,
+    /// minimum inflation rate
+    inflation_min: Decimal256,
+    /// goal of percent bonded atoms
+    goal_bonded: Decimal256,
+    /// expected blocks per year
+    #[serde(serialize_with = "serialize_number_to_string")]
+    blocks_per_year: u64,
+}
+
+impl MintParams {
+    pub fn new(
+        mint_denom: Denom,
+        inflation_rate_change: Decimal256,
+        inflation_max: Decimal256,
+        inflation_min: Decimal256,
+        goal_bonded: Decimal256,
+        blocks_per_year: u64,
+    ) -> Result<Self, anyhow::Error> {
+        for (name, value) in [
+            ("inflation rate change", inflation_rate_change),
+            ("max inflation", inflation_max),
+            ("min inflation", inflation_min),
+            ("goal bonded", goal_bonded),
+        ] {
+            if value > Decimal256::one() {
+                return Err(anyhow::anyhow!("{name} too large: {value}"));
+            }
+        }
+
+        if inflation_max < inflation_min {
+            return Err(anyhow::anyhow!(
+                "max inflation ({inflation_max}) must be greater than or equal to min inflation ({inflation_min})"
+            ));
+        }
+
+        if goal_bonded.is_zero() {
+            return Err(anyhow::anyhow!(
+                "goal bonded must be positive: {goal_bonded}"
+            ));
+        }
+
+        if blocks_per_year == 0 {
+            return Err(anyhow::anyhow!(
+                "blocks per year must be positive: {blocks_per_year}"
+            ));
+        }
+
+        Ok(Self {
+            mint_denom,
+            inflation_rate_change,
+            inflation_max,
+            inflation_min,
+            goal_bonded,
+            blocks_per_year,
+        })
+    }
+
+    pub fn mint_denom(&self) -> &Denom {
+        &self.mint_denom
+    }
+
+    pub fn inflation_rate_change(&self) -> Decimal256 {
+        self.inflation_rate_change
+    }
+
+    pub fn inflation_max(&self) -> Decimal256 {
+        self.inflation_max
+    }
+
+    pub fn inflation_min(&self) -> Decimal256 {
+        self.inflation_min
+    }
+
+    pub fn goal_bonded(&self) -> Decimal256 {
+        self.goal_bonded
+    }
+
+    pub fn blocks_per_year(&self) -> u64 {
+        self.blocks_per_year
+    }
+}
+
+impl Protobuf<MintParamsRaw> for MintParams {}
+
+impl Default for MintParams {
+    fn default() -> Self {
+        Self {
+            mint_denom: Denom::try_from(environment::DEFAULT_MINT_DENOM)
+                .expect("default denom should be valid"),
+            inflation_rate_change: Decimal256::from_atomics(13u64, 2)
+                .expect("hardcoded value cannot fail"),
+            inflation_max: Decimal256::from_atomics(20u64, 2).expect("hardcoded value cannot fail"),
+            inflation_min: Decimal256::from_atomics(7u64, 2).expect("hardcoded value cannot fail"),
+            goal_bonded: Decimal256::from_atomics(67u64, 2).expect("hardcoded value cannot fail"),
+            // assuming 5 second block times
+            blocks_per_year: 60 * 60 * 8766 / 5,
+        }
+    }
+}
+
+impl ParamsSerialize for MintParams {
+    fn keys() -> HashSet<&'static str> {
+        [
+            KEY_MINT_DENOM,
+            KEY_INFLATION_RATE_CHANGE,
+            KEY_INFLATION_MAX,
+            KEY_INFLATION_MIN,
+            KEY_GOAL_BONDED,
+            KEY_BLOCKS_PER_YEAR,
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
+        vec![
+            (
+                KEY_MINT_DENOM,
+                format!("\"{}\"", self.mint_denom).into_bytes(),
+            ),
+            (
+                KEY_INFLATION_RATE_CHANGE,
+                self.inflation_rate_change.to_string().into_bytes(),
+            ),
+            (
+                KEY_INFLATION_MAX,
+                self.inflation_max.to_string().into_bytes(),
+            ),
+            (
+                KEY_INFLATION_MIN,
+                self.inflation_min.to_string().into_bytes(),
+            ),
+            (KEY_GOAL_BONDED, self.goal_bonded.to_string().into_bytes()),
+            (
+                KEY_BLOCKS_PER_YEAR,
+                format!("\"{}\"", self.blocks_per_year).into_bytes(),
+            ),
+        ]
+    }
+}
+
+impl ParamsDeserialize for MintParams {
+    fn from_raw(mut fields: HashMap<&'static str, Vec<u8>>) -> Self {
+        let mut decimal = |key: &'static str| -> Decimal256 {
+            Decimal256::from_str(
+                &String::from_utf8(
+                    ParamKind::Bytes
+                        .parse_param(fields.remove(key).unwrap_or_corrupt())
+                        .bytes()
+                        .unwrap_or_corrupt(),
+                )
+                .unwrap_or_corrupt(),
+            )
+            .unwrap_or_corrupt()
+        };
+
+        let inflation_rate_change = decimal(KEY_INFLATION_RATE_CHANGE);
+        let inflation_max = decimal(KEY_INFLATION_MAX);
+        let inflation_min = decimal(KEY_INFLATION_MIN);
+        let goal_bonded = decimal(KEY_GOAL_BONDED);
+
+        let mint_denom = ParamKind::String
+            .parse_param(fields.remove(KEY_MINT_DENOM).unwrap_or_corrupt())
+            .string()
+            .unwrap_or_corrupt()
+            .strip_prefix('\"')
+            .unwrap_or_corrupt()
+            .strip_suffix('\"')
+            .unwrap_or_corrupt()
+            .try_into()
+            .unwrap_or_corrupt();
+
+        let blocks_per_year = ParamKind::U64
+            .parse_param(fields.remove(KEY_BLOCKS_PER_YEAR).unwrap_or_corrupt())
+            .unsigned_64()
+            .unwrap_or_corrupt();
+
+        Self {
+            mint_denom,
+            inflation_rate_change,
+            inflation_max,
+            inflation_min,
+            goal_bonded,
+            blocks_per_year,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MintParamsKeeper<PSK: ParamsSubspaceKey> {
+    pub params_subspace_key: PSK,
+}
+
+impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for MintParamsKeeper<PSK> {
+    type Param = MintParams;
+
+    fn psk(&self) -> &PSK {
+        &self.params_subspace_key
+    }
+
+    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
+        let decimal = |value: &[u8]| -> Option<Decimal256> {
+            let bytes = ParamKind::Bytes.parse_param(value.to_vec()).bytes()?;
+            Decimal256::from_str(&String::from_utf8(bytes).ok()?).ok()
+        };
+
+        match String::from_utf8_lossy(key.as_ref()).as_ref() {
+            KEY_MINT_DENOM => ParamKind::String
+                .parse_param(value.as_ref().to_vec())
+                .string()
+                .and_then(|denom| {
+                    denom
+                        .strip_prefix('\"')
+                        .and_then(|denom| denom.strip_suffix('\"'))
+                        .map(|denom| Denom::try_from(denom.to_owned()).is_ok())
+                })
+                .unwrap_or_default(),
+            KEY_INFLATION_RATE_CHANGE | KEY_INFLATION_MAX | KEY_INFLATION_MIN => {
+                decimal(value.as_ref()).is_some_and(|value| value <= Decimal256::one())
+            }
+            KEY_GOAL_BONDED => decimal(value.as_ref())
+                .is_some_and(|value| !value.is_zero() && value <= Decimal256::one()),
+            KEY_BLOCKS_PER_YEAR => ParamKind::U64
+                .parse_param(value.as_ref().to_vec())
+                .unsigned_64()
+                .is_some_and(|blocks| blocks > 0),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::params::SubspaceParseError;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Subspace;
+
+    impl ParamsSubspaceKey for Subspace {
+        fn name(&self) -> &'static str {
+            "mint/"
+        }
+
+        fn from_subspace_str(val: &str) -> Result<Self, SubspaceParseError> {
+            match val {
+                "mint/" => Ok(Self),
+                _ => Err(SubspaceParseError(val.to_owned())),
+            }
+        }
+    }
+
+    #[test]
+    fn validate_accepts_serialized_params() {
+        for (key, value) in MintParams::default().to_raw() {
+            assert!(
+                MintParamsKeeper::<Subspace>::validate(key, value),
+                "{key} should be valid"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_rejects_invalid_values() {
+        let keeper_validate = MintParamsKeeper::<Subspace>::validate;
+
+        assert!(!keeper_validate(KEY_INFLATION_MAX, "1.500000000000000000"));
+        assert!(!keeper_validate(KEY_GOAL_BONDED, "0.000000000000000000"));
+        assert!(!keeper_validate(KEY_BLOCKS_PER_YEAR, "\"0\""));
+        assert!(!keeper_validate(KEY_MINT_DENOM, "\"1\""));
+        assert!(!keeper_validate("Unknown", "\"uatom\""));
+    }
+}
@@ -0,0 +1,212 @@
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    error::{MathOperation, NumericError},
+    types::{
+        base::coin::UnsignedCoin,
+        decimal256::{CosmosDecimalProtoString, Decimal256},
+        uint::Uint256,
+    },
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::MintParams;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MinterRaw {
+    #[prost(string, tag = "1")]
+    pub inflation: String,
+    #[prost(string, tag = "2")]
+    pub annual_provisions: String,
+}
+
+impl From<Minter> for MinterRaw {
+    fn from(
+        Minter {
+            inflation,
+            annual_provisions,
+        }: Minter,
+    ) -> Self {
+        Self {
+            inflation: inflation.to_cosmos_proto_string(),
+            annual_provisions: annual_provisions.to_cosmos_proto_string(),
+        }
+    }
+}
+
+/// Minter represents the minting state.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Minter {
+    /// current annual inflation rate
+    pub inflation: Decimal256,
+    /// current annual expected provisions
+    pub annual_provisions: Decimal256,
+}
+
+impl TryFrom<MinterRaw> for Minter {
+    type Error = CoreError;
+
+    fn try_from(
+        MinterRaw {
+            inflation,
+            annual_provisions,
+        }: MinterRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            inflation: Decimal256::from_cosmos_proto_string(&inflation)
+                .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?,
+            annual_provisions: Decimal256::from_cosmos_proto_string(&annual_provisions)
+                .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?,
+        })
+    }
+}
+
+impl Protobuf<MinterRaw> for Minter {}
+
+impl Default for Minter {
+    fn default() -> Self {
+        Self {
+            inflation: Decimal256::from_atomics(13u64, 2).expect("hardcoded value cannot fail"),
+            annual_provisions: Decimal256::zero(),
+        }
+    }
+}
+
+impl Minter {
+    /// next_inflation_rate returns the new inflation rate for the next block. The rate moves
+    /// towards the maximum while the bonded ratio is below the goal and towards the minimum
+    /// while it is above the goal.
+    pub fn next_inflation_rate(
+        &self,
+        params: &MintParams,
+        bonded_ratio: Decimal256,
+    ) -> Result<Decimal256, NumericError> {
+        // The target annual inflation rate is recalculated for each provisions cycle. The
+        // inflation is also subject to a rate change (positive or negative) depending on
+        // the distance from the desired ratio (67%). The maximum rate change possible is
+        // defined to be 13% per year, however the annual inflation is capped as between
+        // 7% and 20%.
+        let blocks_per_year = Decimal256::from_atomics(params.blocks_per_year(), 0)?;
+        let ratio_to_goal = bonded_ratio
+            .checked_div(params.goal_bonded())
+            .map_err(|_| NumericError::Overflow(MathOperation::Div))?;
+
+        // (1 - bondedRatio/GoalBonded) * InflationRateChange
+        let (distance, increase) = if ratio_to_goal < Decimal256::one() {
+            (Decimal256::one() - ratio_to_goal, true)
+        } else {
+            (ratio_to_goal - Decimal256::one(), false)
+        };
+        let change = distance
+            .checked_mul(params.inflation_rate_change())
+            .map_err(|_| NumericError::Overflow(MathOperation::Mul))?
+            .checked_div(blocks_per_year)
+            .map_err(|_| NumericError::Overflow(MathOperation::Div))?;
+
+        let inflation = if increase {
+            self.inflation
+                .checked_add(change)
+                .map_err(|_| NumericError::Overflow(MathOperation::Add))?
+        } else {
+            self.inflation.saturating_sub(change)
+        };
+
+        Ok(inflation.clamp(params.inflation_min(), params.inflation_max()))
+    }
+
+    /// next_annual_provisions returns the annual provisions based on current total
+    /// supply and inflation rate.
+    pub fn next_annual_provisions(
+        &self,
+        total_supply: Uint256,
+    ) -> Result<Decimal256, NumericError> {
+        self.inflation
+            .checked_mul(Decimal256::from_atomics(total_supply, 0)?)
+            .map_err(|_| NumericError::Overflow(MathOperation::Mul))
+    }
+
+    /// block_provision returns the provisions for a block based on the annual
+    /// provisions rate.
+    pub fn block_provision(&self, params: &MintParams) -> Result<UnsignedCoin, NumericError> {
+        let blocks_per_year = Decimal256::from_atomics(params.blocks_per_year(), 0)?;
+        let provision = self
+            .annual_provisions
+            .checked_div(blocks_per_year)
+            .map_err(|_| NumericError::Overflow(MathOperation::Div))?;
+
+        Ok(UnsignedCoin {
+            denom: params.mint_denom().clone(),
+            amount: provision.to_uint_floor(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal256 {
+        Decimal256::from_str(value).expect("hardcoded value is valid")
+    }
+
+    #[test]
+    fn next_inflation_rate_moves_towards_bonded_goal() {
+        let params = MintParams::default();
+        let blocks_per_year = Decimal256::from_atomics(params.blocks_per_year(), 0).unwrap();
+        let minter = Minter::default();
+
+        // no bonded tokens: inflation grows by the whole rate change per year
+        let inflation = minter.next_inflation_rate(&params, dec("0")).unwrap();
+        assert_eq!(
+            inflation,
+            dec("0.13") + params.inflation_rate_change() / blocks_per_year
+        );
+
+        // goal reached: inflation stays the same
+        let inflation = minter
+            .next_inflation_rate(&params, params.goal_bonded())
+            .unwrap();
+        assert_eq!(inflation, dec("0.13"));
+
+        // everything bonded: inflation decreases
+        let inflation = minter.next_inflation_rate(&params, dec("1")).unwrap();
+        assert!(inflation < dec("0.13"));
+    }
+
+    #[test]
+    fn next_inflation_rate_is_capped() {
+        let params = MintParams::default();
+
+        let minter = Minter {
+            inflation: dec("0.2"),
+            annual_provisions: dec("0"),
+        };
+        let inflation = minter.next_inflation_rate(&params, dec("0")).unwrap();
+        assert_eq!(inflation, params.inflation_max());
+
+        let minter = Minter {
+            inflation: dec("0.07"),
+            annual_provisions: dec("0"),
+        };
+        let inflation = minter.next_inflation_rate(&params, dec("1")).unwrap();
+        assert_eq!(inflation, params.inflation_min());
+    }
+
+    #[test]
+    fn block_provision_is_truncated() {
+        let params = MintParams::default();
+        let minter = Minter::default();
+        let minter = Minter {
+            annual_provisions: minter
+                .next_annual_provisions(Uint256::from(100_000_000_000u64))
+                .unwrap(),
+            ..minter
+        };
+        assert_eq!(minter.annual_provisions, dec("13000000000"));
+
+        let provision = minter.block_provision(&params).unwrap();
+        assert_eq!(provision.denom, *params.mint_denom());
+        assert_eq!(provision.amount, Uint256::from(2059u64));
+    }
+}
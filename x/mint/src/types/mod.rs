@@ -0,0 +1,5 @@
+mod minter;
+mod query;
+
+pub use minter::*;
+pub use query::*;
@@ -0,0 +1,123 @@
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    derive::{Protobuf, Query, Raw},
+    types::decimal256::{CosmosDecimalProtoString, Decimal256},
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::{MintParams, MintParamsRaw};
+
+// =====
+// Requests
+// =====
+
+/// QueryParamsRequest is the request type for the Query/Params RPC method.
+#[derive(Clone, PartialEq, Message, Query, Raw, Protobuf)]
+#[query(url = "/cosmos.mint.v1beta1.Query/Params")]
+pub struct QueryParamsRequest {}
+
+/// QueryInflationRequest is the request type for the Query/Inflation RPC method.
+#[derive(Clone, PartialEq, Message, Query, Raw, Protobuf)]
+#[query(url = "/cosmos.mint.v1beta1.Query/Inflation")]
+pub struct QueryInflationRequest {}
+
+/// QueryAnnualProvisionsRequest is the request type for the
+/// Query/AnnualProvisions RPC method.
+#[derive(Clone, PartialEq, Message, Query, Raw, Protobuf)]
+#[query(url = "/cosmos.mint.v1beta1.Query/AnnualProvisions")]
+pub struct QueryAnnualProvisionsRequest {}
+
+// =====
+// Responses
+// =====
+
+/// QueryParamsResponse is the response type for the Query/Params RPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Raw, Protobuf)]
+pub struct QueryParamsResponse {
+    /// params defines the parameters of the module.
+    #[proto(optional)]
+    #[raw(kind(message), raw = MintParamsRaw, optional)]
+    pub params: MintParams,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryInflationResponseRaw {
+    #[prost(bytes, tag = "1")]
+    pub inflation: Vec<u8>,
+}
+
+impl From<QueryInflationResponse> for QueryInflationResponseRaw {
+    fn from(QueryInflationResponse { inflation }: QueryInflationResponse) -> Self {
+        Self {
+            inflation: inflation.to_cosmos_proto_string().into_bytes(),
+        }
+    }
+}
+
+/// QueryInflationResponse is the response type for the Query/Inflation RPC
+/// method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query)]
+pub struct QueryInflationResponse {
+    /// inflation is the current minting inflation value.
+    pub inflation: Decimal256,
+}
+
+impl TryFrom<QueryInflationResponseRaw> for QueryInflationResponse {
+    type Error = CoreError;
+
+    fn try_from(
+        QueryInflationResponseRaw { inflation }: QueryInflationResponseRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            inflation: decimal_from_bytes(inflation)?,
+        })
+    }
+}
+
+impl Protobuf<QueryInflationResponseRaw> for QueryInflationResponse {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryAnnualProvisionsResponseRaw {
+    #[prost(bytes, tag = "1")]
+    pub annual_provisions: Vec<u8>,
+}
+
+impl From<QueryAnnualProvisionsResponse> for QueryAnnualProvisionsResponseRaw {
+    fn from(
+        QueryAnnualProvisionsResponse { annual_provisions }: QueryAnnualProvisionsResponse,
+    ) -> Self {
+        Self {
+            annual_provisions: annual_provisions.to_cosmos_proto_string().into_bytes(),
+        }
+    }
+}
+
+/// QueryAnnualProvisionsResponse is the response type for the
+/// Query/AnnualProvisions RPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query)]
+pub struct QueryAnnualProvisionsResponse {
+    /// annual_provisions is the current minting annual provisions value.
+    pub annual_provisions: Decimal256,
+}
+
+impl TryFrom<QueryAnnualProvisionsResponseRaw> for QueryAnnualProvisionsResponse {
+    type Error = CoreError;
+
+    fn try_from(
+        QueryAnnualProvisionsResponseRaw { annual_provisions }: QueryAnnualProvisionsResponseRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            annual_provisions: decimal_from_bytes(annual_provisions)?,
+        })
+    }
+}
+
+impl Protobuf<QueryAnnualProvisionsResponseRaw> for QueryAnnualProvisionsResponse {}
+
+/// The cosmos SDK encodes the decimals of the mint queries as bytes of their protobuf string.
+fn decimal_from_bytes(bytes: Vec<u8>) -> Result<Decimal256, CoreError> {
+    let value = String::from_utf8(bytes).map_err(|e| CoreError::DecodeGeneral(e.to_string()))?;
+    Decimal256::from_cosmos_proto_string(&value)
+        .map_err(|e| CoreError::DecodeGeneral(e.to_string()))
+}
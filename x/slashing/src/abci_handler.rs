@@ -51,6 +51,14 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         self.keeper.init_genesis(ctx, genesis)
     }
 
+    pub fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        for_zero_height: bool,
+    ) -> GenesisState {
+        self.keeper.export_genesis(ctx, for_zero_height)
+    }
+
     pub fn tx<DB: Database + Sync + Send>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
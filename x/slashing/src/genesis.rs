@@ -2,7 +2,7 @@ use crate::{SignerInfo, SlashingParams, ValidatorMissedBlocks};
 use serde::{Deserialize, Serialize};
 
 /// GenesisState defines the slashing module's genesis state.
-#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub struct GenesisState {
     /// params defines all the paramaters of related to deposit.
     pub params: SlashingParams,
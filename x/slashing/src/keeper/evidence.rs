@@ -0,0 +1,147 @@
+use super::*;
+use gears::{
+    tendermint::types::time::timestamp::Timestamp, x::keepers::slashing::EvidenceSlashingKeeper,
+};
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M: Module>
+    EvidenceSlashingKeeper<SK, M> for Keeper<SK, PSK, SSK, M>
+{
+    fn pubkey<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<Option<PublicKey>, GasStoreErrors> {
+        self.get_pub_key(ctx, addr)
+    }
+
+    fn has_validator_signing_info<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(self.validator_signing_info(ctx, addr)?.is_some())
+    }
+
+    fn is_tombstoned<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(self
+            .validator_signing_info(ctx, addr)?
+            .map(|info| info.tombstoned)
+            .unwrap_or_default())
+    }
+
+    fn slash_fraction_double_sign<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Decimal256, GasStoreErrors> {
+        Ok(self
+            .slashing_params_keeper
+            .try_get(ctx)?
+            .slash_fraction_double_sign)
+    }
+
+    /// slash attempts to slash a validator. The slash is delegated to the staking
+    /// module to make the necessary validator changes.
+    fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        amount: Decimal256,
+        validator_power: i64,
+        height: i64,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.push_event(Event {
+            r#type: "slash".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "address".into(),
+                    value: addr.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "power".into(),
+                    value: format!("\"{}\"", validator_power).into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "reason".into(),
+                    value: "double_sign".to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        let power = VotingPower::try_from(validator_power)
+            .expect("evidence power is the voting power provided by tendermint");
+        // the distribution height is negative for the pre-genesis block, nothing is filtered then
+        let height = u32::try_from(height).unwrap_or_default();
+        self.staking_keeper.slash(ctx, addr, height, power, amount)
+    }
+
+    /// jail attempts to jail a validator. The jail is delegated to the staking module
+    /// to make the necessary validator changes.
+    fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        self.staking_keeper.jail(ctx, addr)?;
+
+        ctx.push_event(Event {
+            r#type: "slash".to_string(),
+            attributes: vec![EventAttribute {
+                key: "jailed".into(),
+                value: addr.to_string().into(),
+                index: false,
+            }],
+        });
+
+        Ok(())
+    }
+
+    /// jail_until attempts to set a validator's jailed_until attribute in its signing
+    /// info.
+    fn jail_until<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        time: Timestamp,
+    ) -> Result<(), GasStoreErrors> {
+        // TODO: panics in sdk
+        let Some(mut sign_info) = self.validator_signing_info(ctx, addr)? else {
+            tracing::error!(
+                "cannot jail validator {addr} that does not have any signing information"
+            );
+            return Ok(());
+        };
+
+        sign_info.jailed_until = time;
+        self.set_validator_signing_info(ctx, addr, &sign_info)
+    }
+
+    /// tombstone attempts to tombstone a validator.
+    fn tombstone<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        // TODO: panics in sdk
+        let Some(mut sign_info) = self.validator_signing_info(ctx, addr)? else {
+            tracing::error!(
+                "cannot tombstone validator {addr} that does not have any signing information"
+            );
+            return Ok(());
+        };
+
+        if sign_info.tombstoned {
+            tracing::error!("cannot tombstone validator {addr} that is already tombstoned");
+            return Ok(());
+        }
+
+        sign_info.tombstoned = true;
+        self.set_validator_signing_info(ctx, addr, &sign_info)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Keeper, ValidatorSigningInfo};
+use gears::{
+    context::TransactionalContext,
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    tendermint::types::time::timestamp::Timestamp,
+    types::{
+        address::{AccAddress, ConsAddress, ValAddress},
+        decimal256::Decimal256,
+        store::gas::errors::GasStoreErrors,
+    },
+    x::{
+        errors::StakingHooksError,
+        keepers::{
+            auth::AuthKeeper,
+            staking::{KeeperHooks, SlashingStakingKeeper},
+        },
+        module::Module,
+        types::validator::StakingValidator,
+    },
+};
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M: Module>
+    Keeper<SK, PSK, SSK, M>
+{
+    /// creates the signing info of a bonded validator if it doesn't exist yet
+    pub fn after_validator_bonded_hook<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        // Update the signing info start height or create a new signing info
+        if self.validator_signing_info(ctx, cons_addr)?.is_none() {
+            let signing_info = ValidatorSigningInfo {
+                address: cons_addr.clone(),
+                start_height: ctx.height(),
+                index_offset: 0,
+                jailed_until: Timestamp::UNIX_EPOCH,
+                tombstoned: false,
+                missed_blocks_counter: 0,
+            };
+            self.set_validator_signing_info(ctx, cons_addr, &signing_info)?;
+        }
+        Ok(())
+    }
+
+    /// sets the address-pubkey relation of a created validator
+    pub fn after_validator_created_hook<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: &ValAddress,
+    ) -> Result<(), GasStoreErrors> {
+        if let Some(validator) = self.staking_keeper.validator(ctx, val_addr)? {
+            self.add_pub_key(ctx, validator.cons_pub_key())?;
+        }
+        Ok(())
+    }
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        SSK: SlashingStakingKeeper<SK, M>,
+        AK: AuthKeeper<SK, M>,
+        M: Module,
+    > KeeperHooks<SK, AK, M> for Keeper<SK, PSK, SSK, M>
+{
+    fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.after_validator_created_hook(ctx, &val_addr)?)
+    }
+
+    fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.delete_pub_key(ctx, &cons_addr)?)
+    }
+
+    fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(self.after_validator_bonded_hook(ctx, &cons_addr)?)
+    }
+
+    fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+        _fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+}
@@ -4,8 +4,9 @@ use crate::{
         addr_pubkey_relation_key, validator_missed_block_bit_array_key,
         validator_missed_block_bit_array_prefix_key, validator_signing_info_key,
     },
-    GenesisState, MsgUnjail, QueryParamsRequest, QueryParamsResponse, QuerySigningInfoRequest,
-    QuerySigningInfoResponse, SlashingParamsKeeper, ValidatorSigningInfo,
+    GenesisState, MissedBlock, MsgUnjail, QueryParamsRequest, QueryParamsResponse,
+    QuerySigningInfoRequest, QuerySigningInfoResponse, SignerInfo, SlashingParamsKeeper,
+    ValidatorMissedBlocks, ValidatorSigningInfo,
 };
 use gears::extensions::gas::GasResultExt;
 use gears::{
@@ -42,6 +43,9 @@ use gears::{
 };
 use std::marker::PhantomData;
 
+mod evidence;
+mod hooks;
+
 pub(crate) const VALIDATOR_SIGNING_INFO_KEY_PREFIX: [u8; 1] = [0x1];
 pub(crate) const VALIDATOR_MISSED_BLOCK_BIT_ARRAY_KEY_PREFIX: [u8; 1] = [0x2];
 pub(crate) const ADDR_PUBKEY_RELATION_KEY_PREFIX: [u8; 1] = [0x3];
@@ -92,10 +96,11 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
             .collect();
         pub_keys
             .into_iter()
-            .for_each(|pub_key| self.add_pub_key(ctx, &pub_key));
+            .for_each(|pub_key| self.add_pub_key(ctx, &pub_key).unwrap_gas());
 
         genesis.signing_infos.into_iter().for_each(|info| {
             self.set_validator_signing_info(ctx, &info.address, &info.validator_signing_info)
+                .unwrap_gas()
         });
 
         genesis.missed_blocks.into_iter().for_each(|block| {
@@ -112,6 +117,61 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         self.slashing_params_keeper.set(ctx, genesis.params);
     }
 
+    /// export_genesis returns the slashing state at the height of the context. With
+    /// `for_zero_height` the start heights of signing infos are reset to zero.
+    pub fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        for_zero_height: bool,
+    ) -> GenesisState {
+        let signing_infos = self
+            .validator_signing_infos(ctx, None)
+            .1
+            .into_iter()
+            .map(|mut info| {
+                if for_zero_height {
+                    info.start_height = 0;
+                }
+                SignerInfo {
+                    address: info.address.clone(),
+                    validator_signing_info: info,
+                }
+            })
+            .collect();
+
+        let store = ctx.kv_store(&self.store_key);
+        let mut missed_blocks: Vec<ValidatorMissedBlocks> = vec![];
+        for (key, value) in store
+            .prefix_store(VALIDATOR_MISSED_BLOCK_BIT_ARRAY_KEY_PREFIX)
+            .into_range(..)
+        {
+            // the key is a length prefixed address followed by the index
+            let (address, index) = key
+                .split_first()
+                .and_then(|(len, rest)| rest.split_at_checked(*len as usize))
+                .unwrap_or_corrupt();
+            let address = ConsAddress::try_from(address.to_vec()).unwrap_or_corrupt();
+            let missed = MissedBlock {
+                index: u32::from_le_bytes(index.try_into().unwrap_or_corrupt()),
+                missed: serde_json::from_slice(&value).unwrap_or_corrupt(),
+            };
+
+            match missed_blocks.last_mut() {
+                Some(blocks) if blocks.address == address => blocks.missed_blocks.push(missed),
+                _ => missed_blocks.push(ValidatorMissedBlocks {
+                    address,
+                    missed_blocks: vec![missed],
+                }),
+            }
+        }
+
+        GenesisState {
+            params: self.slashing_params_keeper.get(ctx),
+            signing_infos,
+            missed_blocks,
+        }
+    }
+
     pub fn handle_validator_signature<DB: Database>(
         &self,
         ctx: &mut BlockContext<'_, DB, SK>,
@@ -123,6 +183,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
 
         // fetch the validator public key
         self.get_pub_key(ctx, &cons_addr)
+            .unwrap_gas()
             .ok_or(ValidatorHandlingError::ConsensusNotFound)?;
 
         // fetch signing info
@@ -206,14 +267,14 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
 
         // if we are past the minimum height and the validator has missed too many blocks, punish them
         if height > min_height && sign_info.missed_blocks_counter > max_missed {
-            let validator_is_jailed = self
+            let validator_exists_and_not_jailed = self
                 .staking_keeper
                 .validator_by_cons_addr(ctx, &cons_addr)
                 .unwrap_gas()
-                .map(|v| v.is_jailed())
+                .map(|v| !v.is_jailed())
                 .unwrap_or_default();
 
-            if validator_is_jailed {
+            if validator_exists_and_not_jailed {
                 // Downtime confirmed: slash and jail the validator
                 // We need to retrieve the stake distribution which signed the block, so we subtract ValidatorUpdateDelay from the evidence height,
                 // and subtract an additional 1 since this is the LastCommit.
@@ -291,7 +352,8 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         }
 
         // Set the updated signing info
-        self.set_validator_signing_info(ctx, &cons_addr, &sign_info);
+        self.set_validator_signing_info(ctx, &cons_addr, &sign_info)
+            .unwrap_gas();
 
         Ok(())
     }
@@ -414,24 +476,24 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
     //
 
     /// get_pub_key returns the pubkey from the adddress-pubkey relation
-    pub fn get_pub_key<DB: Database>(
+    pub fn get_pub_key<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
-        ctx: &BlockContext<'_, DB, SK>,
+        ctx: &CTX,
         addr: &ConsAddress,
-    ) -> Option<PublicKey> {
+    ) -> Result<Option<PublicKey>, GasStoreErrors> {
         let store = ctx.kv_store(&self.store_key);
         let key = addr_pubkey_relation_key(addr.clone());
         store
             .get(&key)
-            .map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt())
+            .map(|pub_key| pub_key.map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt()))
     }
 
     /// add_pub_key sets a address-pubkey relation
-    pub fn add_pub_key<DB: Database>(
+    pub fn add_pub_key<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
-        ctx: &mut InitContext<'_, DB, SK>,
+        ctx: &mut CTX,
         pub_key: &PublicKey,
-    ) {
+    ) -> Result<(), GasStoreErrors> {
         let mut store = ctx.kv_store_mut(&self.store_key);
         // TODO: check the addr type for genesis
         let addr = ConsAddress::from(pub_key.clone());
@@ -442,6 +504,18 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         store.set(key, value)
     }
 
+    /// delete_pub_key removes a address-pubkey relation
+    pub fn delete_pub_key<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        let key = addr_pubkey_relation_key(addr.clone());
+        store.delete(&key)?;
+        Ok(())
+    }
+
     /// validator_signing_info gets the validator signing
     pub fn validator_signing_info<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
@@ -451,7 +525,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         let store = ctx.kv_store(&self.store_key);
         let key = validator_signing_info_key(addr.clone());
         store.get(&key).map(|sign_info| {
-            sign_info.map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt())
+            sign_info.map(|bytes| ValidatorSigningInfo::decode_vec(&bytes).unwrap_or_corrupt())
         })
     }
 
@@ -472,13 +546,13 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
     }
 
     /// set_validator_signing_info sets the validator signing info to a consensus address key
-    pub fn set_validator_signing_info<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+    pub fn set_validator_signing_info<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         addr: &ConsAddress,
         signing_info: &ValidatorSigningInfo,
-    ) {
-        let mut store = ctx.infallible_store_mut(&self.store_key);
+    ) -> Result<(), GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
         let key = validator_signing_info_key(addr.clone());
         let value = signing_info.encode_vec();
         store.set(key, value)
@@ -2,17 +2,21 @@ use crate::{
     error::StakingTxError, GenesisState, Keeper, Message, QueryDelegationRequest,
     QueryDelegationResponse, QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
     QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
-    QueryParamsRequest, QueryParamsResponse, QueryPoolRequest, QueryPoolResponse,
-    QueryRedelegationRequest, QueryRedelegationResponse, QueryUnbondingDelegationRequest,
-    QueryUnbondingDelegationResponse, QueryValidatorRequest, QueryValidatorResponse,
-    QueryValidatorsRequest, QueryValidatorsResponse, Redelegation, RedelegationEntryResponse,
-    RedelegationResponse,
+    QueryDelegatorValidatorRequest, QueryDelegatorValidatorResponse,
+    QueryDelegatorValidatorsRequest, QueryDelegatorValidatorsResponse, QueryHistoricalInfoRequest,
+    QueryHistoricalInfoResponse, QueryParamsRequest, QueryParamsResponse, QueryPoolRequest,
+    QueryPoolResponse, QueryRedelegationRequest, QueryRedelegationResponse,
+    QueryUnbondingDelegationRequest, QueryUnbondingDelegationResponse,
+    QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse, QueryValidatorRequest,
+    QueryValidatorResponse, QueryValidatorUnbondingDelegationsRequest,
+    QueryValidatorUnbondingDelegationsResponse, QueryValidatorsRequest, QueryValidatorsResponse,
+    Redelegation, RedelegationEntryResponse, RedelegationResponse,
 };
 use gears::extensions::gas::GasResultExt;
 use gears::tendermint::request::{RequestBeginBlock, RequestEndBlock};
 use gears::{
     application::handlers::node::{ABCIHandler, ModuleInfo, TxError},
-    baseapp::{errors::QueryError, QueryRequest, QueryResponse},
+    baseapp::{errors::QueryError, mode::RunTxMode, QueryRequest, QueryResponse},
     context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
     core::Protobuf,
     derive::Query,
@@ -54,7 +58,12 @@ pub enum StakingNodeQueryRequest {
     Delegations(QueryDelegatorDelegationsRequest),
     UnbondingDelegation(QueryUnbondingDelegationRequest),
     UnbondingDelegations(QueryDelegatorUnbondingDelegationsRequest),
-    Redelegation(QueryRedelegationRequest),
+    Redelegations(QueryRedelegationRequest),
+    ValidatorDelegations(QueryValidatorDelegationsRequest),
+    ValidatorUnbondingDelegations(QueryValidatorUnbondingDelegationsRequest),
+    DelegatorValidators(QueryDelegatorValidatorsRequest),
+    DelegatorValidator(QueryDelegatorValidatorRequest),
+    HistoricalInfo(QueryHistoricalInfoRequest),
     Pool(QueryPoolRequest),
     Params(QueryParamsRequest),
 }
@@ -75,7 +84,12 @@ pub enum StakingNodeQueryResponse {
     Delegations(QueryDelegatorDelegationsResponse),
     UnbondingDelegation(QueryUnbondingDelegationResponse),
     UnbondingDelegations(QueryDelegatorUnbondingDelegationsResponse),
-    Redelegation(QueryRedelegationResponse),
+    Redelegations(QueryRedelegationResponse),
+    ValidatorDelegations(QueryValidatorDelegationsResponse),
+    ValidatorUnbondingDelegations(QueryValidatorUnbondingDelegationsResponse),
+    DelegatorValidators(QueryDelegatorValidatorsResponse),
+    DelegatorValidator(QueryDelegatorValidatorResponse),
+    HistoricalInfo(QueryHistoricalInfoResponse),
     Pool(QueryPoolResponse),
     Params(QueryParamsResponse),
 }
@@ -133,8 +147,33 @@ impl<
                     ),
                 )
             }
-            StakingNodeQueryRequest::Redelegation(req) => {
-                StakingNodeQueryResponse::Redelegation(self.query_redelegations(ctx, req))
+            StakingNodeQueryRequest::Redelegations(req) => {
+                StakingNodeQueryResponse::Redelegations(self.query_redelegations(ctx, req))
+            }
+            StakingNodeQueryRequest::ValidatorDelegations(req) => {
+                StakingNodeQueryResponse::ValidatorDelegations(
+                    self.keeper.query_validator_delegations(ctx, req),
+                )
+            }
+            StakingNodeQueryRequest::ValidatorUnbondingDelegations(req) => {
+                StakingNodeQueryResponse::ValidatorUnbondingDelegations(
+                    self.keeper.query_validator_unbonding_delegations(ctx, req),
+                )
+            }
+            StakingNodeQueryRequest::DelegatorValidators(req) => {
+                StakingNodeQueryResponse::DelegatorValidators(
+                    self.keeper.query_delegator_validators(ctx, req),
+                )
+            }
+            StakingNodeQueryRequest::DelegatorValidator(req) => {
+                StakingNodeQueryResponse::DelegatorValidator(
+                    self.keeper.query_delegator_validator(ctx, req),
+                )
+            }
+            StakingNodeQueryRequest::HistoricalInfo(req) => {
+                StakingNodeQueryResponse::HistoricalInfo(
+                    self.keeper.query_historical_info(ctx, req),
+                )
             }
             StakingNodeQueryRequest::Pool(_) => {
                 StakingNodeQueryResponse::Pool(self.query_pool(ctx))
@@ -149,7 +188,7 @@ impl<
         &self,
         _: &mut TxContext<'_, DB, Self::StoreKey>,
         _: &gears::types::tx::raw::TxWithRaw<Self::Message>,
-        _: bool,
+        _: RunTxMode,
     ) -> Result<(), TxError> {
         Ok(())
     }
@@ -168,6 +207,9 @@ impl<
             Message::Delegate(msg) => self.keeper.delegate_cmd_handler(ctx, msg),
             Message::Redelegate(msg) => self.keeper.redelegate_cmd_handler(ctx, msg),
             Message::Undelegate(msg) => self.keeper.undelegate_cmd_handler(ctx, msg),
+            Message::CancelUnbondingDelegation(msg) => self
+                .keeper
+                .cancel_unbonding_delegation_cmd_handler(ctx, msg),
         };
 
         result.map_err(|e| Into::<StakingTxError>::into(e).into::<MI>())
@@ -181,6 +223,14 @@ impl<
         self.genesis(ctx, genesis)
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        for_zero_height: bool,
+    ) -> Self::Genesis {
+        self.keeper.export_genesis(ctx, for_zero_height)
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -226,11 +276,45 @@ impl<
                     .query_unbonding_delegations(ctx, req)?
                     .into_bytes())
             }
-            "/cosmos.staking.v1beta1.Query/Redelegation" => {
+            "/cosmos.staking.v1beta1.Query/Redelegations" => {
                 let req = QueryRedelegationRequest::decode(query.data)?;
 
                 Ok(self.query_redelegations(ctx, req).into_bytes())
             }
+            "/cosmos.staking.v1beta1.Query/ValidatorDelegations" => {
+                let req = QueryValidatorDelegationsRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_validator_delegations(ctx, req)
+                    .into_bytes())
+            }
+            "/cosmos.staking.v1beta1.Query/ValidatorUnbondingDelegations" => {
+                let req = QueryValidatorUnbondingDelegationsRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_validator_unbonding_delegations(ctx, req)
+                    .into_bytes())
+            }
+            "/cosmos.staking.v1beta1.Query/DelegatorValidators" => {
+                let req = QueryDelegatorValidatorsRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_delegator_validators(ctx, req)
+                    .into_bytes())
+            }
+            "/cosmos.staking.v1beta1.Query/DelegatorValidator" => {
+                let req = QueryDelegatorValidatorRequest::decode(query.data)?;
+
+                Ok(self.keeper.query_delegator_validator(ctx, req).into_bytes())
+            }
+            "/cosmos.staking.v1beta1.Query/HistoricalInfo" => {
+                let req = QueryHistoricalInfoRequest::decode(query.data)?;
+
+                Ok(self.keeper.query_historical_info(ctx, req).into_bytes())
+            }
             "/cosmos.staking.v1beta1.Query/Params" => {
                 Ok(self.keeper.query_params(ctx).into_bytes())
             }
@@ -1,9 +1,14 @@
 use crate::{
     QueryDelegationRequest, QueryDelegationResponse, QueryDelegatorDelegationsRequest,
     QueryDelegatorDelegationsResponse, QueryDelegatorUnbondingDelegationsRequest,
-    QueryDelegatorUnbondingDelegationsResponse, QueryParamsRequest, QueryParamsResponse,
-    QueryRedelegationRequest, QueryRedelegationResponse, QueryUnbondingDelegationResponse,
-    QueryValidatorRequest, QueryValidatorResponse, QueryValidatorsRequest, QueryValidatorsResponse,
+    QueryDelegatorUnbondingDelegationsResponse, QueryDelegatorValidatorRequest,
+    QueryDelegatorValidatorResponse, QueryDelegatorValidatorsRequest,
+    QueryDelegatorValidatorsResponse, QueryHistoricalInfoRequest, QueryHistoricalInfoResponse,
+    QueryParamsRequest, QueryParamsResponse, QueryRedelegationRequest, QueryRedelegationResponse,
+    QueryUnbondingDelegationResponse, QueryValidatorDelegationsRequest,
+    QueryValidatorDelegationsResponse, QueryValidatorRequest, QueryValidatorResponse,
+    QueryValidatorUnbondingDelegationsRequest, QueryValidatorUnbondingDelegationsResponse,
+    QueryValidatorsRequest, QueryValidatorsResponse,
 };
 use clap::{Args, Subcommand};
 use gears::{
@@ -36,6 +41,13 @@ pub enum StakingCommands {
     UnbondingDelegation(UnbondingDelegationCommand),
     UnbondingDelegations(UnbondingDelegationsCommand),
     Redelegation(RedelegationCommand),
+    Redelegations(RedelegationsCommand),
+    RedelegationsFrom(RedelegationsFromCommand),
+    ValidatorDelegations(ValidatorDelegationsCommand),
+    ValidatorUnbondingDelegations(ValidatorUnbondingDelegationsCommand),
+    DelegatorValidators(DelegatorValidatorsCommand),
+    DelegatorValidator(DelegatorValidatorCommand),
+    HistoricalInfo(HistoricalInfoCommand),
     Params,
 }
 
@@ -100,6 +112,67 @@ pub struct RedelegationCommand {
     pub dst_validator_address: ValAddress,
 }
 
+/// Query all redelegation records for a delegator
+#[derive(Args, Debug, Clone)]
+pub struct RedelegationsCommand {
+    /// Delegator address who made redelegations
+    pub delegator_address: AccAddress,
+    #[command(flatten)]
+    pub pagination: Option<CliPaginationRequest>,
+}
+
+/// Query all outgoing redelegations from a validator
+#[derive(Args, Debug, Clone)]
+pub struct RedelegationsFromCommand {
+    /// Source validator address of the redelegations
+    pub src_validator_address: ValAddress,
+    #[command(flatten)]
+    pub pagination: Option<CliPaginationRequest>,
+}
+
+/// Query all the delegations made to one validator
+#[derive(Args, Debug, Clone)]
+pub struct ValidatorDelegationsCommand {
+    /// Validator address which received delegations
+    pub validator_address: ValAddress,
+    #[command(flatten)]
+    pub pagination: Option<CliPaginationRequest>,
+}
+
+/// Query all unbonding-delegation records from one validator
+#[derive(Args, Debug, Clone)]
+pub struct ValidatorUnbondingDelegationsCommand {
+    /// Validator address from which coins are unbonded
+    pub validator_address: ValAddress,
+    #[command(flatten)]
+    pub pagination: Option<CliPaginationRequest>,
+}
+
+/// Query all the validators a delegator is bonded to
+#[derive(Args, Debug, Clone)]
+pub struct DelegatorValidatorsCommand {
+    /// Delegator address who made delegations
+    pub delegator_address: AccAddress,
+    #[command(flatten)]
+    pub pagination: Option<CliPaginationRequest>,
+}
+
+/// Query a validator a delegator is bonded to
+#[derive(Args, Debug, Clone)]
+pub struct DelegatorValidatorCommand {
+    /// Delegator address who made delegation
+    pub delegator_address: AccAddress,
+    /// Validator address which is addressed to delegation
+    pub validator_address: ValAddress,
+}
+
+/// Query historical info at given height
+#[derive(Args, Debug, Clone)]
+pub struct HistoricalInfoCommand {
+    /// Height of the block
+    pub height: i64,
+}
+
 #[derive(Debug, Clone)]
 pub struct StakingQueryHandler;
 
@@ -158,12 +231,65 @@ impl QueryHandler for StakingQueryHandler {
                 delegator_address,
                 src_validator_address,
                 dst_validator_address,
-            }) => StakingQuery::Redelegation(QueryRedelegationRequest {
+            }) => StakingQuery::Redelegations(QueryRedelegationRequest {
                 delegator_address: delegator_address.clone().into(),
                 src_validator_address: src_validator_address.clone().into(),
                 dst_validator_address: dst_validator_address.clone().into(),
                 pagination: None,
             }),
+            StakingCommands::Redelegations(RedelegationsCommand {
+                delegator_address,
+                pagination,
+            }) => StakingQuery::Redelegations(QueryRedelegationRequest {
+                delegator_address: delegator_address.clone().into(),
+                src_validator_address: None,
+                dst_validator_address: None,
+                pagination: pagination.to_owned().try_map(PaginationRequest::try_from)?,
+            }),
+            StakingCommands::RedelegationsFrom(RedelegationsFromCommand {
+                src_validator_address,
+                pagination,
+            }) => StakingQuery::Redelegations(QueryRedelegationRequest {
+                delegator_address: None,
+                src_validator_address: src_validator_address.clone().into(),
+                dst_validator_address: None,
+                pagination: pagination.to_owned().try_map(PaginationRequest::try_from)?,
+            }),
+            StakingCommands::ValidatorDelegations(ValidatorDelegationsCommand {
+                validator_address,
+                pagination,
+            }) => StakingQuery::ValidatorDelegations(QueryValidatorDelegationsRequest {
+                validator_addr: validator_address.clone(),
+                pagination: pagination.to_owned().try_map(PaginationRequest::try_from)?,
+            }),
+            StakingCommands::ValidatorUnbondingDelegations(
+                ValidatorUnbondingDelegationsCommand {
+                    validator_address,
+                    pagination,
+                },
+            ) => StakingQuery::ValidatorUnbondingDelegations(
+                QueryValidatorUnbondingDelegationsRequest {
+                    validator_addr: validator_address.clone(),
+                    pagination: pagination.to_owned().try_map(PaginationRequest::try_from)?,
+                },
+            ),
+            StakingCommands::DelegatorValidators(DelegatorValidatorsCommand {
+                delegator_address,
+                pagination,
+            }) => StakingQuery::DelegatorValidators(QueryDelegatorValidatorsRequest {
+                delegator_addr: delegator_address.clone(),
+                pagination: pagination.to_owned().try_map(PaginationRequest::try_from)?,
+            }),
+            StakingCommands::DelegatorValidator(DelegatorValidatorCommand {
+                delegator_address,
+                validator_address,
+            }) => StakingQuery::DelegatorValidator(QueryDelegatorValidatorRequest {
+                delegator_addr: delegator_address.clone(),
+                validator_addr: validator_address.clone(),
+            }),
+            StakingCommands::HistoricalInfo(HistoricalInfoCommand { height }) => {
+                StakingQuery::HistoricalInfo(QueryHistoricalInfoRequest { height: *height })
+            }
             StakingCommands::Params => StakingQuery::Params(QueryParamsRequest {}),
         };
 
@@ -194,9 +320,28 @@ impl QueryHandler for StakingQueryHandler {
             StakingCommands::UnbondingDelegations(_) => StakingQueryResponse::UnbondingDelegations(
                 QueryDelegatorUnbondingDelegationsResponse::decode_vec(&query_bytes)?,
             ),
-            StakingCommands::Redelegation(_) => StakingQueryResponse::Redelegation(
+            StakingCommands::Redelegation(_)
+            | StakingCommands::Redelegations(_)
+            | StakingCommands::RedelegationsFrom(_) => StakingQueryResponse::Redelegations(
                 QueryRedelegationResponse::decode_vec(&query_bytes)?,
             ),
+            StakingCommands::ValidatorDelegations(_) => StakingQueryResponse::ValidatorDelegations(
+                QueryValidatorDelegationsResponse::decode_vec(&query_bytes)?,
+            ),
+            StakingCommands::ValidatorUnbondingDelegations(_) => {
+                StakingQueryResponse::ValidatorUnbondingDelegations(
+                    QueryValidatorUnbondingDelegationsResponse::decode_vec(&query_bytes)?,
+                )
+            }
+            StakingCommands::DelegatorValidators(_) => StakingQueryResponse::DelegatorValidators(
+                QueryDelegatorValidatorsResponse::decode_vec(&query_bytes)?,
+            ),
+            StakingCommands::DelegatorValidator(_) => StakingQueryResponse::DelegatorValidator(
+                QueryDelegatorValidatorResponse::decode_vec(&query_bytes)?,
+            ),
+            StakingCommands::HistoricalInfo(_) => StakingQueryResponse::HistoricalInfo(
+                QueryHistoricalInfoResponse::decode_vec(&query_bytes)?,
+            ),
             StakingCommands::Params => {
                 StakingQueryResponse::Params(QueryParamsResponse::decode_vec(&query_bytes)?)
             }
@@ -215,7 +360,12 @@ pub enum StakingQuery {
     Delegations(QueryDelegatorDelegationsRequest),
     UnbondingDelegation(QueryDelegationRequest),
     UnbondingDelegations(QueryDelegatorUnbondingDelegationsRequest),
-    Redelegation(QueryRedelegationRequest),
+    Redelegations(QueryRedelegationRequest),
+    ValidatorDelegations(QueryValidatorDelegationsRequest),
+    ValidatorUnbondingDelegations(QueryValidatorUnbondingDelegationsRequest),
+    DelegatorValidators(QueryDelegatorValidatorsRequest),
+    DelegatorValidator(QueryDelegatorValidatorRequest),
+    HistoricalInfo(QueryHistoricalInfoRequest),
     Params(QueryParamsRequest),
 }
 
@@ -229,6 +379,11 @@ pub enum StakingQueryResponse {
     Delegations(QueryDelegatorDelegationsResponse),
     UnbondingDelegation(QueryUnbondingDelegationResponse),
     UnbondingDelegations(QueryDelegatorUnbondingDelegationsResponse),
-    Redelegation(QueryRedelegationResponse),
+    Redelegations(QueryRedelegationResponse),
+    ValidatorDelegations(QueryValidatorDelegationsResponse),
+    ValidatorUnbondingDelegations(QueryValidatorUnbondingDelegationsResponse),
+    DelegatorValidators(QueryDelegatorValidatorsResponse),
+    DelegatorValidator(QueryDelegatorValidatorResponse),
+    HistoricalInfo(QueryHistoricalInfoResponse),
     Params(QueryParamsResponse),
 }
@@ -1,6 +1,6 @@
 use crate::{
-    CommissionRates, CreateValidator, DelegateMsg, Description, EditDescription, EditValidator,
-    Message as StakingMessage, RedelegateMsg, UndelegateMsg,
+    CancelUnbondingDelegationMsg, CommissionRates, CreateValidator, DelegateMsg, Description,
+    EditDescription, EditValidator, Message as StakingMessage, RedelegateMsg, UndelegateMsg,
 };
 use anyhow::Result;
 use clap::{Args, Subcommand};
@@ -153,6 +153,15 @@ pub enum StakingCommands {
         /// Amount of coins to unbond
         amount: UnsignedCoin,
     },
+    /// Cancel unbonding delegation and delegate back to the validator
+    CancelUnbond {
+        /// The validator account address
+        validator_address: ValAddress,
+        /// Amount of coins to cancel unbonding
+        amount: UnsignedCoin,
+        /// The height at which the unbonding took place
+        creation_height: u32,
+    },
 }
 
 pub fn run_staking_tx_command(
@@ -229,5 +238,17 @@ pub fn run_staking_tx_command(
             validator_address: validator_address.clone(),
             amount: amount.clone(),
         })),
+        StakingCommands::CancelUnbond {
+            validator_address,
+            amount,
+            creation_height,
+        } => Ok(StakingMessage::CancelUnbondingDelegation(
+            CancelUnbondingDelegationMsg {
+                delegator_address: from_address.clone(),
+                validator_address: validator_address.clone(),
+                amount: amount.clone(),
+                creation_height: *creation_height,
+            },
+        )),
     }
 }
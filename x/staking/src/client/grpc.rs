@@ -62,16 +62,40 @@ where
 
     async fn validator_delegations(
         &self,
-        _request: Request<QueryValidatorDelegationsRequest>,
+        request: Request<QueryValidatorDelegationsRequest>,
     ) -> Result<Response<QueryValidatorDelegationsResponse>, Status> {
-        unimplemented!()
+        info!("Received a gRPC request staking::validator_delegations");
+        let req = StakingNodeQueryRequest::ValidatorDelegations(request.into_inner().try_into()?);
+        let response = self.app.typed_query(req)?;
+        let response: StakingNodeQueryResponse = response.try_into()?;
+
+        if let StakingNodeQueryResponse::ValidatorDelegations(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            ))
+        }
     }
 
     async fn validator_unbonding_delegations(
         &self,
-        _request: Request<QueryValidatorUnbondingDelegationsRequest>,
+        request: Request<QueryValidatorUnbondingDelegationsRequest>,
     ) -> Result<Response<QueryValidatorUnbondingDelegationsResponse>, Status> {
-        unimplemented!()
+        info!("Received a gRPC request staking::validator_unbonding_delegations");
+        let req = StakingNodeQueryRequest::ValidatorUnbondingDelegations(
+            request.into_inner().try_into()?,
+        );
+        let response = self.app.typed_query(req)?;
+        let response: StakingNodeQueryResponse = response.try_into()?;
+
+        if let StakingNodeQueryResponse::ValidatorUnbondingDelegations(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            ))
+        }
     }
 
     async fn delegation(
@@ -111,16 +135,38 @@ where
 
     async fn delegator_validators(
         &self,
-        _request: Request<QueryDelegatorValidatorsRequest>,
+        request: Request<QueryDelegatorValidatorsRequest>,
     ) -> Result<Response<QueryDelegatorValidatorsResponse>, Status> {
-        unimplemented!()
+        info!("Received a gRPC request staking::delegator_validators");
+        let req = StakingNodeQueryRequest::DelegatorValidators(request.into_inner().try_into()?);
+        let response = self.app.typed_query(req)?;
+        let response: StakingNodeQueryResponse = response.try_into()?;
+
+        if let StakingNodeQueryResponse::DelegatorValidators(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            ))
+        }
     }
 
     async fn delegator_validator(
         &self,
-        _request: Request<QueryDelegatorValidatorRequest>,
+        request: Request<QueryDelegatorValidatorRequest>,
     ) -> Result<Response<QueryDelegatorValidatorResponse>, Status> {
-        unimplemented!()
+        info!("Received a gRPC request staking::delegator_validator");
+        let req = StakingNodeQueryRequest::DelegatorValidator(request.into_inner().try_into()?);
+        let response = self.app.typed_query(req)?;
+        let response: StakingNodeQueryResponse = response.try_into()?;
+
+        if let StakingNodeQueryResponse::DelegatorValidator(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(
+                "An internal error occurred while querying the application state.",
+            ))
+        }
     }
 
     async fn historical_info(
@@ -1,7 +1,10 @@
 use crate::{
     QueryDelegationRequest, QueryDelegatorDelegationsRequest,
-    QueryDelegatorUnbondingDelegationsRequest, QueryPoolRequest, QueryValidatorRequest,
-    QueryValidatorsRequest, StakingNodeQueryRequest, StakingNodeQueryResponse,
+    QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorValidatorRequest,
+    QueryDelegatorValidatorsRequest, QueryHistoricalInfoRequest, QueryPoolRequest,
+    QueryRedelegationRequest, QueryUnbondingDelegationRequest, QueryValidatorDelegationsRequest,
+    QueryValidatorRequest, QueryValidatorUnbondingDelegationsRequest, QueryValidatorsRequest,
+    StakingNodeQueryRequest, StakingNodeQueryResponse,
 };
 use axum::{
     extract::{Path, Query, State},
@@ -93,6 +96,22 @@ pub async fn delegator_delegations<
     Ok(Json(res))
 }
 
+pub async fn unbonding_delegation<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((validator_addr, delegator_addr)): Path<(ValAddress, AccAddress)>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::UnbondingDelegation(QueryUnbondingDelegationRequest {
+        delegator_addr,
+        validator_addr,
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub async fn unbonding_delegations<
     QReq: QueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
@@ -111,6 +130,121 @@ pub async fn unbonding_delegations<
     Ok(Json(res))
 }
 
+pub async fn validator_delegations<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(validator_addr): Path<ValAddress>,
+    Query(pagination): Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::ValidatorDelegations(QueryValidatorDelegationsRequest {
+        validator_addr,
+        pagination: Some(PaginationRequest::from(pagination)),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn validator_unbonding_delegations<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(validator_addr): Path<ValAddress>,
+    Query(pagination): Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::ValidatorUnbondingDelegations(
+        QueryValidatorUnbondingDelegationsRequest {
+            validator_addr,
+            pagination: Some(PaginationRequest::from(pagination)),
+        },
+    );
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_validators<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_addr): Path<AccAddress>,
+    Query(pagination): Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::DelegatorValidators(QueryDelegatorValidatorsRequest {
+        delegator_addr,
+        pagination: Some(PaginationRequest::from(pagination)),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_validator<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((delegator_addr, validator_addr)): Path<(AccAddress, ValAddress)>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::DelegatorValidator(QueryDelegatorValidatorRequest {
+        delegator_addr,
+        validator_addr,
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RedelegationsQuery {
+    src_validator_addr: Option<ValAddress>,
+    dst_validator_addr: Option<ValAddress>,
+    // TODO: serde(flatten) doesn't work
+    offset: Option<u32>,
+    limit: Option<u8>,
+}
+
+pub async fn redelegations<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_addr): Path<AccAddress>,
+    Query(RedelegationsQuery {
+        src_validator_addr,
+        dst_validator_addr,
+        offset,
+        limit,
+    }): Query<RedelegationsQuery>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::Redelegations(QueryRedelegationRequest {
+        delegator_address: Some(delegator_addr),
+        src_validator_address: src_validator_addr,
+        dst_validator_address: dst_validator_addr,
+        pagination: Some(PaginationRequest::from(Pagination::new(offset, limit))),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn historical_info<
+    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(height): Path<i64>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = StakingNodeQueryRequest::HistoricalInfo(QueryHistoricalInfoRequest { height });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub async fn pool<
     QReq: QueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
@@ -143,18 +277,43 @@ pub fn get_router<
     Router::new()
         .route("/v1beta1/validators", get(validators))
         .route("/v1beta1/validators/:validator_addr", get(validator))
+        .route(
+            "/v1beta1/validators/:validator_addr/delegations",
+            get(validator_delegations),
+        )
         .route(
             "/v1beta1/validators/:validator_addr/delegations/:delegator_addr",
             get(delegation),
         )
+        .route(
+            "/v1beta1/validators/:validator_addr/delegations/:delegator_addr/unbonding_delegation",
+            get(unbonding_delegation),
+        )
         .route(
             "/v1beta1/delegations/:delegator_addr",
             get(delegator_delegations),
         )
+        .route(
+            "/v1beta1/validators/:validator_addr/unbonding_delegations",
+            get(validator_unbonding_delegations),
+        )
         .route(
             "/v1beta1/delegators/:delegator_addr/unbonding_delegations",
             get(unbonding_delegations),
         )
+        .route(
+            "/v1beta1/delegators/:delegator_addr/redelegations",
+            get(redelegations),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_addr/validators",
+            get(delegator_validators),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_addr/validators/:validator_addr",
+            get(delegator_validator),
+        )
+        .route("/v1beta1/historical_info/:height", get(historical_info))
         .route("/v1beta1/pool", get(pool))
         .route("/v1beta1/params", get(params))
 }
@@ -36,6 +36,9 @@ pub(crate) mod keeper {
     pub(crate) const ATTRIBUTE_KEY_DELEGATOR: &str = "delegator";
     pub(crate) const ATTRIBUTE_KEY_NEW_SHARES: &str = "new_shares";
     pub(crate) const ATTRIBUTE_KEY_COMPLETION_TIME: &str = "completion_time";
+    pub(crate) const ATTRIBUTE_KEY_CREATION_HEIGHT: &str = "creation_height";
+    pub(crate) const ATTRIBUTE_KEY_INFRACTION_HEIGHT: &str = "infraction_height";
+    pub(crate) const ATTRIBUTE_KEY_BURNED_COINS: &str = "burned_coins";
     // TODO: check
     pub(crate) const ATTRIBUTE_VALUE_CATEGORY: &str = "staking";
 
@@ -47,6 +50,8 @@ pub(crate) mod keeper {
     pub(crate) const EVENT_TYPE_DELEGATE: &str = "delegate";
     pub(crate) const EVENT_TYPE_REDELEGATE: &str = "redelegate";
     pub(crate) const EVENT_TYPE_UNBOND: &str = "unbond";
+    pub(crate) const EVENT_TYPE_CANCEL_UNBONDING_DELEGATION: &str = "cancel_unbonding_delegation";
+    pub(crate) const EVENT_TYPE_SLASH: &str = "slash";
 }
 
 pub(crate) mod proto {
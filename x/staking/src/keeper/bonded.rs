@@ -55,7 +55,7 @@ impl<
         &self,
         ctx: &mut CTX,
         validator: &mut Validator,
-    ) -> Result<(), GasStoreErrors> {
+    ) -> anyhow::Result<()> {
         // delete the validator by power index, as the key will change
         self.delete_validator_by_power_index(ctx, validator)?;
 
@@ -67,7 +67,7 @@ impl<
         // delete from queue if present
         self.delete_unbonding_validators_queue(ctx, validator)?;
         // trigger hook
-        self.after_validator_bonded(ctx, validator);
+        self.after_validator_bonded(ctx, validator)?;
 
         Ok(())
     }
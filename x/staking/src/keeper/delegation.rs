@@ -38,10 +38,10 @@ impl<
         let mut delegation = if let Some(delegation) =
             self.delegation(ctx, del_addr, &validator.operator_address)?
         {
-            self.before_delegation_shares_modified(ctx, del_addr, &validator.operator_address);
+            self.before_delegation_shares_modified(ctx, del_addr, &validator.operator_address)?;
             delegation
         } else {
-            self.before_delegation_created(ctx, del_addr, &validator.operator_address);
+            self.before_delegation_created(ctx, del_addr, &validator.operator_address)?;
             Delegation {
                 delegator_address: del_addr.clone(),
                 validator_address: validator.operator_address.clone(),
@@ -115,7 +115,7 @@ impl<
             ctx,
             &delegation.delegator_address,
             &delegation.validator_address,
-        );
+        )?;
 
         Ok(new_shares)
     }
@@ -0,0 +1,14 @@
+use gears::x::keepers::{gov::GovernanceBankKeeper, staking::DistributionStakingKeeper};
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        BK: StakingBankKeeper<SK, M> + GovernanceBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > DistributionStakingKeeper<SK, M> for Keeper<SK, PSK, AK, BK, KH, M>
+{
+}
@@ -0,0 +1,41 @@
+use gears::x::keepers::{
+    gov::GovernanceBankKeeper,
+    mint::MintingBankKeeper,
+    staking::{GovStakingKeeper, MintingStakingKeeper},
+};
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        BK: GovernanceBankKeeper<SK, M> + MintingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > MintingStakingKeeper<SK, M> for Keeper<SK, PSK, AK, BK, KH, M>
+{
+    fn staking_token_supply<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<UnsignedCoin, GasStoreErrors> {
+        self.bank_keeper
+            .supply(ctx, self.staking_params_keeper.try_get(ctx)?.bond_denom())
+    }
+
+    fn bonded_ratio<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Decimal256, GasStoreErrors> {
+        let stake_supply = self.staking_token_supply(ctx)?;
+        if stake_supply.amount.is_zero() {
+            return Ok(Decimal256::zero());
+        }
+
+        let bonded_tokens = self.total_bonded_tokens(ctx)?;
+        Ok(Decimal256::from_ratio(
+            bonded_tokens.amount,
+            stake_supply.amount,
+        ))
+    }
+}
@@ -8,6 +8,7 @@ use gears::{
         decimal256::Decimal256,
     },
     x::{
+        errors::StakingHooksError,
         keepers::{auth::AuthKeeper, staking::KeeperHooks},
         module::Module,
     },
@@ -34,14 +35,16 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         &self,
         _ctx: &mut CTX,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         _ctx: &mut CTX,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -49,7 +52,8 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _cons_addr: ConsAddress,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -57,7 +61,8 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _cons_addr: ConsAddress,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -65,7 +70,8 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _cons_addr: ConsAddress,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -73,7 +79,8 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _del_addr: AccAddress,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -81,7 +88,8 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _del_addr: AccAddress,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -89,7 +97,8 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _del_addr: AccAddress,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -97,7 +106,8 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _del_addr: AccAddress,
         _val_addr: ValAddress,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 
     fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -105,6 +115,7 @@ impl<SK: StoreKey, AK: AuthKeeper<SK, M> + Send + Sync + 'static, M: Module> Kee
         _ctx: &mut CTX,
         _val_addr: ValAddress,
         _fraction: Decimal256,
-    ) {
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
     }
 }
@@ -2,16 +2,17 @@ use crate::{
     consts::{error::SERDE_ENCODING_DOMAIN_TYPE, keeper::*},
     error::StakingGenesisError,
     Delegation, DvPair, DvvTriplet, GenesisState, LastValidatorPower, Pool, Redelegation,
-    StakingParamsKeeper, UnbondingDelegation, Validator,
+    StakingParamsKeeper, UnbondingDelegation, Validator, Validators,
 };
 use anyhow::anyhow;
 use gears::{
     application::keepers::params::ParamsKeeper,
     context::{
-        block::BlockContext, init::InitContext, InfallibleContext, QueryableContext,
-        TransactionalContext,
+        block::BlockContext, init::InitContext, query::QueryContext, InfallibleContext,
+        QueryableContext, TransactionalContext,
     },
-    extensions::gas::GasResultExt,
+    core::Protobuf,
+    extensions::{corruption::UnwrapCorrupt, gas::GasResultExt},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
     tendermint::types::{
@@ -29,6 +30,7 @@ use gears::{
         uint::Uint256,
     },
     x::{
+        errors::StakingHooksError,
         keepers::{auth::AuthKeeper, staking::KeeperHooks, staking::StakingBankKeeper},
         module::Module,
         types::validator::BondStatus,
@@ -41,12 +43,18 @@ use std::{cmp::Ordering, collections::HashMap};
 
 mod bonded;
 mod delegation;
+mod distribution;
 mod gov;
 mod historical_info;
 mod hooks;
+mod mint;
 mod mock_hook_keeper;
 mod query;
 mod redelegation;
+mod slash;
+mod slashing;
+#[cfg(test)]
+mod testing;
 mod tx;
 mod unbonded;
 mod unbonding;
@@ -130,7 +138,7 @@ impl<
                 .unwrap_gas();
 
             if !genesis.exported {
-                self.after_validator_created(ctx, &validator);
+                self.after_validator_created(ctx, &validator)?;
             }
 
             if validator.status == BondStatus::Unbonding {
@@ -158,7 +166,7 @@ impl<
                     ctx,
                     &delegation.delegator_address,
                     &delegation.validator_address,
-                );
+                )?;
             }
 
             self.set_delegation(ctx, &delegation).unwrap_gas();
@@ -168,7 +176,7 @@ impl<
                     ctx,
                     &delegation.delegator_address,
                     &delegation.validator_address,
-                );
+                )?;
             }
         }
 
@@ -277,6 +285,83 @@ impl<
         Ok(res)
     }
 
+    pub fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        for_zero_height: bool,
+    ) -> GenesisState {
+        let store = ctx.kv_store(&self.store_key);
+
+        let mut validators: Vec<Validator> = store
+            .clone()
+            .prefix_store(VALIDATORS_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| Validator::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect();
+
+        let last_validator_powers = store
+            .clone()
+            .prefix_store(LAST_VALIDATOR_POWER_KEY)
+            .into_range(..)
+            .map(|(key, bytes)| LastValidatorPower {
+                address: ValAddress::try_from_prefix_length_bytes(&key).unwrap_or_corrupt(),
+                power: <i64 as prost::Message>::decode(bytes.as_ref()).unwrap_or_corrupt(),
+            })
+            .collect();
+
+        let delegations = store
+            .clone()
+            .prefix_store(DELEGATION_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| Delegation::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect();
+
+        let mut unbonding_delegations: Vec<UnbondingDelegation> = store
+            .clone()
+            .prefix_store(UNBONDING_DELEGATION_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| UnbondingDelegation::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect();
+
+        let mut redelegations: Vec<Redelegation> = store
+            .prefix_store(REDELEGATION_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| Redelegation::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect();
+
+        // heights of the exported chain are meaningless for a chain which starts from zero height
+        if for_zero_height {
+            for validator in &mut validators {
+                validator.unbonding_height = 0;
+            }
+
+            for entry in unbonding_delegations
+                .iter_mut()
+                .flat_map(|ubd| ubd.entries.iter_mut())
+            {
+                entry.creation_height = 0;
+            }
+
+            for entry in redelegations
+                .iter_mut()
+                .flat_map(|red| red.entries.iter_mut())
+            {
+                entry.creation_height = 0;
+            }
+        }
+
+        GenesisState {
+            params: self.staking_params_keeper.get(ctx),
+            validators: Validators::try_from(validators).unwrap_or_corrupt(),
+            last_total_power: self.last_total_power(ctx).unwrap_or_default(),
+            exported: true,
+            last_validator_powers,
+            delegations,
+            unbonding_delegations,
+            redelegations,
+        }
+    }
+
     /// BlockValidatorUpdates calculates the ValidatorUpdates for the current block
     /// Called in each EndBlock
     pub fn block_validator_updates<DB: Database>(
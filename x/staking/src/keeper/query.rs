@@ -1,10 +1,18 @@
 use super::*;
 use crate::{
+    types::keys::{
+        get_ubds_by_val_index_key, redelegations_from_val_src_index_key, redelegations_key,
+    },
     DelegationResponse, QueryDelegationRequest, QueryDelegationResponse,
     QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
     QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
-    QueryParamsResponse, QueryUnbondingDelegationRequest, QueryUnbondingDelegationResponse,
-    QueryValidatorRequest, QueryValidatorResponse, QueryValidatorsRequest, QueryValidatorsResponse,
+    QueryDelegatorValidatorRequest, QueryDelegatorValidatorResponse,
+    QueryDelegatorValidatorsRequest, QueryDelegatorValidatorsResponse, QueryHistoricalInfoRequest,
+    QueryHistoricalInfoResponse, QueryParamsResponse, QueryUnbondingDelegationRequest,
+    QueryUnbondingDelegationResponse, QueryValidatorDelegationsRequest,
+    QueryValidatorDelegationsResponse, QueryValidatorRequest, QueryValidatorResponse,
+    QueryValidatorUnbondingDelegationsRequest, QueryValidatorUnbondingDelegationsResponse,
+    QueryValidatorsRequest, QueryValidatorsResponse,
 };
 use gears::{
     baseapp::errors::QueryError,
@@ -191,6 +199,123 @@ impl<
         })
     }
 
+    pub fn query_validator_delegations<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: QueryValidatorDelegationsRequest,
+    ) -> QueryValidatorDelegationsResponse {
+        let store = ctx.kv_store(&self.store_key);
+        let store = store.prefix_store(DELEGATION_KEY);
+        // delegations are stored by delegator, so all of them are filtered by the validator
+        let iterator = store
+            .into_range(..)
+            .map(|(k, bytes)| (k, Delegation::decode_vec(&bytes).unwrap_or_corrupt()))
+            .filter(|(_k, del)| del.validator_address == query.validator_addr);
+        let (p_result, iterator) = iterator.maybe_paginate(
+            query
+                .pagination
+                .map(gears::extensions::pagination::Pagination::from),
+        );
+
+        let delegation_responses = iterator
+            .filter_map(|(_k, del)| self.delegation_to_delegation_response(ctx, del).ok())
+            .collect();
+
+        QueryValidatorDelegationsResponse {
+            delegation_responses,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
+    pub fn query_validator_unbonding_delegations<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: QueryValidatorUnbondingDelegationsRequest,
+    ) -> QueryValidatorUnbondingDelegationsResponse {
+        let store = ctx.kv_store(&self.store_key);
+        let store = store.prefix_store(get_ubds_by_val_index_key(&query.validator_addr));
+        let (p_result, iterator) = store.into_range(..).maybe_paginate(
+            query
+                .pagination
+                .map(gears::extensions::pagination::Pagination::from),
+        );
+
+        // the keys of the index are the delegator addresses
+        let unbonding_responses = iterator
+            .filter_map(|(k, _)| {
+                let del_addr = AccAddress::try_from_prefix_length_bytes(&k).unwrap_or_corrupt();
+                self.unbonding_delegation(ctx, &del_addr, &query.validator_addr)
+                    .unwrap_gas()
+            })
+            .collect();
+
+        QueryValidatorUnbondingDelegationsResponse {
+            unbonding_responses,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
+    pub fn query_delegator_validators<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: QueryDelegatorValidatorsRequest,
+    ) -> QueryDelegatorValidatorsResponse {
+        let store = ctx.kv_store(&self.store_key);
+        let store = store.prefix_store(
+            [
+                &DELEGATION_KEY,
+                query.delegator_addr.prefix_len_bytes().as_slice(),
+            ]
+            .concat(),
+        );
+        let (p_result, iterator) = store.into_range(..).maybe_paginate(
+            query
+                .pagination
+                .map(gears::extensions::pagination::Pagination::from),
+        );
+
+        let validators = iterator
+            .filter_map(|(_k, bytes)| {
+                let delegation = Delegation::decode_vec(&bytes).unwrap_or_corrupt();
+                self.validator(ctx, &delegation.validator_address)
+                    .unwrap_gas()
+            })
+            .map(Into::into)
+            .collect();
+
+        QueryDelegatorValidatorsResponse {
+            validators,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
+    pub fn query_delegator_validator<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: QueryDelegatorValidatorRequest,
+    ) -> QueryDelegatorValidatorResponse {
+        let validator = self
+            .delegation(ctx, &query.delegator_addr, &query.validator_addr)
+            .unwrap_gas()
+            .and_then(|_| self.validator(ctx, &query.validator_addr).unwrap_gas())
+            .map(Into::into);
+
+        QueryDelegatorValidatorResponse { validator }
+    }
+
+    pub fn query_historical_info<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        query: QueryHistoricalInfoRequest,
+    ) -> QueryHistoricalInfoResponse {
+        // negative heights have no historical info
+        let hist = u32::try_from(query.height)
+            .ok()
+            .and_then(|height| self.historical_info(ctx, height).unwrap_gas());
+
+        QueryHistoricalInfoResponse { hist }
+    }
+
     pub fn redelegations<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -199,33 +324,66 @@ impl<
         dst_validator_address: &Option<ValAddress>,
         pagination: Option<Pagination>,
     ) -> (Option<PaginationResult>, Vec<Redelegation>) {
-        let redelegations = match (
+        match (
             delegator_address,
             src_validator_address,
             dst_validator_address,
         ) {
-            (Some(a), Some(v1), Some(v2)) => self
-                .redelegation(ctx, a, v1, v2)
-                .unwrap_gas()
-                .map(|red| vec![red])
-                .unwrap_or_default(),
-            (None, Some(_v1), None) => {
-                /*
-                  TODO: add logic for a query with only src validator
-                    redels = k.GetRedelegationsFromSrcValidator(ctx, params.SrcValidatorAddr)
-                */
-                todo!()
-            }
-            _ => {
-                // TODO: add logic for a query to get all redelegations
-                //     redels = k.GetAllRedelegations(ctx, params.DelegatorAddr, params.SrcValidatorAddr, params.DstValidatorAddr)
-                todo!()
+            (Some(a), Some(v1), Some(v2)) => {
+                let redelegations = self
+                    .redelegation(ctx, a, v1, v2)
+                    .unwrap_gas()
+                    .map(|red| vec![red])
+                    .unwrap_or_default();
+
+                let (p_result, iter) = redelegations.into_iter().maybe_paginate(pagination);
+                (p_result, iter.collect())
             }
-        };
+            (None, Some(v1), None) => {
+                let store = ctx.kv_store(&self.store_key);
+                let store = store.prefix_store(redelegations_from_val_src_index_key(v1));
+                let (p_result, iter) = store.into_range(..).maybe_paginate(pagination);
 
-        let (p_result, iter) = redelegations.into_iter().maybe_paginate(pagination);
+                // the keys of the index are the delegator addresses followed by
+                // the destination validator addresses
+                let redelegations = iter
+                    .filter_map(|(k, _)| {
+                        let del_len = *k.first().unwrap_or_corrupt() as usize;
+                        let (del_addr, dst_addr) = k.split_at(del_len + 1);
+                        let del_addr =
+                            AccAddress::try_from_prefix_length_bytes(del_addr).unwrap_or_corrupt();
+                        let dst_addr =
+                            ValAddress::try_from_prefix_length_bytes(dst_addr).unwrap_or_corrupt();
+                        self.redelegation(ctx, &del_addr, v1, &dst_addr)
+                            .unwrap_gas()
+                    })
+                    .collect();
 
-        (p_result, iter.collect())
+                (p_result, redelegations)
+            }
+            _ => {
+                let prefix = match delegator_address {
+                    Some(a) => redelegations_key(a),
+                    None => REDELEGATION_KEY.to_vec(),
+                };
+                let store = ctx.kv_store(&self.store_key);
+                let store = store.prefix_store(prefix);
+                let iter = store
+                    .into_range(..)
+                    .map(|(k, bytes)| (k, Redelegation::decode_vec(&bytes).unwrap_or_corrupt()))
+                    .filter(|(_k, red)| {
+                        src_validator_address
+                            .iter()
+                            .all(|v1| &red.validator_src_address == v1)
+                            && dst_validator_address
+                                .iter()
+                                .all(|v2| &red.validator_dst_address == v2)
+                    });
+                let (p_result, iter) = iter.maybe_paginate(pagination);
+
+                (p_result, iter.map(|(_k, red)| red).collect())
+            }
+        }
     }
 
     pub fn query_params<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> QueryParamsResponse {
@@ -235,3 +393,357 @@ impl<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        baseapp::ConsensusParams,
+        derive::{ParamsKeys, StoreKeys},
+        extensions::{pagination::PaginationByOffset, testing::UnwrapTesting},
+        store::{bank::multi::ApplicationMultiBank, database::MemDB, query::QueryMultiStore},
+        tendermint::types::{chain_id::ChainId, proto::header::Header},
+        types::pagination::request::{PaginationKind, PaginationRequest},
+        utils::node::{build_init_ctx, build_store},
+        x::keepers::mocks::{auth::MockAuthKeeper, bank::MockBankKeeper},
+    };
+
+    use super::*;
+    use crate::{HistoricalInfo, MockHookKeeper, StakingParams};
+
+    type TestKeeper = Keeper<
+        SpaceKey,
+        SubspaceKey,
+        MockAuthKeeper,
+        MockBankKeeper,
+        MockHookKeeper<SpaceKey, MockAuthKeeper, Modules>,
+        Modules,
+    >;
+
+    fn delegator(i: u8) -> AccAddress {
+        // the last delegator is longer, as module accounts are
+        let len = if i == 3 { 32 } else { 20 };
+        AccAddress::try_from(vec![i; len]).unwrap_test()
+    }
+
+    fn validator_address(i: u8) -> ValAddress {
+        ValAddress::try_from(vec![10 + i; 20]).unwrap_test()
+    }
+
+    fn validator(i: u8) -> Validator {
+        serde_json::from_value(serde_json::json!({
+            "operator_address": validator_address(i).to_string(),
+            "consensus_pubkey": {
+                "type": "tendermint/PubKeyEd25519",
+                "value": "6Ob7SEB++IzwqXQQ/pgsD/bkxXNl+LDBhJZwpKuvnMo="
+            },
+            "jailed": false,
+            "status": "BOND_STATUS_BONDED",
+            "tokens": "100",
+            "delegator_shares": "100.000000000000000000",
+            "description": {
+                "moniker": "",
+                "identity": "",
+                "website": "",
+                "security_contact": "",
+                "details": ""
+            },
+            "unbonding_height": "0",
+            "unbonding_time": "1970-01-01T00:00:00Z",
+            "commission": {
+                "commission_rates": {
+                    "rate": "0.100000000000000000",
+                    "max_rate": "0.200000000000000000",
+                    "max_change_rate": "0.010000000000000000"
+                },
+                "update_time": "1970-01-01T00:00:00Z"
+            },
+            "min_self_delegation": "1"
+        }))
+        .unwrap_test()
+    }
+
+    fn redelegation(del: u8, src: u8, dst: u8) -> Redelegation {
+        Redelegation {
+            delegator_address: delegator(del),
+            validator_src_address: validator_address(src),
+            validator_dst_address: validator_address(dst),
+            entries: vec![],
+        }
+    }
+
+    fn historical_info() -> HistoricalInfo {
+        HistoricalInfo {
+            header: Header {
+                chain_id: ChainId::new("test-chain").unwrap_test(),
+                height: 5,
+                ..Default::default()
+            },
+            validators: vec![validator(1)],
+        }
+    }
+
+    fn pagination(offset: u32, limit: u8) -> Option<PaginationRequest> {
+        Some(PaginationRequest {
+            kind: PaginationKind::Offset { offset },
+            limit,
+        })
+    }
+
+    /// Commits delegations of delegators 1, 2 and 3 to validator 1, of delegator 1 to
+    /// validators 2 and 3, redelegations of delegators 1 and 3 from validator 1 and of
+    /// delegator 2 from validator 2, and historical info at height 5
+    fn setup() -> (TestKeeper, ApplicationMultiBank<MemDB, SpaceKey>) {
+        let keeper = TestKeeper::new(
+            SpaceKey::Staking,
+            SubspaceKey::Staking,
+            MockAuthKeeper::former().form(),
+            MockBankKeeper::former()
+                .balance("0uatom".parse().unwrap_test())
+                .form(),
+            None,
+            Modules::BondedPool,
+            Modules::NotBondedPool,
+        );
+        let mut multi_store = build_store();
+
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper
+            .staking_params_keeper
+            .set(&mut ctx, StakingParams::default());
+        for i in 1..=3 {
+            keeper.set_validator(&mut ctx, &validator(i)).unwrap_test();
+        }
+        for (del, val) in [(1, 1), (2, 1), (3, 1), (1, 2), (1, 3)] {
+            let delegation = Delegation {
+                delegator_address: delegator(del),
+                validator_address: validator_address(val),
+                shares: Decimal256::from_atomics(10u64, 0).unwrap_test(),
+            };
+            keeper.set_delegation(&mut ctx, &delegation).unwrap_test();
+        }
+        for (del, src, dst) in [(1, 1, 2), (3, 1, 3), (2, 2, 3)] {
+            keeper
+                .set_redelegation(&mut ctx, &redelegation(del, src, dst))
+                .unwrap_test();
+        }
+        keeper
+            .set_historical_info(&mut ctx, 5, &historical_info())
+            .unwrap_test();
+
+        multi_store.commit();
+
+        (keeper, multi_store)
+    }
+
+    fn query_ctx(
+        multi_store: &ApplicationMultiBank<MemDB, SpaceKey>,
+    ) -> QueryContext<MemDB, SpaceKey> {
+        QueryContext::new(
+            QueryMultiStore::new(multi_store, 1).unwrap_test(),
+            1,
+            Timestamp::UNIX_EPOCH,
+        )
+        .unwrap_test()
+    }
+
+    #[test]
+    fn validator_delegations() {
+        let (keeper, multi_store) = setup();
+        let ctx = query_ctx(&multi_store);
+        let query = |pagination| {
+            let response = keeper.query_validator_delegations(
+                &ctx,
+                QueryValidatorDelegationsRequest {
+                    validator_addr: validator_address(1),
+                    pagination,
+                },
+            );
+            let delegators = response
+                .delegation_responses
+                .into_iter()
+                .map(|response| response.delegation.unwrap_test().delegator_address)
+                .collect::<Vec<_>>();
+
+            (delegators, response.pagination.map(|p| p.next_key))
+        };
+
+        assert_eq!(
+            query(None),
+            (vec![delegator(1), delegator(2), delegator(3)], None)
+        );
+        assert_eq!(
+            query(pagination(0, 2)),
+            (
+                vec![delegator(1), delegator(2)],
+                Some(
+                    [
+                        delegator(3).prefix_len_bytes(),
+                        validator_address(1).prefix_len_bytes(),
+                    ]
+                    .concat()
+                )
+            )
+        );
+        assert_eq!(query(pagination(1, 2)), (vec![delegator(3)], Some(vec![])));
+    }
+
+    #[test]
+    fn delegator_validators() {
+        let (keeper, multi_store) = setup();
+        let ctx = query_ctx(&multi_store);
+        let query = |pagination| {
+            let response = keeper.query_delegator_validators(
+                &ctx,
+                QueryDelegatorValidatorsRequest {
+                    delegator_addr: delegator(1),
+                    pagination,
+                },
+            );
+            let validators = response
+                .validators
+                .into_iter()
+                .map(|validator| validator.operator_address)
+                .collect::<Vec<_>>();
+
+            (validators, response.pagination.map(|p| p.next_key))
+        };
+
+        assert_eq!(
+            query(None),
+            (
+                vec![
+                    validator_address(1),
+                    validator_address(2),
+                    validator_address(3)
+                ],
+                None
+            )
+        );
+        assert_eq!(
+            query(pagination(0, 2)),
+            (
+                vec![validator_address(1), validator_address(2)],
+                Some(validator_address(3).prefix_len_bytes())
+            )
+        );
+        assert_eq!(
+            query(pagination(1, 2)),
+            (vec![validator_address(3)], Some(vec![]))
+        );
+    }
+
+    #[test]
+    fn redelegations_from_source_validator() {
+        let (keeper, multi_store) = setup();
+        let ctx = query_ctx(&multi_store);
+
+        // the index keys hold delegator addresses of different lengths
+        let (p_result, redelegations) =
+            keeper.redelegations(&ctx, &None, &Some(validator_address(1)), &None, None);
+        assert!(p_result.is_none());
+        assert_eq!(
+            redelegations,
+            vec![redelegation(1, 1, 2), redelegation(3, 1, 3)]
+        );
+
+        let (p_result, redelegations) = keeper.redelegations(
+            &ctx,
+            &None,
+            &Some(validator_address(1)),
+            &None,
+            Some(Pagination::from(PaginationByOffset {
+                offset: 0,
+                limit: 1,
+            })),
+        );
+        assert_eq!(
+            p_result.unwrap_test().next_key,
+            Some(
+                [
+                    delegator(3).prefix_len_bytes(),
+                    validator_address(3).prefix_len_bytes(),
+                ]
+                .concat()
+            )
+        );
+        assert_eq!(redelegations, vec![redelegation(1, 1, 2)]);
+    }
+
+    #[test]
+    fn redelegations() {
+        let (keeper, multi_store) = setup();
+        let ctx = query_ctx(&multi_store);
+        let query = |del: Option<u8>, src: Option<u8>, dst: Option<u8>| {
+            let (_, redelegations) = keeper.redelegations(
+                &ctx,
+                &del.map(delegator),
+                &src.map(validator_address),
+                &dst.map(validator_address),
+                None,
+            );
+            redelegations
+        };
+
+        assert_eq!(
+            query(Some(1), Some(1), Some(2)),
+            vec![redelegation(1, 1, 2)]
+        );
+        assert!(query(Some(1), Some(1), Some(3)).is_empty());
+        assert_eq!(query(Some(1), None, None), vec![redelegation(1, 1, 2)]);
+        assert_eq!(
+            query(None, None, Some(3)),
+            vec![redelegation(2, 2, 3), redelegation(3, 1, 3)]
+        );
+        assert!(query(Some(2), Some(1), None).is_empty());
+        assert_eq!(
+            query(None, None, None),
+            vec![
+                redelegation(1, 1, 2),
+                redelegation(2, 2, 3),
+                redelegation(3, 1, 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn historical_info_by_height() {
+        let (keeper, multi_store) = setup();
+        let ctx = query_ctx(&multi_store);
+        let query =
+            |height| keeper.query_historical_info(&ctx, QueryHistoricalInfoRequest { height });
+
+        assert_eq!(query(5).hist, Some(historical_info()));
+        assert_eq!(query(6).hist, None);
+        assert_eq!(query(-5).hist, None);
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+    #[skey(params = Params)]
+    enum SpaceKey {
+        #[skey(to_string = "staking")]
+        Staking,
+        #[skey(to_string = "params")]
+        Params,
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys)]
+    enum SubspaceKey {
+        #[pkey(to_string = "staking/")]
+        Staking,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Modules {
+        BondedPool,
+        NotBondedPool,
+    }
+
+    impl Module for Modules {
+        fn name(&self) -> String {
+            match self {
+                Modules::BondedPool => crate::BONDED_POOL_NAME.into(),
+                Modules::NotBondedPool => crate::NOT_BONDED_POOL_NAME.into(),
+            }
+        }
+    }
+}
@@ -0,0 +1,684 @@
+use super::*;
+use crate::types::keys::{get_ubds_by_val_index_key, redelegations_from_val_src_index_key};
+use gears::{
+    extensions::corruption::UnwrapCorrupt, tendermint::types::proto::validator::VotingPower,
+    types::address::ConsAddress, x::errors::BankKeeperError,
+};
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, KH, M>
+{
+    /// slash a validator for an infraction committed at a known height. Find the contributing
+    /// stake at that height and burn the specified slash_factor of it, updating unbonding
+    /// delegations & redelegations appropriately. Returns the amount of burned tokens.
+    ///
+    /// CONTRACT:
+    ///    slash_factor is non-negative
+    /// CONTRACT:
+    ///    Infraction was committed equal to or less than an unbonding period in the past,
+    ///    so all unbonding delegations and redelegations from that height are stored
+    /// CONTRACT:
+    ///    Slash will not slash unbonded validators (for the above reason)
+    /// CONTRACT:
+    ///    Infraction was committed at the current height or at a past height,
+    ///    not at a height in the future
+    pub fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+        infraction_height: u32,
+        power: VotingPower,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        // Amount of slashing = slash slash_factor * power at time of infraction
+        let amount = Uint256::from(power.power()) * Uint256::from(self.power_reduction(ctx));
+        let slash_amount = amount.mul_floor(slash_factor);
+
+        // ref https://github.com/cosmos/cosmos-sdk/issues/1348
+        let Some(mut validator) = self.validator_by_cons_addr(ctx, cons_addr)? else {
+            // If not found, the validator must have been overslashed and removed - so we don't need to do anything
+            // NOTE:  Correctness dependent on invariant that unbonding delegations / redelegations must also have been completely
+            //        slashed in this case - which we don't explicitly check, but should be true.
+            // Log the slash attempt for future reference (maybe we should tag it too)
+            tracing::error!(
+                name: "WARNING: ignored attempt to slash a nonexistent validator; we recommend you investigate immediately",
+                target: "module::staking",
+                validator = cons_addr.to_string(),
+            );
+            return Ok(Uint256::zero());
+        };
+
+        // should not be slashing an unbonded validator
+        assert_ne!(
+            validator.status,
+            BondStatus::Unbonded,
+            "should not be slashing unbonded validator: {}",
+            validator.operator_address
+        );
+
+        // call the before-modification hook. Slashing runs for infractions found in begin
+        // block, so as in the SDK a failing hook is only logged and can't prevent the
+        // slash. Running out of gas is still an error.
+        match self.before_validator_modified(ctx, &validator) {
+            Err(StakingHooksError::GasError(e)) => return Err(e),
+            Err(e) => tracing::error!("failed to call before validator modified hook: {e}"),
+            Ok(()) => (),
+        }
+
+        // Track remaining slash amount for the validator
+        // This will decrease when we slash unbondings and
+        // redelegations, as that stake has since unbonded
+        let mut remaining_slash_amount = slash_amount;
+
+        let height = ctx.height();
+        match infraction_height.cmp(&height) {
+            Ordering::Greater => {
+                // Can't slash infractions in the future
+                panic!(
+                    "impossible attempt to slash future infraction at height {} but we are at height {}",
+                    infraction_height, height
+                );
+            }
+            Ordering::Equal => {
+                // Special-case slash at current height for efficiency - we don't need to
+                // look through unbonding delegations or redelegations.
+                tracing::info!(
+                    name: "slashing at current height; not scanning unbonding delegations & redelegations",
+                    target: "module::staking",
+                    ?infraction_height,
+                );
+            }
+            Ordering::Less => {
+                // Iterate through unbonding delegations from slashed validator
+                for ubd in
+                    self.unbonding_delegations_from_validator(ctx, &validator.operator_address)?
+                {
+                    let amount_slashed =
+                        self.slash_unbonding_delegation(ctx, ubd, infraction_height, slash_factor)?;
+                    remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+                }
+
+                // Iterate through redelegations from slashed source validator
+                for redelegation in
+                    self.redelegations_from_src_validator(ctx, &validator.operator_address)?
+                {
+                    let amount_slashed = self.slash_redelegation(
+                        ctx,
+                        redelegation,
+                        infraction_height,
+                        slash_factor,
+                    )?;
+                    remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+                }
+            }
+        }
+
+        // cannot decrease balance below zero
+        let tokens_to_burn = remaining_slash_amount.min(validator.tokens);
+
+        // we need to calculate the *effective* slash fraction for distribution
+        if !validator.tokens.is_zero() {
+            let effective_fraction =
+                Decimal256::from_ratio(tokens_to_burn, validator.tokens).min(Decimal256::one());
+
+            // call the before-slashed hook. The SDK logs the error of the hook and burns
+            // the tokens anyway, as skipping the burn would let the infraction go unpunished.
+            match self.before_validator_slashed(ctx, &validator, effective_fraction) {
+                Err(StakingHooksError::GasError(e)) => return Err(e),
+                Err(e) => tracing::error!("failed to call before validator slashed hook: {e}"),
+                Ok(()) => (),
+            }
+        }
+
+        // Deduct from validator's bonded tokens and update the validator.
+        // Burn the slashed tokens from the pool account and decrease the total supply.
+        self.remove_validator_tokens(ctx, &mut validator, tokens_to_burn)?;
+
+        match validator.status {
+            BondStatus::Bonded => self.burn_bonded_tokens(ctx, tokens_to_burn)?,
+            BondStatus::Unbonding | BondStatus::Unbonded => {
+                self.burn_not_bonded_tokens(ctx, tokens_to_burn)?
+            }
+            BondStatus::Unspecified => panic!("invalid validator status"),
+        }
+
+        ctx.push_event(Event {
+            r#type: EVENT_TYPE_SLASH.to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: ATTRIBUTE_KEY_VALIDATOR.into(),
+                    value: validator.operator_address.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: ATTRIBUTE_KEY_INFRACTION_HEIGHT.into(),
+                    value: infraction_height.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: ATTRIBUTE_KEY_BURNED_COINS.into(),
+                    value: tokens_to_burn.to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        tracing::info!(
+            name: "validator slashed by slash factor",
+            target: "module::staking",
+            validator = validator.operator_address.to_string(),
+            slash_factor = slash_factor.to_string(),
+            burned = tokens_to_burn.to_string(),
+        );
+
+        Ok(tokens_to_burn)
+    }
+
+    /// slash_unbonding_delegation slashes an unbonding delegation and updates the pool.
+    /// Returns the amount that would have been slashed assuming the unbonding delegation
+    /// had enough stake to slash (the amount actually slashed may be less if there's
+    /// insufficient stake remaining).
+    pub fn slash_unbonding_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        mut ubd: UnbondingDelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut burned_amount = Uint256::zero();
+
+        // perform slashing on all entries within the unbonding delegation
+        for i in 0..ubd.entries.len() {
+            let entry = &ubd.entries[i];
+
+            // If unbonding started before this height, stake didn't contribute to infraction
+            if entry.creation_height < infraction_height {
+                continue;
+            }
+
+            if entry.is_mature(&now) {
+                // Unbonding delegation no longer eligible for slashing, skip it
+                continue;
+            }
+
+            // Calculate slash amount proportional to stake contributing to infraction
+            let slash_amount = entry.initial_balance.mul_floor(slash_factor);
+            total_slash_amount += slash_amount;
+
+            // Don't slash more tokens than held
+            // Possible since the unbonding delegation may already
+            // have been slashed, and slash amounts are calculated
+            // according to stake held at time of infraction
+            let unbonding_slash_amount = slash_amount.min(entry.balance);
+
+            // Update unbonding delegation if necessary
+            if unbonding_slash_amount.is_zero() {
+                continue;
+            }
+
+            burned_amount += unbonding_slash_amount;
+            ubd.entries[i].balance -= unbonding_slash_amount;
+            self.set_unbonding_delegation(ctx, &ubd)?;
+        }
+
+        self.burn_not_bonded_tokens(ctx, burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    /// slash_redelegation slashes a redelegation and updates the pool.
+    /// Returns the amount that would have been slashed assuming the redelegation
+    /// had enough stake to slash (the amount actually slashed may be less if there's
+    /// insufficient stake remaining).
+    pub fn slash_redelegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        redelegation: Redelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut bonded_burned_amount = Uint256::zero();
+        let mut not_bonded_burned_amount = Uint256::zero();
+
+        // perform slashing on all entries within the redelegation
+        for entry in &redelegation.entries {
+            // If redelegation started before this height, stake didn't contribute to infraction
+            if entry.creation_height < infraction_height {
+                continue;
+            }
+
+            if entry.is_mature(&now) {
+                // Redelegation no longer eligible for slashing, skip it
+                continue;
+            }
+
+            // Calculate slash amount proportional to stake contributing to infraction
+            let slash_amount = entry.initial_balance.mul_floor(slash_factor);
+            total_slash_amount += slash_amount;
+
+            // Unbond from target validator
+            let mut shares_to_unbond = slash_factor * entry.share_dst;
+            if shares_to_unbond.is_zero() {
+                continue;
+            }
+
+            let Some(delegation) = self.delegation(
+                ctx,
+                &redelegation.delegator_address,
+                &redelegation.validator_dst_address,
+            )?
+            else {
+                // If deleted, delegation has zero shares, and we can't unbond any more
+                continue;
+            };
+
+            if shares_to_unbond > delegation.shares {
+                shares_to_unbond = delegation.shares;
+            }
+
+            let tokens_to_burn = self
+                .unbond(
+                    ctx,
+                    &redelegation.delegator_address,
+                    &redelegation.validator_dst_address,
+                    shares_to_unbond,
+                )
+                .unwrap_or_else(|e| panic!("error unbonding delegator: {e}"));
+
+            let dst_validator = self
+                .validator(ctx, &redelegation.validator_dst_address)?
+                .expect("destination validator of a redelegation should exist");
+
+            // tokens of a redelegation are delegated to the destination validator
+            match dst_validator.status {
+                BondStatus::Bonded => bonded_burned_amount += tokens_to_burn,
+                BondStatus::Unbonding | BondStatus::Unbonded => {
+                    not_bonded_burned_amount += tokens_to_burn
+                }
+                BondStatus::Unspecified => panic!("unknown validator status"),
+            }
+        }
+
+        self.burn_bonded_tokens(ctx, bonded_burned_amount)?;
+        self.burn_not_bonded_tokens(ctx, not_bonded_burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    /// Returns all unbonding delegations from a particular validator
+    pub fn unbonding_delegations_from_validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        val_addr: &ValAddress,
+    ) -> Result<Vec<UnbondingDelegation>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        let del_addrs = store
+            .prefix_store(get_ubds_by_val_index_key(val_addr))
+            .into_range(..)
+            .map(|res| {
+                res.map(|(key, _)| {
+                    AccAddress::try_from_prefix_length_bytes(&key).unwrap_or_corrupt()
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut ubds = Vec::with_capacity(del_addrs.len());
+        for del_addr in del_addrs {
+            if let Some(ubd) = self.unbonding_delegation(ctx, &del_addr, val_addr)? {
+                ubds.push(ubd);
+            }
+        }
+
+        Ok(ubds)
+    }
+
+    /// Returns all redelegations from a particular validator
+    pub fn redelegations_from_src_validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        val_src_addr: &ValAddress,
+    ) -> Result<Vec<Redelegation>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        // key is of the form del_addr.len() (1 byte) || del_addr || val_dst_addr.len() (1 byte) || val_dst_addr
+        let addrs = store
+            .prefix_store(redelegations_from_val_src_index_key(val_src_addr))
+            .into_range(..)
+            .map(|res| {
+                res.map(|(key, _)| {
+                    let del_addr_len = *key.first().unwrap_or_corrupt() as usize;
+                    (
+                        AccAddress::try_from_prefix_length_bytes(&key[..=del_addr_len])
+                            .unwrap_or_corrupt(),
+                        ValAddress::try_from_prefix_length_bytes(&key[del_addr_len + 1..])
+                            .unwrap_or_corrupt(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut redelegations = Vec::with_capacity(addrs.len());
+        for (del_addr, val_dst_addr) in addrs {
+            if let Some(redelegation) =
+                self.redelegation(ctx, &del_addr, val_src_addr, &val_dst_addr)?
+            {
+                redelegations.push(redelegation);
+            }
+        }
+
+        Ok(redelegations)
+    }
+
+    /// burn_bonded_tokens removes coins from the bonded pool module account
+    pub fn burn_bonded_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.burn_tokens(ctx, &self.bonded_module, amount)
+    }
+
+    /// burn_not_bonded_tokens removes coins from the not bonded pool module account
+    pub fn burn_not_bonded_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.burn_tokens(ctx, &self.not_bonded_module, amount)
+    }
+
+    fn burn_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        pool: &M,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let params = self.staking_params_keeper.try_get(ctx)?;
+        let coins = UnsignedCoins::new(vec![UnsignedCoin {
+            denom: params.bond_denom().clone(),
+            amount,
+        }])
+        .expect("non zero amount of a single coin is valid");
+
+        // original routine panics on all errors except of the out of gas error
+        match self.bank_keeper.coins_burn(ctx, pool, &coins) {
+            Ok(()) => Ok(()),
+            Err(BankKeeperError::GasError(e)) => Err(e),
+            Err(e) => panic!("failed to burn slashed tokens: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::extensions::testing::UnwrapTesting;
+
+    use super::*;
+    use crate::{keeper::testing::*, UnbondingDelegationEntry};
+
+    /// Power of the slashed validator at the infraction, worth 10_000_000 tokens
+    const POWER: u64 = 10;
+
+    fn power() -> VotingPower {
+        VotingPower::new(POWER).unwrap_test()
+    }
+
+    fn slash_factor() -> Decimal256 {
+        Decimal256::from_ratio(1u64, 10u64)
+    }
+
+    fn entry(creation_height: u32, completion_time: i64, balance: u64) -> UnbondingDelegationEntry {
+        UnbondingDelegationEntry {
+            creation_height,
+            completion_time: Timestamp::try_new(completion_time, 0).unwrap_test(),
+            initial_balance: Uint256::from(5_000_000u64),
+            balance: Uint256::from(balance),
+        }
+    }
+
+    fn burned(keeper: &TestKeeper) -> Vec<(String, UnsignedCoins)> {
+        keeper.bank_keeper.burned.lock().unwrap_test().clone()
+    }
+
+    #[test]
+    fn slash_bonded_validator_burns_from_bonded_pool() {
+        let keeper = keeper();
+        let mut multi_store = multi_store();
+        let mut ctx = block_ctx(&mut multi_store);
+        let validator = validator(BondStatus::Bonded, 10_000_000);
+        setup(&keeper, &mut ctx, &validator);
+
+        let slashed = keeper
+            .slash(
+                &mut ctx,
+                &validator.cons_addr(),
+                HEIGHT,
+                power(),
+                slash_factor(),
+            )
+            .unwrap_test();
+
+        assert_eq!(slashed, Uint256::from(1_000_000u64));
+        assert_eq!(
+            burned(&keeper),
+            vec![(BONDED_POOL_NAME.to_string(), bond_coins(1_000_000))]
+        );
+        let validator = keeper
+            .validator(&ctx, &validator.operator_address)
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(validator.tokens, Uint256::from(9_000_000u64));
+    }
+
+    #[test]
+    fn slash_unbonding_validator_burns_from_not_bonded_pool() {
+        let keeper = keeper();
+        let mut multi_store = multi_store();
+        let mut ctx = block_ctx(&mut multi_store);
+        let validator = validator(BondStatus::Unbonding, 10_000_000);
+        setup(&keeper, &mut ctx, &validator);
+
+        let slashed = keeper
+            .slash(
+                &mut ctx,
+                &validator.cons_addr(),
+                HEIGHT,
+                power(),
+                slash_factor(),
+            )
+            .unwrap_test();
+
+        assert_eq!(slashed, Uint256::from(1_000_000u64));
+        assert_eq!(
+            burned(&keeper),
+            vec![(NOT_BONDED_POOL_NAME.to_string(), bond_coins(1_000_000))]
+        );
+    }
+
+    #[test]
+    fn slash_filters_unbonding_entries_by_infraction_height() {
+        let keeper = keeper();
+        let mut multi_store = multi_store();
+        let mut ctx = block_ctx(&mut multi_store);
+        let validator = validator(BondStatus::Bonded, 10_000_000);
+        setup(&keeper, &mut ctx, &validator);
+        let ubd = UnbondingDelegation {
+            delegator_address: delegator(),
+            validator_address: validator.operator_address.clone(),
+            entries: vec![
+                // unbonding started before the infraction
+                entry(4, TIME + 100, 5_000_000),
+                // contributed to the infraction
+                entry(5, TIME + 100, 5_000_000),
+                // already matured
+                entry(6, TIME, 5_000_000),
+            ],
+        };
+        keeper
+            .set_unbonding_delegation(&mut ctx, &ubd)
+            .unwrap_test();
+
+        let slashed = keeper
+            .slash(&mut ctx, &validator.cons_addr(), 5, power(), slash_factor())
+            .unwrap_test();
+
+        // the unbonding entry takes 500_000 of the 1_000_000 slashed tokens
+        assert_eq!(slashed, Uint256::from(500_000u64));
+        assert_eq!(
+            burned(&keeper),
+            vec![
+                (NOT_BONDED_POOL_NAME.to_string(), bond_coins(500_000)),
+                (BONDED_POOL_NAME.to_string(), bond_coins(500_000)),
+            ]
+        );
+        let balances = keeper
+            .unbonding_delegation(&ctx, &delegator(), &validator.operator_address)
+            .unwrap_test()
+            .unwrap_test()
+            .entries
+            .into_iter()
+            .map(|entry| entry.balance)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            balances,
+            vec![
+                Uint256::from(5_000_000u64),
+                Uint256::from(4_500_000u64),
+                Uint256::from(5_000_000u64)
+            ]
+        );
+    }
+
+    #[test]
+    fn slash_is_capped_at_remaining_amount() {
+        let keeper = keeper();
+        let mut multi_store = multi_store();
+        let mut ctx = block_ctx(&mut multi_store);
+        let validator = validator(BondStatus::Bonded, 300_000);
+        setup(&keeper, &mut ctx, &validator);
+        let ubd = UnbondingDelegation {
+            delegator_address: delegator(),
+            validator_address: validator.operator_address.clone(),
+            entries: vec![entry(5, TIME + 100, 200_000)],
+        };
+        keeper
+            .set_unbonding_delegation(&mut ctx, &ubd)
+            .unwrap_test();
+
+        let slashed = keeper
+            .slash(&mut ctx, &validator.cons_addr(), 5, power(), slash_factor())
+            .unwrap_test();
+
+        // the entry only holds 200_000 of its 500_000 share of the slash, and the validator
+        // only holds 300_000 of the remaining 500_000
+        assert_eq!(slashed, Uint256::from(300_000u64));
+        assert_eq!(
+            burned(&keeper),
+            vec![
+                (NOT_BONDED_POOL_NAME.to_string(), bond_coins(200_000)),
+                (BONDED_POOL_NAME.to_string(), bond_coins(300_000)),
+            ]
+        );
+        let validator = keeper
+            .validator(&ctx, &validator.operator_address)
+            .unwrap_test()
+            .unwrap_test();
+        assert!(validator.tokens.is_zero());
+        let slashed_fractions = keeper
+            .hooks_keeper
+            .as_ref()
+            .unwrap_test()
+            .slashed
+            .lock()
+            .unwrap_test()
+            .clone();
+        assert_eq!(
+            slashed_fractions,
+            vec![(validator.operator_address, Decimal256::one())]
+        );
+    }
+
+    #[test]
+    fn slash_calls_hooks_and_emits_event() {
+        let keeper = keeper();
+        let mut multi_store = multi_store();
+        let mut ctx = block_ctx(&mut multi_store);
+        let validator = validator(BondStatus::Bonded, 10_000_000);
+        setup(&keeper, &mut ctx, &validator);
+
+        keeper
+            .slash(
+                &mut ctx,
+                &validator.cons_addr(),
+                HEIGHT,
+                power(),
+                slash_factor(),
+            )
+            .unwrap_test();
+
+        let hooks = keeper.hooks_keeper.as_ref().unwrap_test();
+        assert_eq!(
+            *hooks.modified.lock().unwrap_test(),
+            vec![validator.operator_address.clone()]
+        );
+        assert_eq!(
+            *hooks.slashed.lock().unwrap_test(),
+            vec![(validator.operator_address.clone(), slash_factor())]
+        );
+        assert_eq!(
+            ctx.events,
+            vec![Event {
+                r#type: EVENT_TYPE_SLASH.to_string(),
+                attributes: vec![
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_VALIDATOR.into(),
+                        value: validator.operator_address.to_string().into(),
+                        index: false,
+                    },
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_INFRACTION_HEIGHT.into(),
+                        value: HEIGHT.to_string().into(),
+                        index: false,
+                    },
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_BURNED_COINS.into(),
+                        value: "1000000".into(),
+                        index: false,
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "impossible attempt to slash future infraction")]
+    fn slash_future_infraction_panics() {
+        let keeper = keeper();
+        let mut multi_store = multi_store();
+        let mut ctx = block_ctx(&mut multi_store);
+        let validator = validator(BondStatus::Bonded, 10_000_000);
+        setup(&keeper, &mut ctx, &validator);
+
+        let height = ctx.height();
+        let _ = keeper.slash(
+            &mut ctx,
+            &validator.cons_addr(),
+            height + 1,
+            power(),
+            slash_factor(),
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use gears::{
+    tendermint::types::proto::validator::VotingPower, types::address::ConsAddress,
+    x::keepers::staking::SlashingStakingKeeper,
+};
+
+use crate::iter::validators::ValidatorsIterator;
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > SlashingStakingKeeper<SK, M> for Keeper<SK, PSK, AK, BK, KH, M>
+{
+    type Validator = Validator;
+    type Delegation = Delegation;
+
+    fn validators_iter<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<impl Iterator<Item = Result<Self::Validator, GasStoreErrors>>, GasStoreErrors> {
+        Ok(ValidatorsIterator::new(ctx.kv_store(&self.store_key))
+            .map(|this| this.map(|(_, validator)| validator)))
+    }
+
+    fn validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ValAddress,
+    ) -> Result<Option<Self::Validator>, GasStoreErrors> {
+        Keeper::validator(self, ctx, addr)
+    }
+
+    fn validator_by_cons_addr<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<Option<Self::Validator>, GasStoreErrors> {
+        Keeper::validator_by_cons_addr(self, ctx, addr)
+    }
+
+    fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        height: u32,
+        power: VotingPower,
+        slash_fraction_downtime: Decimal256,
+    ) -> Result<(), GasStoreErrors> {
+        Keeper::slash(self, ctx, addr, height, power, slash_fraction_downtime)?;
+        Ok(())
+    }
+
+    fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = Keeper::validator_by_cons_addr(self, ctx, addr)?
+            .unwrap_or_else(|| panic!("validator with consensus-Address {addr} not found"));
+        self.jail_validator(ctx, &mut validator)?;
+
+        tracing::info!(
+            name: "validator jailed",
+            target: "module::staking",
+            validator = addr.to_string(),
+        );
+
+        Ok(())
+    }
+
+    fn unjail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = Keeper::validator_by_cons_addr(self, ctx, addr)?
+            .unwrap_or_else(|| panic!("validator with consensus-Address {addr} not found"));
+        self.unjail_validator(ctx, &mut validator)?;
+
+        tracing::info!(
+            name: "validator un-jailed",
+            target: "module::staking",
+            validator = addr.to_string(),
+        );
+
+        Ok(())
+    }
+
+    fn delegation<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        delegator_address: &AccAddress,
+        validator_address: &ValAddress,
+    ) -> Result<Option<Self::Delegation>, GasStoreErrors> {
+        Keeper::delegation(self, ctx, delegator_address, validator_address)
+    }
+
+    fn max_validators<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<u32, GasStoreErrors> {
+        Ok(self.staking_params_keeper.try_get(ctx)?.max_validators())
+    }
+}
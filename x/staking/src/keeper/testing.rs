@@ -0,0 +1,292 @@
+use std::sync::{Arc, Mutex};
+
+use gears::{
+    application::keepers::params::ParamsKeeper,
+    baseapp::{options::NodeOptions, ConsensusParams},
+    context::{block::BlockContext, tx::TxContext, TransactionalContext},
+    derive::{ParamsKeys, StoreKeys},
+    extensions::testing::UnwrapTesting,
+    store::{
+        bank::multi::{ApplicationMultiBank, TransactionMultiBank},
+        database::{Database, MemDB},
+    },
+    tendermint::types::{proto::header::Header, time::timestamp::Timestamp},
+    types::{
+        address::{AccAddress, ConsAddress, ValAddress},
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        decimal256::Decimal256,
+        gas::{
+            kind::{BlockKind, TxKind},
+            GasMeter,
+        },
+        uint::Uint256,
+    },
+    utils::node::build_store,
+    x::{
+        errors::StakingHooksError,
+        keepers::{
+            mocks::{auth::MockAuthKeeper, bank::MockBankKeeper},
+            staking::KeeperHooks,
+        },
+        module::Module,
+        types::validator::BondStatus,
+    },
+};
+
+use crate::{Keeper, StakingParams, Validator, BONDED_POOL_NAME, NOT_BONDED_POOL_NAME};
+
+pub type TestKeeper =
+    Keeper<SpaceKey, SubspaceKey, MockAuthKeeper, MockBankKeeper, RecordingHooks, Modules>;
+
+/// Height of the blocks built by `block_ctx` and `tx_ctx`
+pub const HEIGHT: u32 = 10;
+
+/// Time of the blocks built by `block_ctx` and `tx_ctx`, in seconds
+pub const TIME: i64 = 1000;
+
+pub fn keeper() -> TestKeeper {
+    Keeper::new(
+        SpaceKey::Staking,
+        SubspaceKey::Staking,
+        MockAuthKeeper::former().form(),
+        MockBankKeeper::former()
+            .balance("0uatom".parse().unwrap_test())
+            .form(),
+        Some(RecordingHooks::default()),
+        Modules::BondedPool,
+        Modules::NotBondedPool,
+    )
+}
+
+pub fn multi_store() -> ApplicationMultiBank<MemDB, SpaceKey> {
+    build_store()
+}
+
+fn header() -> Header {
+    Header {
+        height: HEIGHT,
+        time: Timestamp::try_new(TIME, 0).unwrap_test(),
+        ..Default::default()
+    }
+}
+
+pub fn block_ctx(
+    multi_store: &mut ApplicationMultiBank<MemDB, SpaceKey>,
+) -> BlockContext<'_, MemDB, SpaceKey> {
+    BlockContext::new(multi_store, HEIGHT, header(), ConsensusParams::default())
+}
+
+pub fn tx_ctx<'a>(
+    multi_store: &'a mut TransactionMultiBank<MemDB, SpaceKey>,
+    block_gas_meter: &'a mut GasMeter<BlockKind>,
+) -> TxContext<'a, MemDB, SpaceKey> {
+    TxContext::new(
+        multi_store,
+        HEIGHT,
+        header(),
+        ConsensusParams::default(),
+        GasMeter::<TxKind>::infinite(),
+        block_gas_meter,
+        NodeOptions::default(),
+    )
+}
+
+/// Stores the default params and the validator, indexed by its consensus address
+pub fn setup<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+    keeper: &TestKeeper,
+    ctx: &mut CTX,
+    validator: &Validator,
+) {
+    keeper
+        .staking_params_keeper
+        .try_set(ctx, StakingParams::default())
+        .unwrap_test();
+    keeper.set_validator(ctx, validator).unwrap_test();
+    keeper
+        .set_validator_by_cons_addr(ctx, validator)
+        .unwrap_test();
+}
+
+pub fn delegator() -> AccAddress {
+    AccAddress::try_from(vec![1; 20]).unwrap_test()
+}
+
+pub fn validator_address() -> ValAddress {
+    ValAddress::try_from(vec![11; 20]).unwrap_test()
+}
+
+pub fn validator(status: BondStatus, tokens: u64) -> Validator {
+    let mut validator: Validator = serde_json::from_value(serde_json::json!({
+        "operator_address": validator_address().to_string(),
+        "consensus_pubkey": {
+            "type": "tendermint/PubKeyEd25519",
+            "value": "6Ob7SEB++IzwqXQQ/pgsD/bkxXNl+LDBhJZwpKuvnMo="
+        },
+        "jailed": false,
+        "status": "BOND_STATUS_BONDED",
+        "tokens": "0",
+        "delegator_shares": "0.000000000000000000",
+        "description": {
+            "moniker": "",
+            "identity": "",
+            "website": "",
+            "security_contact": "",
+            "details": ""
+        },
+        "unbonding_height": "0",
+        "unbonding_time": "1970-01-01T00:00:00Z",
+        "commission": {
+            "commission_rates": {
+                "rate": "0.100000000000000000",
+                "max_rate": "0.200000000000000000",
+                "max_change_rate": "0.010000000000000000"
+            },
+            "update_time": "1970-01-01T00:00:00Z"
+        },
+        "min_self_delegation": "1"
+    }))
+    .unwrap_test();
+    validator.status = status;
+    validator.tokens = Uint256::from(tokens);
+    validator.delegator_shares = Decimal256::from_atomics(tokens, 0).unwrap_test();
+
+    validator
+}
+
+/// Coins of the bond denom of the default params
+pub fn bond_coins(amount: u64) -> UnsignedCoins {
+    UnsignedCoins::new(vec![UnsignedCoin {
+        denom: StakingParams::default().bond_denom().clone(),
+        amount: Uint256::from(amount),
+    }])
+    .unwrap_test()
+}
+
+/// Staking hooks which record the calls of the hooks about validators
+#[derive(Debug, Clone, Default)]
+pub struct RecordingHooks {
+    pub modified: Arc<Mutex<Vec<ValAddress>>>,
+    pub slashed: Arc<Mutex<Vec<(ValAddress, Decimal256)>>>,
+}
+
+impl KeeperHooks<SpaceKey, MockAuthKeeper, Modules> for RecordingHooks {
+    fn after_validator_created<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_validator_modified<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        self.modified.lock().unwrap_test().push(val_addr);
+        Ok(())
+    }
+
+    fn after_validator_removed<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_bonded<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _cons_addr: ConsAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_shares_modified<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_delegation_removed<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn after_delegation_modified<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        _del_addr: AccAddress,
+        _val_addr: ValAddress,
+    ) -> Result<(), StakingHooksError> {
+        Ok(())
+    }
+
+    fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SpaceKey>>(
+        &self,
+        _ctx: &mut CTX,
+        val_addr: ValAddress,
+        fraction: Decimal256,
+    ) -> Result<(), StakingHooksError> {
+        self.slashed.lock().unwrap_test().push((val_addr, fraction));
+        Ok(())
+    }
+}
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+#[skey(params = Params)]
+pub enum SpaceKey {
+    #[skey(to_string = "staking")]
+    Staking,
+    #[skey(to_string = "params")]
+    Params,
+}
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys)]
+pub enum SubspaceKey {
+    #[pkey(to_string = "staking/")]
+    Staking,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Modules {
+    BondedPool,
+    NotBondedPool,
+}
+
+impl Module for Modules {
+    fn name(&self) -> String {
+        match self {
+            Modules::BondedPool => BONDED_POOL_NAME.into(),
+            Modules::NotBondedPool => NOT_BONDED_POOL_NAME.into(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::{
-    Commission, CreateValidator, DelegateMsg, EditValidator, RedelegateMsg, UndelegateMsg,
+    CancelUnbondingDelegationMsg, Commission, CreateValidator, DelegateMsg, EditValidator,
+    RedelegateMsg, UndelegateMsg,
 };
 use gears::{
     baseapp::ValidatorParams, context::tx::TxContext, extensions::corruption::UnwrapCorrupt,
@@ -72,7 +73,7 @@ impl<
         self.set_new_validator_by_power_index(ctx, &validator)?;
 
         // call the after-creation hook
-        self.after_validator_created(ctx, &validator);
+        self.after_validator_created(ctx, &validator)?;
 
         // move coins from the msg.address account to a (self-delegation) delegator account
         // the validator account and global shares are updated within here
@@ -146,7 +147,7 @@ impl<
                 .create_updated_validator_commission(ctx, &validator, rate)
                 .map_err(|e| anyhow::anyhow!(e.to_string()))?;
             // call the before-modification hook since we're about to update the commission
-            self.before_validator_modified(ctx, &validator);
+            self.before_validator_modified(ctx, &validator)?;
             validator.commission = commission;
         }
 
@@ -444,4 +445,354 @@ impl<
 
         Ok(())
     }
+
+    /// cancel_unbonding_delegation_cmd_handler defines a method for canceling the unbonding
+    /// delegation entry and delegating the canceled amount back to the validator
+    pub fn cancel_unbonding_delegation_cmd_handler<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: &CancelUnbondingDelegationMsg,
+    ) -> Result<(), anyhow::Error> {
+        let params = self.staking_params_keeper.try_get(ctx)?;
+        if &msg.amount.denom != params.bond_denom() {
+            return Err(anyhow::anyhow!(
+                "invalid coin denomination: got {}, expected {}",
+                msg.amount.denom,
+                params.bond_denom()
+            ));
+        }
+
+        let mut validator = if let Some(validator) = self.validator(ctx, &msg.validator_address)? {
+            validator
+        } else {
+            return Err(anyhow::anyhow!("account not found"));
+        };
+
+        // In some situations, the exchange rate becomes invalid, e.g. if
+        // Validator loses all tokens due to slashing. In this case,
+        // make all future delegations invalid.
+        if validator.invalid_ex_rate() {
+            return Err(anyhow::anyhow!("invalid delegation_share exchange rate"));
+        }
+
+        if validator.jailed {
+            return Err(anyhow::anyhow!(
+                "validator for this address is currently jailed"
+            ));
+        }
+
+        let mut ubd = self
+            .unbonding_delegation(ctx, &msg.delegator_address, &msg.validator_address)?
+            .ok_or(anyhow::anyhow!(
+                "unbonding delegation with delegator {} not found for validator {}",
+                msg.delegator_address,
+                msg.validator_address
+            ))?;
+
+        let entry_index = ubd
+            .entries
+            .iter()
+            .position(|entry| entry.creation_height == msg.creation_height)
+            .ok_or(anyhow::anyhow!(
+                "unbonding delegation entry is not found at block height {}",
+                msg.creation_height
+            ))?;
+        let entry = &mut ubd.entries[entry_index];
+
+        if entry.balance < msg.amount.amount {
+            return Err(anyhow::anyhow!(
+                "amount is greater than the unbonding delegation entry balance"
+            ));
+        }
+
+        if entry.is_mature(&ctx.get_time()) {
+            return Err(anyhow::anyhow!("unbonding delegation is already processed"));
+        }
+
+        // delegate back the unbonding delegation amount to the validator
+        self.delegate(
+            ctx,
+            &msg.delegator_address,
+            msg.amount.amount,
+            BondStatus::Unbonding,
+            &mut validator,
+            false,
+        )?;
+
+        let balance = entry.balance - msg.amount.amount;
+        if balance.is_zero() {
+            ubd.entries.remove(entry_index);
+        } else {
+            entry.balance = balance;
+            entry.initial_balance = entry
+                .initial_balance
+                .checked_sub(msg.amount.amount)
+                .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        }
+
+        // set the unbonding delegation or remove it if there are no more entries
+        if ubd.entries.is_empty() {
+            self.remove_unbonding_delegation(ctx, &ubd)?;
+        } else {
+            self.set_unbonding_delegation(ctx, &ubd)?;
+        }
+
+        ctx.append_events(vec![
+            Event {
+                r#type: EVENT_TYPE_CANCEL_UNBONDING_DELEGATION.to_string(),
+                attributes: vec![
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_AMOUNT.into(),
+                        value: serde_json::to_string(&msg.amount)
+                            .expect(SERDE_ENCODING_DOMAIN_TYPE)
+                            .into(),
+                        index: false,
+                    },
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_VALIDATOR.into(),
+                        value: msg.validator_address.to_string().into(),
+                        index: false,
+                    },
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_DELEGATOR.into(),
+                        value: msg.delegator_address.to_string().into(),
+                        index: false,
+                    },
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_CREATION_HEIGHT.into(),
+                        value: msg.creation_height.to_string().into(),
+                        index: false,
+                    },
+                ],
+            },
+            Event {
+                r#type: EVENT_TYPE_MESSAGE.to_string(),
+                attributes: vec![
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_MODULE.into(),
+                        value: ATTRIBUTE_VALUE_CATEGORY.into(),
+                        index: false,
+                    },
+                    EventAttribute {
+                        key: ATTRIBUTE_KEY_SENDER.into(),
+                        value: msg.delegator_address.to_string().into(),
+                        index: false,
+                    },
+                ],
+            },
+        ]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        types::gas::GasMeter,
+    };
+
+    use super::*;
+    use crate::{keeper::testing::*, StakingParams, UnbondingDelegationEntry};
+
+    fn entry(creation_height: u32, completion_time: i64, balance: u64) -> UnbondingDelegationEntry {
+        UnbondingDelegationEntry {
+            creation_height,
+            completion_time: Timestamp::try_new(completion_time, 0).unwrap_test(),
+            initial_balance: Uint256::from(balance),
+            balance: Uint256::from(balance),
+        }
+    }
+
+    fn cancel_msg(amount: u64, creation_height: u32) -> CancelUnbondingDelegationMsg {
+        CancelUnbondingDelegationMsg {
+            delegator_address: delegator(),
+            validator_address: validator_address(),
+            amount: UnsignedCoin {
+                denom: StakingParams::default().bond_denom().clone(),
+                amount: Uint256::from(amount),
+            },
+            creation_height,
+        }
+    }
+
+    /// Commits a validator with the given status and an unbonding delegation to it
+    fn setup_ubd(
+        status: BondStatus,
+        entries: Vec<UnbondingDelegationEntry>,
+    ) -> (TestKeeper, ApplicationMultiBank<MemDB, SpaceKey>) {
+        let keeper = keeper();
+        let mut multi_store = multi_store();
+        {
+            let mut ctx = block_ctx(&mut multi_store);
+            setup(&keeper, &mut ctx, &validator(status, 10_000_000));
+            keeper
+                .set_unbonding_delegation(
+                    &mut ctx,
+                    &UnbondingDelegation {
+                        delegator_address: delegator(),
+                        validator_address: validator_address(),
+                        entries,
+                    },
+                )
+                .unwrap_test();
+        }
+        multi_store.commit();
+
+        (keeper, multi_store)
+    }
+
+    /// Runs the cancel message and returns the result with the unbonding delegation and the
+    /// validator after it
+    fn cancel(
+        keeper: &TestKeeper,
+        multi_store: &ApplicationMultiBank<MemDB, SpaceKey>,
+        msg: CancelUnbondingDelegationMsg,
+    ) -> (anyhow::Result<()>, Option<UnbondingDelegation>, Validator) {
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = tx_ctx(&mut tx_store, &mut block_gas_meter);
+
+        let result = keeper.cancel_unbonding_delegation_cmd_handler(&mut ctx, &msg);
+        let ubd = keeper
+            .unbonding_delegation(&ctx, &delegator(), &validator_address())
+            .unwrap_test();
+        let validator = keeper
+            .validator(&ctx, &validator_address())
+            .unwrap_test()
+            .unwrap_test();
+
+        (result, ubd, validator)
+    }
+
+    #[test]
+    fn cancel_part_of_entry() {
+        let (keeper, multi_store) =
+            setup_ubd(BondStatus::Unbonding, vec![entry(5, TIME + 100, 1_000_000)]);
+
+        let (result, ubd, validator) = cancel(&keeper, &multi_store, cancel_msg(400_000, 5));
+
+        result.unwrap_test();
+        assert_eq!(
+            ubd.unwrap_test().entries,
+            vec![entry(5, TIME + 100, 600_000)]
+        );
+        assert_eq!(validator.tokens, Uint256::from(10_400_000u64));
+        // tokens of an unbonding validator stay in the not bonded pool
+        assert!(keeper
+            .bank_keeper
+            .module_transfers
+            .lock()
+            .unwrap_test()
+            .is_empty());
+    }
+
+    #[test]
+    fn cancel_whole_entry_removes_it() {
+        let (keeper, multi_store) = setup_ubd(
+            BondStatus::Unbonding,
+            vec![
+                entry(5, TIME + 100, 1_000_000),
+                entry(6, TIME + 100, 2_000_000),
+            ],
+        );
+
+        let (result, ubd, validator) = cancel(&keeper, &multi_store, cancel_msg(1_000_000, 5));
+
+        result.unwrap_test();
+        assert_eq!(
+            ubd.unwrap_test().entries,
+            vec![entry(6, TIME + 100, 2_000_000)]
+        );
+        assert_eq!(validator.tokens, Uint256::from(11_000_000u64));
+    }
+
+    #[test]
+    fn cancel_last_entry_removes_unbonding_delegation() {
+        let (keeper, multi_store) =
+            setup_ubd(BondStatus::Unbonding, vec![entry(5, TIME + 100, 1_000_000)]);
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = tx_ctx(&mut tx_store, &mut block_gas_meter);
+        keeper
+            .cancel_unbonding_delegation_cmd_handler(&mut ctx, &cancel_msg(1_000_000, 5))
+            .unwrap_test();
+
+        assert_eq!(
+            keeper
+                .unbonding_delegation(&ctx, &delegator(), &validator_address())
+                .unwrap_test(),
+            None
+        );
+        // the index by validator is removed too
+        assert!(keeper
+            .unbonding_delegations_from_validator(&ctx, &validator_address())
+            .unwrap_test()
+            .is_empty());
+    }
+
+    #[test]
+    fn cancel_matured_entry_fails() {
+        let entries = vec![entry(5, TIME, 1_000_000)];
+        let (keeper, multi_store) = setup_ubd(BondStatus::Unbonding, entries.clone());
+
+        let (result, ubd, validator) = cancel(&keeper, &multi_store, cancel_msg(400_000, 5));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unbonding delegation is already processed"
+        );
+        assert_eq!(ubd.unwrap_test().entries, entries);
+        assert_eq!(validator.tokens, Uint256::from(10_000_000u64));
+    }
+
+    #[test]
+    fn cancel_unknown_creation_height_fails() {
+        let entries = vec![entry(5, TIME + 100, 1_000_000)];
+        let (keeper, multi_store) = setup_ubd(BondStatus::Unbonding, entries.clone());
+
+        let (result, ubd, _) = cancel(&keeper, &multi_store, cancel_msg(400_000, 6));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "unbonding delegation entry is not found at block height 6"
+        );
+        assert_eq!(ubd.unwrap_test().entries, entries);
+    }
+
+    #[test]
+    fn cancel_more_than_entry_balance_fails() {
+        let entries = vec![entry(5, TIME + 100, 1_000_000)];
+        let (keeper, multi_store) = setup_ubd(BondStatus::Unbonding, entries.clone());
+
+        let (result, ubd, _) = cancel(&keeper, &multi_store, cancel_msg(1_000_001, 5));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "amount is greater than the unbonding delegation entry balance"
+        );
+        assert_eq!(ubd.unwrap_test().entries, entries);
+    }
+
+    #[test]
+    fn cancel_to_bonded_validator_moves_tokens_to_bonded_pool() {
+        let (keeper, multi_store) =
+            setup_ubd(BondStatus::Bonded, vec![entry(5, TIME + 100, 1_000_000)]);
+
+        let (result, _, validator) = cancel(&keeper, &multi_store, cancel_msg(400_000, 5));
+
+        result.unwrap_test();
+        assert_eq!(validator.tokens, Uint256::from(10_400_000u64));
+        assert_eq!(
+            *keeper.bank_keeper.module_transfers.lock().unwrap_test(),
+            vec![(
+                NOT_BONDED_POOL_NAME.to_string(),
+                BONDED_POOL_NAME.to_string(),
+                bond_coins(400_000)
+            )]
+        );
+    }
 }
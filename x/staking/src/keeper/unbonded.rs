@@ -26,7 +26,7 @@ impl<
         };
 
         // call the before-delegation-modified hook
-        self.before_delegation_shares_modified(ctx, del_addr, val_addr);
+        self.before_delegation_shares_modified(ctx, del_addr, val_addr)?;
 
         // ensure that we have enough shares to remove
         if delegation.shares < shares {
@@ -63,11 +63,12 @@ impl<
 
         // remove the delegation
         if delegation.shares.is_zero() {
+            self.before_delegation_removed(ctx, del_addr, &delegation.validator_address)?;
             self.remove_delegation(ctx, &delegation)?;
         } else {
             self.set_delegation(ctx, &delegation)?;
             // call the after delegation modification hook
-            self.after_delegation_modified(ctx, del_addr, &delegation.validator_address);
+            self.after_delegation_modified(ctx, del_addr, &delegation.validator_address)?;
         }
 
         // remove the shares and coins from the validator
@@ -76,6 +77,13 @@ impl<
         if validator.delegator_shares.is_zero() && validator.status == BondStatus::Unbonded {
             // if not unbonded, we must instead remove validator in EndBlocker once it finishes its unbonding period
             self.remove_validator(ctx, &validator)?;
+            // the hook is called by `RemoveValidator` in the SDK, which logs its error
+            // instead of failing the unbond of the last delegation of the validator
+            match self.after_validator_removed(ctx, &validator) {
+                Err(StakingHooksError::GasError(e)) => return Err(e.into()),
+                Err(e) => tracing::error!("error in after validator removed hook: {e}"),
+                Ok(()) => (),
+            }
         }
         Ok(tokens_amount)
     }
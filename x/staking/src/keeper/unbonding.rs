@@ -74,14 +74,14 @@ impl<
         Ok(ubd)
     }
 
-    pub fn remove_unbonding_delegation<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+    pub fn remove_unbonding_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
         delegation: &UnbondingDelegation,
-    ) -> Option<Vec<u8>> {
-        let mut store = InfallibleContextMut::infallible_store_mut(ctx, &self.store_key);
+    ) -> Result<Option<Vec<u8>>, GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
         let key = get_ubd_key(&delegation.delegator_address, &delegation.validator_address);
-        store.delete(&key);
+        store.delete(&key)?;
 
         let key =
             get_ubd_by_val_index_key(&delegation.delegator_address, &delegation.validator_address);
@@ -197,21 +197,6 @@ impl<
         store.set(get_unbonding_delegation_time_key(time), bz)
     }
 
-    pub fn after_validator_begin_unbonding<DB: Database, CTX: TransactionalContext<DB, SK>>(
-        &self,
-        ctx: &mut CTX,
-        validator: &Validator,
-    ) -> anyhow::Result<()> {
-        if let Some(ref hooks) = self.hooks_keeper {
-            hooks.after_validator_begin_unbonding(
-                ctx,
-                validator.cons_addr(),
-                validator.operator_address.clone(),
-            );
-        }
-        Ok(())
-    }
-
     pub fn unbond_all_mature_validators<DB: Database>(
         &self,
         ctx: &mut BlockContext<'_, DB, SK>,
@@ -262,6 +247,14 @@ impl<
                     self.unbonding_to_unbonded(ctx, &mut validator).unwrap_gas();
                     if validator.delegator_shares.is_zero() {
                         self.remove_validator(ctx, &validator)?;
+                        // an error can't be returned from the end block without halting the
+                        // chain and the validator is already removed, so as in the SDK the
+                        // error of the hook is only logged
+                        match self.after_validator_removed(ctx, &validator) {
+                            Err(StakingHooksError::GasError(e)) => return Err(e),
+                            Err(e) => tracing::error!("error in after validator removed hook: {e}"),
+                            Ok(()) => (),
+                        }
                     }
                 }
 
@@ -346,7 +339,7 @@ impl<
 
         // set the unbonding delegation or remove it if there are no more entries
         if new_ubd.is_empty() {
-            self.remove_unbonding_delegation(ctx, &ubd);
+            self.remove_unbonding_delegation(ctx, &ubd)?;
         } else {
             self.set_unbonding_delegation(ctx, &ubd)?;
         }
@@ -62,6 +62,22 @@ impl<
         Ok(())
     }
 
+    pub fn unjail_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &mut Validator,
+    ) -> Result<(), GasStoreErrors> {
+        assert!(
+            validator.jailed,
+            "cannot unjail already unjailed validator, validator: {}",
+            validator.operator_address
+        );
+        validator.jailed = false;
+        self.set_validator(ctx, validator)?;
+        self.set_validator_by_power_index(ctx, validator)?;
+        Ok(())
+    }
+
     /// create_updated_validator_commission attempts to create a validator's commission rate.
     /// An error is returned if the new commission rate is invalid.
     pub fn create_updated_validator_commission<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -140,4 +156,17 @@ impl<
         self.set_validator_by_power_index(ctx, validator)?;
         Ok(removed_tokens)
     }
+
+    /// Update the tokens of an existing validator, update the validators power index key
+    pub fn remove_validator_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &mut Validator,
+        tokens_to_remove: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.delete_validator_by_power_index(ctx, validator)?;
+        validator.remove_tokens(tokens_to_remove);
+        self.set_validator(ctx, validator)?;
+        self.set_validator_by_power_index(ctx, validator)
+    }
 }
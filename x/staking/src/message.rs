@@ -1,5 +1,15 @@
-use crate::{CreateValidator, DelegateMsg, EditValidator, RedelegateMsg, UndelegateMsg};
-use gears::derive::AppMessage;
+use crate::{
+    CancelUnbondingDelegationMsg, CreateValidator, DelegateMsg, EditValidator, RedelegateMsg,
+    UndelegateMsg,
+};
+use gears::{
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{RenderError, ValueRenderer},
+    },
+    types::rendering::screen::Screen,
+};
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize, AppMessage)]
@@ -21,4 +31,20 @@ pub enum Message {
     #[serde(rename = "/cosmos.staking.v1beta1.MsgUndelegate")]
     #[msg(url(path = UndelegateMsg::TYPE_URL))]
     Undelegate(UndelegateMsg),
+    #[serde(rename = "/cosmos.staking.v1beta1.MsgCancelUnbondingDelegation")]
+    #[msg(url(path = CancelUnbondingDelegationMsg::TYPE_URL))]
+    CancelUnbondingDelegation(CancelUnbondingDelegationMsg),
+}
+
+impl ValueRenderer for Message {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        match self {
+            Message::CreateValidator(msg) => msg.format(get_metadata),
+            Message::CancelUnbondingDelegation(msg) => msg.format(get_metadata),
+            Message::EditValidator(_)
+            | Message::Delegate(_)
+            | Message::Redelegate(_)
+            | Message::Undelegate(_) => Err(RenderError::NotImplemented),
+        }
+    }
 }
@@ -1,2 +1,3 @@
 pub mod bounded;
 pub mod delegation;
+pub mod validators;
@@ -0,0 +1,30 @@
+use std::borrow::Cow;
+
+use gears::{
+    core::Protobuf,
+    extensions::corruption::UnwrapCorrupt,
+    store::database::Database,
+    types::store::{gas::errors::GasStoreErrors, kv::Store, range::StoreRange},
+};
+
+use crate::{consts::keeper::VALIDATORS_KEY, Validator};
+
+/// Iterates over all validators ordered by operator address
+#[derive(Debug)]
+pub struct ValidatorsIterator<'a, DB>(StoreRange<'a, DB>);
+
+impl<'a, DB: Database> ValidatorsIterator<'a, DB> {
+    pub fn new(store: Store<'a, DB>) -> ValidatorsIterator<'a, DB> {
+        ValidatorsIterator(store.prefix_store(VALIDATORS_KEY).into_range(..))
+    }
+}
+
+impl<'a, DB: Database> Iterator for ValidatorsIterator<'a, DB> {
+    type Item = Result<(Cow<'a, Vec<u8>>, Validator), GasStoreErrors>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|var| {
+            var.map(|(key, value)| (key, Validator::decode_vec(&value).unwrap_or_corrupt()))
+        })
+    }
+}
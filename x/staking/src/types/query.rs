@@ -1,6 +1,6 @@
 use crate::{
-    consts::error::SERDE_ENCODING_DOMAIN_TYPE, Delegation, IbcV046Validator, Pool, Redelegation,
-    RedelegationEntry, StakingParams, UnbondingDelegation,
+    consts::error::SERDE_ENCODING_DOMAIN_TYPE, Delegation, HistoricalInfo, IbcV046Validator, Pool,
+    Redelegation, RedelegationEntry, StakingParams, UnbondingDelegation,
 };
 use gears::{
     core::{errors::CoreError, Protobuf},
@@ -19,6 +19,7 @@ use serde::{Deserialize, Serialize};
 
 mod inner {
     pub use ibc_proto::cosmos::staking::v1beta1::DelegationResponse;
+    pub use ibc_proto::cosmos::staking::v1beta1::QueryHistoricalInfoRequest;
     pub use ibc_proto::cosmos::staking::v1beta1::{
         QueryDelegationRequest, QueryDelegationResponse,
     };
@@ -28,15 +29,29 @@ mod inner {
     pub use ibc_proto::cosmos::staking::v1beta1::{
         QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
     };
+    pub use ibc_proto::cosmos::staking::v1beta1::{
+        QueryDelegatorValidatorRequest, QueryDelegatorValidatorResponse,
+    };
+    pub use ibc_proto::cosmos::staking::v1beta1::{
+        QueryDelegatorValidatorsRequest, QueryDelegatorValidatorsResponse,
+    };
     pub use ibc_proto::cosmos::staking::v1beta1::{QueryParamsRequest, QueryParamsResponse};
     pub use ibc_proto::cosmos::staking::v1beta1::{QueryPoolRequest, QueryPoolResponse};
     pub use ibc_proto::cosmos::staking::v1beta1::{
         QueryUnbondingDelegationRequest, QueryUnbondingDelegationResponse,
     };
+    pub use ibc_proto::cosmos::staking::v1beta1::{
+        QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse,
+    };
     pub use ibc_proto::cosmos::staking::v1beta1::{QueryValidatorRequest, QueryValidatorResponse};
+    pub use ibc_proto::cosmos::staking::v1beta1::{
+        QueryValidatorUnbondingDelegationsRequest, QueryValidatorUnbondingDelegationsResponse,
+    };
     pub use ibc_proto::cosmos::staking::v1beta1::{
         QueryValidatorsRequest, QueryValidatorsResponse,
     };
+
+    pub use gears::tendermint::types::proto::header::HistoricalInfo;
 }
 
 // ===
@@ -113,9 +128,70 @@ pub struct QueryDelegatorUnbondingDelegationsRequest {
     pub pagination: Option<PaginationRequest>,
 }
 
-/// QueryRedelegationRequest is request type for the Query/Redelegation RPC method.
+/// QueryValidatorDelegationsRequest is request type for the
+/// Query/ValidatorDelegations RPC method.
+#[derive(Clone, Debug, PartialEq, Query, Protobuf)]
+#[query(url = "/cosmos.staking.v1beta1.Query/ValidatorDelegations")]
+#[proto(raw = "inner::QueryValidatorDelegationsRequest")]
+pub struct QueryValidatorDelegationsRequest {
+    /// validator_addr defines the validator address to query for.
+    pub validator_addr: ValAddress,
+    /// pagination defines an optional pagination for the request.
+    #[proto(optional)]
+    pub pagination: Option<PaginationRequest>,
+}
+
+/// QueryValidatorUnbondingDelegationsRequest is required type for the
+/// Query/ValidatorUnbondingDelegations RPC method.
+#[derive(Clone, Debug, PartialEq, Query, Protobuf)]
+#[query(url = "/cosmos.staking.v1beta1.Query/ValidatorUnbondingDelegations")]
+#[proto(raw = "inner::QueryValidatorUnbondingDelegationsRequest")]
+pub struct QueryValidatorUnbondingDelegationsRequest {
+    /// validator_addr defines the validator address to query for.
+    pub validator_addr: ValAddress,
+    /// pagination defines an optional pagination for the request.
+    #[proto(optional)]
+    pub pagination: Option<PaginationRequest>,
+}
+
+/// QueryDelegatorValidatorsRequest is request type for the
+/// Query/DelegatorValidators RPC method.
+#[derive(Clone, Debug, PartialEq, Query, Protobuf)]
+#[query(url = "/cosmos.staking.v1beta1.Query/DelegatorValidators")]
+#[proto(raw = "inner::QueryDelegatorValidatorsRequest")]
+pub struct QueryDelegatorValidatorsRequest {
+    /// delegator_addr defines the delegator address to query for.
+    pub delegator_addr: AccAddress,
+    /// pagination defines an optional pagination for the request.
+    #[proto(optional)]
+    pub pagination: Option<PaginationRequest>,
+}
+
+/// QueryDelegatorValidatorRequest is request type for the
+/// Query/DelegatorValidator RPC method.
+#[derive(Clone, Debug, PartialEq, Query, Protobuf)]
+#[query(url = "/cosmos.staking.v1beta1.Query/DelegatorValidator")]
+#[proto(raw = "inner::QueryDelegatorValidatorRequest")]
+pub struct QueryDelegatorValidatorRequest {
+    /// delegator_addr defines the delegator address to query for.
+    pub delegator_addr: AccAddress,
+    /// validator_addr defines the validator address to query for.
+    pub validator_addr: ValAddress,
+}
+
+/// QueryHistoricalInfoRequest is request type for the Query/HistoricalInfo RPC
+/// method.
+#[derive(Clone, Debug, PartialEq, Query, Protobuf)]
+#[query(url = "/cosmos.staking.v1beta1.Query/HistoricalInfo")]
+#[proto(raw = "inner::QueryHistoricalInfoRequest")]
+pub struct QueryHistoricalInfoRequest {
+    /// height defines at which height to query the historical info.
+    pub height: i64,
+}
+
+/// QueryRedelegationRequest is request type for the Query/Redelegations RPC method.
 #[derive(Clone, Debug, PartialEq, Query, Raw, Protobuf)]
-#[query(url = "/cosmos.staking.v1beta1.Query/Redelegation")]
+#[query(url = "/cosmos.staking.v1beta1.Query/Redelegations")]
 pub struct QueryRedelegationRequest {
     /// delegator_addr defines the delegator address to query for.
     #[raw(kind(string), raw = String, optional)]
@@ -224,6 +300,63 @@ pub struct QueryDelegatorUnbondingDelegationsResponse {
     pub pagination: Option<PaginationResponse>,
 }
 
+/// QueryValidatorDelegationsResponse is response type for the
+/// Query/ValidatorDelegations RPC method
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QueryValidatorDelegationsResponse")]
+pub struct QueryValidatorDelegationsResponse {
+    #[proto(repeated)]
+    pub delegation_responses: Vec<DelegationResponse>,
+    /// pagination defines the pagination in the response.
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
+
+/// QueryValidatorUnbondingDelegationsResponse is response type for the
+/// Query/ValidatorUnbondingDelegations RPC method.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QueryValidatorUnbondingDelegationsResponse")]
+pub struct QueryValidatorUnbondingDelegationsResponse {
+    #[proto(repeated)]
+    pub unbonding_responses: Vec<UnbondingDelegation>,
+    /// pagination defines the pagination in the response.
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
+
+/// QueryDelegatorValidatorsResponse is response type for the
+/// Query/DelegatorValidators RPC method.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QueryDelegatorValidatorsResponse")]
+pub struct QueryDelegatorValidatorsResponse {
+    /// validators defines the validators' info of a delegator.
+    #[proto(repeated)]
+    pub validators: Vec<IbcV046Validator>,
+    /// pagination defines the pagination in the response.
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
+
+/// QueryDelegatorValidatorResponse response type for the
+/// Query/DelegatorValidator RPC method.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QueryDelegatorValidatorResponse")]
+pub struct QueryDelegatorValidatorResponse {
+    /// validator defines the validator info.
+    #[proto(optional)]
+    pub validator: Option<IbcV046Validator>,
+}
+
+/// QueryHistoricalInfoResponse is response type for the Query/HistoricalInfo RPC
+/// method.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Query, Raw, Protobuf)]
+pub struct QueryHistoricalInfoResponse {
+    /// hist defines the historical info at the given height.
+    #[raw(kind(message), raw = inner::HistoricalInfo, optional)]
+    #[proto(optional)]
+    pub hist: Option<HistoricalInfo>,
+}
+
 /// RedelegationEntryResponse is equivalent to a RedelegationEntry except that it
 /// contains a balance in addition to shares which is more suitable for client
 /// responses.
@@ -301,7 +434,7 @@ impl From<RedelegationResponse> for RawRedelegationResponse {
 
 impl Protobuf<RawRedelegationResponse> for RedelegationResponse {}
 
-/// QueryRedelegationResponse is the response type for the Query/Redelegation RPC method.
+/// QueryRedelegationResponse is the response type for the Query/Redelegations RPC method.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Query, Raw, Protobuf)]
 pub struct QueryRedelegationResponse {
     /// Redelegation with balance
@@ -2,7 +2,13 @@ use crate::consts::proto::*;
 use gears::{
     core::{errors::CoreError, Protobuf},
     derive::{AppMessage, Protobuf},
-    signing::renderer::value_renderer::ValueRenderer,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
     tendermint::types::{proto::crypto::PublicKey, time::timestamp::Timestamp},
     types::{
         address::{AccAddress, ValAddress},
@@ -10,6 +16,7 @@ use gears::{
         base::coin::UnsignedCoin,
         decimal256::{CosmosDecimalProtoString, Decimal256, ONE_DEC},
         errors::StdError,
+        rendering::screen::{Indent, Screen},
         uint::Uint256,
     },
 };
@@ -382,6 +389,7 @@ impl TryFrom<inner::MsgCreateValidator> for CreateValidator {
             .ok_or(CoreError::MissingField("commission".into()))?;
         let pubkey = val.pubkey.ok_or(CoreError::MissingField("pubkey".into()))?;
         let pubkey = gears::crypto::public::PublicKey::try_from(pubkey)
+            .and_then(|key| key.try_into())
             .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?;
 
         let delegator_address = AccAddress::from_bech32(&val.delegator_address)
@@ -418,7 +426,7 @@ impl TryFrom<inner::MsgCreateValidator> for CreateValidator {
                 .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
             validator_address: ValAddress::from_bech32(&val.validator_address)
                 .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
-            pubkey: pubkey.into(),
+            pubkey,
             value: val
                 .value
                 .ok_or(CoreError::MissingField("value".into()))?
@@ -718,3 +726,105 @@ impl TryFrom<UndelegateMsgRaw> for UndelegateMsg {
 }
 
 impl Protobuf<UndelegateMsgRaw> for UndelegateMsg {}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Message)]
+pub struct CancelUnbondingDelegationMsgRaw {
+    #[prost(string)]
+    pub delegator_address: String,
+    #[prost(string)]
+    pub validator_address: String,
+    #[prost(message, optional)]
+    pub amount: Option<CoinRaw>,
+    #[prost(int64)]
+    pub creation_height: i64,
+}
+
+impl From<CancelUnbondingDelegationMsg> for CancelUnbondingDelegationMsgRaw {
+    fn from(src: CancelUnbondingDelegationMsg) -> Self {
+        Self {
+            delegator_address: src.delegator_address.to_string(),
+            validator_address: src.validator_address.to_string(),
+            amount: Some(src.amount.into()),
+            creation_height: src.creation_height.into(),
+        }
+    }
+}
+
+/// CancelUnbondingDelegationMsg defines the SDK message for performing a cancel unbonding
+/// delegation for delegator. The canceled amount is delegated back to the validator.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(url = "/cosmos.staking.v1beta1.MsgCancelUnbondingDelegation")]
+pub struct CancelUnbondingDelegationMsg {
+    #[msg(signer)]
+    pub delegator_address: AccAddress,
+    pub validator_address: ValAddress,
+    /// amount is always less than or equal to unbonding delegation entry balance
+    pub amount: UnsignedCoin,
+    /// creation_height is the height which the unbonding took place
+    pub creation_height: u32,
+}
+
+impl TryFrom<CancelUnbondingDelegationMsgRaw> for CancelUnbondingDelegationMsg {
+    type Error = CoreError;
+
+    fn try_from(src: CancelUnbondingDelegationMsgRaw) -> Result<Self, Self::Error> {
+        let creation_height = u32::try_from(src.creation_height)
+            .ok()
+            .filter(|height| *height > 0)
+            .ok_or(CoreError::DecodeGeneral(format!(
+                "invalid height: {}",
+                src.creation_height
+            )))?;
+
+        Ok(CancelUnbondingDelegationMsg {
+            delegator_address: AccAddress::from_bech32(&src.delegator_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            validator_address: ValAddress::from_bech32(&src.validator_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            amount: src
+                .amount
+                .ok_or(CoreError::MissingField("Missing field 'amount'.".into()))?
+                .try_into()
+                .map_err(|e| CoreError::Coin(format!("{e}")))?,
+            creation_height,
+        })
+    }
+}
+
+impl Protobuf<CancelUnbondingDelegationMsgRaw> for CancelUnbondingDelegationMsg {}
+
+impl ValueRenderer for CancelUnbondingDelegationMsg {
+    /// Format `CancelUnbondingDelegationMsg`
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Delegator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.delegator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Validator address".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.validator_address.clone()),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Amount".to_string(),
+                content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                    self.amount.clone(),
+                    get_metadata,
+                )?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Creation height".to_string(),
+                content: DefaultPrimitiveRenderer::format(self.creation_height),
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ])
+    }
+}
@@ -246,6 +246,15 @@ impl Validator {
         Ok(issued_tokens)
     }
 
+    /// remove_tokens removes tokens from a validator, delegator shares are left untouched
+    /// so the tokens worth of each share decreases
+    pub fn remove_tokens(&mut self, tokens: Uint256) {
+        self.tokens = self
+            .tokens
+            .checked_sub(tokens)
+            .expect("should not remove more tokens than the validator has");
+    }
+
     pub fn invalid_ex_rate(&self) -> bool {
         self.tokens.is_zero() && (self.delegator_shares > Decimal256::zero())
     }
@@ -379,6 +388,7 @@ impl TryFrom<inner::Validator> for Validator {
             "Missing field 'consensus_pubkey'.".into(),
         ))?;
         let consensus_pubkey = gears::crypto::public::PublicKey::try_from(consensus_pubkey)
+            .and_then(|key| key.try_into())
             .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?;
 
         let commission = value.commission.ok_or(CoreError::MissingField(
@@ -421,7 +431,7 @@ impl TryFrom<inner::Validator> for Validator {
                 security_contact: description.security_contact,
                 details: description.details,
             },
-            consensus_pubkey: consensus_pubkey.into(),
+            consensus_pubkey,
             jailed: value.jailed,
             tokens: Uint256::from_str(&value.tokens)
                 .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?,
@@ -880,4 +890,56 @@ mod tests {
         let res = val.tokens_to_consensus_power(1000000);
         assert_eq!(res, 1099511627776);
     }
+
+    #[test]
+    fn test_remove_tokens() {
+        let val_raw = r#"
+{
+    "operator_address": "cosmosvaloper1v0thzgvzp8vt6q7ystmfm7a9wvg0ppsfetur3d",
+    "consensus_pubkey": {
+        "type": "tendermint/PubKeyEd25519",
+        "value": "CiBO6qrfEwEg7eOTlqlaSKRjd+GoFQOxFhp3cRblbJyBdA=="
+    },
+    "jailed": false,
+    "status": "BOND_STATUS_BONDED",
+    "tokens": "100",
+    "delegator_shares": "100.000000000000000000",
+    "description": {
+        "moniker": "",
+        "identity": "",
+        "website": "",
+        "security_contact": "",
+        "details": ""
+    },
+    "unbonding_height": "0",
+    "unbonding_time": "1970-01-01T00:00:00Z",
+    "commission": {
+        "commission_rates": {
+            "rate": "0.000000000000000000",
+            "max_rate": "0.000000000000000000",
+            "max_change_rate": "0.000000000000000000"
+        },
+        "update_time": "1970-01-01T00:00:00Z"
+    },
+    "min_self_delegation": "1"
+}
+          "#;
+
+        let mut val: Validator = serde_json::from_str(val_raw).unwrap_test();
+
+        val.remove_tokens(Uint256::from(10u64));
+        assert_eq!(val.tokens, Uint256::from(90u64));
+        assert_eq!(
+            val.delegator_shares,
+            Decimal256::from_atomics(100u64, 0).unwrap_test()
+        );
+        assert_eq!(
+            val.tokens_from_shares(Decimal256::from_atomics(10u64, 0).unwrap_test())
+                .unwrap_test(),
+            Decimal256::from_atomics(9u64, 0).unwrap_test()
+        );
+
+        val.remove_tokens(Uint256::from(90u64));
+        assert!(val.invalid_ex_rate());
+    }
 }
@@ -0,0 +1,67 @@
+use gears::{
+    context::{block::BlockContext, init::InitContext, QueryableContext},
+    extensions::gas::GasResultExt,
+    store::{database::Database, StoreKey},
+};
+use tracing::{error, info};
+
+use crate::{Keeper, UpgradeHandler};
+
+#[derive(Debug, Clone)]
+pub struct ABCIHandler<SK: StoreKey, UH: UpgradeHandler<SK>> {
+    keeper: Keeper<SK, UH>,
+}
+
+impl<SK: StoreKey, UH: UpgradeHandler<SK>> ABCIHandler<SK, UH> {
+    pub fn new(keeper: Keeper<SK, UH>) -> Self {
+        ABCIHandler { keeper }
+    }
+
+    pub fn init_genesis<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>) {
+        self.keeper.init_genesis(ctx)
+    }
+
+    /// begin_block applies the scheduled upgrade once the chain reaches the height of the plan.
+    /// The chain halts if the binary has no handler for the upgrade, so that the node operators
+    /// can replace it with the binary which knows about the upgrade.
+    pub fn begin_block<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) {
+        let plan = match self.keeper.upgrade_plan(ctx).unwrap_gas() {
+            Some(plan) => plan,
+            None => return,
+        };
+
+        if plan.should_execute(ctx.height()) {
+            if !self.keeper.has_upgrade_handler(&plan.name) {
+                // we don't have an upgrade handler for this upgrade name, meaning this software
+                // is out of date so shutdown
+                let msg = format!(
+                    "UPGRADE \"{}\" NEEDED at height: {}: {}",
+                    plan.name,
+                    ctx.height(),
+                    plan.info
+                );
+                error!("{msg}");
+                panic!("{msg}");
+            }
+
+            info!(
+                "applying upgrade \"{}\" at height: {}",
+                plan.name,
+                ctx.height()
+            );
+
+            if let Err(e) = self.keeper.apply_upgrade(ctx, plan) {
+                panic!("Error thrown in begin_block method: \n{e}");
+            }
+        } else if self.keeper.has_upgrade_handler(&plan.name) {
+            // the handler of the pending upgrade is already registered, so the binary was
+            // replaced before the upgrade height
+            let msg = format!(
+                "BINARY UPDATED BEFORE TRIGGER! UPGRADE \"{}\" - in binary but not executed on chain",
+                plan.name
+            );
+            error!("{msg}");
+            panic!("{msg}");
+        }
+    }
+}
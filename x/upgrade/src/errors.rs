@@ -0,0 +1,17 @@
+use gears::types::store::gas::errors::GasStoreErrors;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpgradeError {
+    #[error("upgrade cannot be scheduled in the past: plan height {plan} is not greater than current height {current}")]
+    PastHeight { plan: u32, current: u32 },
+    #[error("upgrade with name {0} has already been completed")]
+    AlreadyCompleted(String),
+    #[error("upgrade {name} failed: {source}")]
+    Handler {
+        name: String,
+        #[source]
+        source: anyhow::Error,
+    },
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+}
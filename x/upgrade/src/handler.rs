@@ -0,0 +1,21 @@
+use gears::{
+    context::block::BlockContext,
+    store::{database::Database, StoreKey},
+};
+
+use crate::{ModuleVersionMap, Plan};
+
+/// UpgradeHandler applies a software upgrade once the chain reaches the height of its plan.
+/// A new binary registers a handler under the name of each upgrade it performs, which is
+/// the place to migrate the state to the layout expected by the new binary.
+///
+/// The handler receives the consensus versions of the modules before the upgrade and returns
+/// the versions after it, usually with `Migrator::run_migrations`.
+pub trait UpgradeHandler<SK: StoreKey>: Clone + Send + Sync + 'static {
+    fn handle<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, SK>,
+        plan: &Plan,
+        from: &ModuleVersionMap,
+    ) -> anyhow::Result<ModuleVersionMap>;
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use gears::{
+    context::{block::BlockContext, InfallibleContextMut, QueryableContext, TransactionalContext},
+    core::Protobuf,
+    extensions::{corruption::UnwrapCorrupt, gas::GasResultExt},
+    store::{database::Database, StoreKey},
+    tendermint::types::proto::event::{Event, EventAttribute},
+    types::store::gas::errors::GasStoreErrors,
+};
+
+use crate::{errors::UpgradeError, ModuleVersionMap, Plan, UpgradeHandler};
+
+/// PLAN_KEY is the key under which the current plan is saved
+pub(crate) const PLAN_KEY: [u8; 1] = [0x0];
+/// DONE_KEY_PREFIX is a prefix for storing the heights of the completed upgrades
+pub(crate) const DONE_KEY_PREFIX: [u8; 1] = [0x1];
+/// VERSION_MAP_PREFIX is a prefix for storing the consensus versions of the modules
+pub(crate) const VERSION_MAP_PREFIX: [u8; 1] = [0x2];
+
+/// Keeper of the upgrade store
+#[derive(Debug, Clone)]
+pub struct Keeper<SK: StoreKey, UH: UpgradeHandler<SK>> {
+    store_key: SK,
+    module_versions: ModuleVersionMap,
+    upgrade_handlers: HashMap<String, UH>,
+}
+
+impl<SK: StoreKey, UH: UpgradeHandler<SK>> Keeper<SK, UH> {
+    /// `module_versions` are the consensus versions of the modules of the binary
+    pub fn new(
+        store_key: SK,
+        module_versions: ModuleVersionMap,
+        upgrade_handlers: HashMap<String, UH>,
+    ) -> Self {
+        Self {
+            store_key,
+            module_versions,
+            upgrade_handlers,
+        }
+    }
+
+    /// init_genesis saves the consensus versions of the modules of the binary
+    pub fn init_genesis<DB: Database, CTX: InfallibleContextMut<DB, SK>>(&self, ctx: &mut CTX) {
+        self.set_module_versions(ctx, &self.module_versions)
+            .unwrap_gas();
+    }
+
+    /// has_upgrade_handler returns true if the binary has a handler for the upgrade with given name
+    pub fn has_upgrade_handler(&self, name: &str) -> bool {
+        self.upgrade_handlers.contains_key(name)
+    }
+
+    /// upgrade_plan returns the currently scheduled plan, if any
+    pub fn upgrade_plan<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Option<Plan>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        Ok(store
+            .get(&PLAN_KEY)?
+            .map(|bytes| Plan::decode_vec(&bytes).unwrap_or_corrupt()))
+    }
+
+    /// schedule_upgrade schedules an upgrade based on the specified plan.
+    /// If there is another plan already scheduled, it will overwrite it
+    /// (the latest plan wins).
+    pub fn schedule_upgrade<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        plan: Plan,
+    ) -> Result<(), UpgradeError> {
+        if plan.height <= ctx.height() {
+            return Err(UpgradeError::PastHeight {
+                plan: plan.height,
+                current: ctx.height(),
+            });
+        }
+
+        if self.done_height(ctx, &plan.name)?.is_some() {
+            return Err(UpgradeError::AlreadyCompleted(plan.name));
+        }
+
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.set(PLAN_KEY, plan.encode_vec())?;
+
+        Ok(())
+    }
+
+    /// clear_upgrade_plan clears any schedule upgrade
+    pub fn clear_upgrade_plan<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+    ) -> Result<(), GasStoreErrors> {
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.delete(&PLAN_KEY)?;
+
+        Ok(())
+    }
+
+    /// done_height returns the height at which the given upgrade was executed
+    pub fn done_height<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        name: &str,
+    ) -> Result<Option<u32>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        Ok(store.get(&done_key(name))?.map(|bytes| {
+            let height = u64::from_be_bytes(<[u8; 8]>::try_from(bytes).unwrap_or_corrupt());
+            u32::try_from(height).unwrap_or_corrupt()
+        }))
+    }
+
+    /// module_versions returns the consensus versions of the modules saved in the store
+    pub fn module_versions<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<ModuleVersionMap, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        let store = store.prefix_store(VERSION_MAP_PREFIX);
+
+        let mut versions = ModuleVersionMap::new();
+        for item in store.into_range(..) {
+            let (module, version) = item?;
+            let module = String::from_utf8(module.into_owned()).unwrap_or_corrupt();
+            let version =
+                u64::from_be_bytes(<[u8; 8]>::try_from(version.into_owned()).unwrap_or_corrupt());
+            versions.insert(module, version);
+        }
+
+        Ok(versions)
+    }
+
+    fn set_module_versions<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        versions: &ModuleVersionMap,
+    ) -> Result<(), GasStoreErrors> {
+        let store = ctx.kv_store_mut(&self.store_key);
+        let mut store = store.prefix_store_mut(VERSION_MAP_PREFIX);
+        for (module, version) in versions {
+            store.set(module.as_bytes().to_vec(), version.to_be_bytes())?;
+        }
+
+        Ok(())
+    }
+
+    fn set_done<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        name: &str,
+    ) -> Result<(), GasStoreErrors> {
+        let height = u64::from(ctx.height());
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.set(done_key(name), height.to_be_bytes())
+    }
+
+    /// apply_upgrade runs the upgrade handler of the plan, then clears the plan and marks
+    /// the upgrade as done
+    pub fn apply_upgrade<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, SK>,
+        plan: Plan,
+    ) -> Result<(), UpgradeError> {
+        let handler =
+            self.upgrade_handlers
+                .get(&plan.name)
+                .ok_or_else(|| UpgradeError::Handler {
+                    name: plan.name.clone(),
+                    source: anyhow::anyhow!("no upgrade handler registered"),
+                })?;
+
+        let from = self.module_versions(ctx)?;
+        let versions =
+            handler
+                .handle(ctx, &plan, &from)
+                .map_err(|source| UpgradeError::Handler {
+                    name: plan.name.clone(),
+                    source,
+                })?;
+
+        self.set_module_versions(ctx, &versions)?;
+        self.clear_upgrade_plan(ctx)?;
+        self.set_done(ctx, &plan.name)?;
+
+        ctx.push_event(Event {
+            r#type: "upgrade".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "name".into(),
+                    value: plan.name.into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "height".into(),
+                    value: plan.height.to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        Ok(())
+    }
+}
+
+fn done_key(name: &str) -> Vec<u8> {
+    [DONE_KEY_PREFIX.as_slice(), name.as_bytes()].concat()
+}
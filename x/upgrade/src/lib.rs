@@ -0,0 +1,13 @@
+mod abci_handler;
+pub mod errors;
+mod handler;
+mod keeper;
+mod migration;
+pub mod submission;
+mod types;
+
+pub use abci_handler::*;
+pub use handler::*;
+pub use keeper::*;
+pub use migration::*;
+pub use types::*;
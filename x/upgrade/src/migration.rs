@@ -0,0 +1,223 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+};
+
+use anyhow::anyhow;
+use gears::{
+    context::block::BlockContext,
+    store::{database::Database, StoreKey},
+};
+
+/// ModuleVersionMap maps the names of the modules to their consensus versions
+pub type ModuleVersionMap = BTreeMap<String, u64>;
+
+/// Migration moves the state of a module from one consensus version to the next one
+pub trait Migration<SK: StoreKey>: Clone + Send + Sync + 'static {
+    fn migrate<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) -> anyhow::Result<()>;
+}
+
+/// Migrator holds the consensus versions of the modules of the binary together with the
+/// migrations which bring the older versions of the modules up to date
+#[derive(Debug, Clone)]
+pub struct Migrator<SK: StoreKey, MG: Migration<SK>> {
+    versions: ModuleVersionMap,
+    migrations: HashMap<(String, u64), MG>,
+    _marker: PhantomData<SK>,
+}
+
+impl<SK: StoreKey, MG: Migration<SK>> Migrator<SK, MG> {
+    pub fn new(versions: ModuleVersionMap) -> Self {
+        Self {
+            versions,
+            migrations: HashMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// register_migration registers the migration of the module from `from_version` to the
+    /// next version
+    pub fn register_migration(
+        mut self,
+        module: impl Into<String>,
+        from_version: u64,
+        migration: MG,
+    ) -> Self {
+        self.migrations
+            .insert((module.into(), from_version), migration);
+        self
+    }
+
+    /// versions returns the consensus versions of the modules of the binary
+    pub fn versions(&self) -> &ModuleVersionMap {
+        &self.versions
+    }
+
+    /// run_migrations runs the migrations of every module whose version in `from` is older
+    /// than the version of the binary, in order of the versions. Modules which are missing
+    /// from `from` are new and start at the version of the binary. Returns the versions of
+    /// the modules after the migrations.
+    pub fn run_migrations<DB: Database>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, SK>,
+        from: &ModuleVersionMap,
+    ) -> anyhow::Result<ModuleVersionMap> {
+        for (module, &to_version) in &self.versions {
+            let from_version = match from.get(module) {
+                Some(&from_version) => from_version,
+                None => continue,
+            };
+
+            if from_version > to_version {
+                return Err(anyhow!(
+                    "module {module} is at version {from_version}, which is newer than version {to_version} of the binary"
+                ));
+            }
+
+            for version in from_version..to_version {
+                let Some(migration) = self.migrations.get(&(module.clone(), version)) else {
+                    return Err(anyhow!(
+                        "no migration registered for module {module} from version {version}"
+                    ));
+                };
+
+                migration.migrate(ctx)?;
+            }
+        }
+
+        Ok(self.versions.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::ConsensusParams,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::proto::header::Header,
+    };
+    use strum::EnumIter;
+
+    use super::*;
+
+    const STEPS_KEY: [u8; 1] = [0];
+
+    #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+    enum TestStoreKey {
+        Upgrade,
+    }
+
+    impl StoreKey for TestStoreKey {
+        fn name(&self) -> &'static str {
+            "upgrade"
+        }
+
+        fn params() -> &'static Self {
+            &TestStoreKey::Upgrade
+        }
+    }
+
+    /// Appends its step to the list of the steps which were run
+    #[derive(Debug, Clone)]
+    struct Step(u8);
+
+    impl Migration<TestStoreKey> for Step {
+        fn migrate<DB: Database>(
+            &self,
+            ctx: &mut BlockContext<'_, DB, TestStoreKey>,
+        ) -> anyhow::Result<()> {
+            let mut store = ctx.kv_store_mut(&TestStoreKey::Upgrade);
+            let mut steps = store.get(&STEPS_KEY).unwrap_or_default();
+            steps.push(self.0);
+            store.set(STEPS_KEY, steps);
+
+            Ok(())
+        }
+    }
+
+    fn migrator() -> Migrator<TestStoreKey, Step> {
+        Migrator::new(ModuleVersionMap::from([
+            ("bank".to_owned(), 3),
+            ("staking".to_owned(), 2),
+        ]))
+        .register_migration("bank", 1, Step(11))
+        .register_migration("bank", 2, Step(12))
+        .register_migration("staking", 1, Step(21))
+    }
+
+    fn run(
+        migrator: &Migrator<TestStoreKey, Step>,
+        from: &[(&str, u64)],
+    ) -> (anyhow::Result<ModuleVersionMap>, Vec<u8>) {
+        let mut multi_store: ApplicationMultiBank<MemDB, TestStoreKey> =
+            ApplicationMultiBank::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+        );
+        let from = from
+            .iter()
+            .map(|(module, version)| (module.to_string(), *version))
+            .collect();
+
+        let result = migrator.run_migrations(&mut ctx, &from);
+        let steps = ctx
+            .kv_store(&TestStoreKey::Upgrade)
+            .get(&STEPS_KEY)
+            .unwrap_or_default();
+
+        (result, steps)
+    }
+
+    #[test]
+    fn migrations_run_in_order() {
+        let migrator = migrator();
+
+        let (result, steps) = run(&migrator, &[("bank", 1), ("staking", 1)]);
+
+        assert_eq!(result.unwrap_test(), *migrator.versions());
+        assert_eq!(steps, vec![11, 12, 21]);
+    }
+
+    #[test]
+    fn up_to_date_and_new_modules_are_not_migrated() {
+        let migrator = migrator();
+
+        let (result, steps) = run(&migrator, &[("bank", 3)]);
+
+        assert_eq!(result.unwrap_test(), *migrator.versions());
+        assert!(steps.is_empty());
+    }
+
+    #[test]
+    fn missing_migration_fails() {
+        let migrator = Migrator::new(ModuleVersionMap::from([("bank".to_owned(), 3)]))
+            .register_migration("bank", 1, Step(11));
+
+        let (result, steps) = run(&migrator, &[("bank", 1)]);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "no migration registered for module bank from version 2"
+        );
+        assert_eq!(steps, vec![11]);
+    }
+
+    #[test]
+    fn downgrade_fails() {
+        let migrator = migrator();
+
+        let (result, steps) = run(&migrator, &[("bank", 4)]);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "module bank is at version 4, which is newer than version 3 of the binary"
+        );
+        assert!(steps.is_empty());
+    }
+}
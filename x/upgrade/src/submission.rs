@@ -0,0 +1,60 @@
+use gears::{
+    context::InfallibleContextMut,
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+};
+use gov::{
+    submission::handler::SubmissionHandlingError, types::proposal::Proposal, ProposalHandler,
+};
+
+use crate::{CancelSoftwareUpgradeProposal, Keeper, SoftwareUpgradeProposal, UpgradeHandler};
+
+/// Handler of the software upgrade proposals which passed the governance
+#[derive(Debug, Clone)]
+pub struct UpgradeProposalHandler<SK: StoreKey, UH: UpgradeHandler<SK>> {
+    keeper: Keeper<SK, UH>,
+}
+
+impl<SK: StoreKey, UH: UpgradeHandler<SK>> UpgradeProposalHandler<SK, UH> {
+    pub fn new(keeper: Keeper<SK, UH>) -> Self {
+        Self { keeper }
+    }
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, UH: UpgradeHandler<SK>>
+    ProposalHandler<SK, PSK, Proposal> for UpgradeProposalHandler<SK, UH>
+{
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: Database>(
+        &self,
+        proposal: &Proposal,
+        ctx: &mut CTX,
+    ) -> Result<(), SubmissionHandlingError> {
+        match proposal.content.type_url.as_str() {
+            SoftwareUpgradeProposal::TYPE_URL => {
+                let msg = SoftwareUpgradeProposal::try_from(proposal.content.clone())?;
+
+                self.keeper
+                    .schedule_upgrade(ctx, msg.plan)
+                    .map_err(|e| SubmissionHandlingError::Handling(e.to_string()))
+            }
+            CancelSoftwareUpgradeProposal::TYPE_URL => {
+                CancelSoftwareUpgradeProposal::try_from(proposal.content.clone())?;
+
+                Ok(self.keeper.clear_upgrade_plan(ctx)?)
+            }
+            _ => Err(SubmissionHandlingError::InvalidProposal),
+        }
+    }
+
+    fn check(proposal: &Proposal) -> bool {
+        match proposal.content.type_url.as_str() {
+            SoftwareUpgradeProposal::TYPE_URL => {
+                SoftwareUpgradeProposal::try_from(proposal.content.clone()).is_ok()
+            }
+            CancelSoftwareUpgradeProposal::TYPE_URL => {
+                CancelSoftwareUpgradeProposal::try_from(proposal.content.clone()).is_ok()
+            }
+            _ => false,
+        }
+    }
+}
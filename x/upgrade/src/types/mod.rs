@@ -0,0 +1,5 @@
+mod plan;
+mod proposal;
+
+pub use plan::*;
+pub use proposal::*;
@@ -0,0 +1,99 @@
+use gears::core::{errors::CoreError, Protobuf};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PlanRaw {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+    #[prost(string, tag = "4")]
+    pub info: String,
+}
+
+impl From<Plan> for PlanRaw {
+    fn from(Plan { name, height, info }: Plan) -> Self {
+        Self {
+            name,
+            height: height.into(),
+            info,
+        }
+    }
+}
+
+/// Plan specifies information about a planned upgrade and when it should occur.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Plan {
+    /// Sets the name for the upgrade. This name will be used by the upgraded
+    /// version of the software to apply any special "on-upgrade" commands during
+    /// the first begin block method after the upgrade is applied.
+    pub name: String,
+    /// The height at which the upgrade must be performed.
+    pub height: u32,
+    /// Any application specific upgrade info to be included on-chain
+    /// such as a git commit that validators could automatically upgrade to
+    pub info: String,
+}
+
+impl Plan {
+    /// should_execute returns true if the plan is ready to execute given the current context
+    pub fn should_execute(&self, height: u32) -> bool {
+        self.height <= height
+    }
+}
+
+impl TryFrom<PlanRaw> for Plan {
+    type Error = CoreError;
+
+    fn try_from(PlanRaw { name, height, info }: PlanRaw) -> Result<Self, Self::Error> {
+        if name.is_empty() {
+            return Err(CoreError::DecodeGeneral("name cannot be empty".to_owned()));
+        }
+
+        let height = u32::try_from(height)
+            .map_err(|_| CoreError::DecodeGeneral(format!("invalid height: {height}")))?;
+        if height == 0 {
+            return Err(CoreError::DecodeGeneral(
+                "height must be greater than 0".to_owned(),
+            ));
+        }
+
+        Ok(Self { name, height, info })
+    }
+}
+
+impl Protobuf<PlanRaw> for Plan {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_from_raw_is_validated() {
+        let raw = PlanRaw {
+            name: "v2".to_owned(),
+            height: 100,
+            info: String::new(),
+        };
+
+        let plan = Plan::try_from(raw.clone()).unwrap();
+        assert!(!plan.should_execute(99));
+        assert!(plan.should_execute(100));
+        assert_eq!(PlanRaw::from(plan), raw);
+
+        for raw in [
+            PlanRaw {
+                name: String::new(),
+                ..raw.clone()
+            },
+            PlanRaw {
+                height: 0,
+                ..raw.clone()
+            },
+            PlanRaw { height: -1, ..raw },
+        ] {
+            assert!(Plan::try_from(raw).is_err());
+        }
+    }
+}
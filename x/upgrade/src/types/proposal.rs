@@ -0,0 +1,154 @@
+use bytes::Bytes;
+use gears::core::{errors::CoreError, Protobuf};
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::{Plan, PlanRaw};
+
+#[derive(Clone, PartialEq, Message)]
+pub struct SoftwareUpgradeProposalRaw {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(message, optional, tag = "3")]
+    pub plan: Option<PlanRaw>,
+}
+
+impl From<SoftwareUpgradeProposal> for SoftwareUpgradeProposalRaw {
+    fn from(
+        SoftwareUpgradeProposal {
+            title,
+            description,
+            plan,
+        }: SoftwareUpgradeProposal,
+    ) -> Self {
+        Self {
+            title,
+            description,
+            plan: Some(plan.into()),
+        }
+    }
+}
+
+/// SoftwareUpgradeProposal is a gov Content type for initiating a software
+/// upgrade.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SoftwareUpgradeProposal {
+    pub title: String,
+    pub description: String,
+    pub plan: Plan,
+}
+
+impl SoftwareUpgradeProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal";
+}
+
+impl TryFrom<SoftwareUpgradeProposalRaw> for SoftwareUpgradeProposal {
+    type Error = CoreError;
+
+    fn try_from(
+        SoftwareUpgradeProposalRaw {
+            title,
+            description,
+            plan,
+        }: SoftwareUpgradeProposalRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            title,
+            description,
+            plan: plan
+                .ok_or(CoreError::MissingField("plan".to_owned()))?
+                .try_into()?,
+        })
+    }
+}
+
+impl Protobuf<SoftwareUpgradeProposalRaw> for SoftwareUpgradeProposal {}
+
+impl TryFrom<Any> for SoftwareUpgradeProposal {
+    type Error = CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        if value.type_url != Self::TYPE_URL {
+            Err(CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            ))?
+        }
+        Self::decode::<Bytes>(value.value.into())
+            .map_err(|e| CoreError::DecodeProtobuf(e.to_string()))
+    }
+}
+
+impl From<SoftwareUpgradeProposal> for Any {
+    fn from(msg: SoftwareUpgradeProposal) -> Self {
+        Any {
+            type_url: SoftwareUpgradeProposal::TYPE_URL.to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct CancelSoftwareUpgradeProposalRaw {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+}
+
+impl From<CancelSoftwareUpgradeProposal> for CancelSoftwareUpgradeProposalRaw {
+    fn from(
+        CancelSoftwareUpgradeProposal { title, description }: CancelSoftwareUpgradeProposal,
+    ) -> Self {
+        Self { title, description }
+    }
+}
+
+/// CancelSoftwareUpgradeProposal is a gov Content type for cancelling a software
+/// upgrade.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CancelSoftwareUpgradeProposal {
+    pub title: String,
+    pub description: String,
+}
+
+impl CancelSoftwareUpgradeProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.upgrade.v1beta1.CancelSoftwareUpgradeProposal";
+}
+
+impl TryFrom<CancelSoftwareUpgradeProposalRaw> for CancelSoftwareUpgradeProposal {
+    type Error = CoreError;
+
+    fn try_from(
+        CancelSoftwareUpgradeProposalRaw { title, description }: CancelSoftwareUpgradeProposalRaw,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self { title, description })
+    }
+}
+
+impl Protobuf<CancelSoftwareUpgradeProposalRaw> for CancelSoftwareUpgradeProposal {}
+
+impl TryFrom<Any> for CancelSoftwareUpgradeProposal {
+    type Error = CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        if value.type_url != Self::TYPE_URL {
+            Err(CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            ))?
+        }
+        Self::decode::<Bytes>(value.value.into())
+            .map_err(|e| CoreError::DecodeProtobuf(e.to_string()))
+    }
+}
+
+impl From<CancelSoftwareUpgradeProposal> for Any {
+    fn from(msg: CancelSoftwareUpgradeProposal) -> Self {
+        Any {
+            type_url: CancelSoftwareUpgradeProposal::TYPE_URL.to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
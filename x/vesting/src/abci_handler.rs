@@ -0,0 +1,37 @@
+use gears::{
+    context::tx::TxContext,
+    store::{database::Database, StoreKey},
+    x::{
+        keepers::{auth::AuthKeeper, vesting::VestingBankKeeper},
+        module::Module,
+    },
+};
+
+use crate::{errors::VestingTxError, Keeper, Message};
+
+#[derive(Debug, Clone)]
+pub struct ABCIHandler<SK: StoreKey, AK: AuthKeeper<SK, M>, BK: VestingBankKeeper<SK, M>, M: Module>
+{
+    keeper: Keeper<SK, AK, BK, M>,
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, BK: VestingBankKeeper<SK, M>, M: Module>
+    ABCIHandler<SK, AK, BK, M>
+{
+    pub fn new(keeper: Keeper<SK, AK, BK, M>) -> Self {
+        ABCIHandler { keeper }
+    }
+
+    pub fn tx<DB: Database + Sync + Send>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: &Message,
+    ) -> Result<(), VestingTxError> {
+        match msg {
+            Message::CreateVestingAccount(msg) => self.keeper.create_vesting_account(ctx, msg),
+            Message::CreatePeriodicVestingAccount(msg) => {
+                self.keeper.create_periodic_vesting_account(ctx, msg)
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use gears::types::{account::Period, address::AccAddress, base::coins::UnsignedCoins};
+use serde::Deserialize;
+
+use crate::{Message, MsgCreatePeriodicVestingAccount, MsgCreateVestingAccount};
+
+#[derive(Args, Debug, Clone)]
+pub struct VestingTxCli {
+    #[command(subcommand)]
+    pub command: VestingCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum VestingCommands {
+    /// Create a new vesting account funded with an allocation of tokens. The
+    /// account can either be a delayed or continuous vesting account, which is determined
+    /// by the '--delayed' flag. All vesting accounts created will have their start time
+    /// set by the committed block's time. The end_time must be provided as a UNIX epoch
+    /// timestamp.
+    CreateVestingAccount {
+        /// to address
+        to_address: AccAddress,
+        /// amount
+        amount: UnsignedCoins,
+        /// end time as a UNIX epoch timestamp
+        end_time: i64,
+        /// Create a delayed vesting account if true
+        #[arg(long, default_value_t = false)]
+        delayed: bool,
+    },
+    /// Create a new periodic vesting account funded with an allocation of tokens. The
+    /// periods are read from a JSON file with the start time as a UNIX epoch timestamp
+    /// and the coins and length in seconds of each period.
+    CreatePeriodicVestingAccount {
+        /// to address
+        to_address: AccAddress,
+        /// path to the JSON file with the vesting periods
+        periods_file: PathBuf,
+    },
+}
+
+/// Input of the periodic vesting account command, e.g.
+/// `{"start_time": 1625204910, "periods": [{"coins": "10stake", "length_seconds": 2592000}]}`
+#[derive(Deserialize, Debug, Clone)]
+struct VestingPeriodsInput {
+    start_time: i64,
+    periods: Vec<PeriodInput>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct PeriodInput {
+    coins: String,
+    length_seconds: i64,
+}
+
+pub fn run_vesting_tx_command(args: VestingTxCli, from_address: AccAddress) -> Result<Message> {
+    match args.command {
+        VestingCommands::CreateVestingAccount {
+            to_address,
+            amount,
+            end_time,
+            delayed,
+        } => Ok(Message::CreateVestingAccount(MsgCreateVestingAccount {
+            from_address,
+            to_address,
+            amount,
+            end_time,
+            delayed,
+        })),
+        VestingCommands::CreatePeriodicVestingAccount {
+            to_address,
+            periods_file,
+        } => {
+            let input: VestingPeriodsInput = serde_json::from_slice(&std::fs::read(periods_file)?)?;
+
+            let vesting_periods = input
+                .periods
+                .into_iter()
+                .map(|period| {
+                    Ok(Period {
+                        length: period.length_seconds,
+                        amount: period.coins.parse::<UnsignedCoins>()?.into_inner(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(Message::CreatePeriodicVestingAccount(
+                MsgCreatePeriodicVestingAccount {
+                    from_address,
+                    to_address,
+                    start_time: input.start_time,
+                    vesting_periods,
+                },
+            ))
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
+    types::{
+        account::VestingError, address::AccAddress, base::errors::CoinsError,
+        store::gas::errors::GasStoreErrors,
+    },
+    x::errors::{AccountNotFound, BankKeeperError},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum VestingTxError {
+    #[error("account {0} already exists")]
+    AccountExists(AccAddress),
+    #[error(transparent)]
+    AccountNotFound(#[from] AccountNotFound),
+    #[error(transparent)]
+    Coins(#[from] CoinsError),
+    #[error(transparent)]
+    Vesting(#[from] VestingError),
+    #[error(transparent)]
+    Bank(#[from] BankKeeperError),
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+}
+
+impl VestingTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        TxError::new::<MI>(self.to_string(), nz::u16!(1))
+    }
+}
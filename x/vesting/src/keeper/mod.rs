@@ -0,0 +1,152 @@
+use std::marker::PhantomData;
+
+use gears::{
+    context::{tx::TxContext, TransactionalContext},
+    store::{database::Database, StoreKey},
+    tendermint::types::proto::event::{Event, EventAttribute},
+    types::{
+        account::{
+            Account, BaseAccount, BaseVestingAccount, ContinuousVestingAccount,
+            DelayedVestingAccount, PeriodicVestingAccount,
+        },
+        address::AccAddress,
+        base::coins::UnsignedCoins,
+        msg::send::MsgSend,
+    },
+    x::{
+        errors::{AccountNotFound, BankKeeperError},
+        keepers::{auth::AuthKeeper, vesting::VestingBankKeeper},
+        module::Module,
+    },
+};
+
+use crate::{errors::VestingTxError, MsgCreatePeriodicVestingAccount, MsgCreateVestingAccount};
+
+/// Keeper of the vesting module. Vesting accounts are stored by the auth module and the
+/// funds are transferred by the bank module, so the keeper has no store of its own.
+#[derive(Debug, Clone)]
+pub struct Keeper<SK: StoreKey, AK: AuthKeeper<SK, M>, BK: VestingBankKeeper<SK, M>, M: Module> {
+    auth_keeper: AK,
+    bank_keeper: BK,
+    _marker: PhantomData<(SK, M)>,
+}
+
+impl<SK: StoreKey, AK: AuthKeeper<SK, M>, BK: VestingBankKeeper<SK, M>, M: Module>
+    Keeper<SK, AK, BK, M>
+{
+    pub fn new(auth_keeper: AK, bank_keeper: BK) -> Self {
+        Self {
+            auth_keeper,
+            bank_keeper,
+            _marker: PhantomData,
+        }
+    }
+
+    /// create_vesting_account creates a continuous or delayed vesting account at the
+    /// recipient address and funds it from the sender
+    pub fn create_vesting_account<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        MsgCreateVestingAccount {
+            from_address,
+            to_address,
+            amount,
+            end_time,
+            delayed,
+        }: &MsgCreateVestingAccount,
+    ) -> Result<(), VestingTxError> {
+        let base_account = self.new_base_account(ctx, to_address)?;
+        let base_vesting_account =
+            BaseVestingAccount::new(base_account, amount.inner().clone(), *end_time);
+
+        let account = if *delayed {
+            Account::DelayedVesting(DelayedVestingAccount::new(base_vesting_account))
+        } else {
+            let start_time = i64::from(ctx.get_time().timestamp_seconds());
+            Account::ContinuousVesting(ContinuousVestingAccount::new(
+                base_vesting_account,
+                start_time,
+            ))
+        };
+        self.auth_keeper.set_account(ctx, account)?;
+
+        self.fund_account(ctx, from_address, to_address, amount.clone())
+    }
+
+    /// create_periodic_vesting_account creates a periodic vesting account at the
+    /// recipient address and funds it with the sum of the coins of all periods
+    pub fn create_periodic_vesting_account<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        MsgCreatePeriodicVestingAccount {
+            from_address,
+            to_address,
+            start_time,
+            vesting_periods,
+        }: &MsgCreatePeriodicVestingAccount,
+    ) -> Result<(), VestingTxError> {
+        let base_account = self.new_base_account(ctx, to_address)?;
+        let account =
+            PeriodicVestingAccount::new(base_account, *start_time, vesting_periods.clone());
+        account.validate()?;
+        let amount = UnsignedCoins::new(account.base_vesting_account.original_vesting.clone())?;
+        self.auth_keeper
+            .set_account(ctx, Account::PeriodicVesting(account))?;
+
+        self.fund_account(ctx, from_address, to_address, amount)
+    }
+
+    /// Creates a new base account for the recipient. Vesting accounts can't be created over
+    /// existing accounts.
+    fn new_base_account<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        address: &AccAddress,
+    ) -> Result<BaseAccount, VestingTxError> {
+        if self.auth_keeper.has_account(ctx, address)? {
+            return Err(VestingTxError::AccountExists(address.clone()));
+        }
+
+        self.auth_keeper.create_new_base_account(ctx, address)?;
+        let account = self
+            .auth_keeper
+            .get_account(ctx, address)?
+            .ok_or(AccountNotFound::from(address.clone()))?;
+
+        Ok(account.base_account().clone())
+    }
+
+    fn fund_account<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        from_address: &AccAddress,
+        to_address: &AccAddress,
+        amount: UnsignedCoins,
+    ) -> Result<(), VestingTxError> {
+        self.bank_keeper
+            .is_send_enabled_coins(ctx, amount.inner())?;
+
+        if self.bank_keeper.blocked_addr(to_address) {
+            Err(BankKeeperError::Blocked(to_address.clone()))?
+        }
+
+        self.bank_keeper.send_coins_from_account_to_account(
+            ctx,
+            &MsgSend {
+                from_address: from_address.clone(),
+                to_address: to_address.clone(),
+                amount,
+            },
+        )?;
+
+        ctx.push_event(Event::new(
+            "message",
+            [
+                EventAttribute::new("module".into(), "vesting".into(), false),
+                EventAttribute::new("sender".into(), from_address.to_string().into(), false),
+            ],
+        ));
+
+        Ok(())
+    }
+}
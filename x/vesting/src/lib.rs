@@ -0,0 +1,12 @@
+mod abci_handler;
+mod client;
+pub mod errors;
+mod keeper;
+mod message;
+mod types;
+
+pub use abci_handler::*;
+pub use client::*;
+pub use keeper::*;
+pub use message::*;
+pub use types::*;
@@ -0,0 +1,13 @@
+use crate::types::{MsgCreatePeriodicVestingAccount, MsgCreateVestingAccount};
+use gears::derive::AppMessage;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, AppMessage)]
+pub enum Message {
+    #[serde(rename = "/cosmos.vesting.v1beta1.MsgCreateVestingAccount")]
+    #[msg(url(path = MsgCreateVestingAccount::TYPE_URL))]
+    CreateVestingAccount(MsgCreateVestingAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.MsgCreatePeriodicVestingAccount")]
+    #[msg(url(path = MsgCreatePeriodicVestingAccount::TYPE_URL))]
+    CreatePeriodicVestingAccount(MsgCreatePeriodicVestingAccount),
+}
@@ -0,0 +1,3 @@
+mod tx;
+
+pub use tx::*;
@@ -0,0 +1,192 @@
+use gears::{
+    core::{errors::CoreError, serializers::serialize_number_to_string, Protobuf},
+    derive::AppMessage,
+    types::{
+        account::{Period, PeriodRaw},
+        address::AccAddress,
+        base::{
+            coin::{inner::Coin as RawCoin, UnsignedCoin},
+            coins::UnsignedCoins,
+            errors::CoinError,
+        },
+    },
+};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_aux::prelude::deserialize_number_from_string;
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgCreateVestingAccountRaw {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<RawCoin>,
+    #[prost(int64, tag = "4")]
+    pub end_time: i64,
+    #[prost(bool, tag = "5")]
+    pub delayed: bool,
+}
+
+impl From<MsgCreateVestingAccount> for MsgCreateVestingAccountRaw {
+    fn from(
+        MsgCreateVestingAccount {
+            from_address,
+            to_address,
+            amount,
+            end_time,
+            delayed,
+        }: MsgCreateVestingAccount,
+    ) -> Self {
+        Self {
+            from_address: from_address.into(),
+            to_address: to_address.into(),
+            amount: amount.into_inner().into_iter().map(Into::into).collect(),
+            end_time,
+            delayed,
+        }
+    }
+}
+
+/// MsgCreateVestingAccount defines a message that enables creating a vesting
+/// account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(url = "/cosmos.vesting.v1beta1.MsgCreateVestingAccount")]
+pub struct MsgCreateVestingAccount {
+    #[msg(signer)]
+    pub from_address: AccAddress,
+    pub to_address: AccAddress,
+    pub amount: UnsignedCoins,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_number_to_string")]
+    pub end_time: i64,
+    #[serde(default)]
+    pub delayed: bool,
+}
+
+impl TryFrom<MsgCreateVestingAccountRaw> for MsgCreateVestingAccount {
+    type Error = CoreError;
+
+    fn try_from(
+        MsgCreateVestingAccountRaw {
+            from_address,
+            to_address,
+            amount,
+            end_time,
+            delayed,
+        }: MsgCreateVestingAccountRaw,
+    ) -> Result<Self, Self::Error> {
+        if end_time <= 0 {
+            return Err(CoreError::DecodeGeneral(
+                "invalid end time: must be positive".into(),
+            ));
+        }
+
+        let amount = amount
+            .into_iter()
+            .map(UnsignedCoin::try_from)
+            .collect::<Result<Vec<_>, CoinError>>()
+            .map_err(|e| CoreError::Coin(e.to_string()))?;
+
+        Ok(Self {
+            from_address: AccAddress::from_bech32(&from_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            to_address: AccAddress::from_bech32(&to_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            amount: UnsignedCoins::new(amount).map_err(|e| CoreError::Coins(e.to_string()))?,
+            end_time,
+            delayed,
+        })
+    }
+}
+
+impl Protobuf<MsgCreateVestingAccountRaw> for MsgCreateVestingAccount {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgCreatePeriodicVestingAccountRaw {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(int64, tag = "3")]
+    pub start_time: i64,
+    #[prost(message, repeated, tag = "4")]
+    pub vesting_periods: Vec<PeriodRaw>,
+}
+
+impl From<MsgCreatePeriodicVestingAccount> for MsgCreatePeriodicVestingAccountRaw {
+    fn from(
+        MsgCreatePeriodicVestingAccount {
+            from_address,
+            to_address,
+            start_time,
+            vesting_periods,
+        }: MsgCreatePeriodicVestingAccount,
+    ) -> Self {
+        Self {
+            from_address: from_address.into(),
+            to_address: to_address.into(),
+            start_time,
+            vesting_periods: vesting_periods.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// MsgCreatePeriodicVestingAccount defines a message that enables creating a
+/// periodic vesting account.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(url = "/cosmos.vesting.v1beta1.MsgCreatePeriodicVestingAccount")]
+pub struct MsgCreatePeriodicVestingAccount {
+    #[msg(signer)]
+    pub from_address: AccAddress,
+    pub to_address: AccAddress,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_number_to_string")]
+    pub start_time: i64,
+    pub vesting_periods: Vec<Period>,
+}
+
+impl TryFrom<MsgCreatePeriodicVestingAccountRaw> for MsgCreatePeriodicVestingAccount {
+    type Error = CoreError;
+
+    fn try_from(
+        MsgCreatePeriodicVestingAccountRaw {
+            from_address,
+            to_address,
+            start_time,
+            vesting_periods,
+        }: MsgCreatePeriodicVestingAccountRaw,
+    ) -> Result<Self, Self::Error> {
+        if start_time < 1 {
+            return Err(CoreError::DecodeGeneral(format!(
+                "invalid start time of {start_time}"
+            )));
+        }
+
+        let vesting_periods = vesting_periods
+            .into_iter()
+            .map(Period::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (i, period) in vesting_periods.iter().enumerate() {
+            if period.length < 1 {
+                return Err(CoreError::DecodeGeneral(format!(
+                    "invalid period length of {} in period {i}",
+                    period.length
+                )));
+            }
+        }
+
+        Ok(Self {
+            from_address: AccAddress::from_bech32(&from_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            to_address: AccAddress::from_bech32(&to_address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            start_time,
+            vesting_periods,
+        })
+    }
+}
+
+impl Protobuf<MsgCreatePeriodicVestingAccountRaw> for MsgCreatePeriodicVestingAccount {}